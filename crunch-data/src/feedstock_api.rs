@@ -0,0 +1,199 @@
+//! `api/feedstock/<name>.json`: one record per feedstock under a stable URL, so pages
+//! and external tools can fetch a single feedstock without the whole dataset.
+//!
+//! Stability: like `status.json`, fields are only ever added within a `version`.
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Bump on any breaking change to the format below
+pub const FEEDSTOCK_API_VERSION: u32 = 1;
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct FeedstockRecord {
+    pub version: u32,
+    /// Feedstock name, e.g. "numpy-feedstock"
+    pub name: String,
+    /// "recipe_v1", "non_standard_v1", "meta_yaml" or "unknown"
+    pub recipe_type: String,
+    /// When the collector first saw the current recipe type (RFC 3339)
+    pub last_changed: String,
+    pub downloads: Option<i64>,
+    pub maintainers: Vec<String>,
+    pub summary: Option<String>,
+    /// Recipe path of a near-miss Recipe v1 filename, e.g. "recipe/recipe.yml"
+    pub recipe_file: Option<String>,
+    pub recipe_features: Vec<String>,
+    /// Latest CI state of the default branch, Recipe v1 only
+    pub build_state: Option<String>,
+    pub rattler_build_pin: Option<String>,
+    pub attribution: Option<Attribution>,
+    pub revert: Option<Revert>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Attribution {
+    /// "conversion" or "new_feedstock"
+    pub contribution_type: String,
+    pub contributors: Vec<String>,
+    pub date: String,
+    pub commit_sha: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Revert {
+    pub converted_at: String,
+    pub reverted_at: String,
+}
+
+fn string(table: &toml::Table, key: &str) -> Option<String> {
+    table.get(key).and_then(|v| v.as_str()).map(String::from)
+}
+
+fn strings(table: &toml::Table, key: &str) -> Vec<String> {
+    table
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The public record of the `feedstock_states` entry `entry`
+pub fn feedstock_record(name: &str, entry: &toml::Table) -> FeedstockRecord {
+    let attribution = entry
+        .get("attribution")
+        .and_then(|v| v.as_table())
+        .map(|attribution| Attribution {
+            contribution_type: string(attribution, "contribution_type").unwrap_or_default(),
+            contributors: strings(attribution, "contributors"),
+            date: string(attribution, "date").unwrap_or_default(),
+            commit_sha: string(attribution, "commit_sha"),
+        });
+    let revert = entry
+        .get("revert")
+        .and_then(|v| v.as_table())
+        .map(|revert| Revert {
+            converted_at: string(revert, "converted_at").unwrap_or_default(),
+            reverted_at: string(revert, "reverted_at").unwrap_or_default(),
+        });
+    FeedstockRecord {
+        version: FEEDSTOCK_API_VERSION,
+        name: name.to_string(),
+        recipe_type: string(entry, "recipe_type").unwrap_or_else(|| "unknown".to_string()),
+        last_changed: string(entry, "last_changed").unwrap_or_default(),
+        downloads: entry.get("downloads").and_then(|v| v.as_integer()),
+        maintainers: strings(entry, "maintainers"),
+        summary: string(entry, "summary"),
+        recipe_file: string(entry, "recipe_file"),
+        recipe_features: strings(entry, "recipe_features"),
+        build_state: string(entry, "build_state"),
+        rattler_build_pin: string(entry, "rattler_build_pin"),
+        attribution,
+        revert,
+    }
+}
+
+/// Whether `name` can be used as a file name as is
+fn is_safe_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && !name.contains(['/', '\\'])
+}
+
+/// Write a record per feedstock into `dir`, and remove the records of feedstocks that
+/// are gone. Returns how many records were written.
+pub fn write_feedstock_api(dir: &Path, feedstocks: &toml::Table) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+    let mut written = HashSet::new();
+    for (name, entry) in feedstocks {
+        let Some(entry) = entry.as_table().filter(|_| is_safe_name(name)) else {
+            continue;
+        };
+        let file_name = format!("{}.json", name);
+        let record = serde_json::to_string(&feedstock_record(name, entry))?;
+        fs::write(dir.join(&file_name), record)?;
+        written.insert(file_name);
+    }
+
+    for file in fs::read_dir(dir)? {
+        let path = file?.path();
+        let stale = path.extension().is_some_and(|ext| ext == "json")
+            && path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| !written.contains(name));
+        if stale {
+            fs::remove_file(path)?;
+        }
+    }
+    Ok(written.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_feedstock_api() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [numpy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-03-01T00:00:00Z"
+            downloads = 1000
+            maintainers = ["alice"]
+            recipe_features = ["multiple-outputs"]
+
+            [numpy-feedstock.attribution]
+            contribution_type = "conversion"
+            contributors = ["bob"]
+            date = "2025-03-01T00:00:00Z"
+
+            [numpy-feedstock.recipe_commit_cache]
+            sha = "abc"
+
+            [scipy-feedstock]
+            recipe_type = "meta_yaml"
+            last_changed = "2025-01-01T00:00:00Z"
+
+            ["../escape"]
+            recipe_type = "meta_yaml"
+            "#,
+        )
+        .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let api_dir = dir.path().join("api/feedstock");
+        fs::create_dir_all(&api_dir).unwrap();
+        fs::write(api_dir.join("gone-feedstock.json"), "{}").unwrap();
+
+        assert_eq!(write_feedstock_api(&api_dir, &feedstocks).unwrap(), 2);
+        assert!(!api_dir.join("gone-feedstock.json").exists());
+        assert!(!dir.path().join("api/escape.json").exists());
+
+        let numpy: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(api_dir.join("numpy-feedstock.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(numpy["version"], FEEDSTOCK_API_VERSION);
+        assert_eq!(numpy["recipe_type"], "recipe_v1");
+        assert_eq!(numpy["downloads"], 1000);
+        assert_eq!(numpy["attribution"]["contributors"][0], "bob");
+        assert!(numpy["attribution"]["commit_sha"].is_null());
+        assert!(numpy.get("recipe_commit_cache").is_none());
+
+        let scipy = feedstock_record(
+            "scipy-feedstock",
+            feedstocks["scipy-feedstock"].as_table().unwrap(),
+        );
+        assert_eq!(scipy.downloads, None);
+        assert_eq!(scipy.attribution, None);
+        assert!(scipy.maintainers.is_empty());
+    }
+}
@@ -1,25 +1,214 @@
-use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::collections::{btree_map, BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use site_schema::{achievements, schema};
+
+mod feedstock_api;
+mod newsletter;
+mod noscript;
+mod status;
+mod weekly_totals;
+
+/// Weeks of per-contributor activity exported for the web sparkline unless set with
+/// `--activity-weeks`. Longer ranges are drawn from `CONTRIBUTIONS_FILE`, which the site
+/// only fetches when asked to.
+const DEFAULT_ACTIVITY_WEEKS: usize = 52;
+
+/// Recently updated Recipe v1 feedstocks are those changed within this many days...
+const RECENT_WINDOW_DAYS: i64 = 14;
+/// ...at most this many of them...
+const RECENT_MAX_ENTRIES: usize = 100;
+/// ...but at least this many, so quiet weeks don't leave the list empty
+const RECENT_MIN_ENTRIES: usize = 10;
+
+/// Unconverted feedstocks pushed to within this many days of the collection count as
+/// actively maintained
+const ACTIVE_WINDOW_DAYS: i64 = 90;
+
+/// Conversions of packages with more downloads than this go to the landing page ticker...
+const NOTABLE_MIN_DOWNLOADS: i64 = 1_000_000;
+/// ...newest first, at most this many
+const NOTABLE_RECENT_ENTRIES: usize = 10;
+
+/// Months after conversion at which each conversion cohort's retention is reported
+const COHORT_MONTHS: [u32; 4] = [1, 3, 6, 12];
+
+/// Maintainers of fewer feedstocks are left out of the completionist ranking, where
+/// converting a single package would otherwise mean 100%
+const COMPLETIONIST_MIN_MAINTAINED: u32 = 5;
+/// Number of completionists exported for the web
+const COMPLETIONIST_LIMIT: usize = 20;
+
+/// Directory (relative to the workspace root) holding one crunched snapshot per day,
+/// as `data/YYYY-MM-DD/stats.toml`, plus a `latest.toml` manifest pointing at the newest
+const SNAPSHOT_DIR: &str = "data";
+
+/// Opt-in contributor profiles (relative to the workspace root), keyed by GitHub login
+const CONTRIBUTORS_FILE: &str = "contributors.toml";
+
+/// GitHub names and avatars cached by the collector's `resolve-contributors`, next to the
+/// input stats file
+const IDENTITIES_FILE: &str = "contributor-identities.json";
+
+/// Longest display name or affiliation accepted from `contributors.toml`
+const MAX_PROFILE_TEXT: usize = 64;
+
+/// Hand-maintained list of notable ecosystem dates (relative to the workspace root),
+/// shown as annotations on the site's charts
+const EVENTS_FILE: &str = "events.toml";
+
+/// Fallback for visitors without WebAssembly, written next to the site data and inlined
+/// into index.html by trunk
+const NOSCRIPT_FILE: &str = "noscript.html";
+
+/// Weekly digest e-mail (relative to the workspace root), picked up as a CI artifact
+const NEWSLETTER_FILE: &str = "newsletter.html";
+
+/// Achievements earned per contributor, published with the snapshots so people can embed
+/// their badges elsewhere
+const ACHIEVEMENTS_FILE: &str = "achievements.json";
+
+/// Every achievement unlock across contributors, newest first, published with the snapshots
+const ACHIEVEMENT_FEED_FILE: &str = "achievement-feed.json";
+
+/// Latest unlocks shown on the site
+const ACHIEVEMENT_FEED_ENTRIES: usize = 8;
+
+/// Unknown fields listed when a crunched file doesn't match the site's schema
+const SCHEMA_MISMATCHES_SHOWN: usize = 5;
+
+/// Compact, versioned summary for conda-forge's status page, published with the snapshots
+const STATUS_FILE: &str = "status.json";
+
+/// Every dated contribution per contributor, published with the snapshots and fetched by
+/// the site's contributor details on demand
+const CONTRIBUTIONS_FILE: &str = "contributions.toml";
+
+/// Converted and unconverted feedstocks of every recipe maintainer, published with the
+/// snapshots for the site's maintainer search and for outreach lists
+const MAINTAINER_COVERAGE_FILE: &str = "maintainer-coverage.toml";
+
+/// Sections only shown on the site's insights page, published with the snapshots and
+/// fetched when the page is opened rather than embedded in the bundle
+const INSIGHTS_FILE: &str = "insights.toml";
+const INSIGHTS_SECTIONS: [&str; 5] = [
+    "contributor_retention",
+    "contributor_mix",
+    "conversion_cohorts",
+    "bot_activity_rates",
+    "conversion_forecast",
+];
+
+/// The `[data_quality]` report, published with the snapshots and fetched by the site's
+/// data quality page when it is opened
+const DATA_QUALITY_FILE: &str = "data-quality.toml";
+
+/// Conversions, new feedstocks and the Recipe v1 share per week as CSV, published with
+/// the snapshots for spreadsheets to import
+const WEEKLY_TOTALS_FILE: &str = "weekly_totals.csv";
+
+/// One JSON record per feedstock, as `<name>.json`, published with the snapshots so a
+/// single feedstock can be fetched without the whole dataset
+const FEEDSTOCK_API_DIR: &str = "api/feedstock";
+
+/// Command line options. Without any, the collector's `feedstock-stats.toml` is crunched
+/// into `web/src/stats.toml` along with the snapshot, achievements and newsletter.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+    /// Directory to read and write everything relative to instead of the workspace root,
+    /// for a crunch-data binary installed outside the checkout
+    root: Option<PathBuf>,
+    /// Weeks of activity per contributor, `DEFAULT_ACTIVITY_WEEKS` if not given
+    activity_weeks: Option<usize>,
+    /// Only write the site data, e.g. when testing the site against an old snapshot
+    only_web: bool,
+}
+
+const USAGE: &str = "usage: crunch-data [--input <feedstock-stats.toml>] [--output <stats.toml>] \
+     [--root <dir>] [--activity-weeks <n>] [--only-web]";
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "--output" | "--root" => {
+                let path = args
+                    .next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| format!("{} needs a path", arg))?;
+                match arg.as_str() {
+                    "--input" => parsed.input = Some(path),
+                    "--output" => parsed.output = Some(path),
+                    _ => parsed.root = Some(path),
+                }
+            }
+            "--activity-weeks" => {
+                let weeks = args
+                    .next()
+                    .and_then(|weeks| weeks.parse().ok())
+                    .filter(|weeks| *weeks > 0)
+                    .ok_or_else(|| format!("{} needs a positive number of weeks", arg))?;
+                parsed.activity_weeks = Some(weeks);
+            }
+            "--only-web" => parsed.only_web = true,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(parsed)
+}
 
 fn main() {
+    let args = parse_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {}\n{}", e, USAGE);
+        std::process::exit(2);
+    });
+
+    let activity_weeks = args.activity_weeks.unwrap_or(DEFAULT_ACTIVITY_WEEKS);
     let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
-    let workspace_root = manifest_dir.parent().unwrap();
+    let workspace_root = args
+        .root
+        .as_deref()
+        .unwrap_or_else(|| manifest_dir.parent().unwrap());
 
-    let input_path = workspace_root.join("feedstock-stats.toml");
-    let output_path = workspace_root.join("web/src/stats.toml");
+    let input_path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| workspace_root.join("feedstock-stats.toml"));
+    let output_path = args
+        .output
+        .clone()
+        .unwrap_or_else(|| workspace_root.join("web/src/stats.toml"));
+
+    // A missing default input just means the collector hasn't run yet
+    let content = fs::read_to_string(&input_path);
+    if let (Err(e), Some(_)) = (&content, &args.input) {
+        eprintln!("error: failed to read {}: {}", input_path.display(), e);
+        std::process::exit(1);
+    }
 
-    if let Ok(content) = fs::read_to_string(&input_path) {
-        if let Ok(toml_data) = toml::from_str::<toml::Table>(&content) {
+    if let Ok(content) = content {
+        if let Ok(mut toml_data) = toml::from_str::<toml::Table>(&content) {
+            merge_feedstock_shards(&mut toml_data, &input_path);
             let mut summary = toml::Table::new();
+            let collected_at = toml_data
+                .get("last_updated")
+                .and_then(|v| v.as_str())
+                .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+                .map_or_else(Utc::now, |d| d.with_timezone(&Utc));
 
             // Extract only the summary fields we need
             if let Some(total) = toml_data.get("total_feedstocks") {
                 summary.insert("total_feedstocks".to_string(), total.clone());
             }
-            if let Some(v1_count) = toml_data.get("recipe_v1_count") {
-                summary.insert("recipe_v1_count".to_string(), v1_count.clone());
+            if let Some(v1_count) = recipe_v1_count(&toml_data) {
+                summary.insert(
+                    "recipe_v1_count".to_string(),
+                    toml::Value::Integer(v1_count),
+                );
             }
             if let Some(meta_count) = toml_data.get("meta_yaml_count") {
                 summary.insert("meta_yaml_count".to_string(), meta_count.clone());
@@ -27,59 +216,607 @@ fn main() {
             if let Some(unknown) = toml_data.get("unknown_count") {
                 summary.insert("unknown_count".to_string(), unknown.clone());
             }
+            if let Some(non_standard) = toml_data.get("non_standard_v1_count") {
+                summary.insert("non_standard_v1_count".to_string(), non_standard.clone());
+            }
             if let Some(updated) = toml_data.get("last_updated") {
                 summary.insert("last_updated".to_string(), updated.clone());
             }
+            summary.insert(
+                "activity_weeks".to_string(),
+                toml::Value::Integer(activity_weeks as i64),
+            );
+            summary.insert(
+                "recently_updated_days".to_string(),
+                toml::Value::Integer(RECENT_WINDOW_DAYS),
+            );
 
             // Process feedstock states for recent updates and leaderboard
             if let Some(feedstocks) = toml_data.get("feedstock_states") {
                 if let Some(feedstocks_table) = feedstocks.as_table() {
                     // Generate recently updated feedstocks
-                    let recent_table = extract_recently_updated(feedstocks_table);
+                    let recent_table = extract_recently_updated(feedstocks_table, Utc::now());
                     summary.insert(
                         "recently_updated".to_string(),
                         toml::Value::Table(recent_table),
                     );
 
-                    // Generate leaderboard from attributions
-                    let top_contributors = extract_top_contributors(feedstocks_table);
+                    // Generate leaderboard from attributions, with the profiles people added
+                    let mut top_contributors =
+                        extract_top_contributors(feedstocks_table, activity_weeks);
+                    if let Ok(profiles) = fs::read_to_string(workspace_root.join(CONTRIBUTORS_FILE))
+                    {
+                        let profiles = toml::from_str::<toml::Table>(&profiles)
+                            .expect("Failed to parse contributors.toml");
+                        add_contributor_profiles(&mut top_contributors, &profiles);
+                    }
+                    if let Ok(identities) =
+                        fs::read_to_string(input_path.with_file_name(IDENTITIES_FILE))
+                    {
+                        let identities = serde_json::from_str(&identities)
+                            .expect("Failed to parse contributor-identities.json");
+                        add_contributor_identities(&mut top_contributors, &identities);
+                    }
+
+                    // Movement since the leaderboard of a week ago
+                    let snapshot_dir = workspace_root.join(SNAPSHOT_DIR);
+                    if let Some((date, previous)) =
+                        previous_week_snapshot(&snapshot_dir, collected_at.date_naive())
+                    {
+                        let previous = previous
+                            .get("top_contributors")
+                            .and_then(|v| v.as_array())
+                            .map(Vec::as_slice)
+                            .unwrap_or_default();
+                        add_rank_changes(&mut top_contributors, previous);
+                        summary.insert(
+                            "rank_change_since".to_string(),
+                            toml::Value::String(date.to_string()),
+                        );
+                    }
                     summary.insert(
                         "top_contributors".to_string(),
                         toml::Value::Array(top_contributors),
                     );
+
+                    // Latest badges earned by anyone, for the feed on the site
+                    let feed = extract_achievement_feed(feedstocks_table);
+                    summary.insert(
+                        "achievement_feed".to_string(),
+                        toml::Value::Array(
+                            feed.iter()
+                                .take(ACHIEVEMENT_FEED_ENTRIES)
+                                .map(|(login, achievement, date)| {
+                                    toml::Value::Table(toml::Table::from_iter([
+                                        ("login".to_string(), login.as_str().into()),
+                                        ("achievement".to_string(), achievement.id.into()),
+                                        ("date".to_string(), date.as_str().into()),
+                                    ]))
+                                })
+                                .collect(),
+                        ),
+                    );
+
+                    // Badges earned by every contributor, with the date each was earned
+                    if !args.only_web {
+                        let feed_path = workspace_root
+                            .join(SNAPSHOT_DIR)
+                            .join(ACHIEVEMENT_FEED_FILE);
+                        let feed: Vec<_> = feed
+                            .iter()
+                            .map(|(login, achievement, date)| {
+                                serde_json::json!({
+                                    "login": login,
+                                    "id": achievement.id,
+                                    "emoji": achievement.emoji,
+                                    "threshold": achievement.threshold,
+                                    "earned": date,
+                                })
+                            })
+                            .collect();
+                        fs::create_dir_all(workspace_root.join(SNAPSHOT_DIR))
+                            .and_then(|_| {
+                                fs::write(&feed_path, serde_json::to_string_pretty(&feed).unwrap())
+                            })
+                            .expect("Failed to write achievement-feed.json");
+                        println!("📣 Achievement feed written to {}", feed_path.display());
+
+                        let achievements = extract_achievements(feedstocks_table);
+                        let achievements_path =
+                            workspace_root.join(SNAPSHOT_DIR).join(ACHIEVEMENTS_FILE);
+                        fs::create_dir_all(workspace_root.join(SNAPSHOT_DIR))
+                            .and_then(|_| {
+                                fs::write(
+                                    &achievements_path,
+                                    serde_json::to_string_pretty(&achievements).unwrap(),
+                                )
+                            })
+                            .expect("Failed to write achievements.json");
+                        println!("🏅 Achievements written to {}", achievements_path.display());
+
+                        let history = extract_contribution_history(feedstocks_table, Utc::now());
+                        let history_path =
+                            workspace_root.join(SNAPSHOT_DIR).join(CONTRIBUTIONS_FILE);
+                        let history_toml = toml::to_string(&history).unwrap();
+                        check_schema::<schema::ContributionsFile>(
+                            &history_toml,
+                            CONTRIBUTIONS_FILE,
+                        );
+                        fs::write(&history_path, history_toml)
+                            .expect("Failed to write contributions.toml");
+                        println!(
+                            "🗓️  Contribution history written to {}",
+                            history_path.display()
+                        );
+
+                        let coverage = extract_maintainer_coverage(feedstocks_table, Utc::now());
+                        let coverage_path = workspace_root
+                            .join(SNAPSHOT_DIR)
+                            .join(MAINTAINER_COVERAGE_FILE);
+                        let coverage_toml = toml::to_string(&coverage).unwrap();
+                        check_schema::<schema::MaintainerCoverageFile>(
+                            &coverage_toml,
+                            MAINTAINER_COVERAGE_FILE,
+                        );
+                        fs::write(&coverage_path, coverage_toml)
+                            .expect("Failed to write maintainer-coverage.toml");
+                        println!(
+                            "👥 Maintainer coverage written to {}",
+                            coverage_path.display()
+                        );
+
+                        let api_dir = workspace_root.join(SNAPSHOT_DIR).join(FEEDSTOCK_API_DIR);
+                        let records =
+                            feedstock_api::write_feedstock_api(&api_dir, feedstocks_table)
+                                .expect("Failed to write the feedstock records");
+                        println!(
+                            "🗂️  {} feedstock records written to {}",
+                            records,
+                            api_dir.display()
+                        );
+                    }
+
+                    // Highlight of the most downloaded package converted so far
+                    if let Some(biggest) = extract_biggest_conversion(feedstocks_table) {
+                        summary.insert(
+                            "biggest_conversion".to_string(),
+                            toml::Value::Table(biggest),
+                        );
+                    }
+
+                    // Latest conversions of big packages, for the landing page ticker
+                    summary.insert(
+                        "notable_recent".to_string(),
+                        toml::Value::Array(extract_notable_recent(feedstocks_table)),
+                    );
+
+                    // Maintainers who moved the largest share of their own feedstocks to v1
+                    summary.insert(
+                        "completionists".to_string(),
+                        toml::Value::Array(extract_completionists(feedstocks_table)),
+                    );
+
+                    // Conversions done by one of the feedstock's own maintainers
+                    summary.insert(
+                        "self_conversions".to_string(),
+                        toml::Value::Integer(count_self_conversions(feedstocks_table) as i64),
+                    );
+
+                    // Cumulative unique contributors per month, across all contributors
+                    let (total_contributors, growth) = extract_contributor_growth(feedstocks_table);
+                    summary.insert(
+                        "total_contributors".to_string(),
+                        toml::Value::Integer(total_contributors as i64),
+                    );
+                    summary.insert("contributor_growth".to_string(), toml::Value::Array(growth));
+
+                    // Month over month retention and churn of active contributors
+                    summary.insert(
+                        "contributor_retention".to_string(),
+                        toml::Value::Array(extract_contributor_retention(feedstocks_table)),
+                    );
+
+                    // Is the migration broadening participation or relying on a core group?
+                    summary.insert(
+                        "contributor_mix".to_string(),
+                        toml::Value::Array(extract_contributor_mix(feedstocks_table)),
+                    );
+
+                    // How many conversions of each month are still on v1 and building
+                    summary.insert(
+                        "conversion_cohorts".to_string(),
+                        toml::Value::Array(extract_conversion_cohorts(
+                            feedstocks_table,
+                            Utc::now(),
+                        )),
+                    );
+
+                    // Is v1 growing through new packages or through migrations?
+                    summary.insert(
+                        "weekly_v1_growth".to_string(),
+                        toml::Value::Array(extract_weekly_v1_growth(feedstocks_table, Utc::now())),
+                    );
+
+                    // List Recipe v1 feedstocks that need their recipe file renamed
+                    let non_standard = extract_non_standard_recipes(feedstocks_table);
+                    summary.insert(
+                        "non_standard_recipes".to_string(),
+                        toml::Value::Array(non_standard),
+                    );
+
+                    // Bot migration PRs that only need a maintainer to press merge
+                    let pending = toml_data
+                        .get("pending_migration_prs")
+                        .and_then(|p| p.as_array())
+                        .map(Vec::as_slice)
+                        .unwrap_or_default();
+                    summary.insert(
+                        "pending_migrations".to_string(),
+                        toml::Value::Array(extract_pending_migrations(pending, feedstocks_table)),
+                    );
+
+                    // How complete and trustworthy the numbers above are
+                    summary.insert(
+                        "data_quality".to_string(),
+                        toml::Value::Table(extract_data_quality(feedstocks_table)),
+                    );
                 }
             }
 
-            // Include top unconverted feedstocks by downloads
+            // Include top unconverted feedstocks by downloads, with conversion links
             if let Some(top_unconverted) = toml_data.get("top_unconverted_by_downloads") {
-                summary.insert(
-                    "top_unconverted_by_downloads".to_string(),
-                    top_unconverted.clone(),
-                );
+                let mut top_unconverted = top_unconverted.clone();
+                for entry in top_unconverted.as_array_mut().into_iter().flatten() {
+                    if let Some(table) = entry.as_table_mut() {
+                        add_conversion_links(table);
+                        add_activity_flag(table, collected_at);
+                    }
+                }
+                summary.insert("top_unconverted_by_downloads".to_string(), top_unconverted);
+            }
+
+            // Include migration quality metrics (e.g. conversion reverts)
+            if let Some(quality_metrics) = toml_data.get("quality_metrics") {
+                summary.insert("quality_metrics".to_string(), quality_metrics.clone());
+            }
+
+            // Include maintainer count vs. conversion analysis
+            if let Some(rates) = toml_data.get("maintainer_conversion_rates") {
+                summary.insert("maintainer_conversion_rates".to_string(), rates.clone());
+            }
+
+            // Include recipe features most associated with staying unconverted
+            if let Some(rates) = toml_data.get("recipe_feature_rates") {
+                summary.insert("recipe_feature_rates".to_string(), rates.clone());
+            }
+
+            // Include conversion status by how often the bot rerenders feedstocks
+            if let Some(rates) = toml_data.get("bot_activity_rates") {
+                summary.insert("bot_activity_rates".to_string(), rates.clone());
+            }
+
+            // Include rattler-build version adoption among Recipe v1 feedstocks
+            if let Some(adoption) = toml_data.get("rattler_build_adoption") {
+                summary.insert("rattler_build_adoption".to_string(), adoption.clone());
+            }
+
+            // Include conversion status changes among the top feedstocks
+            if let Some(alerts) = toml_data.get("top_feedstock_alerts") {
+                summary.insert("top_feedstock_alerts".to_string(), alerts.clone());
+            }
+
+            // Include the experimental forecast of the next conversions
+            if let Some(forecast) = toml_data.get("conversion_forecast") {
+                summary.insert("conversion_forecast".to_string(), forecast.clone());
+            }
+
+            // Include sections contributed by the collector's analysis hooks
+            if let Some(analyses) = toml_data.get("analyses") {
+                summary.insert("analyses".to_string(), analyses.clone());
+            }
+
+            // Include cf-graph coverage of conda-forge's feedstocks
+            if let Some(coverage) = toml_data.get("coverage") {
+                summary.insert("coverage".to_string(), coverage.clone());
+            }
+
+            // Include the CI state of converted feedstocks
+            if let Some(build_health) = toml_data.get("build_health") {
+                summary.insert("build_health".to_string(), build_health.clone());
+            }
+
+            // Include how many converted recipes pass lint
+            if let Some(recipe_lint) = toml_data.get("recipe_lint") {
+                summary.insert("recipe_lint".to_string(), recipe_lint.clone());
+            }
+
+            // Include the migration progress weighted by dependents
+            if let Some(weighted) = toml_data.get("dependency_weighted") {
+                summary.insert("dependency_weighted".to_string(), weighted.clone());
+            }
+
+            // Annotate the charts with notable ecosystem events
+            if let Ok(events) = fs::read_to_string(workspace_root.join(EVENTS_FILE)) {
+                let events = toml::from_str::<toml::Table>(&events)
+                    .map(|events| extract_events(&events, Utc::now()))
+                    .expect("Failed to parse events.toml");
+                summary.insert("events".to_string(), toml::Value::Array(events));
+            }
+
+            // Sections of the secondary pages go to their own files, so the site's first
+            // load only carries what the dashboard shows
+            let snapshot_dir = workspace_root.join(SNAPSHOT_DIR);
+            fs::create_dir_all(&snapshot_dir).expect("Failed to create the snapshot directory");
+            let insights_toml =
+                toml::to_string(&split_sections(&mut summary, &INSIGHTS_SECTIONS)).unwrap();
+            check_schema::<schema::InsightsFile>(&insights_toml, INSIGHTS_FILE);
+            fs::write(snapshot_dir.join(INSIGHTS_FILE), insights_toml)
+                .expect("Failed to write insights.toml");
+            if let Some(toml::Value::Table(quality)) = summary.remove("data_quality") {
+                let quality_toml = toml::to_string(&quality).unwrap();
+                check_schema::<schema::DataQuality>(&quality_toml, DATA_QUALITY_FILE);
+                fs::write(snapshot_dir.join(DATA_QUALITY_FILE), quality_toml)
+                    .expect("Failed to write data-quality.toml");
             }
 
             // Write the complete summary
             let summary_toml = toml::to_string(&summary).unwrap();
-            fs::write(&output_path, summary_toml).expect("Failed to write summary");
+            check_schema::<schema::StatsFile>(&summary_toml, "stats.toml");
+            if let Some(dir) = output_path.parent() {
+                fs::create_dir_all(dir).expect("Failed to create the output directory");
+            }
+            fs::write(&output_path, &summary_toml).expect("Failed to write summary");
             println!(
                 "✅ Crunched feedstock stats written to {}",
                 output_path.display()
             );
+            let noscript_path = output_path.with_file_name(NOSCRIPT_FILE);
+            fs::write(&noscript_path, noscript::render_noscript(&summary))
+                .expect("Failed to write noscript fallback");
+            if args.only_web {
+                return;
+            }
+
+            // Publish a dated snapshot, named after the day the data was collected
+            let snapshot_date = collected_at.format("%Y-%m-%d").to_string();
+            let snapshot_path = write_snapshot(
+                &workspace_root.join(SNAPSHOT_DIR),
+                &snapshot_date,
+                &summary_toml,
+            )
+            .expect("Failed to write snapshot");
+            println!("📸 Snapshot written to {}", snapshot_path.display());
+
+            // Headline numbers and their change over the last week, for other dashboards
+            let previous = previous_week_snapshot(&snapshot_dir, collected_at.date_naive());
+            let status = status::status(
+                &summary,
+                previous.as_ref().map(|(date, stats)| (*date, stats)),
+            );
+            let status_path = snapshot_dir.join(STATUS_FILE);
+            fs::write(&status_path, serde_json::to_string_pretty(&status).unwrap())
+                .expect("Failed to write status.json");
+            println!("🚦 Status written to {}", status_path.display());
+
+            let weekly_totals_path = snapshot_dir.join(WEEKLY_TOTALS_FILE);
+            fs::write(
+                &weekly_totals_path,
+                weekly_totals::render_weekly_totals(&summary),
+            )
+            .expect("Failed to write weekly_totals.csv");
+            println!(
+                "📈 Weekly totals written to {}",
+                weekly_totals_path.display()
+            );
+
+            // Render the weekly digest e-mail for the mailing workflow
+            if let Some(feedstocks_table) =
+                toml_data.get("feedstock_states").and_then(|f| f.as_table())
+            {
+                let site_url = std::env::var("SITE_URL").ok().filter(|url| !url.is_empty());
+                let html = newsletter::render_newsletter(
+                    &summary,
+                    feedstocks_table,
+                    Utc::now(),
+                    site_url.as_deref(),
+                );
+                let newsletter_path = workspace_root.join(NEWSLETTER_FILE);
+                fs::write(&newsletter_path, html).expect("Failed to write newsletter");
+                println!("📰 Newsletter written to {}", newsletter_path.display());
+            }
+        }
+    }
+}
+
+/// Move the `sections` present in `summary` into a table of their own
+fn split_sections(summary: &mut toml::Table, sections: &[&str]) -> toml::Table {
+    sections
+        .iter()
+        .filter_map(|section| Some((section.to_string(), summary.remove(*section)?)))
+        .collect()
+}
+
+/// Exit before writing `content` as `file_name` if the site couldn't read it, so a
+/// change to the crunched shape fails here rather than blanking sections of the site
+fn check_schema<T: serde::de::DeserializeOwned>(content: &str, file_name: &str) {
+    if let Some(mismatch) = schema_mismatch::<T>(content) {
+        eprintln!(
+            "error: crunched {} doesn't match site-schema/src/schema.rs: {}",
+            file_name, mismatch
+        );
+        std::process::exit(1);
+    }
+}
+
+/// Why `content` doesn't match `T`, None if it does. Unlike the site, which skips
+/// fields it doesn't know so newer snapshots stay readable, fields missing from `T`
+/// count as a mismatch: they are most likely a misspelled section.
+fn schema_mismatch<T: serde::de::DeserializeOwned>(content: &str) -> Option<String> {
+    let mut unknown = Vec::new();
+    let parsed: Result<T, _> =
+        serde_ignored::deserialize(toml::Deserializer::new(content), |path| {
+            unknown.push(path.to_string())
+        });
+    match parsed {
+        Err(e) => Some(e.to_string()),
+        Ok(_) if !unknown.is_empty() => {
+            let more = match unknown.len() {
+                0..=SCHEMA_MISMATCHES_SHOWN => String::new(),
+                n => format!(" and {} more", n - SCHEMA_MISMATCHES_SHOWN),
+            };
+            unknown.truncate(SCHEMA_MISMATCHES_SHOWN);
+            Some(format!(
+                "the site doesn't read {}{}",
+                unknown.join(", "),
+                more
+            ))
+        }
+        Ok(_) => None,
+    }
+}
+
+/// Add the `feedstock_states` of the shards listed in `feedstock_shards` (relative to
+/// `stats_path`), for stats the collector wrote with its `sharded://` store
+fn merge_feedstock_shards(stats: &mut toml::Table, stats_path: &Path) {
+    let shards: Vec<String> = stats
+        .get("feedstock_shards")
+        .and_then(|v| v.as_array())
+        .map(|files| {
+            files
+                .iter()
+                .filter_map(|f| f.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    for file in shards {
+        let path = stats_path.with_file_name(&file);
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+        let mut shard = toml::from_str::<toml::Table>(&content)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e));
+        let Some(toml::Value::Table(states)) = shard.remove("feedstock_states") else {
+            continue;
+        };
+        if let toml::Value::Table(all) = stats
+            .entry("feedstock_states")
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+        {
+            all.extend(states);
         }
     }
 }
 
-/// Extract the 10 most recently updated Recipe v1 feedstocks with attribution
-fn extract_recently_updated(feedstocks_table: &toml::Table) -> toml::Table {
+/// Write `summary_toml` to `<snapshot_dir>/<date>/stats.toml` and regenerate the
+/// `latest.toml` manifest listing the newest and all available snapshot dates
+fn write_snapshot(snapshot_dir: &Path, date: &str, summary_toml: &str) -> std::io::Result<PathBuf> {
+    let day_dir = snapshot_dir.join(date);
+    fs::create_dir_all(&day_dir)?;
+    let snapshot_path = day_dir.join("stats.toml");
+    fs::write(&snapshot_path, summary_toml)?;
+
+    // Every YYYY-MM-DD directory with a stats.toml is an available snapshot
+    let mut snapshots: Vec<String> = fs::read_dir(snapshot_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("stats.toml").is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| chrono::NaiveDate::parse_from_str(name, "%Y-%m-%d").is_ok())
+        .collect();
+    snapshots.sort();
+
+    let latest = snapshots
+        .last()
+        .cloned()
+        .unwrap_or_else(|| date.to_string());
+    let mut manifest = toml::Table::new();
+    manifest.insert(
+        "path".to_string(),
+        toml::Value::String(format!("{}/stats.toml", latest)),
+    );
+    manifest.insert("date".to_string(), toml::Value::String(latest));
+    manifest.insert(
+        "snapshots".to_string(),
+        toml::Value::Array(snapshots.into_iter().map(toml::Value::String).collect()),
+    );
+    fs::write(
+        snapshot_dir.join("latest.toml"),
+        toml::to_string(&manifest).unwrap(),
+    )?;
+
+    Ok(snapshot_path)
+}
+
+/// Add ready-made links for converting an unconverted feedstock: a fork URL and a web
+/// editor link to its meta.yaml, with a title for the pull request. Contributors can't
+/// push to the feedstock, so saving in the editor forks it and opens the pull request
+/// from the fork. `HEAD` is the default branch, which isn't `main` on every feedstock.
+fn add_conversion_links(entry: &mut toml::Table) {
+    let Some(name) = entry.get("name").and_then(|v| v.as_str()).map(String::from) else {
+        return;
+    };
+    let repo = format!("https://github.com/conda-forge/{}", name);
+    let package = name.trim_end_matches("-feedstock");
+    let pr_title = format!("Convert {} to recipe v1", package);
+
+    entry.insert(
+        "fork_url".to_string(),
+        toml::Value::String(format!("{}/fork", repo)),
+    );
+    entry.insert(
+        "edit_url".to_string(),
+        toml::Value::String(format!("{}/edit/HEAD/recipe/meta.yaml", repo)),
+    );
+    entry.insert("pr_title".to_string(), toml::Value::String(pr_title));
+}
+
+/// Mark an unconverted feedstock `active` if it was pushed to recently, leaving it
+/// unset if its last push is unknown
+fn add_activity_flag(entry: &mut toml::Table, collected_at: DateTime<Utc>) {
+    let Some(pushed_at) = entry
+        .get("pushed_at")
+        .and_then(|v| v.as_str())
+        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+    else {
+        return;
+    };
+    let days_since_push = (collected_at - pushed_at.with_timezone(&Utc)).num_days();
+    entry.insert(
+        "active".to_string(),
+        toml::Value::Boolean(days_since_push <= ACTIVE_WINDOW_DAYS),
+    );
+}
+
+/// Recipe v1 feedstocks including the non-standard filenames, which stats files before
+/// version 2 of the layout counted only in `non_standard_v1_count`. Older collectors
+/// wrote the version as `schema_version`.
+fn recipe_v1_count(stats: &toml::Table) -> Option<i64> {
+    let count = |key: &str| stats.get(key).and_then(|v| v.as_integer());
+    let version = count("stats_format_version")
+        .or_else(|| count("schema_version"))
+        .unwrap_or(0);
+    let non_standard = if version < 2 {
+        count("non_standard_v1_count").unwrap_or(0)
+    } else {
+        0
+    };
+    Some(count("recipe_v1_count")? + non_standard)
+}
+
+/// Whether a `feedstock_states` entry uses a Recipe v1 recipe, regardless of its filename
+fn is_v1_state(state: &toml::Value) -> bool {
+    matches!(
+        state.get("recipe_type").and_then(|t| t.as_str()),
+        Some("recipe_v1" | "non_standard_v1")
+    )
+}
+
+/// Extract the Recipe v1 feedstocks updated within the last `RECENT_WINDOW_DAYS`,
+/// with attribution
+fn extract_recently_updated(feedstocks_table: &toml::Table, now: DateTime<Utc>) -> toml::Table {
     let mut recent_feedstocks: Vec<_> = feedstocks_table
         .iter()
         .filter_map(|(name, state)| {
-            // Only include recipe_v1 feedstocks
-            if state
-                .get("recipe_type")
-                .and_then(|recipe_type| recipe_type.as_str().map(|s| s == "recipe_v1"))
-                .unwrap_or(false)
-            {
+            // Only include Recipe v1 feedstocks
+            if is_v1_state(state) {
                 state.get("last_changed").and_then(|date| {
                     let date_str = date.as_str()?.to_string();
                     // Extract contributors from attribution if available
@@ -93,7 +830,11 @@ fn extract_recently_updated(feedstocks_table: &toml::Table) -> toml::Table {
                                 .collect()
                         })
                         .unwrap_or_default();
-                    Some((name.clone(), date_str, contributors))
+                    let summary = state
+                        .get("summary")
+                        .and_then(|s| s.as_str())
+                        .map(String::from);
+                    Some((name.clone(), date_str, contributors, summary))
                 })
             } else {
                 None
@@ -102,134 +843,1358 @@ fn extract_recently_updated(feedstocks_table: &toml::Table) -> toml::Table {
         .collect();
 
     // Sort by last updated date (most recent first)
-    recent_feedstocks.sort_by(|(_, a, _), (_, b, _)| b.cmp(a));
+    recent_feedstocks.sort_by(|(_, a, _, _), (_, b, _, _)| b.cmp(a));
 
-    // Take the 10 most recent
-    recent_feedstocks.truncate(10);
+    // Keep those within the window, topped up to the minimum with the next most recent
+    let window_start = now - chrono::Duration::days(RECENT_WINDOW_DAYS);
+    let in_window = recent_feedstocks
+        .iter()
+        .take_while(|(_, date, _, _)| {
+            DateTime::parse_from_rfc3339(date).is_ok_and(|date| date >= window_start)
+        })
+        .count();
+    recent_feedstocks.truncate(in_window.clamp(RECENT_MIN_ENTRIES, RECENT_MAX_ENTRIES));
 
     // Create a new table for the recent feedstocks
     let mut recent_table = toml::Table::new();
-    for (name, date, contributors) in recent_feedstocks {
+    for (name, date, contributors, summary) in recent_feedstocks {
         let mut entry = toml::Table::new();
         entry.insert("date".to_string(), toml::Value::String(date));
         entry.insert(
             "contributors".to_string(),
             toml::Value::Array(contributors.into_iter().map(toml::Value::String).collect()),
         );
+        if let Some(summary) = summary {
+            entry.insert("summary".to_string(), toml::Value::String(summary));
+        }
         recent_table.insert(name, toml::Value::Table(entry));
     }
 
     recent_table
 }
 
-/// A single feedstock contribution by a contributor
-#[derive(Clone)]
-struct FeedstockContribution {
-    name: String,
-    contribution_type: String,
-    downloads: u64,
-    date: String,
+/// Count all unique contributors and how their number grew month by month.
+/// Returns the total and one `{ month = "YYYY-MM", contributors }` entry per month
+/// from the first contribution onwards, where `contributors` is cumulative. Only
+/// feedstocks on Recipe v1 count, like on the leaderboard, so a reverted conversion
+/// doesn't make its contributors part of the community.
+fn extract_contributor_growth(feedstocks_table: &toml::Table) -> (usize, Vec<toml::Value>) {
+    // Earliest contribution month ("YYYY-MM") per contributor
+    let mut first_months: HashMap<String, String> = HashMap::new();
+
+    for state in feedstocks_table.values().filter(|state| is_v1_state(state)) {
+        let Some(attribution) = state.get("attribution").and_then(|a| a.as_table()) else {
+            continue;
+        };
+        let Some(month) = attribution
+            .get("date")
+            .and_then(|d| d.as_str())
+            .and_then(|d| d.get(..7))
+        else {
+            continue;
+        };
+        let contributors = attribution
+            .get("contributors")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str())
+            .filter(|c| *c != "unknown");
+
+        for contributor in contributors {
+            let first = first_months
+                .entry(contributor.to_string())
+                .or_insert_with(|| month.to_string());
+            if month < first.as_str() {
+                *first = month.to_string();
+            }
+        }
+    }
+
+    let mut new_per_month: BTreeMap<(i32, u32), u32> = BTreeMap::new();
+    for month in first_months.values() {
+        let Some((year, month)) = month.split_once('-') else {
+            continue;
+        };
+        if let (Ok(year), Ok(month)) = (year.parse(), month.parse()) {
+            *new_per_month.entry((year, month)).or_default() += 1;
+        }
+    }
+
+    let mut growth = Vec::new();
+    if let (Some(&start), Some(&end)) = (new_per_month.keys().next(), new_per_month.keys().last()) {
+        // Walk every month so gaps show up as flat segments
+        let mut cumulative = 0;
+        let (mut year, mut month) = start;
+        while (year, month) <= end {
+            cumulative += new_per_month.get(&(year, month)).copied().unwrap_or(0);
+
+            let mut entry = toml::Table::new();
+            entry.insert(
+                "month".to_string(),
+                toml::Value::String(format!("{:04}-{:02}", year, month)),
+            );
+            entry.insert(
+                "contributors".to_string(),
+                toml::Value::Integer(cumulative as i64),
+            );
+            growth.push(toml::Value::Table(entry));
+
+            if month == 12 {
+                year += 1;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+    }
+
+    (first_months.len(), growth)
 }
 
-/// Aggregated stats for a single contributor
-struct ContributorData {
-    conversions: u32,
-    new_feedstocks: u32,
-    total_downloads: u64,
-    feedstocks: Vec<FeedstockContribution>,
+/// Month over month retention of contributors. Returns one
+/// `{ month = "YYYY-MM", active, retained, churned }` entry per month from the first
+/// contribution onwards: `retained` contributors were also active the month before,
+/// `churned` ones were active the month before but not this month.
+fn extract_contributor_retention(feedstocks_table: &toml::Table) -> Vec<toml::Value> {
+    // Lowercased logins active per month
+    let mut active: BTreeMap<(i32, u32), HashSet<String>> = BTreeMap::new();
+
+    for state in feedstocks_table.values() {
+        let Some(attribution) = state.get("attribution").and_then(|a| a.as_table()) else {
+            continue;
+        };
+        let Some((year, month)) = attribution
+            .get("date")
+            .and_then(|d| d.as_str())
+            .and_then(|d| d.get(..7))
+            .and_then(|d| d.split_once('-'))
+        else {
+            continue;
+        };
+        let (Ok(year), Ok(month)) = (year.parse(), month.parse()) else {
+            continue;
+        };
+        let contributors = attribution
+            .get("contributors")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str())
+            .filter(|c| *c != "unknown")
+            .map(str::to_lowercase);
+        active
+            .entry((year, month))
+            .or_default()
+            .extend(contributors);
+    }
+
+    let mut retention = Vec::new();
+    let (Some(&start), Some(&end)) = (active.keys().next(), active.keys().last()) else {
+        return retention;
+    };
+    let empty = HashSet::new();
+    let mut previous = &empty;
+    let (mut year, mut month) = start;
+    while (year, month) <= end {
+        let current = active.get(&(year, month)).unwrap_or(&empty);
+        let retained = current.intersection(previous).count();
+
+        let mut entry = toml::Table::new();
+        entry.insert(
+            "month".to_string(),
+            toml::Value::String(format!("{:04}-{:02}", year, month)),
+        );
+        entry.insert(
+            "active".to_string(),
+            toml::Value::Integer(current.len() as i64),
+        );
+        entry.insert(
+            "retained".to_string(),
+            toml::Value::Integer(retained as i64),
+        );
+        entry.insert(
+            "churned".to_string(),
+            toml::Value::Integer((previous.len() - retained) as i64),
+        );
+        retention.push(toml::Value::Table(entry));
+
+        previous = current;
+        if month == 12 {
+            year += 1;
+            month = 1;
+        } else {
+            month += 1;
+        }
+    }
+
+    retention
 }
 
-/// Weekly activity buckets: (conversions, new_feedstocks) for each of the last 20 weeks
-/// Index 0 = most recent week, index 19 = oldest week
-fn compute_weekly_activity(feedstocks: &[FeedstockContribution]) -> Vec<(u32, u32)> {
-    let now = Utc::now();
-    let mut weekly: Vec<(u32, u32)> = vec![(0, 0); 20];
+/// Conversions per month split by contributor: one `{ month = "YYYY-MM", new, returning }`
+/// entry per month from the first conversion onwards. A conversion counts as `new` when
+/// one of its contributors made their first contribution of any kind that month.
+/// Conversions without a known contributor are left out.
+fn extract_contributor_mix(feedstocks_table: &toml::Table) -> Vec<toml::Value> {
+    // (year, month), lowercased contributors, is a conversion
+    let mut contributions = Vec::new();
+    let mut first_months: HashMap<String, (i32, u32)> = HashMap::new();
 
-    for f in feedstocks {
-        if f.date.is_empty() {
+    for state in feedstocks_table.values() {
+        let Some(attribution) = state.get("attribution").and_then(|a| a.as_table()) else {
+            continue;
+        };
+        let Some((year, month)) = attribution
+            .get("date")
+            .and_then(|d| d.as_str())
+            .and_then(|d| d.get(..7))
+            .and_then(|d| d.split_once('-'))
+        else {
+            continue;
+        };
+        let (Ok(year), Ok(month)) = (year.parse::<i32>(), month.parse::<u32>()) else {
             continue;
+        };
+        let contributors: Vec<String> = attribution
+            .get("contributors")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str())
+            .filter(|c| *c != "unknown")
+            .map(str::to_lowercase)
+            .collect();
+        for contributor in &contributors {
+            let first = first_months
+                .entry(contributor.clone())
+                .or_insert((year, month));
+            *first = (*first).min((year, month));
         }
+        let is_conversion = attribution
+            .get("contribution_type")
+            .and_then(|t| t.as_str())
+            == Some("conversion");
+        contributions.push(((year, month), contributors, is_conversion));
+    }
 
-        // Parse the ISO date
-        if let Ok(date) = DateTime::parse_from_rfc3339(&f.date) {
-            let date_utc = date.with_timezone(&Utc);
-            let days_ago = (now - date_utc).num_days();
+    // (new, returning) conversions per month
+    let mut per_month: BTreeMap<(i32, u32), (u32, u32)> = BTreeMap::new();
+    for (month, contributors, is_conversion) in contributions {
+        if !is_conversion || contributors.is_empty() {
+            continue;
+        }
+        let counts = per_month.entry(month).or_default();
+        if contributors.iter().any(|c| first_months[c] == month) {
+            counts.0 += 1;
+        } else {
+            counts.1 += 1;
+        }
+    }
 
-            if days_ago >= 0 {
-                let weeks_ago = (days_ago / 7) as usize;
-                if weeks_ago < 20 {
-                    match f.contribution_type.as_str() {
-                        "conversion" => weekly[weeks_ago].0 += 1,
-                        "new_feedstock" => weekly[weeks_ago].1 += 1,
-                        _ => {}
-                    }
-                }
-            }
+    let mut mix = Vec::new();
+    let (Some(&start), Some(&end)) = (per_month.keys().next(), per_month.keys().last()) else {
+        return mix;
+    };
+    let (mut year, mut month) = start;
+    while (year, month) <= end {
+        let (new, returning) = per_month.get(&(year, month)).copied().unwrap_or_default();
+
+        let mut entry = toml::Table::new();
+        entry.insert(
+            "month".to_string(),
+            toml::Value::String(format!("{:04}-{:02}", year, month)),
+        );
+        entry.insert("new".to_string(), toml::Value::Integer(new as i64));
+        entry.insert(
+            "returning".to_string(),
+            toml::Value::Integer(returning as i64),
+        );
+        mix.push(toml::Value::Table(entry));
+
+        if month == 12 {
+            year += 1;
+            month = 1;
+        } else {
+            month += 1;
         }
     }
 
-    weekly
+    mix
+}
+
+/// One `{ cohort = "YYYY-MM", converted, still_v1, passing, retained_1m, ... }` entry
+/// per month with conversions, oldest first. `still_v1` and `passing` (still v1 with a
+/// green default branch) describe the feedstocks today; `retained_<N>m` counts the
+/// conversions not reverted within N months and is left out until the whole cohort is
+/// at least N months old. `follow_up_fixed` counts the `follow_up_checked` conversions
+/// that needed a fix to the recipe within a week.
+fn extract_conversion_cohorts(
+    feedstocks_table: &toml::Table,
+    now: DateTime<Utc>,
+) -> Vec<toml::Value> {
+    #[derive(Default)]
+    struct Cohort {
+        converted: i64,
+        still_v1: i64,
+        passing: i64,
+        retained: [i64; COHORT_MONTHS.len()],
+        follow_up_checked: i64,
+        follow_up_fixed: i64,
+    }
+
+    let parse_date = |value: Option<&toml::Value>| {
+        value
+            .and_then(|d| d.as_str())
+            .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+            .map(|d| d.date_naive())
+    };
+
+    let mut cohorts: BTreeMap<(i32, u32), Cohort> = BTreeMap::new();
+    for state in feedstocks_table.values() {
+        let attribution = state.get("attribution").and_then(|a| a.as_table());
+        let revert = state.get("revert").and_then(|r| r.as_table());
+        let conversion = attribution
+            .filter(|a| a.get("contribution_type").and_then(|t| t.as_str()) == Some("conversion"));
+        let converted_at = match conversion {
+            Some(a) => parse_date(a.get("date")),
+            None => parse_date(revert.and_then(|r| r.get("converted_at"))),
+        };
+        let Some(converted_at) = converted_at else {
+            continue;
+        };
+        let reverted_at = parse_date(revert.and_then(|r| r.get("reverted_at")));
+        let still_v1 = is_v1_state(state);
+
+        let cohort = cohorts
+            .entry((converted_at.year(), converted_at.month()))
+            .or_default();
+        cohort.converted += 1;
+        if still_v1 {
+            cohort.still_v1 += 1;
+            if state.get("build_state").and_then(|s| s.as_str()) == Some("SUCCESS") {
+                cohort.passing += 1;
+            }
+        }
+        // Only checks of this conversion count, not of one before a revert
+        let follow_up = state
+            .get("follow_up")
+            .and_then(|f| f.as_table())
+            .filter(|f| f.get("converted_at") == conversion.and_then(|a| a.get("date")));
+        if let Some(follow_up) = follow_up {
+            cohort.follow_up_checked += 1;
+            if follow_up.contains_key("fix_pr") {
+                cohort.follow_up_fixed += 1;
+            }
+        }
+        for (retained, months) in cohort.retained.iter_mut().zip(COHORT_MONTHS) {
+            let horizon = converted_at.checked_add_months(chrono::Months::new(months));
+            if reverted_at.is_none_or(|reverted| Some(reverted) > horizon) {
+                *retained += 1;
+            }
+        }
+    }
+
+    let today = now.date_naive();
+    cohorts
+        .into_iter()
+        .map(|((year, month), cohort)| {
+            let mut entry = toml::Table::new();
+            entry.insert(
+                "cohort".to_string(),
+                toml::Value::String(format!("{:04}-{:02}", year, month)),
+            );
+            entry.insert(
+                "converted".to_string(),
+                toml::Value::Integer(cohort.converted),
+            );
+            entry.insert(
+                "still_v1".to_string(),
+                toml::Value::Integer(cohort.still_v1),
+            );
+            entry.insert("passing".to_string(), toml::Value::Integer(cohort.passing));
+            entry.insert(
+                "follow_up_checked".to_string(),
+                toml::Value::Integer(cohort.follow_up_checked),
+            );
+            entry.insert(
+                "follow_up_fixed".to_string(),
+                toml::Value::Integer(cohort.follow_up_fixed),
+            );
+
+            // The cohort's last conversion happened before the first of the next month
+            let cohort_end = NaiveDate::from_ymd_opt(year, month, 1)
+                .and_then(|d| d.checked_add_months(chrono::Months::new(1)));
+            for (retained, months) in cohort.retained.into_iter().zip(COHORT_MONTHS) {
+                let observable = cohort_end
+                    .and_then(|d| d.checked_add_months(chrono::Months::new(months)))
+                    .is_some_and(|d| d <= today);
+                if observable {
+                    entry.insert(
+                        format!("retained_{}m", months),
+                        toml::Value::Integer(retained),
+                    );
+                }
+            }
+            toml::Value::Table(entry)
+        })
+        .collect()
+}
+
+/// Conversions and new Recipe v1 feedstocks per week (starting Monday), site-wide and
+/// oldest first, up to the current week
+fn extract_weekly_v1_growth(
+    feedstocks_table: &toml::Table,
+    now: DateTime<Utc>,
+) -> Vec<toml::Value> {
+    let mut weeks: BTreeMap<NaiveDate, (u32, u32)> = BTreeMap::new();
+    for state in feedstocks_table.values() {
+        let Some(attribution) = state.get("attribution").and_then(|a| a.as_table()) else {
+            continue;
+        };
+        let Some(date) = attribution
+            .get("date")
+            .and_then(|d| d.as_str())
+            .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+        else {
+            continue;
+        };
+        let week = week_start(date.date_naive());
+        match attribution
+            .get("contribution_type")
+            .and_then(|t| t.as_str())
+        {
+            Some("conversion") => weeks.entry(week).or_default().0 += 1,
+            Some("new_feedstock") => weeks.entry(week).or_default().1 += 1,
+            _ => {}
+        }
+    }
+
+    let Some(&first) = weeks.keys().next() else {
+        return Vec::new();
+    };
+    // Walk every week so quiet weeks show up as gaps
+    let last = week_start(now.date_naive()).max(*weeks.keys().last().unwrap());
+    first
+        .iter_weeks()
+        .take_while(|week| *week <= last)
+        .map(|week| {
+            let (conversions, new_feedstocks) = weeks.get(&week).copied().unwrap_or_default();
+            let mut entry = toml::Table::new();
+            entry.insert("week".to_string(), toml::Value::String(week.to_string()));
+            entry.insert(
+                "conversions".to_string(),
+                toml::Value::Integer(conversions as i64),
+            );
+            entry.insert(
+                "new_feedstocks".to_string(),
+                toml::Value::Integer(new_feedstocks as i64),
+            );
+            toml::Value::Table(entry)
+        })
+        .collect()
+}
+
+/// Monday of the week `date` falls in
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Extract Recipe v1 feedstocks using a near-miss recipe filename, most downloaded first
+fn extract_non_standard_recipes(feedstocks_table: &toml::Table) -> Vec<toml::Value> {
+    let mut non_standard: Vec<_> = feedstocks_table
+        .iter()
+        .filter(|(_, state)| {
+            state.get("recipe_type").and_then(|t| t.as_str()) == Some("non_standard_v1")
+        })
+        .map(|(name, state)| {
+            let recipe_file = state
+                .get("recipe_file")
+                .and_then(|f| f.as_str())
+                .unwrap_or("")
+                .to_string();
+            let downloads = state
+                .get("downloads")
+                .and_then(|d| d.as_integer())
+                .unwrap_or(0);
+            (name.clone(), recipe_file, downloads)
+        })
+        .collect();
+
+    non_standard.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+
+    non_standard
+        .into_iter()
+        .map(|(name, recipe_file, downloads)| {
+            let mut entry = toml::Table::new();
+            entry.insert("name".to_string(), toml::Value::String(name));
+            entry.insert("recipe_file".to_string(), toml::Value::String(recipe_file));
+            entry.insert("downloads".to_string(), toml::Value::Integer(downloads));
+            toml::Value::Table(entry)
+        })
+        .collect()
+}
+
+/// Open migration PRs of feedstocks that are still unconverted, most downloaded first
+fn extract_pending_migrations(
+    prs: &[toml::Value],
+    feedstocks_table: &toml::Table,
+) -> Vec<toml::Value> {
+    let mut pending: Vec<_> = prs
+        .iter()
+        .filter_map(|pr| {
+            let name = pr.get("feedstock")?.as_str()?;
+            let state = feedstocks_table.get(name)?;
+            if is_v1_state(state) {
+                return None;
+            }
+            let number = pr.get("number")?.as_integer()?;
+            let opened = pr
+                .get("opened_at")
+                .and_then(|o| o.as_str())
+                .and_then(|o| o.get(..10))
+                .unwrap_or("")
+                .to_string();
+            let downloads = state
+                .get("downloads")
+                .and_then(|d| d.as_integer())
+                .unwrap_or(0);
+            Some((name.to_string(), number, opened, downloads))
+        })
+        .collect();
+
+    pending.sort_by(|a, b| b.3.cmp(&a.3).then_with(|| a.0.cmp(&b.0)));
+
+    pending
+        .into_iter()
+        .map(|(name, number, opened, downloads)| {
+            let mut entry = toml::Table::new();
+            entry.insert("name".to_string(), toml::Value::String(name));
+            entry.insert("number".to_string(), toml::Value::Integer(number));
+            entry.insert("opened".to_string(), toml::Value::String(opened));
+            entry.insert("downloads".to_string(), toml::Value::Integer(downloads));
+            toml::Value::Table(entry)
+        })
+        .collect()
+}
+
+/// Count gaps in the collected data: feedstocks without download counts or a
+/// known recipe type, unresolved contributor logins, and how confidently each
+/// Recipe v1 feedstock is attributed:
+/// - high: credited to resolved logins via the commit that introduced the recipe
+/// - medium: credited to resolved logins, but without a known commit
+/// - low: at least one contributor couldn't be resolved to a login
+/// - unattributed: no attribution at all
+fn extract_data_quality(feedstocks_table: &toml::Table) -> toml::Table {
+    let (mut missing_downloads, mut unknown_recipe_types, mut unresolved_logins) = (0, 0, 0);
+    let (mut high, mut medium, mut low, mut unattributed) = (0, 0, 0, 0);
+    let mut v1_feedstocks = 0;
+
+    for state in feedstocks_table.values() {
+        if state
+            .get("downloads")
+            .and_then(|d| d.as_integer())
+            .is_none()
+        {
+            missing_downloads += 1;
+        }
+        let recipe_type = state.get("recipe_type").and_then(|t| t.as_str());
+        let is_v1 = is_v1_state(state);
+        if !is_v1 && recipe_type != Some("meta_yaml") {
+            unknown_recipe_types += 1;
+        }
+        if is_v1 {
+            v1_feedstocks += 1;
+        }
+
+        let Some(attribution) = state.get("attribution") else {
+            if is_v1 {
+                unattributed += 1;
+            }
+            continue;
+        };
+
+        let contributors: Vec<&str> = attribution
+            .get("contributors")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c.as_str())
+            .collect();
+        let unresolved = contributors.iter().filter(|c| **c == "unknown").count();
+        unresolved_logins += unresolved;
+
+        if !is_v1 {
+            continue;
+        } else if contributors.is_empty() || unresolved > 0 {
+            low += 1;
+        } else if attribution.get("commit_sha").is_some() {
+            high += 1;
+        } else {
+            medium += 1;
+        }
+    }
+
+    let mut confidence = toml::Table::new();
+    for (level, count) in [
+        ("high", high),
+        ("medium", medium),
+        ("low", low),
+        ("unattributed", unattributed),
+    ] {
+        confidence.insert(level.to_string(), toml::Value::Integer(count));
+    }
+
+    let mut quality = toml::Table::new();
+    quality.insert(
+        "total_feedstocks".to_string(),
+        toml::Value::Integer(feedstocks_table.len() as i64),
+    );
+    quality.insert(
+        "missing_downloads".to_string(),
+        toml::Value::Integer(missing_downloads),
+    );
+    quality.insert(
+        "unknown_recipe_types".to_string(),
+        toml::Value::Integer(unknown_recipe_types),
+    );
+    quality.insert(
+        "unresolved_logins".to_string(),
+        toml::Value::Integer(unresolved_logins as i64),
+    );
+    quality.insert(
+        "v1_feedstocks".to_string(),
+        toml::Value::Integer(v1_feedstocks),
+    );
+    quality.insert(
+        "attribution_confidence".to_string(),
+        toml::Value::Table(confidence),
+    );
+    quality
+}
+
+/// Whether `contributor` converted a feedstock they maintain themselves. The data
+/// doesn't record who created a feedstock, so its current recipe maintainers stand in
+/// for the author who created it on meta.yaml; logins match case-insensitively.
+fn is_self_conversion(state: &toml::Value, contribution_type: &str, contributor: &str) -> bool {
+    contribution_type == "conversion"
+        && state
+            .get("maintainers")
+            .and_then(|m| m.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|m| m.as_str())
+            .any(|m| m.eq_ignore_ascii_case(contributor))
+}
+
+/// Rank maintainers by the share of the feedstocks they maintain that they converted
+/// or created as Recipe v1 themselves, normalizing contributions by workload
+fn extract_completionists(feedstocks_table: &toml::Table) -> Vec<toml::Value> {
+    // Lowercased login -> (login as listed, maintained, done)
+    let mut maintainers: HashMap<String, (String, u32, u32)> = HashMap::new();
+
+    for state in feedstocks_table.values() {
+        let is_v1 = is_v1_state(state);
+        let credited: Vec<&str> = state
+            .get("attribution")
+            .and_then(|a| a.get("contributors"))
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c.as_str())
+            .collect();
+
+        let mut seen: Vec<String> = Vec::new();
+        for maintainer in state
+            .get("maintainers")
+            .and_then(|m| m.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|m| m.as_str())
+        {
+            let key = maintainer.to_lowercase();
+            if seen.contains(&key) {
+                continue;
+            }
+            let entry = maintainers
+                .entry(key.clone())
+                .or_insert_with(|| (maintainer.to_string(), 0, 0));
+            entry.1 += 1;
+            if is_v1 && credited.iter().any(|c| c.eq_ignore_ascii_case(maintainer)) {
+                entry.2 += 1;
+            }
+            seen.push(key);
+        }
+    }
+
+    let mut ranked: Vec<_> = maintainers
+        .into_values()
+        .filter(|(_, maintained, done)| *maintained >= COMPLETIONIST_MIN_MAINTAINED && *done > 0)
+        .collect();
+    // Highest share first (compared as fractions), then most feedstocks done, then by name
+    ranked.sort_by(|a, b| {
+        (b.2 as u64 * a.1 as u64)
+            .cmp(&(a.2 as u64 * b.1 as u64))
+            .then_with(|| b.2.cmp(&a.2))
+            .then_with(|| a.0.cmp(&b.0))
+    });
+
+    ranked
+        .into_iter()
+        .take(COMPLETIONIST_LIMIT)
+        .map(|(name, maintained, done)| {
+            let mut entry = toml::Table::new();
+            entry.insert("name".to_string(), toml::Value::String(name));
+            entry.insert(
+                "maintained".to_string(),
+                toml::Value::Integer(maintained as i64),
+            );
+            entry.insert("converted".to_string(), toml::Value::Integer(done as i64));
+            toml::Value::Table(entry)
+        })
+        .collect()
+}
+
+/// Count conversions credited to one of the feedstock's own maintainers (see
+/// `is_self_conversion`), each feedstock once however many of them were credited
+fn count_self_conversions(feedstocks_table: &toml::Table) -> usize {
+    feedstocks_table
+        .values()
+        .filter(|state| {
+            let Some(attribution) = state.get("attribution") else {
+                return false;
+            };
+            let contribution_type = attribution
+                .get("contribution_type")
+                .and_then(|t| t.as_str())
+                .unwrap_or("");
+            attribution
+                .get("contributors")
+                .and_then(|c| c.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|c| c.as_str())
+                .any(|c| is_self_conversion(state, contribution_type, c))
+        })
+        .count()
+}
+
+/// Check a `contributors.toml` entry, keeping only the valid fields. Problems are
+/// returned so they can be reported without failing the whole crunch.
+fn validate_profile(login: &str, profile: &toml::Value) -> (toml::Table, Vec<String>) {
+    let mut valid = toml::Table::new();
+    let mut problems = Vec::new();
+
+    let Some(profile) = profile.as_table() else {
+        return (valid, vec![format!("{login}: expected a table")]);
+    };
+    for (key, value) in profile {
+        let Some(value) = value.as_str().map(str::trim) else {
+            problems.push(format!("{login}.{key}: expected a string"));
+            continue;
+        };
+        let problem = match key.as_str() {
+            "display_name" | "affiliation" => {
+                if value.is_empty() {
+                    Some("is empty")
+                } else if value.chars().count() > MAX_PROFILE_TEXT {
+                    Some("is too long")
+                } else if value.chars().any(char::is_control) {
+                    Some("contains control characters")
+                } else {
+                    None
+                }
+            }
+            "url" => {
+                if !value.starts_with("https://") || value.len() <= "https://".len() {
+                    Some("must be an https:// link")
+                } else if value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+                    Some("contains whitespace")
+                } else {
+                    None
+                }
+            }
+            _ => Some("is not a known field"),
+        };
+        match problem {
+            Some(problem) => problems.push(format!("{login}.{key} {problem}")),
+            None => {
+                valid.insert(key.clone(), toml::Value::String(value.to_string()));
+            }
+        }
+    }
+    (valid, problems)
+}
+
+/// The newest snapshot at least a week older than `date`, with its date
+fn previous_week_snapshot(
+    snapshot_dir: &Path,
+    date: NaiveDate,
+) -> Option<(NaiveDate, toml::Table)> {
+    let cutoff = date - chrono::Duration::days(7);
+    let previous = fs::read_dir(snapshot_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| NaiveDate::parse_from_str(&name, "%Y-%m-%d").ok())
+        .filter(|day| *day <= cutoff)
+        .max()?;
+    let content = fs::read_to_string(
+        snapshot_dir
+            .join(previous.format("%Y-%m-%d").to_string())
+            .join("stats.toml"),
+    )
+    .ok()?;
+    Some((previous, toml::from_str(&content).ok()?))
+}
+
+/// Set `rank_change` on each leaderboard entry that was on the `previous` leaderboard
+/// too: positive when the contributor moved up, negative when they dropped
+fn add_rank_changes(top_contributors: &mut [toml::Value], previous: &[toml::Value]) {
+    let login = |entry: &toml::Value| {
+        entry
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(str::to_lowercase)
+    };
+    let previous_ranks: HashMap<String, usize> = previous
+        .iter()
+        .enumerate()
+        .filter_map(|(rank, entry)| Some((login(entry)?, rank)))
+        .collect();
+
+    for (rank, contributor) in top_contributors.iter_mut().enumerate() {
+        let Some(previous_rank) = login(contributor).and_then(|l| previous_ranks.get(&l)) else {
+            continue;
+        };
+        if let Some(entry) = contributor.as_table_mut() {
+            entry.insert(
+                "rank_change".to_string(),
+                toml::Value::Integer(*previous_rank as i64 - rank as i64),
+            );
+        }
+    }
+}
+
+/// Attach the validated `contributors.toml` profile to each leaderboard entry,
+/// matching GitHub logins case-insensitively
+fn add_contributor_profiles(top_contributors: &mut [toml::Value], profiles: &toml::Table) {
+    let profiles: HashMap<String, toml::Table> = profiles
+        .iter()
+        .filter_map(|(login, profile)| {
+            let (profile, problems) = validate_profile(login, profile);
+            for problem in problems {
+                println!("⚠️  Ignoring contributors.toml field {}", problem);
+            }
+            (!profile.is_empty()).then(|| (login.to_lowercase(), profile))
+        })
+        .collect();
+
+    for contributor in top_contributors.iter_mut() {
+        let Some(entry) = contributor.as_table_mut() else {
+            continue;
+        };
+        let login = entry.get("name").and_then(|n| n.as_str()).unwrap_or("");
+        if let Some(profile) = profiles.get(&login.to_lowercase()) {
+            entry.insert("profile".to_string(), toml::Value::Table(profile.clone()));
+        }
+    }
+}
+
+/// Attach the cached GitHub identity (name, avatar, renamed or deleted account) to each
+/// leaderboard entry as `github`
+fn add_contributor_identities(
+    top_contributors: &mut [toml::Value],
+    identities: &serde_json::Value,
+) {
+    for contributor in top_contributors.iter_mut() {
+        let Some(entry) = contributor.as_table_mut() else {
+            continue;
+        };
+        let login = entry.get("name").and_then(|n| n.as_str()).unwrap_or("");
+        let identity = &identities["contributors"][login.to_lowercase()];
+        let Some(exists) = identity["exists"].as_bool() else {
+            continue;
+        };
+
+        let mut github = toml::Table::new();
+        github.insert("exists".to_string(), toml::Value::Boolean(exists));
+        for key in ["name", "avatar_url", "renamed_to"] {
+            if let Some(value) = identity[key].as_str() {
+                github.insert(key.to_string(), toml::Value::String(value.to_string()));
+            }
+        }
+        entry.insert("github".to_string(), toml::Value::Table(github));
+    }
+}
+
+/// Place each `[[event]]` on the charts' time axes: its month for the monthly growth
+/// chart and, for past events, how many weeks before `now` it was for the activity bars
+fn extract_events(events_table: &toml::Table, now: DateTime<Utc>) -> Vec<toml::Value> {
+    let mut events: Vec<_> = events_table
+        .get("event")
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|event| {
+            let date = event.get("date")?.as_str()?;
+            let title = event.get("title")?.as_str()?;
+            let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+
+            let mut entry = toml::Table::new();
+            entry.insert("date".to_string(), toml::Value::String(date.to_string()));
+            entry.insert("title".to_string(), toml::Value::String(title.to_string()));
+            if let Some(url) = event.get("url").and_then(|u| u.as_str()) {
+                entry.insert("url".to_string(), toml::Value::String(url.to_string()));
+            }
+            entry.insert(
+                "month".to_string(),
+                toml::Value::String(day.format("%Y-%m").to_string()),
+            );
+            let days_ago = (now.date_naive() - day).num_days();
+            if days_ago >= 0 {
+                entry.insert("weeks_ago".to_string(), toml::Value::Integer(days_ago / 7));
+            }
+            Some((date.to_string(), toml::Value::Table(entry)))
+        })
+        .collect();
+
+    events.sort_by(|a, b| a.0.cmp(&b.0));
+    events.into_iter().map(|(_, event)| event).collect()
+}
+
+/// Identifies the package a feedstock builds, so a recreated feedstock matches the original
+fn package_key(feedstock: &str) -> String {
+    feedstock
+        .trim_end_matches("-feedstock")
+        .to_ascii_lowercase()
+}
+
+/// A single feedstock contribution by a contributor
+#[derive(Clone)]
+struct FeedstockContribution {
+    name: String,
+    contribution_type: String,
+    downloads: u64,
+    date: String,
+    summary: Option<String>,
+    /// Converted by one of the feedstock's own maintainers
+    self_conversion: bool,
+}
+
+/// Aggregated stats for a single contributor
+struct ContributorData {
+    conversions: u32,
+    new_feedstocks: u32,
+    self_conversions: u32,
+    total_downloads: u64,
+    feedstocks: Vec<FeedstockContribution>,
+}
+
+/// Weekly activity buckets: (conversions, new_feedstocks) per week
+/// Index 0 = most recent week; contributions older than `weeks` are left out.
+fn compute_weekly_activity(feedstocks: &[FeedstockContribution], weeks: usize) -> Vec<(u32, u32)> {
+    let now = Utc::now();
+
+    // Resolve how many weeks ago each contribution happened
+    let contributions: Vec<(usize, &str)> = feedstocks
+        .iter()
+        .filter(|f| !f.date.is_empty())
+        .filter_map(|f| {
+            let date_utc = DateTime::parse_from_rfc3339(&f.date)
+                .ok()?
+                .with_timezone(&Utc);
+            let days_ago = (now - date_utc).num_days();
+            (days_ago >= 0).then_some(((days_ago / 7) as usize, f.contribution_type.as_str()))
+        })
+        .filter(|(weeks_ago, _)| *weeks_ago < weeks)
+        .collect();
+
+    let mut weekly: Vec<(u32, u32)> = vec![(0, 0); weeks];
+
+    for (weeks_ago, contribution_type) in contributions {
+        match contribution_type {
+            "conversion" => weekly[weeks_ago].0 += 1,
+            "new_feedstock" => weekly[weeks_ago].1 += 1,
+            _ => {}
+        }
+    }
+
+    weekly
+}
+
+/// Contributions per contributor, keyed by package so each one is credited at most once
+fn collect_contributions(
+    feedstocks_table: &toml::Table,
+) -> HashMap<String, BTreeMap<String, FeedstockContribution>> {
+    let mut contributions: HashMap<String, BTreeMap<String, FeedstockContribution>> =
+        HashMap::new();
+
+    for (name, state) in feedstocks_table.iter() {
+        if let Some(attribution) = state.get("attribution").and_then(|a| a.as_table()) {
+            let contribution_type = attribution
+                .get("contribution_type")
+                .and_then(|t| t.as_str())
+                .unwrap_or("")
+                .to_string();
+
+            let date = attribution
+                .get("date")
+                .and_then(|d| d.as_str())
+                .unwrap_or("")
+                .to_string();
+
+            let downloads = state
+                .get("downloads")
+                .and_then(|d| d.as_integer())
+                .map(|d| d as u64)
+                .unwrap_or(0);
+
+            let summary = state
+                .get("summary")
+                .and_then(|s| s.as_str())
+                .map(String::from);
+
+            let mut contributors: Vec<String> = Vec::new();
+            for contributor in attribution
+                .get("contributors")
+                .and_then(|c| c.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str())
+            {
+                if !contributors
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case(contributor))
+                {
+                    contributors.push(contributor.to_string());
+                }
+            }
+
+            for contributor in contributors {
+                let self_conversion = is_self_conversion(state, &contribution_type, &contributor);
+                let contribution = FeedstockContribution {
+                    name: name.clone(),
+                    contribution_type: contribution_type.clone(),
+                    downloads,
+                    date: date.clone(),
+                    summary: summary.clone(),
+                    self_conversion,
+                };
+                // A recreated feedstock can be attributed again, possibly with another
+                // contribution type; only the earliest contribution counts
+                match contributions
+                    .entry(contributor)
+                    .or_default()
+                    .entry(package_key(name))
+                {
+                    btree_map::Entry::Vacant(slot) => {
+                        slot.insert(contribution);
+                    }
+                    btree_map::Entry::Occupied(mut slot) => {
+                        if contribution.date < slot.get().date {
+                            slot.insert(contribution);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    contributions
+}
+
+/// Every achievement earned with these contributions, and the day ("YYYY-MM-DD") the
+/// contribution crossing its threshold was made. Undated contributions count as the
+/// earliest, and leave the date of achievements they complete unknown.
+fn achievement_dates(
+    feedstocks: &[FeedstockContribution],
+) -> Vec<(&'static achievements::Achievement, Option<String>)> {
+    use achievements::{Category, CONVERSIONS, NEW_FEEDSTOCKS, SELF_CONVERSION, TOTAL};
+
+    let mut ordered: Vec<&FeedstockContribution> = feedstocks.iter().collect();
+    ordered.sort_by(|a, b| a.date.cmp(&b.date));
+
+    let all = TOTAL
+        .iter()
+        .chain(CONVERSIONS)
+        .chain(NEW_FEEDSTOCKS)
+        .chain([&SELF_CONVERSION]);
+    let mut earned: Vec<(&'static achievements::Achievement, Option<String>)> = Vec::new();
+    let (mut total, mut conversions, mut new_feedstocks, mut self_conversions) = (0, 0, 0, 0);
+    for contribution in ordered {
+        match contribution.contribution_type.as_str() {
+            "conversion" => conversions += 1,
+            "new_feedstock" => new_feedstocks += 1,
+            _ => continue,
+        }
+        total += 1;
+        if contribution.self_conversion {
+            self_conversions += 1;
+        }
+        let date = contribution.date.get(..10).map(String::from);
+        for achievement in all.clone() {
+            let count = match achievement.category {
+                Category::Total => total,
+                Category::Conversions => conversions,
+                Category::NewFeedstocks => new_feedstocks,
+                Category::SelfConversion => self_conversions,
+            };
+            let already_earned = earned.iter().any(|(a, _)| a.id == achievement.id);
+            if count >= achievement.threshold && !already_earned {
+                earned.push((achievement, date.clone()));
+            }
+        }
+    }
+    earned
+}
+
+/// Achievements earned per contributor login, e.g.
+/// `{"alice": [{"id": "forge-apprentice", "emoji": "⭐", "threshold": 10, "earned": "2025-03-01"}]}`
+fn extract_achievements(feedstocks_table: &toml::Table) -> serde_json::Value {
+    let mut by_login = serde_json::Map::new();
+    let mut contributors: Vec<_> = collect_contributions(feedstocks_table)
+        .into_iter()
+        .filter(|(login, _)| login != "unknown")
+        .collect();
+    contributors.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (login, feedstocks) in contributors {
+        let feedstocks: Vec<_> = feedstocks.into_values().collect();
+        let earned: Vec<_> = achievement_dates(&feedstocks)
+            .into_iter()
+            .map(|(achievement, date)| {
+                serde_json::json!({
+                    "id": achievement.id,
+                    "emoji": achievement.emoji,
+                    "threshold": achievement.threshold,
+                    "earned": date,
+                })
+            })
+            .collect();
+        if !earned.is_empty() {
+            by_login.insert(login, serde_json::Value::Array(earned));
+        }
+    }
+    serde_json::Value::Object(by_login)
+}
+
+/// Every dated achievement unlock as (login, achievement, "YYYY-MM-DD"), newest first
+fn extract_achievement_feed(
+    feedstocks_table: &toml::Table,
+) -> Vec<(String, &'static achievements::Achievement, String)> {
+    let mut feed: Vec<_> = collect_contributions(feedstocks_table)
+        .into_iter()
+        .filter(|(login, _)| login != "unknown")
+        .flat_map(|(login, feedstocks)| {
+            let feedstocks: Vec<_> = feedstocks.into_values().collect();
+            achievement_dates(&feedstocks)
+                .into_iter()
+                .filter_map(|(achievement, date)| Some((login.clone(), achievement, date?)))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    // Ties by login, then in the order the achievements were earned
+    feed.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+    feed
+}
+
+/// The most downloaded feedstock converted from meta.yaml so far, with who converted it
+fn extract_biggest_conversion(feedstocks_table: &toml::Table) -> Option<toml::Table> {
+    let (name, state, attribution) = feedstocks_table
+        .iter()
+        .filter_map(|(name, state)| {
+            let attribution = state.get("attribution")?.as_table()?;
+            let contribution_type = attribution.get("contribution_type")?.as_str()?;
+            (contribution_type == "conversion").then_some((name, state, attribution))
+        })
+        .max_by_key(|(_, state, _)| state.get("downloads").and_then(|d| d.as_integer()))?;
+
+    let mut biggest = toml::Table::new();
+    biggest.insert("feedstock".to_string(), toml::Value::String(name.clone()));
+    for (key, value) in [
+        ("downloads", state.get("downloads")),
+        ("summary", state.get("summary")),
+        ("contributors", attribution.get("contributors")),
+        ("date", attribution.get("date")),
+    ] {
+        if let Some(value) = value {
+            biggest.insert(key.to_string(), value.clone());
+        }
+    }
+    Some(biggest)
+}
+
+/// Latest conversions of packages with over `NOTABLE_MIN_DOWNLOADS` downloads, newest first
+fn extract_notable_recent(feedstocks_table: &toml::Table) -> Vec<toml::Value> {
+    let mut notable: Vec<_> = feedstocks_table
+        .iter()
+        .filter_map(|(name, state)| {
+            let attribution = state.get("attribution")?.as_table()?;
+            if attribution.get("contribution_type")?.as_str()? != "conversion" {
+                return None;
+            }
+            let downloads = state.get("downloads")?.as_integer()?;
+            let date = attribution.get("date")?.as_str()?;
+            (downloads > NOTABLE_MIN_DOWNLOADS).then_some((name, downloads, date, attribution))
+        })
+        .collect();
+
+    notable.sort_by(|a, b| b.2.cmp(a.2).then_with(|| a.0.cmp(b.0)));
+
+    notable
+        .into_iter()
+        .take(NOTABLE_RECENT_ENTRIES)
+        .map(|(name, downloads, date, attribution)| {
+            let mut entry = toml::Table::new();
+            entry.insert("feedstock".to_string(), toml::Value::String(name.clone()));
+            entry.insert("downloads".to_string(), toml::Value::Integer(downloads));
+            entry.insert("date".to_string(), toml::Value::String(date.to_string()));
+            if let Some(contributors) = attribution.get("contributors") {
+                entry.insert("contributors".to_string(), contributors.clone());
+            }
+            toml::Value::Table(entry)
+        })
+        .collect()
+}
+
+/// Every contribution per contributor login, oldest first, e.g.
+/// `alice = [{ feedstock = "foo-feedstock", date = "...", contribution_type = "conversion" }]`.
+/// `generated_at` is the reference for bucketing them into weeks, like `weekly_activity`.
+fn extract_contribution_history(feedstocks_table: &toml::Table, now: DateTime<Utc>) -> toml::Table {
+    let contributors: toml::Table = collect_contributions(feedstocks_table)
+        .into_iter()
+        .filter(|(login, _)| login != "unknown")
+        .map(|(login, feedstocks)| {
+            let mut feedstocks: Vec<_> = feedstocks
+                .into_values()
+                .filter(|f| !f.date.is_empty())
+                .collect();
+            feedstocks.sort_by(|a, b| a.date.cmp(&b.date));
+            let contributions = feedstocks
+                .into_iter()
+                .map(|f| {
+                    let mut contribution = toml::Table::new();
+                    contribution.insert("feedstock".to_string(), toml::Value::String(f.name));
+                    contribution.insert("date".to_string(), toml::Value::String(f.date));
+                    contribution.insert(
+                        "contribution_type".to_string(),
+                        toml::Value::String(f.contribution_type),
+                    );
+                    toml::Value::Table(contribution)
+                })
+                .collect();
+            (login, toml::Value::Array(contributions))
+        })
+        .collect();
+
+    let mut history = toml::Table::new();
+    history.insert(
+        "generated_at".to_string(),
+        toml::Value::String(now.to_rfc3339()),
+    );
+    history.insert("contributors".to_string(), toml::Value::Table(contributors));
+    history
 }
 
-/// Extract top contributors from attribution data with enriched statistics
-fn extract_top_contributors(feedstocks_table: &toml::Table) -> Vec<toml::Value> {
-    // Aggregate contributions by contributor
-    let mut contributor_stats: HashMap<String, ContributorData> = HashMap::new();
+/// Every recipe maintainer with the feedstocks they maintain, split into converted and
+/// unconverted ones. Most maintained first, then by login.
+fn extract_maintainer_coverage(feedstocks_table: &toml::Table, now: DateTime<Utc>) -> toml::Table {
+    // Lowercased login -> (login as listed, converted, unconverted)
+    let mut maintainers: HashMap<String, (String, u32, Vec<String>)> = HashMap::new();
 
-    for (name, state) in feedstocks_table.iter() {
-        if let Some(attribution) = state.get("attribution").and_then(|a| a.as_table()) {
-            let contribution_type = attribution
-                .get("contribution_type")
-                .and_then(|t| t.as_str())
-                .unwrap_or("")
-                .to_string();
+    for (name, state) in feedstocks_table {
+        let is_v1 = is_v1_state(state);
+        let mut seen: Vec<String> = Vec::new();
+        for maintainer in state
+            .get("maintainers")
+            .and_then(|m| m.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|m| m.as_str())
+        {
+            let key = maintainer.to_lowercase();
+            if seen.contains(&key) {
+                continue;
+            }
+            let entry = maintainers
+                .entry(key.clone())
+                .or_insert_with(|| (maintainer.to_string(), 0, Vec::new()));
+            if is_v1 {
+                entry.1 += 1;
+            } else {
+                entry.2.push(name.clone());
+            }
+            seen.push(key);
+        }
+    }
 
-            let date = attribution
-                .get("date")
-                .and_then(|d| d.as_str())
-                .unwrap_or("")
-                .to_string();
+    let mut coverage: Vec<_> = maintainers.into_values().collect();
+    coverage.sort_by(|a, b| {
+        (b.1 as usize + b.2.len())
+            .cmp(&(a.1 as usize + a.2.len()))
+            .then_with(|| a.0.cmp(&b.0))
+    });
 
-            let downloads = state
-                .get("downloads")
-                .and_then(|d| d.as_integer())
-                .map(|d| d as u64)
-                .unwrap_or(0);
+    let maintainers = coverage
+        .into_iter()
+        .map(|(login, converted, unconverted)| {
+            let mut entry = toml::Table::new();
+            entry.insert("login".to_string(), toml::Value::String(login));
+            entry.insert(
+                "maintained".to_string(),
+                toml::Value::Integer(converted as i64 + unconverted.len() as i64),
+            );
+            entry.insert(
+                "converted".to_string(),
+                toml::Value::Integer(converted as i64),
+            );
+            entry.insert(
+                "unconverted".to_string(),
+                toml::Value::Array(unconverted.into_iter().map(toml::Value::String).collect()),
+            );
+            toml::Value::Table(entry)
+        })
+        .collect();
 
-            let contributors = attribution
-                .get("contributors")
-                .and_then(|c| c.as_array())
-                .map(|arr| {
-                    arr.iter()
-                        .filter_map(|v| v.as_str().map(String::from))
-                        .collect::<Vec<_>>()
-                })
-                .unwrap_or_default();
+    let mut file = toml::Table::new();
+    file.insert(
+        "generated_at".to_string(),
+        toml::Value::String(now.to_rfc3339()),
+    );
+    file.insert("maintainers".to_string(), toml::Value::Array(maintainers));
+    file
+}
 
-            for contributor in contributors {
-                let entry = contributor_stats.entry(contributor).or_insert(ContributorData {
-                    conversions: 0,
-                    new_feedstocks: 0,
-                    total_downloads: 0,
-                    feedstocks: Vec::new(),
-                });
-
-                match contribution_type.as_str() {
-                    "conversion" => entry.conversions += 1,
-                    "new_feedstock" => entry.new_feedstocks += 1,
-                    _ => {}
-                }
+/// Extract top contributors from attribution data with enriched statistics, with the
+/// last `activity_weeks` of weekly activity each
+fn extract_top_contributors(
+    feedstocks_table: &toml::Table,
+    activity_weeks: usize,
+) -> Vec<toml::Value> {
+    let contributions = collect_contributions(feedstocks_table);
 
-                entry.total_downloads += downloads;
-                entry.feedstocks.push(FeedstockContribution {
-                    name: name.clone(),
-                    contribution_type: contribution_type.clone(),
-                    downloads,
-                    date: date.clone(),
-                });
+    // Aggregate the deduplicated contributions by contributor
+    let contributor_stats = contributions.into_iter().map(|(contributor, feedstocks)| {
+        let mut data = ContributorData {
+            conversions: 0,
+            new_feedstocks: 0,
+            self_conversions: 0,
+            total_downloads: 0,
+            feedstocks: Vec::new(),
+        };
+        for feedstock in feedstocks.into_values() {
+            match feedstock.contribution_type.as_str() {
+                "conversion" => data.conversions += 1,
+                "new_feedstock" => data.new_feedstocks += 1,
+                _ => {}
+            }
+            if feedstock.self_conversion {
+                data.self_conversions += 1;
             }
+            data.total_downloads += feedstock.downloads;
+            data.feedstocks.push(feedstock);
         }
-    }
+        (contributor, data)
+    });
 
     // Sort by total contributions (descending)
     let mut sorted: Vec<_> = contributor_stats.into_iter().collect();
@@ -259,6 +2224,22 @@ fn extract_top_contributors(feedstocks_table: &toml::Table) -> Vec<toml::Value>
                 "total_downloads".to_string(),
                 toml::Value::Integer(data.total_downloads as i64),
             );
+            entry.insert(
+                "self_conversions".to_string(),
+                toml::Value::Integer(data.self_conversions as i64),
+            );
+
+            // Day each achievement was earned, shown in the badge tooltips
+            let earned: toml::Table = achievement_dates(&data.feedstocks)
+                .into_iter()
+                .filter_map(|(achievement, date)| {
+                    Some((achievement.id.to_string(), toml::Value::String(date?)))
+                })
+                .collect();
+            entry.insert(
+                "achievements_earned".to_string(),
+                toml::Value::Table(earned),
+            );
 
             // Find first and last contribution dates
             let mut dates: Vec<&str> = data
@@ -283,7 +2264,7 @@ fn extract_top_contributors(feedstocks_table: &toml::Table) -> Vec<toml::Value>
             }
 
             // Compute weekly activity from ALL feedstocks (before truncating)
-            let weekly_activity = compute_weekly_activity(&data.feedstocks);
+            let weekly_activity = compute_weekly_activity(&data.feedstocks, activity_weeks);
             let weekly_array: Vec<toml::Value> = weekly_activity
                 .into_iter()
                 .map(|(conv, new)| {
@@ -297,7 +2278,27 @@ fn extract_top_contributors(feedstocks_table: &toml::Table) -> Vec<toml::Value>
 
             // Sort feedstocks by downloads (descending) and take top 10
             let mut sorted_feedstocks = data.feedstocks;
-            sorted_feedstocks.sort_by(|a, b| b.downloads.cmp(&a.downloads));
+            sorted_feedstocks.sort_by_key(|f| std::cmp::Reverse(f.downloads));
+
+            // Most downloaded package this contributor converted (not created)
+            if let Some(largest) = sorted_feedstocks
+                .iter()
+                .find(|f| f.contribution_type == "conversion")
+            {
+                let mut conversion = toml::Table::new();
+                conversion.insert(
+                    "name".to_string(),
+                    toml::Value::String(largest.name.clone()),
+                );
+                conversion.insert(
+                    "downloads".to_string(),
+                    toml::Value::Integer(largest.downloads as i64),
+                );
+                entry.insert(
+                    "largest_conversion".to_string(),
+                    toml::Value::Table(conversion),
+                );
+            }
             sorted_feedstocks.truncate(10);
 
             // Find top package
@@ -308,6 +2309,9 @@ fn extract_top_contributors(feedstocks_table: &toml::Table) -> Vec<toml::Value>
                     "downloads".to_string(),
                     toml::Value::Integer(top.downloads as i64),
                 );
+                if let Some(summary) = &top.summary {
+                    top_pkg.insert("summary".to_string(), toml::Value::String(summary.clone()));
+                }
                 entry.insert("top_package".to_string(), toml::Value::Table(top_pkg));
             }
 
@@ -326,6 +2330,12 @@ fn extract_top_contributors(feedstocks_table: &toml::Table) -> Vec<toml::Value>
                         toml::Value::Integer(f.downloads as i64),
                     );
                     fs.insert("date".to_string(), toml::Value::String(f.date));
+                    if let Some(summary) = f.summary {
+                        fs.insert("summary".to_string(), toml::Value::String(summary));
+                    }
+                    if f.self_conversion {
+                        fs.insert("self_conversion".to_string(), toml::Value::Boolean(true));
+                    }
                     toml::Value::Table(fs)
                 })
                 .collect();
@@ -335,3 +2345,1061 @@ fn extract_top_contributors(feedstocks_table: &toml::Table) -> Vec<toml::Value>
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|a| a.to_string()));
+
+        assert_eq!(parse(&[]), Ok(Args::default()));
+        assert_eq!(
+            parse(&["--input", "old.toml", "--only-web"]),
+            Ok(Args {
+                input: Some(PathBuf::from("old.toml")),
+                output: None,
+                root: None,
+                activity_weeks: None,
+                only_web: true,
+            })
+        );
+        assert_eq!(
+            parse(&["--output", "out.toml"]).unwrap().output,
+            Some(PathBuf::from("out.toml"))
+        );
+        assert_eq!(
+            parse(&["--root", "/data/site"]).unwrap().root,
+            Some(PathBuf::from("/data/site"))
+        );
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--root"]).is_err());
+        assert_eq!(
+            parse(&["--activity-weeks", "20"]).unwrap().activity_weeks,
+            Some(20)
+        );
+        assert!(parse(&["--activity-weeks", "0"]).is_err());
+        assert!(parse(&["--activity-weeks", "many"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_recipe_v1_count() {
+        let stats = |version: &str| -> toml::Table {
+            toml::from_str(&format!(
+                "{}recipe_v1_count = 10\nnon_standard_v1_count = 2",
+                version
+            ))
+            .unwrap()
+        };
+        assert_eq!(recipe_v1_count(&stats("")), Some(12));
+        assert_eq!(recipe_v1_count(&stats("schema_version = 1\n")), Some(12));
+        assert_eq!(recipe_v1_count(&stats("schema_version = 2\n")), Some(10));
+        assert_eq!(
+            recipe_v1_count(&stats("stats_format_version = 1\n")),
+            Some(12)
+        );
+        assert_eq!(
+            recipe_v1_count(&stats("stats_format_version = 2\n")),
+            Some(10)
+        );
+        assert_eq!(recipe_v1_count(&toml::Table::new()), None);
+
+        let state = |recipe_type: &str| {
+            toml::Value::Table(
+                toml::from_str(&format!("recipe_type = \"{}\"", recipe_type)).unwrap(),
+            )
+        };
+        assert!(is_v1_state(&state("recipe_v1")));
+        assert!(is_v1_state(&state("non_standard_v1")));
+        assert!(!is_v1_state(&state("meta_yaml")));
+    }
+
+    #[test]
+    fn test_merge_feedstock_shards() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("feedstock-states")).unwrap();
+        fs::write(
+            dir.path().join("feedstock-states/l-o.toml"),
+            "[feedstock_states.numpy-feedstock]\nrecipe_type = \"recipe_v1\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("feedstock-states/s-z.toml"),
+            "[feedstock_states.scipy-feedstock]\nrecipe_type = \"meta_yaml\"\n",
+        )
+        .unwrap();
+        let mut stats: toml::Table = toml::from_str(
+            r#"
+            feedstock_shards = ["feedstock-states/l-o.toml", "feedstock-states/s-z.toml"]
+            total_feedstocks = 2
+            "#,
+        )
+        .unwrap();
+
+        merge_feedstock_shards(&mut stats, &dir.path().join("feedstock-stats.toml"));
+        let states = stats["feedstock_states"].as_table().unwrap();
+        assert_eq!(states.len(), 2);
+        assert_eq!(
+            states["numpy-feedstock"]["recipe_type"].as_str(),
+            Some("recipe_v1")
+        );
+    }
+
+    fn contributor<'a>(top: &'a [toml::Value], name: &str) -> &'a toml::Value {
+        top.iter()
+            .find(|c| c.get("name").and_then(|n| n.as_str()) == Some(name))
+            .unwrap_or_else(|| panic!("{name} is not in the leaderboard"))
+    }
+
+    fn count(contributor: &toml::Value, key: &str) -> i64 {
+        contributor.get(key).and_then(|v| v.as_integer()).unwrap()
+    }
+
+    #[test]
+    fn test_add_activity_flag() {
+        let collected_at = DateTime::parse_from_rfc3339("2025-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let entry = |pushed_at: Option<&str>| {
+            let mut entry = toml::Table::new();
+            if let Some(pushed_at) = pushed_at {
+                entry.insert("pushed_at".to_string(), pushed_at.into());
+            }
+            add_activity_flag(&mut entry, collected_at);
+            entry.get("active").and_then(|v| v.as_bool())
+        };
+
+        assert_eq!(entry(Some("2025-05-20T08:00:00Z")), Some(true));
+        assert_eq!(entry(Some("2024-11-02T08:00:00Z")), Some(false));
+        assert_eq!(entry(None), None);
+    }
+
+    #[test]
+    fn test_add_conversion_links() {
+        let mut entry: toml::Table = toml::from_str(r#"name = "python_abi-feedstock""#).unwrap();
+        add_conversion_links(&mut entry);
+        let link = |key: &str| entry.get(key).and_then(|v| v.as_str()).unwrap();
+        assert_eq!(
+            link("fork_url"),
+            "https://github.com/conda-forge/python_abi-feedstock/fork"
+        );
+        // The default branch, whatever its name
+        assert_eq!(
+            link("edit_url"),
+            "https://github.com/conda-forge/python_abi-feedstock/edit/HEAD/recipe/meta.yaml"
+        );
+        assert_eq!(link("pr_title"), "Convert python_abi to recipe v1");
+        assert!(entry.get("pr_url").is_none());
+
+        let mut unnamed = toml::Table::new();
+        add_conversion_links(&mut unnamed);
+        assert!(unnamed.is_empty());
+    }
+
+    #[test]
+    fn test_add_rank_changes() {
+        let leaderboard = |names: &[&str]| -> Vec<toml::Value> {
+            names
+                .iter()
+                .map(|name| {
+                    let mut entry = toml::Table::new();
+                    entry.insert("name".to_string(), (*name).into());
+                    toml::Value::Table(entry)
+                })
+                .collect()
+        };
+        let previous = leaderboard(&["alice", "Bob", "carol"]);
+        let mut current = leaderboard(&["bob", "carol", "alice", "dave"]);
+
+        add_rank_changes(&mut current, &previous);
+        let changes: Vec<_> = current
+            .iter()
+            .map(|c| c.get("rank_change").and_then(|v| v.as_integer()))
+            .collect();
+        assert_eq!(changes, vec![Some(1), Some(1), Some(-2), None]);
+    }
+
+    #[test]
+    fn test_previous_week_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        for (date, contributor) in [
+            ("2025-03-01", "old"),
+            ("2025-03-03", "week"),
+            ("2025-03-08", "recent"),
+        ] {
+            let summary = format!("[[top_contributors]]\nname = \"{}\"\n", contributor);
+            write_snapshot(dir.path(), date, &summary).unwrap();
+        }
+
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let (date, snapshot) = previous_week_snapshot(dir.path(), today).unwrap();
+        assert_eq!(date.to_string(), "2025-03-03");
+        assert_eq!(
+            snapshot["top_contributors"][0]["name"].as_str(),
+            Some("week")
+        );
+
+        let too_early = NaiveDate::from_ymd_opt(2025, 3, 7).unwrap();
+        assert!(previous_week_snapshot(dir.path(), too_early).is_none());
+    }
+
+    #[test]
+    fn test_recreated_feedstock_is_credited_once() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [foo-feedstock]
+            recipe_type = "recipe_v1"
+            downloads = 100
+            attribution = { contribution_type = "conversion", contributors = ["alice"], date = "2025-01-01T00:00:00Z" }
+
+            [Foo]
+            recipe_type = "recipe_v1"
+            downloads = 100
+            attribution = { contribution_type = "new_feedstock", contributors = ["alice", "bob"], date = "2025-06-01T00:00:00Z" }
+
+            [bar-feedstock]
+            recipe_type = "recipe_v1"
+            downloads = 10
+            attribution = { contribution_type = "new_feedstock", contributors = ["alice"], date = "2025-02-01T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+
+        let top = extract_top_contributors(&feedstocks, DEFAULT_ACTIVITY_WEEKS);
+        let alice = contributor(&top, "alice");
+        // The earliest credit for foo wins over the later re-attribution
+        assert_eq!(count(alice, "conversions"), 1);
+        assert_eq!(count(alice, "new_feedstocks"), 1);
+        assert_eq!(count(alice, "total_downloads"), 110);
+        assert_eq!(alice["feedstocks"].as_array().unwrap().len(), 2);
+
+        let bob = contributor(&top, "bob");
+        assert_eq!(count(bob, "new_feedstocks"), 1);
+        let weeks = |top: &[toml::Value]| {
+            contributor(top, "alice")["weekly_activity"]
+                .as_array()
+                .unwrap()
+                .len()
+        };
+        assert_eq!(weeks(&top), DEFAULT_ACTIVITY_WEEKS);
+        assert_eq!(weeks(&extract_top_contributors(&feedstocks, 20)), 20);
+
+        // Only conversions count towards the largest conversion
+        assert_eq!(
+            alice["largest_conversion"]["name"].as_str(),
+            Some("foo-feedstock")
+        );
+        assert!(bob.get("largest_conversion").is_none());
+        let biggest = extract_biggest_conversion(&feedstocks).unwrap();
+        assert_eq!(biggest["feedstock"].as_str(), Some("foo-feedstock"));
+        assert_eq!(biggest["downloads"].as_integer(), Some(100));
+
+        // The full history keeps every contribution, oldest first
+        let now = DateTime::parse_from_rfc3339("2025-07-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let history = extract_contribution_history(&feedstocks, now);
+        assert_eq!(
+            history["generated_at"].as_str(),
+            Some("2025-07-01T00:00:00+00:00")
+        );
+        let alice = history["contributors"]["alice"].as_array().unwrap();
+        let dates: Vec<_> = alice.iter().map(|c| c["date"].as_str().unwrap()).collect();
+        assert_eq!(dates, ["2025-01-01T00:00:00Z", "2025-02-01T00:00:00Z"]);
+        assert_eq!(alice[0]["contribution_type"].as_str(), Some("conversion"));
+    }
+
+    #[test]
+    fn test_extract_contributor_retention() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [a-feedstock]
+            attribution = { contribution_type = "conversion", contributors = ["alice", "bob"], date = "2025-01-10T00:00:00Z" }
+
+            [b-feedstock]
+            attribution = { contribution_type = "conversion", contributors = ["Alice"], date = "2025-02-03T00:00:00Z" }
+
+            [c-feedstock]
+            attribution = { contribution_type = "new_feedstock", contributors = ["carol"], date = "2025-04-20T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+
+        let retention = extract_contributor_retention(&feedstocks);
+        let rows: Vec<_> = retention
+            .iter()
+            .map(|r| {
+                (
+                    r["month"].as_str().unwrap(),
+                    r["active"].as_integer().unwrap(),
+                    r["retained"].as_integer().unwrap(),
+                    r["churned"].as_integer().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("2025-01", 2, 0, 0),
+                ("2025-02", 1, 1, 1),
+                ("2025-03", 0, 0, 1),
+                ("2025-04", 1, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_contributor_growth() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [a-feedstock]
+            recipe_type = "recipe_v1"
+            attribution = { contribution_type = "conversion", contributors = ["alice", "unknown"], date = "2025-01-10T00:00:00Z" }
+
+            [b-feedstock]
+            recipe_type = "non_standard_v1"
+            attribution = { contribution_type = "new_feedstock", contributors = ["bob"], date = "2025-03-03T00:00:00Z" }
+
+            [c-feedstock]
+            recipe_type = "recipe_v1"
+            attribution = { contribution_type = "conversion", contributors = ["alice"], date = "2024-12-20T00:00:00Z" }
+
+            # Reverted, so carol never joins
+            [d-feedstock]
+            recipe_type = "meta_yaml"
+            attribution = { contribution_type = "conversion", contributors = ["carol"], date = "2024-11-01T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+
+        let (total, growth) = extract_contributor_growth(&feedstocks);
+        assert_eq!(total, 2);
+        let rows: Vec<_> = growth
+            .iter()
+            .map(|r| {
+                (
+                    r["month"].as_str().unwrap(),
+                    r["contributors"].as_integer().unwrap(),
+                )
+            })
+            .collect();
+        // Counted from their earliest month, across the year boundary and a quiet month
+        assert_eq!(
+            rows,
+            [
+                ("2024-12", 1),
+                ("2025-01", 1),
+                ("2025-02", 1),
+                ("2025-03", 2)
+            ]
+        );
+
+        let (total, growth) = extract_contributor_growth(&toml::Table::new());
+        assert_eq!(total, 0);
+        assert!(growth.is_empty());
+    }
+
+    #[test]
+    fn test_extract_data_quality() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [high-feedstock]
+            recipe_type = "recipe_v1"
+            downloads = 10
+            attribution = { contribution_type = "conversion", contributors = ["alice"], date = "2025-01-01T00:00:00Z", commit_sha = "abc" }
+
+            [medium-feedstock]
+            recipe_type = "non_standard_v1"
+            downloads = 10
+            attribution = { contribution_type = "conversion", contributors = ["bob"], date = "2025-01-01T00:00:00Z" }
+
+            [low-feedstock]
+            recipe_type = "recipe_v1"
+            attribution = { contribution_type = "conversion", contributors = ["alice", "unknown"], date = "2025-01-01T00:00:00Z", commit_sha = "def" }
+
+            [unattributed-feedstock]
+            recipe_type = "recipe_v1"
+            downloads = 10
+
+            [reverted-feedstock]
+            recipe_type = "meta_yaml"
+            downloads = 10
+            attribution = { contribution_type = "conversion", contributors = ["unknown"], date = "2025-01-01T00:00:00Z" }
+
+            [odd-feedstock]
+            recipe_type = "unknown"
+            "#,
+        )
+        .unwrap();
+
+        let quality = extract_data_quality(&feedstocks);
+        let count = |table: &toml::Table, key: &str| table[key].as_integer().unwrap();
+        assert_eq!(count(&quality, "total_feedstocks"), 6);
+        assert_eq!(count(&quality, "missing_downloads"), 2);
+        assert_eq!(count(&quality, "unknown_recipe_types"), 1);
+        // Unresolved logins are counted on every feedstock, reverted or not
+        assert_eq!(count(&quality, "unresolved_logins"), 2);
+        assert_eq!(count(&quality, "v1_feedstocks"), 4);
+
+        let confidence = quality["attribution_confidence"].as_table().unwrap();
+        let levels: Vec<_> = ["high", "medium", "low", "unattributed"]
+            .into_iter()
+            .map(|level| count(confidence, level))
+            .collect();
+        assert_eq!(levels, [1, 1, 1, 1]);
+        assert_eq!(
+            schema_mismatch::<schema::DataQuality>(&toml::to_string(&quality).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_duplicate_contributors_are_credited_once() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [foo-feedstock]
+            maintainers = ["alice"]
+            attribution = { contribution_type = "conversion", contributors = ["alice", "Alice", "alice"], date = "2025-01-01T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+
+        let top = extract_top_contributors(&feedstocks, DEFAULT_ACTIVITY_WEEKS);
+        assert_eq!(top.len(), 1);
+        let alice = contributor(&top, "alice");
+        assert_eq!(count(alice, "conversions"), 1);
+        assert_eq!(count(alice, "self_conversions"), 1);
+    }
+
+    #[test]
+    fn test_extract_recently_updated_window() {
+        let now = DateTime::parse_from_rfc3339("2025-03-31T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let feedstock = |days_ago: i64| {
+            let mut state = toml::Table::new();
+            state.insert("recipe_type".into(), "recipe_v1".into());
+            let date = now - chrono::Duration::days(days_ago);
+            state.insert("last_changed".into(), date.to_rfc3339().into());
+            toml::Value::Table(state)
+        };
+
+        // Only a few updates in the window: topped up to the minimum
+        let quiet: toml::Table = (0..20)
+            .map(|i| (format!("f{i}-feedstock"), feedstock(i * 10)))
+            .collect();
+        assert_eq!(
+            extract_recently_updated(&quiet, now).len(),
+            RECENT_MIN_ENTRIES
+        );
+
+        // A busy window: everything within it, up to the maximum
+        let busy: toml::Table = (0..30)
+            .map(|i| (format!("f{i}-feedstock"), feedstock(i / 2)))
+            .chain((0..5).map(|i| (format!("old{i}-feedstock"), feedstock(100))))
+            .collect();
+        assert_eq!(extract_recently_updated(&busy, now).len(), 30);
+        let crowded: toml::Table = (0..150)
+            .map(|i| (format!("f{i}-feedstock"), feedstock(1)))
+            .collect();
+        assert_eq!(
+            extract_recently_updated(&crowded, now).len(),
+            RECENT_MAX_ENTRIES
+        );
+    }
+
+    #[test]
+    fn test_extract_contributor_mix() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [a-feedstock]
+            attribution = { contribution_type = "new_feedstock", contributors = ["alice"], date = "2025-01-10T00:00:00Z" }
+
+            [b-feedstock]
+            attribution = { contribution_type = "conversion", contributors = ["Alice"], date = "2025-01-20T00:00:00Z" }
+
+            [c-feedstock]
+            attribution = { contribution_type = "conversion", contributors = ["alice"], date = "2025-03-02T00:00:00Z" }
+
+            [d-feedstock]
+            attribution = { contribution_type = "conversion", contributors = ["alice", "bob"], date = "2025-03-05T00:00:00Z" }
+
+            [e-feedstock]
+            attribution = { contribution_type = "conversion", contributors = ["unknown"], date = "2025-03-09T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+
+        let mix = extract_contributor_mix(&feedstocks);
+        let rows: Vec<_> = mix
+            .iter()
+            .map(|m| {
+                (
+                    m["month"].as_str().unwrap(),
+                    m["new"].as_integer().unwrap(),
+                    m["returning"].as_integer().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [("2025-01", 1, 0), ("2025-02", 0, 0), ("2025-03", 1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_extract_conversion_cohorts() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [a-feedstock]
+            recipe_type = "recipe_v1"
+            build_state = "SUCCESS"
+            attribution = { contribution_type = "conversion", date = "2025-01-10T00:00:00Z" }
+            follow_up = { converted_at = "2025-01-10T00:00:00Z", fix_pr = 12 }
+
+            [b-feedstock]
+            recipe_type = "meta_yaml"
+            attribution = { contribution_type = "conversion", date = "2025-01-20T00:00:00Z" }
+            revert = { converted_at = "2025-01-20T00:00:00Z", reverted_at = "2025-03-01T00:00:00Z" }
+            follow_up = { converted_at = "2025-01-20T00:00:00Z" }
+
+            [c-feedstock]
+            recipe_type = "recipe_v1"
+            build_state = "FAILURE"
+            attribution = { contribution_type = "conversion", date = "2025-05-02T00:00:00Z" }
+
+            [d-feedstock]
+            recipe_type = "recipe_v1"
+            attribution = { contribution_type = "new_feedstock", date = "2025-01-05T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+        let now = DateTime::parse_from_rfc3339("2025-06-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let cohorts = extract_conversion_cohorts(&feedstocks, now);
+        assert_eq!(cohorts.len(), 2);
+
+        let january = &cohorts[0];
+        assert_eq!(january["cohort"].as_str(), Some("2025-01"));
+        assert_eq!(january["converted"].as_integer(), Some(2));
+        assert_eq!(january["still_v1"].as_integer(), Some(1));
+        assert_eq!(january["passing"].as_integer(), Some(1));
+        assert_eq!(january["retained_1m"].as_integer(), Some(2));
+        assert_eq!(january["retained_3m"].as_integer(), Some(1));
+        assert!(january.get("retained_6m").is_none());
+        assert_eq!(january["follow_up_checked"].as_integer(), Some(2));
+        assert_eq!(january["follow_up_fixed"].as_integer(), Some(1));
+
+        let may = &cohorts[1];
+        assert_eq!(may["cohort"].as_str(), Some("2025-05"));
+        assert_eq!(may["still_v1"].as_integer(), Some(1));
+        assert_eq!(may["passing"].as_integer(), Some(0));
+        assert!(may.get("retained_1m").is_none());
+    }
+
+    #[test]
+    fn test_extract_weekly_v1_growth() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [a-feedstock.attribution]
+            contribution_type = "conversion"
+            date = "2025-03-05T10:00:00Z"
+
+            [b-feedstock.attribution]
+            contribution_type = "new_feedstock"
+            date = "2025-03-03T00:00:00Z"
+
+            [c-feedstock.attribution]
+            contribution_type = "conversion"
+            date = "2025-03-19T00:00:00Z"
+
+            [d-feedstock]
+            recipe_type = "meta_yaml"
+            "#,
+        )
+        .unwrap();
+        let now = DateTime::parse_from_rfc3339("2025-03-26T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let weeks = extract_weekly_v1_growth(&feedstocks, now);
+        let rows: Vec<_> = weeks
+            .iter()
+            .map(|w| {
+                (
+                    w["week"].as_str().unwrap(),
+                    w["conversions"].as_integer().unwrap(),
+                    w["new_feedstocks"].as_integer().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("2025-03-03", 1, 1),
+                ("2025-03-10", 0, 0),
+                ("2025-03-17", 1, 0),
+                ("2025-03-24", 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_notable_recent() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [numpy-feedstock]
+            downloads = 5000000
+            [numpy-feedstock.attribution]
+            contribution_type = "conversion"
+            contributors = ["alice"]
+            date = "2025-03-01T00:00:00Z"
+
+            [scipy-feedstock]
+            downloads = 2000000
+            [scipy-feedstock.attribution]
+            contribution_type = "conversion"
+            contributors = ["bob"]
+            date = "2025-03-05T00:00:00Z"
+
+            [tiny-feedstock]
+            downloads = 1000
+            [tiny-feedstock.attribution]
+            contribution_type = "conversion"
+            date = "2025-03-06T00:00:00Z"
+
+            [fresh-feedstock]
+            downloads = 9000000
+            [fresh-feedstock.attribution]
+            contribution_type = "new_feedstock"
+            date = "2025-03-07T00:00:00Z"
+            "#,
+        )
+        .unwrap();
+
+        let notable = extract_notable_recent(&feedstocks);
+        let names: Vec<_> = notable
+            .iter()
+            .map(|n| n["feedstock"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["scipy-feedstock", "numpy-feedstock"]);
+        assert_eq!(notable[0]["contributors"][0].as_str(), Some("bob"));
+    }
+
+    #[test]
+    fn test_extract_pending_migrations() {
+        let stats: toml::Table = toml::from_str(
+            r#"
+            [[pending_migration_prs]]
+            feedstock = "a-feedstock"
+            number = 12
+            opened_at = "2025-05-01T08:00:00Z"
+
+            [[pending_migration_prs]]
+            feedstock = "b-feedstock"
+            number = 7
+            opened_at = "2025-04-20T08:00:00Z"
+
+            [[pending_migration_prs]]
+            feedstock = "merged-feedstock"
+            number = 3
+            opened_at = "2025-04-01T08:00:00Z"
+
+            [feedstock_states.a-feedstock]
+            recipe_type = "meta_yaml"
+            downloads = 10
+
+            [feedstock_states.b-feedstock]
+            recipe_type = "meta_yaml"
+            downloads = 500
+
+            [feedstock_states.merged-feedstock]
+            recipe_type = "recipe_v1"
+            "#,
+        )
+        .unwrap();
+        let prs = stats["pending_migration_prs"].as_array().unwrap();
+        let feedstocks = stats["feedstock_states"].as_table().unwrap();
+
+        let pending = extract_pending_migrations(prs, feedstocks);
+        let rows: Vec<_> = pending
+            .iter()
+            .map(|p| {
+                (
+                    p["name"].as_str().unwrap(),
+                    p["number"].as_integer().unwrap(),
+                    p["opened"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("b-feedstock", 7, "2025-04-20"),
+                ("a-feedstock", 12, "2025-05-01"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_events() {
+        let events: toml::Table = toml::from_str(
+            r#"
+            [[event]]
+            date = "2025-03-01"
+            title = "Later"
+
+            [[event]]
+            date = "2025-02-20"
+            title = "Earlier"
+            url = "https://example.com"
+
+            [[event]]
+            date = "2025-04-01"
+            title = "Upcoming"
+
+            [[event]]
+            date = "March"
+            title = "Malformed"
+            "#,
+        )
+        .unwrap();
+        let now = DateTime::parse_from_rfc3339("2025-03-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let events = extract_events(&events, now);
+        let titles: Vec<_> = events
+            .iter()
+            .map(|e| e["title"].as_str().unwrap())
+            .collect();
+        assert_eq!(titles, vec!["Earlier", "Later", "Upcoming"]);
+        assert_eq!(events[0]["month"].as_str(), Some("2025-02"));
+        assert_eq!(events[0]["weeks_ago"].as_integer(), Some(3));
+        assert_eq!(events[1]["weeks_ago"].as_integer(), Some(2));
+        // Future events only show on the monthly chart
+        assert!(events[2].get("weeks_ago").is_none());
+    }
+
+    #[test]
+    fn test_validate_profile() {
+        let profiles: toml::Table = toml::from_str(
+            r#"
+            [alice]
+            display_name = "  Alice Smith "
+            affiliation = ""
+            url = "http://alice.dev"
+            twitter = "@alice"
+
+            [bob]
+            url = "https://bob.dev"
+            display_name = 42
+            "#,
+        )
+        .unwrap();
+
+        let (alice, problems) = validate_profile("alice", &profiles["alice"]);
+        assert_eq!(alice.len(), 1);
+        assert_eq!(alice["display_name"].as_str(), Some("Alice Smith"));
+        assert_eq!(problems.len(), 3);
+
+        let (bob, problems) = validate_profile("bob", &profiles["bob"]);
+        assert_eq!(bob["url"].as_str(), Some("https://bob.dev"));
+        assert_eq!(problems, vec!["bob.display_name: expected a string"]);
+    }
+
+    #[test]
+    fn test_add_contributor_profiles() {
+        let mut top: Vec<toml::Value> = vec![
+            toml::from_str(r#"name = "Alice""#).unwrap(),
+            toml::from_str(r#"name = "bob""#).unwrap(),
+        ];
+        let profiles: toml::Table = toml::from_str(
+            r#"
+            alice = { display_name = "Alice Smith" }
+            bob = { twitter = "@bob" }
+            "#,
+        )
+        .unwrap();
+
+        add_contributor_profiles(&mut top, &profiles);
+        assert_eq!(
+            top[0]["profile"]["display_name"].as_str(),
+            Some("Alice Smith")
+        );
+        // Nothing valid left for bob
+        assert!(top[1].get("profile").is_none());
+    }
+
+    #[test]
+    fn test_add_contributor_identities() {
+        let mut top: Vec<toml::Value> = vec![
+            toml::from_str(r#"name = "Alice""#).unwrap(),
+            toml::from_str(r#"name = "gone""#).unwrap(),
+            toml::from_str(r#"name = "unknown""#).unwrap(),
+        ];
+        let identities = serde_json::json!({
+            "contributors": {
+                "alice": {
+                    "id": "U_1",
+                    "avatar_url": "https://avatars.githubusercontent.com/u/1",
+                    "exists": true,
+                    "renamed_to": "alice-new",
+                    "fetched_at": "2025-06-01T00:00:00Z",
+                },
+                "gone": { "exists": false, "fetched_at": "2025-06-01T00:00:00Z" },
+            }
+        });
+
+        add_contributor_identities(&mut top, &identities);
+        assert_eq!(top[0]["github"]["renamed_to"].as_str(), Some("alice-new"));
+        assert!(top[0]["github"].get("id").is_none());
+        assert_eq!(top[1]["github"]["exists"].as_bool(), Some(false));
+        assert!(top[2].get("github").is_none());
+    }
+
+    #[test]
+    fn test_extract_completionists() {
+        let feedstock = |recipe_type: &str, maintainers: &[&str], contributors: &[&str]| {
+            toml::Value::Table(toml::Table::from_iter([
+                ("recipe_type".to_string(), recipe_type.into()),
+                ("maintainers".to_string(), maintainers.to_vec().into()),
+                (
+                    "attribution".to_string(),
+                    toml::Table::from_iter([(
+                        "contributors".to_string(),
+                        contributors.to_vec().into(),
+                    )])
+                    .into(),
+                ),
+            ]))
+        };
+        let mut feedstocks = toml::Table::new();
+        // alice converted 4 of her 5 feedstocks, bob 5 of his 10
+        for i in 0..10 {
+            let (maintainers, contributors): (&[&str], &[&str]) = match i {
+                0..4 => (&["Alice", "bob"], &["alice"]),
+                4 => (&["Alice", "bob"], &[]),
+                _ => (&["bob", "bob"], &["bob"]),
+            };
+            let recipe_type = if contributors.is_empty() {
+                "meta_yaml"
+            } else {
+                "recipe_v1"
+            };
+            feedstocks.insert(
+                format!("pkg{i}-feedstock"),
+                feedstock(recipe_type, maintainers, contributors),
+            );
+        }
+        // carol maintains too few feedstocks to be ranked
+        feedstocks.insert(
+            "solo-feedstock".to_string(),
+            feedstock("recipe_v1", &["carol"], &["carol"]),
+        );
+
+        let ranked = extract_completionists(&feedstocks);
+        let names: Vec<_> = ranked.iter().map(|c| c["name"].as_str().unwrap()).collect();
+        assert_eq!(names, vec!["Alice", "bob"]);
+        assert_eq!(ranked[0]["maintained"].as_integer(), Some(5));
+        assert_eq!(ranked[0]["converted"].as_integer(), Some(4));
+        // Listed twice in a recipe still counts once
+        assert_eq!(ranked[1]["maintained"].as_integer(), Some(10));
+        assert_eq!(ranked[1]["converted"].as_integer(), Some(5));
+    }
+
+    #[test]
+    fn test_self_conversions() {
+        let feedstock = |contribution_type: &str, maintainers: &[&str], contributors: &[&str]| {
+            toml::Value::Table(toml::Table::from_iter([
+                ("recipe_type".to_string(), "recipe_v1".into()),
+                ("maintainers".to_string(), maintainers.to_vec().into()),
+                (
+                    "attribution".to_string(),
+                    toml::Table::from_iter([
+                        ("contribution_type".to_string(), contribution_type.into()),
+                        ("contributors".to_string(), contributors.to_vec().into()),
+                    ])
+                    .into(),
+                ),
+            ]))
+        };
+
+        let own = feedstock("conversion", &["Alice", "bob"], &["alice"]);
+        assert!(is_self_conversion(&own, "conversion", "alice"));
+        assert!(!is_self_conversion(&own, "conversion", "carol"));
+        // Creating a feedstock on Recipe v1 isn't converting it
+        assert!(!is_self_conversion(&own, "new_feedstock", "alice"));
+        // Without maintainers nobody converts their own feedstock
+        let unmaintained = feedstock("conversion", &[], &["alice"]);
+        assert!(!is_self_conversion(&unmaintained, "conversion", "alice"));
+
+        let feedstocks = toml::Table::from_iter([
+            ("own-feedstock".to_string(), own),
+            // Two maintainers credited together count as one self-conversion
+            (
+                "shared-feedstock".to_string(),
+                feedstock("conversion", &["alice", "bob"], &["alice", "bob"]),
+            ),
+            (
+                "outside-feedstock".to_string(),
+                feedstock("conversion", &["bob"], &["carol"]),
+            ),
+            (
+                "new-feedstock".to_string(),
+                feedstock("new_feedstock", &["alice"], &["alice"]),
+            ),
+            ("unmaintained-feedstock".to_string(), unmaintained),
+        ]);
+        assert_eq!(count_self_conversions(&feedstocks), 2);
+    }
+
+    #[test]
+    fn test_extract_maintainer_coverage() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [numpy-feedstock]
+            recipe_type = "recipe_v1"
+            maintainers = ["Alice", "bob"]
+
+            [scipy-feedstock]
+            recipe_type = "meta_yaml"
+            maintainers = ["alice", "alice"]
+
+            [orphan-feedstock]
+            recipe_type = "meta_yaml"
+            "#,
+        )
+        .unwrap();
+        let now = DateTime::parse_from_rfc3339("2025-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let coverage = extract_maintainer_coverage(&feedstocks, now);
+        let content = toml::to_string(&coverage).unwrap();
+        let file: schema::MaintainerCoverageFile = toml::from_str(&content).unwrap();
+        let rows: Vec<_> = file
+            .maintainers
+            .iter()
+            .map(|m| (m.login.as_str(), m.maintained, m.converted))
+            .collect();
+        // Listed twice in a recipe still counts once
+        assert_eq!(rows, [("Alice", 2, 1), ("bob", 1, 1)]);
+        assert_eq!(file.maintainers[0].unconverted, ["scipy-feedstock"]);
+        assert!(file.maintainers[1].unconverted.is_empty());
+        assert_eq!(
+            schema_mismatch::<schema::MaintainerCoverageFile>(&content),
+            None
+        );
+    }
+
+    #[test]
+    fn test_schema_mismatch() {
+        let valid = "generated_at = \"2025-06-01T00:00:00Z\"\n";
+        assert_eq!(
+            schema_mismatch::<schema::MaintainerCoverageFile>(valid),
+            None
+        );
+
+        let misspelled = format!("{}[[maintainer]]\nlogin = \"alice\"\n", valid);
+        assert_eq!(
+            schema_mismatch::<schema::MaintainerCoverageFile>(&misspelled).as_deref(),
+            Some("the site doesn't read maintainer")
+        );
+
+        let many: String = (0..7).map(|i| format!("extra_{} = {}\n", i, i)).collect();
+        let mismatch =
+            schema_mismatch::<schema::MaintainerCoverageFile>(&format!("{}{}", valid, many));
+        assert!(mismatch.unwrap().ends_with("extra_4 and 2 more"));
+
+        assert!(schema_mismatch::<schema::MaintainerCoverageFile>("maintainers = []").is_some());
+    }
+
+    #[test]
+    fn test_split_sections() {
+        let mut summary: toml::Table = toml::from_str(
+            "recipe_v1_count = 3\ncontributor_mix = []\n[conversion_forecast]\nlikely = []\n",
+        )
+        .unwrap();
+
+        let insights = split_sections(&mut summary, &INSIGHTS_SECTIONS);
+        assert_eq!(
+            insights.keys().collect::<Vec<_>>(),
+            ["contributor_mix", "conversion_forecast"]
+        );
+        assert_eq!(summary.keys().collect::<Vec<_>>(), ["recipe_v1_count"]);
+        assert_eq!(
+            schema_mismatch::<schema::InsightsFile>(&toml::to_string(&insights).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_package_key() {
+        assert_eq!(package_key("Foo-feedstock"), "foo");
+        assert_eq!(package_key("foo"), "foo");
+    }
+
+    #[test]
+    fn test_extract_achievements_dates_threshold_crossings() {
+        let mut feedstocks = toml::Table::new();
+        for i in 1..=12 {
+            let (contribution_type, maintainers) = match i {
+                3 => ("conversion", "[\"alice\"]"),
+                i if i % 2 == 0 => ("new_feedstock", "[]"),
+                _ => ("conversion", "[]"),
+            };
+            let state: toml::Value = toml::from_str(&format!(
+                r#"
+                recipe_type = "recipe_v1"
+                maintainers = {maintainers}
+                attribution = {{ contribution_type = "{contribution_type}", contributors = ["alice", "unknown"], date = "2025-01-{i:02}T10:00:00Z" }}
+                "#
+            ))
+            .unwrap();
+            feedstocks.insert(format!("pkg{i}-feedstock"), state);
+        }
+
+        let achievements = extract_achievements(&feedstocks);
+        assert!(achievements.get("unknown").is_none());
+        let earned: Vec<(&str, &str)> = achievements["alice"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| (a["id"].as_str().unwrap(), a["earned"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            earned,
+            vec![
+                ("homegrown", "2025-01-03"),
+                ("forge-apprentice", "2025-01-10")
+            ]
+        );
+
+        // The leaderboard carries the same dates for the badge tooltips
+        let top = extract_top_contributors(&feedstocks, DEFAULT_ACTIVITY_WEEKS);
+        let alice = contributor(&top, "alice");
+        assert_eq!(
+            alice["achievements_earned"]["forge-apprentice"].as_str(),
+            Some("2025-01-10")
+        );
+
+        // Newest unlock first
+        let feed = extract_achievement_feed(&feedstocks);
+        let feed: Vec<(&str, &str, &str)> = feed
+            .iter()
+            .map(|(login, achievement, date)| (login.as_str(), achievement.id, date.as_str()))
+            .collect();
+        assert_eq!(
+            feed,
+            vec![
+                ("alice", "forge-apprentice", "2025-01-10"),
+                ("alice", "homegrown", "2025-01-03")
+            ]
+        );
+    }
+}
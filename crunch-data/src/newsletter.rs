@@ -0,0 +1,366 @@
+//! Weekly digest e-mail, rendered as self-contained HTML that a mailing workflow can send.
+//!
+//! The e-mail is described as a list of blocks that expand to the table-based, inline-styled
+//! markup e-mail clients need, collapsing to the full width of small screens.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Length of the period the digest covers
+const DIGEST_DAYS: i64 = 7;
+/// Conversions listed in the digest
+const TOP_CONVERSIONS: usize = 10;
+/// Unconverted feedstocks suggested at the end of the digest
+const CALL_TO_ACTION: usize = 5;
+
+/// Building blocks of the e-mail
+enum Block {
+    Heading(String),
+    Text(String),
+    /// A big number with a caption
+    Stat {
+        value: String,
+        caption: String,
+    },
+    List(Vec<ListItem>),
+    Button {
+        label: String,
+        url: String,
+    },
+}
+
+struct ListItem {
+    title: String,
+    url: String,
+    detail: String,
+}
+
+/// Render the digest of the week up to `now`. `summary` is the crunched site data and
+/// `feedstocks_table` the collector's feedstock states; `site_url` links to the dashboard.
+pub fn render_newsletter(
+    summary: &toml::Table,
+    feedstocks_table: &toml::Table,
+    now: DateTime<Utc>,
+    site_url: Option<&str>,
+) -> String {
+    let since = now - Duration::days(DIGEST_DAYS);
+    let count = |key: &str| summary.get(key).and_then(|v| v.as_integer()).unwrap_or(0);
+    let (v1, total) = (count("recipe_v1_count"), count("total_feedstocks"));
+    let percent = if total == 0 {
+        0.0
+    } else {
+        v1 as f64 * 100.0 / total as f64
+    };
+
+    let converted = converted_since(feedstocks_table, since);
+    let mut blocks = vec![
+        Block::Heading("Are we Recipe v1 yet? Weekly digest".to_string()),
+        Block::Text(format!(
+            "Week of {} to {}",
+            since.format("%b %d"),
+            now.format("%b %d, %Y")
+        )),
+        Block::Stat {
+            value: format!("{:.1}%", percent),
+            caption: format!("of {} feedstocks are on Recipe v1", total),
+        },
+        Block::Stat {
+            value: converted.len().to_string(),
+            caption: "feedstocks moved to Recipe v1 this week".to_string(),
+        },
+        Block::Heading("Top conversions this week".to_string()),
+    ];
+
+    if converted.is_empty() {
+        blocks.push(Block::Text(
+            "No feedstocks were converted this week.".to_string(),
+        ));
+    } else {
+        blocks.push(Block::List(
+            converted
+                .into_iter()
+                .take(TOP_CONVERSIONS)
+                .map(|(name, downloads, contributors)| {
+                    let mut detail = format!("~{} downloads", format_count(downloads));
+                    if !contributors.is_empty() {
+                        let logins: Vec<_> =
+                            contributors.iter().map(|c| format!("@{}", c)).collect();
+                        detail = format!("by {} · {}", logins.join(", "), detail);
+                    }
+                    ListItem {
+                        title: package_name(&name).to_string(),
+                        url: format!("https://github.com/conda-forge/{}", name),
+                        detail,
+                    }
+                })
+                .collect(),
+        ));
+    }
+
+    let suggestions = call_to_action(summary);
+    if !suggestions.is_empty() {
+        blocks.push(Block::Heading("Help convert these next".to_string()));
+        blocks.push(Block::Text(
+            "The most downloaded feedstocks still on meta.yaml. Each link opens a pull request to convert it."
+                .to_string(),
+        ));
+        blocks.push(Block::List(suggestions));
+    }
+
+    if let Some(url) = site_url {
+        blocks.push(Block::Button {
+            label: "See the full dashboard".to_string(),
+            url: url.to_string(),
+        });
+    }
+
+    render_email("Recipe v1 weekly digest", &blocks)
+}
+
+/// Feedstocks converted since `since`, as (name, downloads, contributors), most downloaded first
+fn converted_since(
+    feedstocks_table: &toml::Table,
+    since: DateTime<Utc>,
+) -> Vec<(String, i64, Vec<String>)> {
+    let mut converted: Vec<_> = feedstocks_table
+        .iter()
+        .filter_map(|(name, state)| {
+            let attribution = state.get("attribution")?;
+            if !crate::is_v1_state(state)
+                || attribution.get("contribution_type")?.as_str()? != "conversion"
+            {
+                return None;
+            }
+            let changed = state.get("last_changed")?.as_str()?;
+            let changed = DateTime::parse_from_rfc3339(changed).ok()?;
+            if changed < since {
+                return None;
+            }
+            let downloads = state
+                .get("downloads")
+                .and_then(|d| d.as_integer())
+                .unwrap_or(0);
+            let contributors = attribution
+                .get("contributors")
+                .and_then(|c| c.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|c| c.as_str())
+                .filter(|c| *c != "unknown")
+                .map(String::from)
+                .collect();
+            Some((name.clone(), downloads, contributors))
+        })
+        .collect();
+    converted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    converted
+}
+
+/// Top unconverted feedstocks to suggest, those with all dependencies converted first
+fn call_to_action(summary: &toml::Table) -> Vec<ListItem> {
+    let mut candidates: Vec<&toml::Table> = summary
+        .get("top_unconverted_by_downloads")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_table())
+        .collect();
+    let is_ready = |table: &toml::Table| {
+        table
+            .get("blocked_by")
+            .and_then(|b| b.as_array())
+            .is_some_and(|b| b.is_empty())
+    };
+    // Stable, so each group stays ordered by downloads
+    candidates.sort_by_key(|table| !is_ready(table));
+
+    candidates
+        .into_iter()
+        .take(CALL_TO_ACTION)
+        .filter_map(|table| {
+            let name = table.get("name")?.as_str()?;
+            let downloads = table.get("downloads")?.as_integer()?;
+            let mut detail = format!("~{} downloads", format_count(downloads));
+            if is_ready(table) {
+                detail.push_str(" · all dependencies converted");
+            }
+            Some(ListItem {
+                title: package_name(name).to_string(),
+                url: table
+                    .get("edit_url")
+                    .and_then(|u| u.as_str())
+                    .map(String::from)
+                    .unwrap_or_else(|| format!("https://github.com/conda-forge/{}", name)),
+                detail,
+            })
+        })
+        .collect()
+}
+
+fn package_name(feedstock: &str) -> &str {
+    feedstock.trim_end_matches("-feedstock")
+}
+
+/// Compact count, e.g. 1234567 -> "1.2M"
+fn format_count(count: i64) -> String {
+    match count {
+        c if c >= 1_000_000 => format!("{:.1}M", c as f64 / 1_000_000.0),
+        c if c >= 1_000 => format!("{:.1}K", c as f64 / 1_000.0),
+        c => c.to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Expand the blocks into a complete HTML e-mail
+fn render_email(title: &str, blocks: &[Block]) -> String {
+    const FONT: &str = "font-family:Inter,Segoe UI,Helvetica,Arial,sans-serif;";
+
+    let rows: String = blocks
+        .iter()
+        .map(|block| {
+            let content = match block {
+                Block::Heading(text) => format!(
+                    r#"<h2 style="{FONT}margin:16px 0 0;font-size:20px;color:#111827">{}</h2>"#,
+                    escape(text)
+                ),
+                Block::Text(text) => format!(
+                    r#"<p style="{FONT}margin:0;font-size:14px;line-height:20px;color:#6b7280">{}</p>"#,
+                    escape(text)
+                ),
+                Block::Stat { value, caption } => format!(
+                    r#"<p style="{FONT}margin:0;font-size:32px;font-weight:700;color:#059669">{}</p><p style="{FONT}margin:0;font-size:14px;color:#6b7280">{}</p>"#,
+                    escape(value),
+                    escape(caption)
+                ),
+                Block::List(items) => items
+                    .iter()
+                    .map(|item| {
+                        format!(
+                            r#"<p style="{FONT}margin:0;padding:8px 0;border-bottom:1px dashed #e5e7eb;font-size:14px"><a href="{}" style="color:#2563eb;font-weight:600;text-decoration:none">{}</a><br><span style="color:#6b7280">{}</span></p>"#,
+                            escape(&item.url),
+                            escape(&item.title),
+                            escape(&item.detail)
+                        )
+                    })
+                    .collect(),
+                Block::Button { label, url } => format!(
+                    r#"<a href="{}" style="{FONT}display:inline-block;margin-top:16px;padding:10px 20px;border-radius:6px;background:#111827;color:#ffffff;font-size:14px;font-weight:600;text-decoration:none">{}</a>"#,
+                    escape(url),
+                    escape(label)
+                ),
+            };
+            format!(
+                r#"<tr><td class="px" style="padding:8px 32px">{}</td></tr>"#,
+                content
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<style>
+@media only screen and (max-width: 620px) {{
+  .container {{ width: 100% !important; border-radius: 0 !important; }}
+  .px {{ padding-left: 16px !important; padding-right: 16px !important; }}
+}}
+</style>
+</head>
+<body style="margin:0;padding:0;background:#f9fafb">
+<table role="presentation" width="100%" cellpadding="0" cellspacing="0" style="background:#f9fafb">
+<tr><td align="center" style="padding:24px 0">
+<table role="presentation" class="container" width="600" cellpadding="0" cellspacing="0" style="width:600px;max-width:600px;background:#ffffff;border:1px solid #e5e7eb;border-radius:8px;padding:16px 0">
+{rows}
+</table>
+</td></tr>
+</table>
+</body>
+</html>
+"#,
+        title = escape(title),
+        rows = rows
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newsletter_lists_this_weeks_conversions_and_suggestions() {
+        let feedstocks: toml::Table = toml::from_str(
+            r#"
+            [small-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-03-06T00:00:00Z"
+            downloads = 10
+            attribution = { contribution_type = "conversion", contributors = ["alice"], date = "2025-03-05T00:00:00Z" }
+
+            [big-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-03-05T00:00:00Z"
+            downloads = 2500000
+            attribution = { contribution_type = "conversion", contributors = ["bob"], date = "2025-03-04T00:00:00Z" }
+
+            [old-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-01-01T00:00:00Z"
+            attribution = { contribution_type = "conversion", contributors = ["carol"], date = "2025-01-01T00:00:00Z" }
+
+            [staged-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-03-06T00:00:00Z"
+            attribution = { contribution_type = "new_feedstock", contributors = ["dave"], date = "2025-03-06T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+        let summary: toml::Table = toml::from_str(
+            r#"
+            recipe_v1_count = 25
+            total_feedstocks = 100
+
+            [[top_unconverted_by_downloads]]
+            name = "blocked-feedstock"
+            downloads = 9000
+            blocked_by = ["lib-feedstock"]
+            edit_url = "https://github.com/conda-forge/blocked-feedstock/edit/HEAD/recipe/meta.yaml"
+
+            [[top_unconverted_by_downloads]]
+            name = "ready-feedstock"
+            downloads = 500
+            blocked_by = []
+            "#,
+        )
+        .unwrap();
+        let now = DateTime::parse_from_rfc3339("2025-03-07T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let html = render_newsletter(&summary, &feedstocks, now, Some("https://example.org/"));
+
+        assert!(html.contains("25.0%"));
+        let big = html.find(">big<").unwrap();
+        let small = html.find(">small<").unwrap();
+        assert!(big < small);
+        assert!(html.contains("by @bob · ~2.5M downloads"));
+        assert!(!html.contains(">old<"));
+        assert!(!html.contains(">staged<"));
+
+        // Ready feedstocks are suggested first, linking to the editor when there is a link
+        let ready = html.find(">ready<").unwrap();
+        let blocked = html.find(">blocked<").unwrap();
+        assert!(ready < blocked);
+        assert!(html.contains("blocked-feedstock/edit/HEAD/recipe/meta.yaml"));
+        assert!(html.contains(r#"href="https://github.com/conda-forge/ready-feedstock""#));
+        assert!(html.contains(r#"href="https://example.org/""#));
+    }
+}
@@ -0,0 +1,59 @@
+//! Static `<noscript>` summary inlined into the site's index.html, so visitors whose
+//! browser can't run the WebAssembly app still see the headline numbers.
+
+use chrono::DateTime;
+
+/// Render the fallback from the crunched site data
+pub fn render_noscript(summary: &toml::Table) -> String {
+    let count = |key: &str| summary.get(key).and_then(|v| v.as_integer()).unwrap_or(0);
+    let (v1, total) = (count("recipe_v1_count"), count("total_feedstocks"));
+    let percent = if total == 0 {
+        0.0
+    } else {
+        v1 as f64 * 100.0 / total as f64
+    };
+    let updated = summary
+        .get("last_updated")
+        .and_then(|v| v.as_str())
+        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+        .map(|d| format!("<p>Data as of {}.</p>\n", d.format("%B %-d, %Y")))
+        .unwrap_or_default();
+
+    format!(
+        r#"<noscript>
+<div style="max-width:40rem;margin:4rem auto;padding:0 1rem;font-family:Inter,Segoe UI,Helvetica,Arial,sans-serif;color:#111827;text-align:center">
+<h1>Are we Recipe v1 yet?</h1>
+<p style="font-size:3rem;font-weight:700;margin:1rem 0">{:.1}%</p>
+<p>{} of {} conda-forge feedstocks have moved from meta.yaml to recipe.yaml.</p>
+{}<p>Enable JavaScript and WebAssembly to see the full dashboard.</p>
+</div>
+</noscript>
+"#,
+        percent, v1, total, updated
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_noscript() {
+        let summary: toml::Table = toml::from_str(
+            r#"
+            total_feedstocks = 200
+            recipe_v1_count = 50
+            last_updated = "2025-03-05T10:00:00Z"
+            "#,
+        )
+        .unwrap();
+
+        let html = render_noscript(&summary);
+        assert!(html.starts_with("<noscript>"));
+        assert!(html.contains(">25.0%</p>"));
+        assert!(html.contains("50 of 200 conda-forge feedstocks"));
+        assert!(html.contains("Data as of March 5, 2025."));
+
+        assert!(render_noscript(&toml::Table::new()).contains(">0.0%</p>"));
+    }
+}
@@ -0,0 +1,139 @@
+//! `status.json`: a compact summary of the migration for conda-forge's status page and
+//! other dashboards to embed.
+//!
+//! Stability: within a `version`, fields are only ever added. Removing or renaming a
+//! field or changing its meaning bumps `version`, so consumers can pin the one they read.
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// Bump on any breaking change to the format below
+pub const STATUS_VERSION: u32 = 2;
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Status {
+    pub version: u32,
+    /// When the data was collected (RFC 3339)
+    pub last_updated: String,
+    /// Share of feedstocks on Recipe v1, 0-100 rounded to one decimal
+    pub percentage: f64,
+    pub counts: Counts,
+    /// Change since the newest snapshot at least 7 days older, `null` until there is one
+    pub delta_7d: Option<Delta>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Counts {
+    pub total: i64,
+    pub recipe_v1: i64,
+    pub meta_yaml: i64,
+    /// Recipe v1 feedstocks with a near-miss recipe filename, also counted in `recipe_v1`
+    /// since version 2
+    pub non_standard_v1: i64,
+    pub unknown: i64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Delta {
+    /// Date of the snapshot compared with ("YYYY-MM-DD")
+    pub since: String,
+    pub recipe_v1: i64,
+    pub total: i64,
+    /// Percentage points, rounded to one decimal
+    pub percentage: f64,
+}
+
+fn counts(summary: &toml::Table) -> Counts {
+    let count = |key: &str| summary.get(key).and_then(|v| v.as_integer()).unwrap_or(0);
+    Counts {
+        total: count("total_feedstocks"),
+        recipe_v1: count("recipe_v1_count"),
+        meta_yaml: count("meta_yaml_count"),
+        non_standard_v1: count("non_standard_v1_count"),
+        unknown: count("unknown_count"),
+    }
+}
+
+fn percentage(counts: &Counts) -> f64 {
+    if counts.total == 0 {
+        0.0
+    } else {
+        counts.recipe_v1 as f64 * 100.0 / counts.total as f64
+    }
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+/// Summarize the crunched site data, compared with `previous` (a snapshot's date and data)
+pub fn status(summary: &toml::Table, previous: Option<(NaiveDate, &toml::Table)>) -> Status {
+    let current = counts(summary);
+    let delta_7d = previous.map(|(date, previous)| {
+        let previous = counts(previous);
+        Delta {
+            since: date.format("%Y-%m-%d").to_string(),
+            recipe_v1: current.recipe_v1 - previous.recipe_v1,
+            total: current.total - previous.total,
+            percentage: round1(percentage(&current) - percentage(&previous)),
+        }
+    });
+
+    Status {
+        version: STATUS_VERSION,
+        last_updated: summary
+            .get("last_updated")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        percentage: round1(percentage(&current)),
+        counts: current,
+        delta_7d,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status() {
+        let summary: toml::Table = toml::from_str(
+            r#"
+            total_feedstocks = 300
+            recipe_v1_count = 100
+            meta_yaml_count = 195
+            non_standard_v1_count = 2
+            unknown_count = 3
+            last_updated = "2025-03-08T06:00:00Z"
+            "#,
+        )
+        .unwrap();
+        let previous: toml::Table = toml::from_str(
+            r#"
+            total_feedstocks = 298
+            recipe_v1_count = 90
+            "#,
+        )
+        .unwrap();
+        let since = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        let status = status(&summary, Some((since, &previous)));
+        assert_eq!(status.version, STATUS_VERSION);
+        assert_eq!(status.percentage, 33.3);
+        assert_eq!(status.counts.meta_yaml, 195);
+        let delta = status.delta_7d.as_ref().unwrap();
+        assert_eq!(delta.since, "2025-03-01");
+        assert_eq!(delta.recipe_v1, 10);
+        assert_eq!(delta.total, 2);
+        // 33.33% now vs 30.20% a week ago
+        assert_eq!(delta.percentage, 3.1);
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["counts"]["recipe_v1"], 100);
+        assert_eq!(json["last_updated"], "2025-03-08T06:00:00Z");
+
+        let first_run = super::status(&summary, None);
+        assert!(serde_json::to_value(&first_run).unwrap()["delta_7d"].is_null());
+    }
+}
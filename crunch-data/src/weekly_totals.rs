@@ -0,0 +1,97 @@
+//! `weekly_totals.csv`: Recipe v1 conversions and new feedstocks per week, with the share
+//! of feedstocks on Recipe v1 at the end of each week, for spreadsheets to import from a
+//! single URL.
+//!
+//! Stability: the columns below keep their names and order; new ones are only appended.
+
+/// Column names, in order
+const HEADER: &str = "week_start,conversions,new_feedstocks,cumulative_percentage";
+
+/// Render the crunched `weekly_v1_growth` as CSV, oldest week first. The share at the end
+/// of each week is worked back from today's counts by taking off the contributions of
+/// the weeks after it, so it is an estimate where feedstocks were reverted or removed.
+pub fn render_weekly_totals(summary: &toml::Table) -> String {
+    let count =
+        |table: &toml::Table, key: &str| table.get(key).and_then(|v| v.as_integer()).unwrap_or(0);
+    let weeks: Vec<(&str, i64, i64)> = summary
+        .get("weekly_v1_growth")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|week| {
+            let week = week.as_table()?;
+            Some((
+                week.get("week")?.as_str()?,
+                count(week, "conversions"),
+                count(week, "new_feedstocks"),
+            ))
+        })
+        .collect();
+
+    let mut recipe_v1 = count(summary, "recipe_v1_count");
+    let mut total = count(summary, "total_feedstocks");
+    let mut rows = Vec::with_capacity(weeks.len());
+    for &(week, conversions, new_feedstocks) in weeks.iter().rev() {
+        let percentage = if total > 0 {
+            recipe_v1.max(0) as f64 * 100.0 / total as f64
+        } else {
+            0.0
+        };
+        rows.push(format!(
+            "{},{},{},{:.2}",
+            week, conversions, new_feedstocks, percentage
+        ));
+        recipe_v1 -= conversions + new_feedstocks;
+        total -= new_feedstocks;
+    }
+
+    let mut csv = String::from(HEADER);
+    csv.push('\n');
+    for row in rows.iter().rev() {
+        csv.push_str(row);
+        csv.push('\n');
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_weekly_totals() {
+        let summary: toml::Table = toml::from_str(
+            r#"
+            total_feedstocks = 200
+            recipe_v1_count = 50
+
+            [[weekly_v1_growth]]
+            week = "2025-03-03"
+            conversions = 10
+            new_feedstocks = 0
+
+            [[weekly_v1_growth]]
+            week = "2025-03-10"
+            conversions = 0
+            new_feedstocks = 0
+
+            [[weekly_v1_growth]]
+            week = "2025-03-17"
+            conversions = 5
+            new_feedstocks = 5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            render_weekly_totals(&summary),
+            "week_start,conversions,new_feedstocks,cumulative_percentage\n\
+             2025-03-03,10,0,20.51\n\
+             2025-03-10,0,0,20.51\n\
+             2025-03-17,5,5,25.00\n"
+        );
+
+        let empty: toml::Table = toml::from_str("total_feedstocks = 0").unwrap();
+        assert_eq!(render_weekly_totals(&empty), format!("{}\n", HEADER));
+    }
+}
@@ -0,0 +1,72 @@
+//! Crunch a stats file written by data-collector, so the crunched summary can't drift
+//! from what the collector writes without the site schema check catching it
+
+use data_collector::models::FeedstockStats;
+use data_collector::stats::write_stats;
+use std::process::Command;
+
+const STATS: &str = r#"
+stats_format_version = 3
+total_feedstocks = 3
+recipe_v1_count = 2
+meta_yaml_count = 1
+unknown_count = 0
+non_standard_v1_count = 1
+last_updated = "2025-06-01T00:00:00+00:00"
+
+[feedstock_states.numpy-feedstock]
+recipe_type = "recipe_v1"
+last_changed = "2025-05-01T00:00:00Z"
+downloads = 1000
+
+[feedstock_states.numpy-feedstock.attribution]
+contribution_type = "conversion"
+contributors = ["alice"]
+date = "2025-05-01T00:00:00Z"
+
+[feedstock_states.pandas-feedstock]
+recipe_type = "non_standard_v1"
+recipe_file = "recipe.yml"
+last_changed = "2025-04-01T00:00:00Z"
+
+[feedstock_states.scipy-feedstock]
+recipe_type = "meta_yaml"
+last_changed = "2025-01-01T00:00:00Z"
+downloads = 500
+"#;
+
+/// Write `STATS` the way the collector saves its stats and crunch it into a temporary
+/// site root, returning the crunched stats.toml
+fn crunch_collector_stats() -> toml::Table {
+    let root = tempfile::tempdir().unwrap();
+    let input = root.path().join("feedstock-stats.toml");
+    let mut stats: FeedstockStats = toml::from_str(STATS).unwrap();
+    let feedstock_states = std::mem::take(&mut stats.feedstock_states);
+    write_stats(&input.to_string_lossy(), &stats, &feedstock_states).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_crunch-data"))
+        .arg("--input")
+        .arg(&input)
+        .arg("--root")
+        .arg(root.path())
+        .arg("--only-web")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "crunch-data failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let crunched = std::fs::read_to_string(root.path().join("web/src/stats.toml")).unwrap();
+    toml::from_str(&crunched).unwrap()
+}
+
+#[test]
+fn test_crunch_collector_stats() {
+    let summary = crunch_collector_stats();
+    assert_eq!(summary["total_feedstocks"].as_integer(), Some(3));
+    // Already includes the non-standard filename
+    assert_eq!(summary["recipe_v1_count"].as_integer(), Some(2));
+    assert_eq!(summary["non_standard_v1_count"].as_integer(), Some(1));
+}
@@ -0,0 +1,132 @@
+//! Benchmarks for the per-feedstock hot paths of the collector.
+//!
+//! Run with `cargo bench -p data-collector`. Fixtures in `benches/fixtures` are trimmed
+//! node_attrs files covering a rattler-build, a conda-build and a minimal feedstock.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use data_collector::{
+    determine_recipe_type_from_node, extract_package_summary, extract_rattler_build_pin,
+    extract_recipe_features, extract_recipe_maintainers, extract_schema_version,
+    parse_node_attrs_file, FeedstockEntry, FeedstockStats, NodeAttrsJson, QualityMetrics,
+    RattlerBuildAdoption, STATS_FORMAT_VERSION,
+};
+
+/// Roughly the number of feedstocks on conda-forge
+const FEEDSTOCK_COUNT: usize = 25_000;
+
+fn fixture_paths() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/fixtures");
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .expect("fixture directory missing")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn parse_fixtures() -> Vec<NodeAttrsJson> {
+    fixture_paths()
+        .iter()
+        .map(|path| parse_node_attrs_file(path).expect("invalid fixture"))
+        .collect()
+}
+
+/// Build the entry the collector would store for `node_data`
+fn classify(node_data: &NodeAttrsJson) -> FeedstockEntry {
+    FeedstockEntry {
+        recipe_type: determine_recipe_type_from_node(node_data),
+        last_changed: "2025-01-01T00:00:00+00:00".to_string(),
+        downloads: Some(1_000_000),
+        maintainers: extract_recipe_maintainers(node_data),
+        summary: extract_package_summary(node_data),
+        rattler_build_pin: extract_rattler_build_pin(node_data),
+        schema_version: extract_schema_version(node_data),
+        recipe_features: extract_recipe_features(node_data),
+        ..Default::default()
+    }
+}
+
+/// Stats with `FEEDSTOCK_COUNT` entries cycling through the fixtures
+fn stats_fixture() -> FeedstockStats {
+    let entries: Vec<FeedstockEntry> = parse_fixtures().iter().map(classify).collect();
+    let feedstock_states: BTreeMap<String, FeedstockEntry> = (0..FEEDSTOCK_COUNT)
+        .map(|i| {
+            (
+                format!("package-{}-feedstock", i),
+                entries[i % entries.len()].clone(),
+            )
+        })
+        .collect();
+
+    FeedstockStats {
+        stats_format_version: STATS_FORMAT_VERSION,
+        total_feedstocks: FEEDSTOCK_COUNT as u32,
+        recipe_v1_count: 0,
+        meta_yaml_count: 0,
+        unknown_count: 0,
+        non_standard_v1_count: 0,
+        last_updated: "2025-01-01T00:00:00+00:00".to_string(),
+        feedstock_states,
+        top_unconverted_by_downloads: Vec::new(),
+        quality_metrics: QualityMetrics::default(),
+        maintainer_conversion_rates: Vec::new(),
+        recipe_feature_rates: Vec::new(),
+        bot_activity_rates: Vec::new(),
+        coverage: None,
+        build_health: None,
+        pending_migration_prs: Vec::new(),
+        recipe_lint: None,
+        dependency_weighted: None,
+        rattler_build_adoption: RattlerBuildAdoption::default(),
+        top_feedstock_alerts: Vec::new(),
+        conversion_forecast: None,
+        unannounced_conversions: BTreeMap::new(),
+        unchecked_recipe_files: Vec::new(),
+        analyses: BTreeMap::new(),
+        memory_profile: Vec::new(),
+    }
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let paths = fixture_paths();
+    c.bench_function("parse_node_attrs", |b| {
+        b.iter(|| {
+            for path in &paths {
+                black_box(parse_node_attrs_file(black_box(path)).unwrap());
+            }
+        })
+    });
+}
+
+fn bench_classify(c: &mut Criterion) {
+    let nodes = parse_fixtures();
+    c.bench_function("classify_feedstock", |b| {
+        b.iter(|| {
+            for node_data in &nodes {
+                black_box(classify(black_box(node_data)));
+            }
+        })
+    });
+}
+
+fn bench_toml(c: &mut Criterion) {
+    let stats = stats_fixture();
+    let serialized = toml::to_string_pretty(&stats).unwrap();
+
+    let mut group = c.benchmark_group("feedstock_stats_toml");
+    group.sample_size(10);
+    group.bench_function("serialize", |b| {
+        b.iter(|| black_box(toml::to_string_pretty(black_box(&stats)).unwrap()))
+    });
+    group.bench_function("deserialize", |b| {
+        b.iter(|| black_box(toml::from_str::<FeedstockStats>(black_box(&serialized)).unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_classify, bench_toml);
+criterion_main!(benches);
@@ -1,2 +1,76 @@
+use std::path::{Path, PathBuf};
+
+use crate::stats::StatsStore;
+
 pub const CF_GRAPH_REPO_URL: &str = "https://github.com/regro/cf-graph-countyfair.git";
+/// Tarball of cf-graph's main branch, for `--cf-graph-source tarball`
+pub const CF_GRAPH_TARBALL_URL: &str =
+    "https://codeload.github.com/regro/cf-graph-countyfair/tar.gz/refs/heads/main";
 pub const CF_GRAPH_LOCAL_PATH: &str = "../cf-graph-countyfair";
+/// Blobless clone of cf-graph with its full history, for `history backfill`
+pub const CF_GRAPH_HISTORY_PATH: &str = "../cf-graph-countyfair-history";
+/// Local mirror of raw recipe files, see `RecipeStore`
+pub const RECIPE_STORE_PATH: &str = "../recipe-store";
+/// Newest-first version order of each package, see `VersionOrderCache`
+pub const VERSION_ORDER_CACHE_PATH: &str = "../download-version-cache.json";
+/// Progress of an interrupted download crawl, see `DownloadCheckpoint`
+pub const DOWNLOAD_CHECKPOINT_PATH: &str = "../download-checkpoint.json";
+
+/// Files and directories the collector reads and writes. Relative paths are resolved
+/// against the working directory.
+#[derive(Clone, Debug)]
+pub struct Paths {
+    pub stats_file: PathBuf,
+    /// Where the stats are loaded from and saved to, see `StatsStore`
+    pub store: StatsStore,
+    pub cf_graph: PathBuf,
+    pub recipe_store: PathBuf,
+    pub version_cache: PathBuf,
+    pub download_checkpoint: PathBuf,
+    /// Cached GitHub identities of contributors, see `IdentityCache`
+    pub identity_cache: PathBuf,
+    /// node_attrs entries that aren't feedstocks, see `NodeAttrsIgnoreList`
+    pub node_attrs_ignore: PathBuf,
+    /// Dated snapshots of the counts, see `History`
+    pub history: PathBuf,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            stats_file: default_stats_file(),
+            store: StatsStore::Toml(default_stats_file()),
+            cf_graph: CF_GRAPH_LOCAL_PATH.into(),
+            recipe_store: RECIPE_STORE_PATH.into(),
+            version_cache: VERSION_ORDER_CACHE_PATH.into(),
+            download_checkpoint: DOWNLOAD_CHECKPOINT_PATH.into(),
+            identity_cache: default_identity_cache(&default_stats_file()),
+            node_attrs_ignore: default_node_attrs_ignore(&default_stats_file()),
+            history: default_history(&default_stats_file()),
+        }
+    }
+}
+
+/// feedstock-stats.toml in the workspace root under `cargo run`, else in the working
+/// directory
+pub fn default_stats_file() -> PathBuf {
+    match std::env::var("CARGO_MANIFEST_DIR") {
+        Ok(dir) => PathBuf::from(dir).join("../feedstock-stats.toml"),
+        Err(_) => PathBuf::from("feedstock-stats.toml"),
+    }
+}
+
+/// contributor-identities.json next to the stats file, where crunch-data picks it up
+pub fn default_identity_cache(stats_file: &Path) -> PathBuf {
+    stats_file.with_file_name("contributor-identities.json")
+}
+
+/// node-attrs-ignore.toml next to the stats file
+pub fn default_node_attrs_ignore(stats_file: &Path) -> PathBuf {
+    stats_file.with_file_name("node-attrs-ignore.toml")
+}
+
+/// history.toml next to the stats file
+pub fn default_history(stats_file: &Path) -> PathBuf {
+    stats_file.with_file_name("history.toml")
+}
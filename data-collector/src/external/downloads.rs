@@ -1,14 +1,27 @@
+use crate::config::Paths;
+use crate::stats::write_atomically;
 use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
 use futures::{stream, StreamExt};
 use indicatif::{ProgressBar, ProgressStyle};
 use rattler_conda_types::Version;
-use serde::Deserialize;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
 
 const GRAPHQL_ENDPOINT: &str = "https://prefix.dev/api/graphql";
 const CONCURRENT_REQUESTS: usize = 50;
 const PACKAGES_PER_PAGE: u32 = 50;
 const TOP_VERSIONS_LIMIT: usize = 10;
+const MAX_PAGE_RETRIES: u32 = 3;
+/// Above this share of failed pages the fetched counts are too skewed to rank by
+const MAX_FAILED_PAGE_SHARE: f64 = 0.05;
+/// Save the crawl checkpoint every this many pages
+const CHECKPOINT_INTERVAL: u32 = 20;
+/// Older checkpoints are discarded, their counts are too stale to merge
+const CHECKPOINT_MAX_AGE_HOURS: i64 = 12;
 
 // GraphQL response types
 #[derive(Deserialize)]
@@ -46,14 +59,254 @@ struct DownloadCount {
     version: String,
 }
 
+/// Download counts fetched from prefix.dev, with the pages that failed despite retries
+pub struct DownloadCounts {
+    /// Summed downloads by feedstock name
+    pub counts: HashMap<String, u64>,
+    pub failed_pages: Vec<u32>,
+    pub total_pages: u32,
+}
+
+impl DownloadCounts {
+    /// Share of pages that couldn't be fetched (0.0 - 1.0)
+    pub fn failed_share(&self) -> f64 {
+        if self.total_pages == 0 {
+            0.0
+        } else {
+            self.failed_pages.len() as f64 / self.total_pages as f64
+        }
+    }
+}
+
+/// Progress of a crawl, saved periodically so an interrupted one resumes where it
+/// stopped instead of fetching every page again
+#[derive(Serialize, Deserialize)]
+pub struct DownloadCheckpoint {
+    pub started_at: DateTime<Utc>,
+    pub total_pages: u32,
+    /// First page not processed yet; all earlier pages are in `counts` or `failed_pages`
+    pub next_page: u32,
+    /// Pages after `next_page` that are processed too, as pages finish out of order
+    #[serde(default)]
+    pub done_pages: BTreeSet<u32>,
+    pub counts: HashMap<String, u64>,
+    pub failed_pages: Vec<u32>,
+}
+
+impl DownloadCheckpoint {
+    fn new(total_pages: u32, now: DateTime<Utc>) -> Self {
+        Self {
+            started_at: now,
+            total_pages,
+            next_page: 1,
+            done_pages: BTreeSet::new(),
+            counts: HashMap::new(),
+            failed_pages: Vec::new(),
+        }
+    }
+
+    /// Load the checkpoint of a recent crawl over the same number of pages. Page
+    /// boundaries shift when the page count changes, so such crawls start over.
+    pub fn load(path: &Path, total_pages: u32, now: DateTime<Utc>) -> Option<Self> {
+        let checkpoint: Self = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+        let fresh = now - checkpoint.started_at < Duration::hours(CHECKPOINT_MAX_AGE_HOURS);
+        (fresh && checkpoint.total_pages == total_pages).then_some(checkpoint)
+    }
+
+    /// Write the checkpoint, replacing the previous one only once completely written
+    pub fn save(&self, path: &Path) -> Result<()> {
+        write_atomically(path, |writer| Ok(serde_json::to_writer(writer, self)?))
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Pages not processed yet
+    fn remaining_pages(&self) -> Vec<u32> {
+        (self.next_page..=self.total_pages)
+            .filter(|page| !self.done_pages.contains(page))
+            .collect()
+    }
+
+    /// Add the result of `page`, one of the `remaining_pages`
+    fn record(
+        &mut self,
+        page: u32,
+        result: Result<Vec<Package>>,
+        version_cache: &mut VersionOrderCache,
+    ) {
+        match result {
+            Ok(packages) => {
+                for pkg in packages {
+                    let total = aggregate_top_versions(
+                        &pkg.name,
+                        &pkg.download_counts,
+                        TOP_VERSIONS_LIMIT,
+                        version_cache,
+                    );
+                    if total > 0 {
+                        let feedstock_name = format!("{}-feedstock", pkg.name);
+                        self.counts.insert(feedstock_name, total);
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("⚠️  Warning: Failed to fetch page: {:#}", e);
+                self.failed_pages.push(page);
+            }
+        }
+        self.done_pages.insert(page);
+        while self.done_pages.remove(&self.next_page) {
+            self.next_page += 1;
+        }
+    }
+}
+
+/// Newest-first top versions of each package, keyed by a hash of its version set.
+/// Download counts change every run but the versions rarely do, so unchanged packages
+/// skip parsing and sorting all their version strings.
+#[derive(Serialize, Deserialize, Default)]
+pub struct VersionOrderCache {
+    packages: HashMap<String, CachedVersionOrder>,
+    /// Packages whose order was reused during this run
+    #[serde(skip)]
+    pub hits: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedVersionOrder {
+    versions_sha256: String,
+    top_versions: Vec<String>,
+}
+
+impl VersionOrderCache {
+    /// Load the cache, starting empty if it's missing or unreadable
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Top `limit` versions of `package`, newest first, sorting only if its versions changed
+    fn top_versions(&mut self, package: &str, versions: &[&str], limit: usize) -> &[String] {
+        let mut sorted = versions.to_vec();
+        sorted.sort_unstable();
+        let mut hasher = Sha256::new();
+        hasher.update(limit.to_le_bytes());
+        for version in sorted {
+            hasher.update(version.as_bytes());
+            hasher.update(b"\n");
+        }
+        let versions_sha256 = format!("{:x}", hasher.finalize());
+
+        let cached = self
+            .packages
+            .get(package)
+            .is_some_and(|cached| cached.versions_sha256 == versions_sha256);
+        if cached {
+            self.hits += 1;
+        } else {
+            self.packages.insert(
+                package.to_string(),
+                CachedVersionOrder {
+                    versions_sha256,
+                    top_versions: newest_versions(versions, limit),
+                },
+            );
+        }
+        &self.packages[package].top_versions
+    }
+}
+
+/// Decide which download counts to use, given a fetch and the previous run's counts:
+/// - all pages fetched: the fetched counts
+/// - a few pages failed: the fetched counts, filling the gaps from the previous run
+/// - too many pages failed, or the fetch failed entirely: the previous run's counts,
+///   as a skewed ranking is worse than a day-old one (unless there is no previous run)
+pub fn counts_with_fallback(
+    fetched: Result<DownloadCounts>,
+    previous: HashMap<String, u64>,
+) -> Result<HashMap<String, u64>> {
+    match fetched {
+        Ok(fetched) if fetched.failed_pages.is_empty() => Ok(fetched.counts),
+        Ok(fetched) if fetched.failed_share() <= MAX_FAILED_PAGE_SHARE => {
+            println!(
+                "⚠️  {} of {} download pages failed, using the previous run's counts for missing feedstocks",
+                fetched.failed_pages.len(),
+                fetched.total_pages
+            );
+            let mut counts = previous;
+            counts.extend(fetched.counts);
+            Ok(counts)
+        }
+        Ok(fetched) if previous.is_empty() => {
+            println!(
+                "⚠️  {} of {} download pages failed and there are no previous counts, using partial results",
+                fetched.failed_pages.len(),
+                fetched.total_pages
+            );
+            Ok(fetched.counts)
+        }
+        Ok(fetched) => {
+            println!(
+                "⚠️  {} of {} download pages failed, falling back to the previous run's download counts",
+                fetched.failed_pages.len(),
+                fetched.total_pages
+            );
+            Ok(previous)
+        }
+        Err(e) if previous.is_empty() => Err(e),
+        Err(e) => {
+            println!(
+                "⚠️  Failed to fetch download counts ({}), falling back to the previous run's",
+                e
+            );
+            Ok(previous)
+        }
+    }
+}
+
+/// Save download counts as the artifact of the `fetch-downloads` stage
+pub fn write_download_counts(path: &Path, counts: &HashMap<String, u64>) -> Result<()> {
+    let sorted: BTreeMap<_, _> = counts.iter().collect();
+    fs::write(path, serde_json::to_string_pretty(&sorted)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Load download counts saved by the `fetch-downloads` stage
+pub fn load_download_counts(path: &Path) -> Result<HashMap<String, u64>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Invalid download counts in {}", path.display()))
+}
+
 /// Fetch download counts for all conda-forge packages from prefix.dev GraphQL API
-pub async fn fetch_download_counts() -> Result<HashMap<String, u64>> {
+pub async fn fetch_download_counts(paths: &Paths) -> Result<DownloadCounts> {
     let client = reqwest::Client::new();
+    let cache_path = paths.version_cache.as_path();
+    let mut version_cache = VersionOrderCache::load(cache_path);
 
     // First, fetch to get total page count
     let total_pages = fetch_page_count(&client).await?;
     println!("📊 Found {} pages of packages to fetch", total_pages);
 
+    let checkpoint_path = paths.download_checkpoint.as_path();
+    let mut checkpoint = match DownloadCheckpoint::load(checkpoint_path, total_pages, Utc::now()) {
+        Some(checkpoint) => {
+            println!(
+                "⏯️  Resuming the interrupted crawl at page {}",
+                checkpoint.next_page
+            );
+            checkpoint
+        }
+        None => DownloadCheckpoint::new(total_pages, Utc::now()),
+    };
+
     // Set up progress bar
     let pb = ProgressBar::new(total_pages as u64);
     pb.set_style(
@@ -63,45 +316,74 @@ pub async fn fetch_download_counts() -> Result<HashMap<String, u64>> {
             .progress_chars("█▓░"),
     );
 
-    // Fetch all pages concurrently with limited parallelism
-    let results: Vec<Result<Vec<Package>>> = stream::iter(1..=total_pages)
+    let remaining = checkpoint.remaining_pages();
+    pb.set_position(u64::from(total_pages) - remaining.len() as u64);
+
+    // Fetch the remaining pages concurrently with limited parallelism, in whatever order
+    // they finish; the checkpoint keeps track of which are done
+    let mut pages = stream::iter(remaining)
         .map(|page| {
             let client = client.clone();
-            async move { fetch_page(&client, page).await }
+            async move { (page, fetch_page_with_retries(&client, page).await) }
         })
-        .buffer_unordered(CONCURRENT_REQUESTS)
-        .inspect(|_| pb.inc(1))
-        .collect()
-        .await;
+        .buffer_unordered(CONCURRENT_REQUESTS);
+    let mut processed = 0;
+    while let Some((page, result)) = pages.next().await {
+        pb.inc(1);
+        checkpoint.record(page, result, &mut version_cache);
+        processed += 1;
+        if processed % CHECKPOINT_INTERVAL == 0 {
+            if let Err(e) = checkpoint.save(checkpoint_path) {
+                eprintln!("⚠️  Warning: {:#}", e);
+            }
+        }
+    }
 
     pb.finish_with_message("✅ Download counts fetched!");
+    // The crawl is complete, the next run starts over
+    let _ = fs::remove_file(checkpoint_path);
 
-    // Process results into HashMap
-    let mut download_counts = HashMap::new();
+    let DownloadCheckpoint {
+        counts: download_counts,
+        mut failed_pages,
+        ..
+    } = checkpoint;
+    failed_pages.sort_unstable();
 
-    for result in results {
-        match result {
-            Ok(packages) => {
-                for pkg in packages {
-                    let total = aggregate_top_versions(&pkg.download_counts, TOP_VERSIONS_LIMIT);
-                    if total > 0 {
-                        let feedstock_name = format!("{}-feedstock", pkg.name);
-                        download_counts.insert(feedstock_name, total);
-                    }
-                }
+    println!(
+        "📦 Fetched download counts for {} packages ({} with unchanged versions)",
+        download_counts.len(),
+        version_cache.hits
+    );
+    if let Err(e) = version_cache.save(cache_path) {
+        eprintln!("⚠️  Warning: {:#}", e);
+    }
+    if !failed_pages.is_empty() {
+        println!("⚠️  Failed download pages: {:?}", failed_pages);
+    }
+
+    Ok(DownloadCounts {
+        counts: download_counts,
+        failed_pages,
+        total_pages,
+    })
+}
+
+/// Fetch a page, retrying transient failures with exponential backoff (1s, 2s, 4s)
+async fn fetch_page_with_retries(client: &reqwest::Client, page: u32) -> Result<Vec<Package>> {
+    let mut attempt = 0;
+    loop {
+        match fetch_page(client, page).await {
+            Ok(packages) => return Ok(packages),
+            Err(e) if attempt >= MAX_PAGE_RETRIES => {
+                return Err(e.context(format!("Giving up after {} retries", MAX_PAGE_RETRIES)))
             }
-            Err(e) => {
-                eprintln!("⚠️  Warning: Failed to fetch page: {}", e);
+            Err(_) => {
+                tokio::time::sleep(std::time::Duration::from_secs(1 << attempt)).await;
+                attempt += 1;
             }
         }
     }
-
-    println!(
-        "📦 Fetched download counts for {} packages",
-        download_counts.len()
-    );
-
-    Ok(download_counts)
 }
 
 /// Fetch the total number of pages from the API
@@ -160,6 +442,7 @@ async fn fetch_page(client: &reqwest::Client, page: u32) -> Result<Vec<Package>>
         .json(&serde_json::json!({ "query": query }))
         .send()
         .await
+        .and_then(|response| response.error_for_status())
         .context(format!("Failed to fetch page {}", page))?
         .json()
         .await
@@ -173,7 +456,12 @@ async fn fetch_page(client: &reqwest::Client, page: u32) -> Result<Vec<Package>>
 }
 
 /// Aggregate download counts for the top N versions (sorted by version descending)
-fn aggregate_top_versions(counts: &[DownloadCount], limit: usize) -> u64 {
+fn aggregate_top_versions(
+    package: &str,
+    counts: &[DownloadCount],
+    limit: usize,
+    cache: &mut VersionOrderCache,
+) -> u64 {
     if counts.is_empty() {
         return 0;
     }
@@ -184,19 +472,22 @@ fn aggregate_top_versions(counts: &[DownloadCount], limit: usize) -> u64 {
         *by_version.entry(&c.version).or_default() += c.count;
     }
 
-    // Sort versions using rattler's Version type (handles conda versioning correctly)
-    let mut versions: Vec<_> = by_version.into_iter().collect();
-    versions.sort_by(|(a, _), (b, _)| {
-        match (a.parse::<Version>(), b.parse::<Version>()) {
-            (Ok(va), Ok(vb)) => vb.cmp(&va), // Descending (newest first)
-            (Ok(_), Err(_)) => std::cmp::Ordering::Less, // Valid versions come first
-            (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
-            (Err(_), Err(_)) => b.cmp(a), // String fallback, descending
-        }
-    });
-
     // Sum top N versions
-    versions.iter().take(limit).map(|(_, count)| count).sum()
+    let versions: Vec<&str> = by_version.keys().copied().collect();
+    cache
+        .top_versions(package, &versions, limit)
+        .iter()
+        .filter_map(|version| by_version.get(version.as_str()))
+        .sum()
+}
+
+/// The `limit` newest versions, using rattler's Version type (handles conda versioning
+/// correctly). Unparseable versions come last, by string descending.
+fn newest_versions(versions: &[&str], limit: usize) -> Vec<String> {
+    let mut versions = versions.to_vec();
+    // Parse each version once, not on every comparison
+    versions.sort_by_cached_key(|v| std::cmp::Reverse((v.parse::<Version>().ok(), *v)));
+    versions.into_iter().take(limit).map(String::from).collect()
 }
 
 #[cfg(test)]
@@ -224,19 +515,141 @@ mod tests {
             },
         ];
 
+        let mut cache = VersionOrderCache::default();
+
         // Top 2 versions: 3.0.0 (300) + 2.0.0 (200) = 500
-        assert_eq!(aggregate_top_versions(&counts, 2), 500);
+        assert_eq!(aggregate_top_versions("pkg", &counts, 2, &mut cache), 500);
 
         // Top 3 versions: 3.0.0 (300) + 2.0.0 (200) + 1.5.0 (150) = 650
-        assert_eq!(aggregate_top_versions(&counts, 3), 650);
+        assert_eq!(aggregate_top_versions("pkg", &counts, 3, &mut cache), 650);
 
         // All versions
-        assert_eq!(aggregate_top_versions(&counts, 10), 750);
+        assert_eq!(aggregate_top_versions("pkg", &counts, 10, &mut cache), 750);
+    }
+
+    fn fetched(failed_pages: Vec<u32>) -> Result<DownloadCounts> {
+        Ok(DownloadCounts {
+            counts: HashMap::from([("a-feedstock".to_string(), 10)]),
+            failed_pages,
+            total_pages: 20,
+        })
+    }
+
+    #[test]
+    fn test_counts_with_fallback() {
+        let previous = || {
+            HashMap::from([
+                ("a-feedstock".to_string(), 5),
+                ("b-feedstock".to_string(), 7),
+            ])
+        };
+
+        // Complete fetch is used as is
+        let counts = counts_with_fallback(fetched(vec![]), previous()).unwrap();
+        assert_eq!(counts.len(), 1);
+
+        // 1 of 20 pages failed: gaps are filled, fresh counts win
+        let counts = counts_with_fallback(fetched(vec![3]), previous()).unwrap();
+        assert_eq!(counts["a-feedstock"], 10);
+        assert_eq!(counts["b-feedstock"], 7);
+
+        // 2 of 20 pages failed: too skewed, the previous counts are used
+        let counts = counts_with_fallback(fetched(vec![3, 4]), previous()).unwrap();
+        assert_eq!(counts["a-feedstock"], 5);
+
+        // Without previous counts partial results beat none
+        let counts = counts_with_fallback(fetched(vec![3, 4]), HashMap::new()).unwrap();
+        assert_eq!(counts["a-feedstock"], 10);
+
+        let failed = || Err(anyhow::anyhow!("prefix.dev is down"));
+        assert_eq!(counts_with_fallback(failed(), previous()).unwrap().len(), 2);
+        assert!(counts_with_fallback(failed(), HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_download_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+        let now = Utc::now();
+        let mut cache = VersionOrderCache::default();
+
+        let mut checkpoint = DownloadCheckpoint::new(10, now);
+        let package = Package {
+            name: "numpy".to_string(),
+            download_counts: vec![DownloadCount {
+                count: 5,
+                version: "2.0".to_string(),
+            }],
+        };
+        checkpoint.record(1, Ok(vec![package]), &mut cache);
+        checkpoint.record(2, Err(anyhow::anyhow!("timeout")), &mut cache);
+        // Pages finish out of order
+        checkpoint.record(5, Ok(vec![]), &mut cache);
+        checkpoint.record(4, Ok(vec![]), &mut cache);
+        checkpoint.save(&path).unwrap();
+
+        // A recent crawl over the same pages resumes with the pages not recorded yet
+        let mut resumed = DownloadCheckpoint::load(&path, 10, now + Duration::hours(1)).unwrap();
+        assert_eq!(resumed.next_page, 3);
+        assert_eq!(resumed.remaining_pages(), [3, 6, 7, 8, 9, 10]);
+        assert_eq!(resumed.counts["numpy-feedstock"], 5);
+        assert_eq!(resumed.failed_pages, [2]);
+
+        // Filling the gap moves past the pages already done
+        resumed.record(3, Ok(vec![]), &mut cache);
+        assert_eq!(resumed.next_page, 6);
+        assert!(resumed.done_pages.is_empty());
+
+        // Stale checkpoints and changed page counts start over
+        assert!(DownloadCheckpoint::load(&path, 10, now + Duration::hours(13)).is_none());
+        assert!(DownloadCheckpoint::load(&path, 11, now).is_none());
     }
 
     #[test]
     fn test_aggregate_empty() {
         let counts: Vec<DownloadCount> = vec![];
-        assert_eq!(aggregate_top_versions(&counts, 10), 0);
+        let mut cache = VersionOrderCache::default();
+        assert_eq!(aggregate_top_versions("pkg", &counts, 10, &mut cache), 0);
+    }
+
+    #[test]
+    fn test_version_order_cache() {
+        let count = |version: &str, count| DownloadCount {
+            version: version.to_string(),
+            count,
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("versions.json");
+        let mut cache = VersionOrderCache::default();
+        let counts = vec![count("1.0", 10), count("1.10", 20), count("1.9", 30)];
+        assert_eq!(aggregate_top_versions("pkg", &counts, 2, &mut cache), 50);
+        cache.save(&path).unwrap();
+
+        // Same versions with new counts reuse the stored order
+        let mut cache = VersionOrderCache::load(&path);
+        let counts = vec![count("1.0", 1), count("1.10", 2), count("1.9", 3)];
+        assert_eq!(aggregate_top_versions("pkg", &counts, 2, &mut cache), 5);
+        assert_eq!(cache.hits, 1);
+
+        // A new release changes the version set and is sorted again
+        let counts = vec![count("1.9", 3), count("1.10", 2), count("2.0", 4)];
+        assert_eq!(aggregate_top_versions("pkg", &counts, 2, &mut cache), 6);
+        assert_eq!(cache.hits, 1);
+
+        // Unparseable versions come last
+        assert_eq!(
+            newest_versions(&["latest", "0.1", "dev"], 3),
+            ["0.1", "latest", "dev"]
+        );
+    }
+
+    #[test]
+    fn test_download_counts_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("downloads.json");
+        let counts = HashMap::from([("numpy".to_string(), 100), ("scipy".to_string(), 50)]);
+
+        write_download_counts(&path, &counts).unwrap();
+        assert_eq!(load_download_counts(&path).unwrap(), counts);
     }
 }
@@ -2,9 +2,22 @@ use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::process::Command;
 
+use super::RecipeStore;
+
 const GITHUB_GRAPHQL_URL: &str = "https://api.github.com/graphql";
 const BATCH_SIZE: usize = 50;
 
+/// Recipe v1 filenames conda-smithy picks up
+pub const CANONICAL_RECIPE_FILES: &[&str] = &["recipe/recipe.yaml", "recipe.yaml"];
+
+/// Near-miss recipe filenames that should be renamed to the canonical one
+pub const NON_STANDARD_RECIPE_FILES: &[&str] = &[
+    "recipe/recipe.yml",
+    "recipe.yml",
+    "recipe/Recipe.yaml",
+    "Recipe.yaml",
+];
+
 /// Known bot patterns for detecting automated commits
 const BOT_PATTERNS: &[&str] = &[
     "conda-forge-admin",
@@ -67,12 +80,37 @@ pub struct RateLimitInfo {
 }
 
 /// Information about a Pull Request
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PullRequestInfo {
     pub number: u32,
     pub author: String,
 }
 
+/// An open pull request found by a search
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenPullRequest {
+    /// Repository name, e.g. "numpy-feedstock"
+    pub repository: String,
+    pub number: u32,
+    /// RFC 3339
+    pub created_at: String,
+}
+
+/// How a commit changed the canonical recipe file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecipeFileChange {
+    pub path: &'static str,
+    /// REST file status, e.g. "added", "modified" or "renamed"
+    pub status: String,
+}
+
+impl RecipeFileChange {
+    /// Whether this commit created the recipe file (renames count, the content came along)
+    pub fn created_file(&self) -> bool {
+        self.status == "added" || self.status == "renamed"
+    }
+}
+
 /// A commit within a Pull Request
 #[derive(Debug, Clone)]
 pub struct PrCommit {
@@ -81,6 +119,16 @@ pub struct PrCommit {
     pub files_changed: Vec<String>,
 }
 
+/// A GitHub account as currently known to GitHub
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitHubUser {
+    /// GraphQL node id, which stays the same when the account is renamed
+    pub id: String,
+    pub login: String,
+    pub name: Option<String>,
+    pub avatar_url: String,
+}
+
 #[derive(Deserialize)]
 struct GraphQLResponse {
     data: Option<serde_json::Value>,
@@ -155,6 +203,23 @@ impl GitHubClient {
         })
     }
 
+    /// Count the non-archived feedstock repositories in the conda-forge organization
+    pub async fn count_org_feedstocks(&self) -> Result<u32> {
+        let query = r#"query {
+  search(query: "org:conda-forge feedstock in:name archived:false", type: REPOSITORY) {
+    repositoryCount
+  }
+}"#;
+
+        let response = self.execute_query(query).await?;
+        response
+            .get("search")
+            .and_then(|s| s.get("repositoryCount"))
+            .and_then(|c| c.as_u64())
+            .map(|c| c as u32)
+            .context("No repositoryCount in response")
+    }
+
     /// Batch query multiple feedstocks for their first recipe.yaml commit
     pub async fn batch_query_recipe_history(
         &self,
@@ -167,7 +232,7 @@ impl GitHubClient {
         // Process in chunks of BATCH_SIZE
         let mut all_results = Vec::new();
         let mut all_pagination_needed = Vec::new();
-        let total_chunks = (feedstocks.len() + BATCH_SIZE - 1) / BATCH_SIZE;
+        let total_chunks = feedstocks.len().div_ceil(BATCH_SIZE);
 
         for (i, chunk) in feedstocks.chunks(BATCH_SIZE).enumerate() {
             eprint!("\r📦 Batch {}/{} ({} feedstocks)...", i + 1, total_chunks, all_results.len());
@@ -394,7 +459,8 @@ impl GitHubClient {
         Ok(None)
     }
 
-    /// Batch query PRs for multiple commits using GraphQL
+    /// Batch query the PRs that introduced commits, via GraphQL `associatedPullRequests`
+    /// in aliased batches instead of one REST call per commit.
     /// Returns a map of feedstock name -> PullRequestInfo
     pub async fn batch_query_prs_for_commits(
         &self,
@@ -407,7 +473,7 @@ impl GitHubClient {
         }
 
         let mut results = HashMap::new();
-        let total_batches = (commits.len() + BATCH_SIZE - 1) / BATCH_SIZE;
+        let total_batches = commits.len().div_ceil(BATCH_SIZE);
 
         for (batch_idx, chunk) in commits.chunks(BATCH_SIZE).enumerate() {
             eprint!(
@@ -417,60 +483,10 @@ impl GitHubClient {
                 results.len()
             );
 
-            // Build GraphQL query
-            let mut query = String::from("query {\n");
-            for (i, (feedstock, sha)) in chunk.iter().enumerate() {
-                query.push_str(&format!(
-                    r#"  repo{}: repository(owner: "conda-forge", name: "{}") {{
-    object(oid: "{}") {{
-      ... on Commit {{
-        associatedPullRequests(first: 1) {{
-          nodes {{
-            number
-            author {{ login }}
-          }}
-        }}
-      }}
-    }}
-  }}
-"#,
-                    i, feedstock, sha
-                ));
-            }
-            query.push_str("}\n");
-
-            let response = self.execute_query(&query).await?;
-
-            // Parse results
-            for (i, (feedstock, _)) in chunk.iter().enumerate() {
-                let repo_key = format!("repo{}", i);
-                if let Some(pr_info) = response
-                    .get(&repo_key)
-                    .and_then(|r| r.get("object"))
-                    .and_then(|o| o.get("associatedPullRequests"))
-                    .and_then(|prs| prs.get("nodes"))
-                    .and_then(|nodes| nodes.as_array())
-                    .and_then(|arr| arr.first())
-                {
-                    let number = pr_info
-                        .get("number")
-                        .and_then(|n| n.as_u64())
-                        .unwrap_or(0) as u32;
-                    let author = pr_info
-                        .get("author")
-                        .and_then(|a| a.get("login"))
-                        .and_then(|l| l.as_str())
-                        .unwrap_or("unknown")
-                        .to_string();
-
-                    if number > 0 {
-                        results.insert(
-                            feedstock.to_string(),
-                            PullRequestInfo { number, author },
-                        );
-                    }
-                }
-            }
+            let response = self
+                .execute_query(&build_prs_for_commits_query(chunk))
+                .await?;
+            results.extend(parse_prs_for_commits_response(chunk, &response));
         }
         eprintln!(); // Newline after progress
 
@@ -490,7 +506,7 @@ impl GitHubClient {
         }
 
         let mut results = HashMap::new();
-        let total_batches = (feedstocks.len() + BATCH_SIZE - 1) / BATCH_SIZE;
+        let total_batches = feedstocks.len().div_ceil(BATCH_SIZE);
 
         for (batch_idx, chunk) in feedstocks.chunks(BATCH_SIZE).enumerate() {
             eprint!(
@@ -552,6 +568,156 @@ impl GitHubClient {
         Ok(results)
     }
 
+    /// Batch check which feedstocks keep their recipe under a near-miss filename only
+    /// Returns a map of feedstock name -> non-standard recipe path
+    pub async fn batch_find_non_standard_recipes(
+        &self,
+        feedstocks: &[String],
+    ) -> Result<std::collections::HashMap<String, String>> {
+        use std::collections::HashMap;
+
+        let mut results = HashMap::new();
+        let total_batches = feedstocks.len().div_ceil(BATCH_SIZE);
+
+        for (batch_idx, chunk) in feedstocks.chunks(BATCH_SIZE).enumerate() {
+            eprint!(
+                "\r   Checking recipe filenames: batch {}/{}...",
+                batch_idx + 1,
+                total_batches
+            );
+
+            let query = build_recipe_files_query(chunk);
+            let response = self.execute_query(&query).await?;
+            results.extend(parse_recipe_files_response(chunk, &response));
+        }
+        eprintln!(); // Newline after progress
+
+        Ok(results)
+    }
+
+    /// Batch fetch when each feedstock was last pushed to (any branch), as RFC 3339
+    /// Returns a map of feedstock name -> pushedAt
+    pub async fn batch_fetch_pushed_at(
+        &self,
+        feedstocks: &[String],
+    ) -> Result<std::collections::HashMap<String, String>> {
+        use std::collections::HashMap;
+
+        let mut results = HashMap::new();
+        for chunk in feedstocks.chunks(BATCH_SIZE) {
+            let query = build_pushed_at_query(chunk);
+            let response = self.execute_query(&query).await?;
+            results.extend(parse_pushed_at_response(chunk, &response));
+        }
+
+        Ok(results)
+    }
+
+    /// Find the open Recipe v1 migration PRs the autotick bot made on conda-forge
+    /// feedstocks. GitHub search returns at most 1000 results.
+    pub async fn search_open_migration_prs(&self) -> Result<Vec<OpenPullRequest>> {
+        let mut results = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let query = build_pr_search_query(MIGRATION_PR_SEARCH, cursor.as_deref());
+            let response = self.execute_query(&query).await?;
+            let (prs, next) = parse_pr_search_response(&response);
+            results.extend(prs);
+            eprint!("\r   Searching migration PRs: {} found...", results.len());
+
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        eprintln!(); // Newline after progress
+
+        Ok(results)
+    }
+
+    /// Batch fetch the GitHub accounts of `logins`
+    /// Returns a map of requested login -> account; logins without an account are left out
+    pub async fn batch_fetch_users(
+        &self,
+        logins: &[String],
+    ) -> Result<std::collections::HashMap<String, GitHubUser>> {
+        let mut results = std::collections::HashMap::new();
+        for chunk in logins.chunks(BATCH_SIZE) {
+            let query = build_users_query(chunk);
+            let response = self.execute_query(&query).await?;
+            results.extend(parse_users_response(chunk, &response));
+        }
+        Ok(results)
+    }
+
+    /// Batch fetch GitHub accounts by node id, to find the current login of renamed ones
+    /// Returns a map of node id -> account; deleted accounts are left out
+    pub async fn batch_fetch_users_by_id(
+        &self,
+        ids: &[String],
+    ) -> Result<std::collections::HashMap<String, GitHubUser>> {
+        let mut results = std::collections::HashMap::new();
+        for chunk in ids.chunks(BATCH_SIZE) {
+            let query = build_users_by_id_query(chunk);
+            let response = self.execute_query(&query).await?;
+            results.extend(parse_users_response(chunk, &response));
+        }
+        Ok(results)
+    }
+
+    /// Batch fetch the combined CI state (Azure Pipelines statuses and GitHub checks) of
+    /// the head commit on each feedstock's default branch
+    /// Returns a map of feedstock name -> state, e.g. "SUCCESS", "FAILURE" or "PENDING"
+    pub async fn batch_fetch_build_states(
+        &self,
+        feedstocks: &[String],
+    ) -> Result<std::collections::HashMap<String, String>> {
+        use std::collections::HashMap;
+
+        let mut results = HashMap::new();
+        let total_batches = feedstocks.len().div_ceil(BATCH_SIZE);
+
+        for (batch_idx, chunk) in feedstocks.chunks(BATCH_SIZE).enumerate() {
+            eprint!(
+                "\r  Fetching build status batch {}/{}...",
+                batch_idx + 1,
+                total_batches
+            );
+            let query = build_build_state_query(chunk);
+            let response = self.execute_query(&query).await?;
+            results.extend(parse_build_state_response(chunk, &response));
+        }
+        eprintln!(); // Newline after progress
+
+        Ok(results)
+    }
+
+    /// Batch find the first PR after each conversion that changed the recipe file between
+    /// `since` and `until` (RFC 3339), leaving out the conversion's own PR and bot PRs
+    /// Returns a map of feedstock name -> PR number, or None when there was no such PR;
+    /// feedstocks that can't be found are left out
+    pub async fn batch_find_follow_up_fixes(
+        &self,
+        conversions: &[(&str, &str, &str)], // Vec of (feedstock, since, until)
+    ) -> Result<std::collections::HashMap<String, Option<u32>>> {
+        let mut results = std::collections::HashMap::new();
+        let total_batches = conversions.len().div_ceil(BATCH_SIZE);
+
+        for (batch_idx, chunk) in conversions.chunks(BATCH_SIZE).enumerate() {
+            eprint!(
+                "\r   Checking follow-up fixes: batch {}/{}...",
+                batch_idx + 1,
+                total_batches
+            );
+            let response = self.execute_query(&build_follow_up_query(chunk)).await?;
+            results.extend(parse_follow_up_response(chunk, &response));
+        }
+        eprintln!(); // Newline after progress
+
+        Ok(results)
+    }
+
     /// Batch fetch the first non-bot commit author from PRs
     /// For bot-authored PRs, we need to find who actually made the conversion
     /// Returns a map of feedstock name -> human contributor username
@@ -566,7 +732,7 @@ impl GitHubClient {
         }
 
         let mut results = HashMap::new();
-        let total_batches = (bot_prs.len() + BATCH_SIZE - 1) / BATCH_SIZE;
+        let total_batches = bot_prs.len().div_ceil(BATCH_SIZE);
 
         for (batch_idx, chunk) in bot_prs.chunks(BATCH_SIZE).enumerate() {
             eprint!(
@@ -724,6 +890,54 @@ impl GitHubClient {
         Ok(false)
     }
 
+    /// Look up how a commit changed recipe.yaml (None if it didn't touch it). Fails if
+    /// GitHub doesn't answer with a 2xx status.
+    pub async fn get_recipe_file_change(
+        &self,
+        feedstock: &str,
+        commit_sha: &str,
+    ) -> Result<Option<RecipeFileChange>> {
+        let url = format!(
+            "https://api.github.com/repos/conda-forge/{}/commits/{}",
+            feedstock, commit_sha
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github.v3+json")
+            .header("User-Agent", "are-we-recipe-v1-yet/1.0")
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!(
+                "Looking up commit {} of {} failed: {}",
+                commit_sha,
+                feedstock,
+                status
+            );
+        }
+
+        let commit: serde_json::Value = response.json().await?;
+        Ok(parse_recipe_file_change(&commit))
+    }
+
+    /// Find the commit that created the recipe file at `path` via a local clone
+    pub async fn find_recipe_creating_commit(
+        &self,
+        feedstock: &str,
+        path: &'static str,
+    ) -> Result<Option<FirstRecipeCommit>> {
+        self.find_oldest_commit_via_clone(&PaginationNeeded {
+            feedstock: feedstock.to_string(),
+            path,
+        })
+        .await
+    }
+
     /// Check if recipe.yaml exists in the very first commit of the repo
     /// Uses git clone for efficiency instead of API pagination
     pub async fn has_recipe_yaml_in_first_commit(&self, feedstock: &str) -> Result<bool> {
@@ -944,6 +1158,331 @@ fn build_batch_query(feedstocks: &[String]) -> String {
     query
 }
 
+/// Build a query checking every canonical and near-miss recipe path of each feedstock
+fn build_recipe_files_query(feedstocks: &[String]) -> String {
+    let mut query = String::from("query {\n");
+    for (i, feedstock) in feedstocks.iter().enumerate() {
+        query.push_str(&format!(
+            "  repo{}: repository(owner: \"conda-forge\", name: \"{}\") {{\n",
+            i, feedstock
+        ));
+        for (j, path) in CANONICAL_RECIPE_FILES
+            .iter()
+            .chain(NON_STANDARD_RECIPE_FILES)
+            .enumerate()
+        {
+            query.push_str(&format!(
+                "    file{}: object(expression: \"HEAD:{}\") {{ id }}\n",
+                j, path
+            ));
+        }
+        query.push_str("  }\n");
+    }
+    query.push_str("}\n");
+    query
+}
+
+/// Pick out feedstocks that have no canonical recipe file but do have a near-miss one
+fn parse_recipe_files_response(
+    feedstocks: &[String],
+    response: &serde_json::Value,
+) -> Vec<(String, String)> {
+    let mut results = Vec::new();
+
+    for (i, feedstock) in feedstocks.iter().enumerate() {
+        let Some(repo) = response.get(format!("repo{}", i)).filter(|r| !r.is_null()) else {
+            continue;
+        };
+        let exists = |j: usize| {
+            repo.get(format!("file{}", j))
+                .is_some_and(|file| !file.is_null())
+        };
+
+        if (0..CANONICAL_RECIPE_FILES.len()).any(exists) {
+            continue;
+        }
+        let non_standard = NON_STANDARD_RECIPE_FILES
+            .iter()
+            .enumerate()
+            .find(|(j, _)| exists(CANONICAL_RECIPE_FILES.len() + j));
+        if let Some((_, path)) = non_standard {
+            results.push((feedstock.clone(), path.to_string()));
+        }
+    }
+
+    results
+}
+
+fn build_pushed_at_query(feedstocks: &[String]) -> String {
+    let mut query = String::from("query {\n");
+    for (i, feedstock) in feedstocks.iter().enumerate() {
+        query.push_str(&format!(
+            "  repo{}: repository(owner: \"conda-forge\", name: \"{}\") {{ pushedAt }}\n",
+            i, feedstock
+        ));
+    }
+    query.push_str("}\n");
+    query
+}
+
+fn parse_pushed_at_response(
+    feedstocks: &[String],
+    response: &serde_json::Value,
+) -> Vec<(String, String)> {
+    feedstocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, feedstock)| {
+            let pushed_at = response[format!("repo{}", i)]["pushedAt"].as_str()?;
+            Some((feedstock.clone(), pushed_at.to_string()))
+        })
+        .collect()
+}
+
+const USER_FIELDS: &str = "id login name avatarUrl";
+
+/// Open PRs by the autotick bot that migrate a feedstock to Recipe v1
+const MIGRATION_PR_SEARCH: &str =
+    "org:conda-forge is:pr is:open author:app/regro-cf-autotick-bot in:title \"recipe v1\"";
+
+fn build_pr_search_query(search: &str, cursor: Option<&str>) -> String {
+    let after = cursor
+        .map(|cursor| format!(", after: \"{}\"", cursor))
+        .unwrap_or_default();
+    format!(
+        "query {{\n  search(query: {:?}, type: ISSUE, first: 100{}) {{ pageInfo {{ hasNextPage endCursor }} nodes {{ ... on PullRequest {{ number createdAt repository {{ name }} }} }} }}\n}}\n",
+        search, after
+    )
+}
+
+/// PRs of one search page, and the cursor of the next page if there is one
+fn parse_pr_search_response(
+    response: &serde_json::Value,
+) -> (Vec<OpenPullRequest>, Option<String>) {
+    let search = &response["search"];
+    let prs = search["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|pr| {
+            Some(OpenPullRequest {
+                repository: pr["repository"]["name"].as_str()?.to_string(),
+                number: pr["number"].as_u64()? as u32,
+                created_at: pr["createdAt"].as_str()?.to_string(),
+            })
+        })
+        .collect();
+    let next = if search["pageInfo"]["hasNextPage"].as_bool() == Some(true) {
+        search["pageInfo"]["endCursor"].as_str().map(String::from)
+    } else {
+        None
+    };
+    (prs, next)
+}
+
+fn build_users_query(logins: &[String]) -> String {
+    let mut query = String::from("query {\n");
+    for (i, login) in logins.iter().enumerate() {
+        query.push_str(&format!(
+            "  user{}: user(login: \"{}\") {{ {} }}\n",
+            i, login, USER_FIELDS
+        ));
+    }
+    query.push_str("}\n");
+    query
+}
+
+fn build_users_by_id_query(ids: &[String]) -> String {
+    let mut query = String::from("query {\n");
+    for (i, id) in ids.iter().enumerate() {
+        query.push_str(&format!(
+            "  user{}: node(id: \"{}\") {{ ... on User {{ {} }} }}\n",
+            i, id, USER_FIELDS
+        ));
+    }
+    query.push_str("}\n");
+    query
+}
+
+/// Accounts by the login or id they were requested with, in the order of `keys`
+fn parse_users_response(
+    keys: &[String],
+    response: &serde_json::Value,
+) -> Vec<(String, GitHubUser)> {
+    keys.iter()
+        .enumerate()
+        .filter_map(|(i, key)| {
+            let user = &response[format!("user{}", i)];
+            Some((
+                key.clone(),
+                GitHubUser {
+                    id: user["id"].as_str()?.to_string(),
+                    login: user["login"].as_str()?.to_string(),
+                    name: user["name"]
+                        .as_str()
+                        .filter(|name| !name.is_empty())
+                        .map(String::from),
+                    avatar_url: user["avatarUrl"].as_str()?.to_string(),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// How many PRs to look at per commit; a commit can be in several, e.g. reverted ones
+const ASSOCIATED_PRS: usize = 5;
+
+fn build_prs_for_commits_query(commits: &[(&str, &str)]) -> String {
+    let mut query = String::from("query {\n");
+    for (i, (feedstock, sha)) in commits.iter().enumerate() {
+        query.push_str(&format!(
+            "  repo{}: repository(owner: \"conda-forge\", name: \"{}\") {{ object(oid: \"{}\") {{ ... on Commit {{ associatedPullRequests(first: {}) {{ nodes {{ number merged author {{ login }} }} }} }} }} }}\n",
+            i, feedstock, sha, ASSOCIATED_PRS
+        ));
+    }
+    query.push_str("}\n");
+    query
+}
+
+/// The PR of each commit by feedstock, preferring the one that got merged. Commits
+/// without a PR (pushed directly) or that can't be found are left out. PRs by deleted
+/// accounts are credited to "unknown"
+fn parse_prs_for_commits_response(
+    commits: &[(&str, &str)],
+    response: &serde_json::Value,
+) -> Vec<(String, PullRequestInfo)> {
+    commits
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (feedstock, _))| {
+            let prs = response[format!("repo{}", i)]["object"]["associatedPullRequests"]["nodes"]
+                .as_array()?;
+            let pr = prs
+                .iter()
+                .find(|pr| pr["merged"].as_bool() == Some(true))
+                .or_else(|| prs.first())?;
+            let number = pr["number"].as_u64().filter(|n| *n > 0)? as u32;
+            let author = pr["author"]["login"]
+                .as_str()
+                .unwrap_or("unknown")
+                .to_string();
+            Some((feedstock.to_string(), PullRequestInfo { number, author }))
+        })
+        .collect()
+}
+
+/// How many recipe commits to look at per path after a conversion
+const FOLLOW_UP_COMMITS: usize = 20;
+
+fn build_follow_up_query(conversions: &[(&str, &str, &str)]) -> String {
+    let mut query = String::from("query {\n");
+    for (i, (feedstock, since, until)) in conversions.iter().enumerate() {
+        query.push_str(&format!(
+            "  repo{}: repository(owner: \"conda-forge\", name: \"{}\") {{ defaultBranchRef {{ target {{ ... on Commit {{\n",
+            i, feedstock
+        ));
+        for (j, path) in CANONICAL_RECIPE_FILES.iter().enumerate() {
+            query.push_str(&format!(
+                "    file{}: history(first: {}, path: \"{}\", since: \"{}\", until: \"{}\") {{ nodes {{ committedDate associatedPullRequests(first: {}) {{ nodes {{ number merged mergedAt author {{ login }} }} }} }} }}\n",
+                j, FOLLOW_UP_COMMITS, path, since, until, ASSOCIATED_PRS
+            ));
+        }
+        query.push_str("  } } } }\n");
+    }
+    query.push_str("}\n");
+    query
+}
+
+/// The oldest commit in the window is the conversion, so its PR is left out along with
+/// bot PRs and PRs merged after `until`. Of the rest, the one merged first is the fix.
+fn parse_follow_up_response(
+    conversions: &[(&str, &str, &str)],
+    response: &serde_json::Value,
+) -> Vec<(String, Option<u32>)> {
+    conversions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (feedstock, _, until))| {
+            let target = &response[format!("repo{}", i)]["defaultBranchRef"]["target"];
+            if target.is_null() {
+                return None;
+            }
+
+            // (committed date, merged PR) of each commit, oldest first
+            let mut commits: Vec<(&str, Option<&serde_json::Value>)> = (0..CANONICAL_RECIPE_FILES
+                .len())
+                .filter_map(|j| target[format!("file{}", j)]["nodes"].as_array())
+                .flatten()
+                .filter_map(|commit| {
+                    let pr = commit["associatedPullRequests"]["nodes"]
+                        .as_array()
+                        .and_then(|prs| prs.iter().find(|pr| pr["merged"].as_bool() == Some(true)));
+                    Some((commit["committedDate"].as_str()?, pr))
+                })
+                .collect();
+            commits.sort_by_key(|(date, _)| *date);
+
+            let conversion_pr = commits
+                .first()
+                .and_then(|(_, pr)| (*pr)?["number"].as_u64());
+            let fix = commits
+                .iter()
+                .filter_map(|(_, pr)| {
+                    let pr = (*pr)?;
+                    let number = pr["number"].as_u64()?;
+                    let merged_at = pr["mergedAt"].as_str()?;
+                    let by_bot = pr["author"]["login"].as_str().is_some_and(is_bot_username);
+                    (Some(number) != conversion_pr && !by_bot && merged_at <= *until)
+                        .then_some((merged_at, number as u32))
+                })
+                .min()
+                .map(|(_, number)| number);
+            Some((feedstock.to_string(), fix))
+        })
+        .collect()
+}
+
+fn build_build_state_query(feedstocks: &[String]) -> String {
+    let mut query = String::from("query {\n");
+    for (i, feedstock) in feedstocks.iter().enumerate() {
+        query.push_str(&format!(
+            "  repo{}: repository(owner: \"conda-forge\", name: \"{}\") {{ defaultBranchRef {{ target {{ ... on Commit {{ statusCheckRollup {{ state }} }} }} }} }}\n",
+            i, feedstock
+        ));
+    }
+    query.push_str("}\n");
+    query
+}
+
+/// Feedstocks without any status or check on their head commit are left out
+fn parse_build_state_response(
+    feedstocks: &[String],
+    response: &serde_json::Value,
+) -> Vec<(String, String)> {
+    feedstocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, feedstock)| {
+            let state = response[format!("repo{}", i)]["defaultBranchRef"]["target"]
+                ["statusCheckRollup"]["state"]
+                .as_str()?;
+            Some((feedstock.clone(), state.to_string()))
+        })
+        .collect()
+}
+
+/// Find the canonical recipe file in a REST commit response and how it was changed
+fn parse_recipe_file_change(commit: &serde_json::Value) -> Option<RecipeFileChange> {
+    commit["files"].as_array()?.iter().find_map(|file| {
+        let filename = file["filename"].as_str()?;
+        let path = CANONICAL_RECIPE_FILES.iter().find(|p| **p == filename)?;
+        Some(RecipeFileChange {
+            path,
+            status: file["status"].as_str()?.to_string(),
+        })
+    })
+}
+
 /// Info about a feedstock that needs git clone to find oldest commit
 /// (used when batch query returns >100 commits and needs pagination)
 #[derive(Debug)]
@@ -1051,8 +1590,12 @@ fn extract_first_commit_with_pagination(
     }
 }
 
-/// Fetch maintainers from recipe.yaml in a feedstock repo (fallback)
-pub async fn fetch_recipe_maintainers(feedstock: &str) -> Result<Vec<String>> {
+/// Fetch maintainers from recipe.yaml in a feedstock repo (fallback), through the
+/// local recipe store
+pub async fn fetch_recipe_maintainers(
+    feedstock: &str,
+    store: &mut RecipeStore,
+) -> Result<Vec<String>> {
     let paths = ["recipe.yaml", "recipe/recipe.yaml"];
 
     for path in paths {
@@ -1061,16 +1604,11 @@ pub async fn fetch_recipe_maintainers(feedstock: &str) -> Result<Vec<String>> {
             feedstock, path
         );
 
-        let response = reqwest::get(&url).await;
-        if let Ok(resp) = response {
-            if resp.status().is_success() {
-                if let Ok(content) = resp.text().await {
-                    // Parse YAML to extract maintainers
-                    if let Some(maintainers) = extract_maintainers_from_yaml(&content) {
-                        if !maintainers.is_empty() {
-                            return Ok(maintainers);
-                        }
-                    }
+        if let Ok(Some(content)) = store.fetch(&url).await {
+            // Parse YAML to extract maintainers
+            if let Some(maintainers) = extract_maintainers_from_yaml(&content) {
+                if !maintainers.is_empty() {
+                    return Ok(maintainers);
                 }
             }
         }
@@ -1147,4 +1685,248 @@ extra:
         let maintainers = extract_maintainers_from_yaml(yaml).unwrap();
         assert_eq!(maintainers, vec!["user1", "user2", "user3"]);
     }
+
+    #[test]
+    fn test_parse_recipe_file_change() {
+        let commit = serde_json::json!({
+            "files": [
+                { "filename": "README.md", "status": "modified" },
+                { "filename": "recipe/recipe.yaml", "status": "modified" },
+            ]
+        });
+        let change = parse_recipe_file_change(&commit).unwrap();
+        assert_eq!(change.path, "recipe/recipe.yaml");
+        assert!(!change.created_file());
+
+        let commit = serde_json::json!({
+            "files": [{ "filename": "recipe.yaml", "status": "added" }]
+        });
+        assert!(parse_recipe_file_change(&commit).unwrap().created_file());
+
+        let commit =
+            serde_json::json!({ "files": [{ "filename": "meta.yaml", "status": "added" }] });
+        assert_eq!(parse_recipe_file_change(&commit), None);
+    }
+
+    #[test]
+    fn test_parse_recipe_files_response() {
+        let feedstocks: Vec<String> = ["canonical", "near-miss", "missing", "none"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let response = serde_json::json!({
+            "repo0": { "file0": { "id": "a" }, "file2": { "id": "b" } },
+            "repo1": { "file0": null, "file1": null, "file2": null, "file3": { "id": "c" } },
+            "repo2": null,
+            "repo3": { "file0": null },
+        });
+
+        let results = parse_recipe_files_response(&feedstocks, &response);
+        assert_eq!(
+            results,
+            vec![("near-miss".to_string(), "recipe.yml".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_pushed_at_response() {
+        let feedstocks = vec!["numpy-feedstock".to_string(), "gone-feedstock".to_string()];
+        assert!(build_pushed_at_query(&feedstocks)
+            .contains("repo1: repository(owner: \"conda-forge\", name: \"gone-feedstock\")"));
+
+        let response = serde_json::json!({
+            "repo0": { "pushedAt": "2025-06-01T12:00:00Z" },
+            "repo1": null,
+        });
+        assert_eq!(
+            parse_pushed_at_response(&feedstocks, &response),
+            vec![(
+                "numpy-feedstock".to_string(),
+                "2025-06-01T12:00:00Z".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_users_response() {
+        let logins = vec!["Alice".to_string(), "gone".to_string()];
+        assert!(build_users_query(&logins).contains("user1: user(login: \"gone\")"));
+        assert!(build_users_by_id_query(&["U_1".to_string()]).contains("node(id: \"U_1\")"));
+
+        let response = serde_json::json!({
+            "user0": {
+                "id": "U_1",
+                "login": "alice",
+                "name": "",
+                "avatarUrl": "https://avatars.githubusercontent.com/u/1?v=4",
+            },
+            "user1": null,
+        });
+        assert_eq!(
+            parse_users_response(&logins, &response),
+            vec![(
+                "Alice".to_string(),
+                GitHubUser {
+                    id: "U_1".to_string(),
+                    login: "alice".to_string(),
+                    name: None,
+                    avatar_url: "https://avatars.githubusercontent.com/u/1?v=4".to_string(),
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_pr_search_response() {
+        let query = build_pr_search_query(MIGRATION_PR_SEARCH, Some("Y3Vyc29y"));
+        assert!(query.contains("after: \"Y3Vyc29y\""));
+        assert!(query.contains("\\\"recipe v1\\\""));
+
+        let response = serde_json::json!({
+            "search": {
+                "pageInfo": { "hasNextPage": true, "endCursor": "Y3Vyc29yOjI=" },
+                "nodes": [
+                    {
+                        "number": 42,
+                        "createdAt": "2025-06-01T12:00:00Z",
+                        "repository": { "name": "numpy-feedstock" },
+                    },
+                    // Issues matching the search have no PullRequest fields
+                    {},
+                ],
+            },
+        });
+        let (prs, next) = parse_pr_search_response(&response);
+        assert_eq!(
+            prs,
+            vec![OpenPullRequest {
+                repository: "numpy-feedstock".to_string(),
+                number: 42,
+                created_at: "2025-06-01T12:00:00Z".to_string(),
+            }]
+        );
+        assert_eq!(next.as_deref(), Some("Y3Vyc29yOjI="));
+
+        let last_page = serde_json::json!({
+            "search": { "pageInfo": { "hasNextPage": false, "endCursor": null }, "nodes": [] },
+        });
+        assert_eq!(parse_pr_search_response(&last_page), (vec![], None));
+    }
+
+    #[test]
+    fn test_parse_build_state_response() {
+        let feedstocks = vec![
+            "numpy-feedstock".to_string(),
+            "unchecked-feedstock".to_string(),
+            "gone-feedstock".to_string(),
+        ];
+        assert!(build_build_state_query(&feedstocks).contains("statusCheckRollup { state }"));
+
+        let response = serde_json::json!({
+            "repo0": { "defaultBranchRef": { "target": { "statusCheckRollup": { "state": "SUCCESS" } } } },
+            "repo1": { "defaultBranchRef": { "target": { "statusCheckRollup": null } } },
+            "repo2": null,
+        });
+        assert_eq!(
+            parse_build_state_response(&feedstocks, &response),
+            vec![("numpy-feedstock".to_string(), "SUCCESS".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_follow_up_response() {
+        let window = ("2025-03-01T00:00:00Z", "2025-03-08T00:00:00Z");
+        let conversions = [
+            ("numpy-feedstock", window.0, window.1),
+            ("scipy-feedstock", window.0, window.1),
+            ("gone-feedstock", window.0, window.1),
+        ];
+        let query = build_follow_up_query(&conversions);
+        assert!(query.contains(
+            "file0: history(first: 20, path: \"recipe/recipe.yaml\", since: \"2025-03-01T00:00:00Z\", until: \"2025-03-08T00:00:00Z\")"
+        ));
+
+        let pr = |number: u32, merged_at: &str, login: &str| {
+            serde_json::json!({ "associatedPullRequests": { "nodes": [
+                { "number": number, "merged": true, "mergedAt": merged_at, "author": { "login": login } },
+            ] } })
+        };
+        let commit = |date: &str, pr: serde_json::Value| {
+            let mut commit = pr;
+            commit["committedDate"] = date.into();
+            commit
+        };
+        let response = serde_json::json!({
+            "repo0": { "defaultBranchRef": { "target": {
+                "file0": { "nodes": [
+                    commit("2025-03-06T00:00:00Z", pr(14, "2025-03-06T00:00:00Z", "carol")),
+                    commit("2025-03-04T00:00:00Z", pr(13, "2025-03-04T00:00:00Z", "regro-cf-autotick-bot")),
+                    commit("2025-03-02T00:00:00Z", pr(12, "2025-03-03T00:00:00Z", "alice")),
+                    commit("2025-03-01T00:00:00Z", pr(12, "2025-03-03T00:00:00Z", "alice")),
+                ] },
+                "file1": { "nodes": [] },
+            } } },
+            "repo1": { "defaultBranchRef": { "target": {
+                "file0": { "nodes": [
+                    commit("2025-03-07T00:00:00Z", pr(8, "2025-03-10T00:00:00Z", "bob")),
+                    commit("2025-03-01T00:00:00Z", pr(7, "2025-03-01T00:00:00Z", "bob")),
+                ] },
+                "file1": { "nodes": [] },
+            } } },
+            "repo2": null,
+        });
+        assert_eq!(
+            parse_follow_up_response(&conversions, &response),
+            vec![
+                ("numpy-feedstock".to_string(), Some(14)),
+                ("scipy-feedstock".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_prs_for_commits_response() {
+        let commits = [
+            ("numpy-feedstock", "abc123"),
+            ("direct-push-feedstock", "def456"),
+            ("ghost-feedstock", "0123ab"),
+            ("gone-feedstock", "fedcba"),
+        ];
+        let query = build_prs_for_commits_query(&commits);
+        assert!(query.contains(
+            "repo1: repository(owner: \"conda-forge\", name: \"direct-push-feedstock\") { object(oid: \"def456\")"
+        ));
+        assert!(query.contains("associatedPullRequests(first: 5)"));
+
+        let response = serde_json::json!({
+            "repo0": { "object": { "associatedPullRequests": { "nodes": [
+                { "number": 12, "merged": false, "author": { "login": "alice" } },
+                { "number": 15, "merged": true, "author": { "login": "bob" } },
+            ] } } },
+            "repo1": { "object": { "associatedPullRequests": { "nodes": [] } } },
+            "repo2": { "object": { "associatedPullRequests": { "nodes": [
+                { "number": 3, "merged": true, "author": null },
+            ] } } },
+            "repo3": { "object": null },
+        });
+        assert_eq!(
+            parse_prs_for_commits_response(&commits, &response),
+            vec![
+                (
+                    "numpy-feedstock".to_string(),
+                    PullRequestInfo {
+                        number: 15,
+                        author: "bob".to_string()
+                    }
+                ),
+                (
+                    "ghost-feedstock".to_string(),
+                    PullRequestInfo {
+                        number: 3,
+                        author: "unknown".to_string()
+                    }
+                ),
+            ]
+        );
+    }
 }
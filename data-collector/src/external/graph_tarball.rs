@@ -0,0 +1,176 @@
+//! Fetch node_attrs and pr_info from the cf-graph tarball on codeload instead of a git
+//! sparse checkout, so collecting needs no git binary. The tarball is streamed and
+//! only the wanted entries are written out.
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use crate::config::CF_GRAPH_TARBALL_URL;
+
+/// Top-level directories of the repository that are extracted
+const EXTRACTED_DIRS: &[&str] = &["node_attrs", "pr_info"];
+
+/// Download the tarball into the cf-graph path unless it holds node_attrs and pr_info
+/// already, e.g. from an earlier run or a sparse checkout
+pub async fn ensure_graph_tarball(
+    repo_path: &Path,
+    force_reload: bool,
+    verbose: bool,
+) -> Result<()> {
+    let repo_path = repo_path.to_path_buf();
+    if force_reload && repo_path.exists() {
+        println!("🗑️  Removing existing cf-graph data for a fresh download...");
+        fs::remove_dir_all(&repo_path).context("Failed to remove existing cf-graph data")?;
+    }
+    if EXTRACTED_DIRS
+        .iter()
+        .all(|dir| repo_path.join(dir).exists())
+    {
+        if verbose {
+            println!("📂 Using existing cf-graph data in {}", repo_path.display());
+        }
+        return Ok(());
+    }
+    if repo_path.exists() {
+        println!("📂 Existing cf-graph data incomplete, downloading again...");
+        fs::remove_dir_all(&repo_path).context("Failed to remove existing cf-graph data")?;
+    }
+
+    println!("📥 Streaming node_attrs and pr_info from the cf-graph-countyfair tarball...");
+    // The tar reader is blocking, so the download runs on a blocking thread too
+    let files = tokio::task::spawn_blocking(move || download_graph_tarball(&repo_path))
+        .await
+        .context("Tarball download panicked")??;
+    println!("✅ Extracted {} files from the tarball", files);
+    Ok(())
+}
+
+/// Download and extract into `repo_path`. Entries are extracted into a temporary
+/// directory next to it, which only replaces `repo_path` once complete.
+fn download_graph_tarball(repo_path: &Path) -> Result<usize> {
+    let response = reqwest::blocking::Client::builder()
+        .user_agent("are-we-recipe-v1-yet/1.0")
+        // The default 30 second timeout would cut off the download itself
+        .timeout(None)
+        .build()?
+        .get(CF_GRAPH_TARBALL_URL)
+        .send()
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to download {}", CF_GRAPH_TARBALL_URL))?;
+
+    let parent = repo_path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    fs::create_dir_all(parent)?;
+    let partial = tempfile::tempdir_in(parent)?;
+    let files = extract_graph_tarball(response, partial.path())?;
+    fs::rename(partial.keep(), repo_path).with_context(|| {
+        format!(
+            "Failed to move the extracted files to {}",
+            repo_path.display()
+        )
+    })?;
+    Ok(files)
+}
+
+/// Write the node_attrs and pr_info files of the gzipped tarball read from `reader`
+/// into `dest`, dropping the `<repo>-<branch>/` directory every entry is under.
+/// Returns how many files were written.
+pub fn extract_graph_tarball(reader: impl Read, dest: &Path) -> Result<usize> {
+    let mut archive = tar::Archive::new(GzDecoder::new(reader));
+    let mut files = 0;
+    for entry in archive.entries().context("Failed to read the tarball")? {
+        let mut entry = entry.context("Failed to read the tarball")?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let Some(relative) = wanted_path(&entry.path()?) else {
+            continue;
+        };
+        let target = dest.join(relative);
+        if let Some(dir) = target.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        entry
+            .unpack(&target)
+            .with_context(|| format!("Failed to write {}", target.display()))?;
+        files += 1;
+    }
+    Ok(files)
+}
+
+/// `path` without its first component, if it is under one of `EXTRACTED_DIRS`. Paths
+/// with anything but plain names (e.g. `..`) are never wanted.
+fn wanted_path(path: &Path) -> Option<PathBuf> {
+    let mut components = path.components();
+    components.next()?;
+    let relative: PathBuf = components
+        .map(|component| match component {
+            Component::Normal(name) => Some(name),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    let top = relative.components().next()?;
+    EXTRACTED_DIRS
+        .iter()
+        .any(|dir| top.as_os_str() == *dir)
+        .then_some(relative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+
+    #[test]
+    fn test_extract_graph_tarball() {
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Default::default()));
+        let mut add = |path: &str, contents: &str| {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, contents.as_bytes())
+                .unwrap();
+        };
+        add("cf-graph-countyfair-main/node_attrs/n/u/numpy.json", "{}");
+        add(
+            "cf-graph-countyfair-main/pr_info/n/u/numpy.json",
+            "{\"PRed\": []}",
+        );
+        add("cf-graph-countyfair-main/graph.json", "{}");
+        add("cf-graph-countyfair-main/versions/n/u/numpy.json", "{}");
+        drop(add);
+        let tarball = builder.into_inner().unwrap().finish().unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let files = extract_graph_tarball(tarball.as_slice(), dir.path()).unwrap();
+        assert_eq!(files, 2);
+        assert!(dir.path().join("node_attrs/n/u/numpy.json").is_file());
+        assert!(dir.path().join("pr_info/n/u/numpy.json").is_file());
+        assert!(!dir.path().join("graph.json").exists());
+        assert!(!dir.path().join("versions").exists());
+    }
+
+    #[test]
+    fn test_wanted_path() {
+        assert_eq!(
+            wanted_path(Path::new("repo-main/node_attrs/a/b.json")),
+            Some(PathBuf::from("node_attrs/a/b.json"))
+        );
+        assert_eq!(
+            wanted_path(Path::new("repo-main/node_attrs_old/a.json")),
+            None
+        );
+        assert_eq!(
+            wanted_path(Path::new("repo-main/pr_info/../../etc/x")),
+            None
+        );
+        assert_eq!(wanted_path(Path::new("node_attrs")), None);
+    }
+}
@@ -0,0 +1,35 @@
+//! Run an external recipe linter, e.g. `conda-smithy recipe-lint --conda-forge`, over
+//! recipe.yaml files
+
+use anyhow::{Context, Result};
+use std::fs;
+
+/// Lint `recipe` (recipe.yaml content) with `command`, which gets the directory holding
+/// the recipe appended as its last argument. Ok(true) when the linter exits successfully.
+pub async fn lint_recipe(command: &str, recipe: &str) -> Result<bool> {
+    let dir = tempfile::tempdir()?;
+    fs::write(dir.path().join("recipe.yaml"), recipe)?;
+
+    let mut parts = command.split_whitespace();
+    let program = parts.next().context("The lint command is empty")?;
+    let output = tokio::process::Command::new(program)
+        .args(parts)
+        .arg(dir.path())
+        .output()
+        .await
+        .with_context(|| format!("Failed to run {}", program))?;
+    Ok(output.status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lint_recipe_exit_status() {
+        assert!(lint_recipe("true", "package: {}").await.unwrap());
+        assert!(!lint_recipe("false", "package: {}").await.unwrap());
+        assert!(lint_recipe("no-such-linter", "").await.is_err());
+        assert!(lint_recipe("", "").await.is_err());
+    }
+}
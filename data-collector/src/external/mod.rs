@@ -1,5 +1,13 @@
 pub mod downloads;
 pub mod github;
+pub mod graph_tarball;
+pub mod linter;
+pub mod object_storage;
+pub mod recipe_store;
 
 pub use downloads::*;
 pub use github::*;
+pub use graph_tarball::*;
+pub use linter::*;
+pub use object_storage::*;
+pub use recipe_store::*;
@@ -0,0 +1,201 @@
+//! Local mirror of raw recipe files from raw.githubusercontent.com. Contents are stored
+//! by their SHA-256 and revalidated with ETags, so unchanged recipes aren't downloaded
+//! again on later runs.
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const INDEX_FILE: &str = "index.json";
+const OBJECTS_DIR: &str = "objects";
+
+/// Latest known version of a URL
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct StoredRecipe {
+    etag: Option<String>,
+    sha256: String,
+}
+
+/// What GitHub answered for a URL, before it is applied to the store
+enum Download {
+    /// GitHub couldn't be reached
+    Offline(reqwest::Error),
+    NotModified,
+    NotFound,
+    Content(Option<String>, String),
+    Failed(StatusCode),
+}
+
+pub struct RecipeStore {
+    root: PathBuf,
+    /// URL -> stored version, saved as `index.json`
+    index: BTreeMap<String, StoredRecipe>,
+    client: reqwest::Client,
+    /// Requests answered from the store (304 Not Modified or offline)
+    pub hits: usize,
+    pub downloads: usize,
+}
+
+impl RecipeStore {
+    /// Open the store at `root`, starting empty if it doesn't exist yet
+    pub fn open(root: &Path) -> Result<Self> {
+        let index_path = root.join(INDEX_FILE);
+        let index = match fs::read_to_string(&index_path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("Invalid recipe store index {}", index_path.display()))?,
+            Err(_) => BTreeMap::new(),
+        };
+        Ok(Self {
+            root: root.to_path_buf(),
+            index,
+            client: reqwest::Client::new(),
+            hits: 0,
+            downloads: 0,
+        })
+    }
+
+    /// Fetch `url`, revalidating a stored copy with its ETag. Returns None if the
+    /// file doesn't exist. When GitHub can't be reached, the stored copy is used.
+    pub async fn fetch(&mut self, url: &str) -> Result<Option<String>> {
+        let download = self.download(url).await;
+        self.apply(url, download)
+    }
+
+    /// `fetch` each of `urls`, with up to `concurrency` requests in flight. Results
+    /// are in the order of `urls`.
+    pub async fn fetch_many(
+        &mut self,
+        urls: &[String],
+        concurrency: usize,
+    ) -> Vec<Result<Option<String>>> {
+        let downloads: Vec<Download> = futures::stream::iter(urls)
+            .map(|url| self.download(url))
+            .buffered(concurrency)
+            .collect()
+            .await;
+        urls.iter()
+            .zip(downloads)
+            .map(|(url, download)| self.apply(url, download))
+            .collect()
+    }
+
+    /// Request `url`, revalidating the stored copy if there is one
+    async fn download(&self, url: &str) -> Download {
+        let mut request = self.client.get(url);
+        if let Some(etag) = self.index.get(url).and_then(|s| s.etag.as_ref()) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => return Download::Offline(e),
+        };
+        match response.status() {
+            StatusCode::NOT_MODIFIED => Download::NotModified,
+            StatusCode::NOT_FOUND => Download::NotFound,
+            status if status.is_success() => {
+                let etag = response
+                    .headers()
+                    .get(ETAG)
+                    .and_then(|v| v.to_str().ok())
+                    .map(String::from);
+                match response.text().await {
+                    Ok(content) => Download::Content(etag, content),
+                    Err(e) => Download::Offline(e),
+                }
+            }
+            status => Download::Failed(status),
+        }
+    }
+
+    fn apply(&mut self, url: &str, download: Download) -> Result<Option<String>> {
+        let stored = self.index.get(url).cloned();
+        match download {
+            Download::Offline(e) => match stored {
+                Some(stored) => {
+                    self.hits += 1;
+                    self.read_object(&stored.sha256).map(Some)
+                }
+                None => Err(e.into()),
+            },
+            Download::NotModified if stored.is_some() => {
+                self.hits += 1;
+                self.read_object(&stored.unwrap().sha256).map(Some)
+            }
+            Download::NotFound => {
+                self.index.remove(url);
+                Ok(None)
+            }
+            Download::Content(etag, content) => {
+                self.downloads += 1;
+                self.insert(url, etag, &content)?;
+                Ok(Some(content))
+            }
+            Download::NotModified => {
+                anyhow::bail!("Fetching {} failed: {}", url, StatusCode::NOT_MODIFIED)
+            }
+            Download::Failed(status) => anyhow::bail!("Fetching {} failed: {}", url, status),
+        }
+    }
+
+    /// Write the index, call once done fetching
+    pub fn save(&self) -> Result<()> {
+        fs::create_dir_all(&self.root)?;
+        let index_path = self.root.join(INDEX_FILE);
+        fs::write(&index_path, serde_json::to_string_pretty(&self.index)?)
+            .with_context(|| format!("Failed to write {}", index_path.display()))
+    }
+
+    fn insert(&mut self, url: &str, etag: Option<String>, content: &str) -> Result<()> {
+        let sha256 = format!("{:x}", Sha256::digest(content.as_bytes()));
+        let path = self.object_path(&sha256);
+        // Identical recipes (e.g. on another branch or path) share one object
+        if !path.exists() {
+            fs::create_dir_all(self.root.join(OBJECTS_DIR))?;
+            fs::write(&path, content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        self.index
+            .insert(url.to_string(), StoredRecipe { etag, sha256 });
+        Ok(())
+    }
+
+    fn read_object(&self, sha256: &str) -> Result<String> {
+        let path = self.object_path(sha256);
+        fs::read_to_string(&path).with_context(|| format!("Missing recipe {}", path.display()))
+    }
+
+    fn object_path(&self, sha256: &str) -> PathBuf {
+        self.root.join(OBJECTS_DIR).join(sha256)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_contents_share_an_object() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = RecipeStore::open(dir.path()).unwrap();
+        store
+            .insert("https://a/recipe.yaml", Some("\"a\"".to_string()), "x: 1")
+            .unwrap();
+        store.insert("https://b/recipe.yaml", None, "x: 1").unwrap();
+        store.save().unwrap();
+
+        let objects = fs::read_dir(dir.path().join(OBJECTS_DIR)).unwrap().count();
+        assert_eq!(objects, 1);
+
+        let reopened = RecipeStore::open(dir.path()).unwrap();
+        assert_eq!(reopened.index, store.index);
+        let stored = &reopened.index["https://a/recipe.yaml"];
+        assert_eq!(stored.etag.as_deref(), Some("\"a\""));
+        assert_eq!(reopened.read_object(&stored.sha256).unwrap(), "x: 1");
+    }
+}
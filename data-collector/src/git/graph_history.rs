@@ -0,0 +1,137 @@
+//! Read node_attrs as they were at past cf-graph-countyfair commits, from a blobless
+//! clone with the full history instead of the shallow sparse checkout
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::config::CF_GRAPH_REPO_URL;
+
+/// Object ids passed to one `git fetch` when filling in blobs of a blobless clone
+const FETCH_CHUNK: usize = 1000;
+
+/// Clone the commit history (without file contents) into `repo_path`, or fetch the
+/// commits added since the last run
+pub fn ensure_history_clone(repo_path: &Path, verbose: bool) -> Result<()> {
+    if repo_path.exists() {
+        if verbose {
+            println!("📂 Updating cf-graph history in {}", repo_path.display());
+        }
+        git(
+            repo_path,
+            &["fetch", "origin", "+refs/heads/main:refs/heads/main"],
+        )?;
+        return Ok(());
+    }
+
+    println!("📥 Cloning the cf-graph-countyfair history (commits and trees only)...");
+    let output = Command::new("git")
+        .args(["clone", "--bare", "--filter=blob:none", "--single-branch"])
+        .args(["--branch", "main", CF_GRAPH_REPO_URL])
+        .arg(repo_path)
+        .output()
+        .context("Failed to run git clone")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git clone failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(())
+}
+
+/// Last commit on `main` made before the end of `date` (UTC), None if there is none
+pub fn commit_at(repo_path: &Path, date: NaiveDate) -> Result<Option<String>> {
+    let before = format!("--before={}T23:59:59Z", date);
+    let commit = git(
+        repo_path,
+        &["rev-list", "-1", "--first-parent", &before, "main"],
+    )?;
+    let commit = commit.trim();
+    Ok((!commit.is_empty()).then(|| commit.to_string()))
+}
+
+/// (path, blob id) of every node_attrs JSON file at `commit`
+pub fn node_attrs_blobs(repo_path: &Path, commit: &str) -> Result<Vec<(String, String)>> {
+    let listing = git(repo_path, &["ls-tree", "-r", commit, "--", "node_attrs"])?;
+    Ok(listing
+        .lines()
+        .filter_map(|line| {
+            // "<mode> blob <id>\t<path>"
+            let (meta, path) = line.split_once('\t')?;
+            let mut fields = meta.split_whitespace();
+            let (_, kind, id) = (fields.next()?, fields.next()?, fields.next()?);
+            (kind == "blob" && path.ends_with(".json")).then(|| (path.to_string(), id.to_string()))
+        })
+        .collect())
+}
+
+/// Contents of the blobs `ids`, in order. In a blobless clone, the missing blobs are
+/// fetched first in large batches, since git would otherwise fetch them one by one.
+pub fn read_blobs(repo_path: &Path, ids: &[String]) -> Result<Vec<Vec<u8>>> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let promisor = git(repo_path, &["config", "--get", "remote.origin.promisor"])
+        .is_ok_and(|value| value.trim() == "true");
+    if promisor {
+        for chunk in ids.chunks(FETCH_CHUNK) {
+            let mut args = vec!["fetch", "--quiet", "--no-tags", "origin"];
+            args.extend(chunk.iter().map(String::as_str));
+            git(repo_path, &args)?;
+        }
+    }
+
+    let mut child = Command::new("git")
+        .current_dir(repo_path)
+        .args(["cat-file", "--batch"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Failed to run git cat-file")?;
+    let mut stdin = child.stdin.take().context("git cat-file has no stdin")?;
+    let request = ids.join("\n") + "\n";
+    // Write from a thread so a full stdout pipe can't block both processes
+    let writer = std::thread::spawn(move || stdin.write_all(request.as_bytes()));
+
+    let mut stdout = BufReader::new(child.stdout.take().context("git cat-file has no stdout")?);
+    let mut blobs = Vec::with_capacity(ids.len());
+    for id in ids {
+        // "<id> <type> <size>\n<contents>\n", or "<id> missing\n"
+        let mut header = String::new();
+        stdout.read_line(&mut header)?;
+        let size: usize = match header.split_whitespace().collect::<Vec<_>>()[..] {
+            [_, "blob", size] => size.parse()?,
+            _ => anyhow::bail!("Can't read blob {}: {}", id, header.trim()),
+        };
+        let mut contents = vec![0; size + 1];
+        stdout.read_exact(&mut contents)?;
+        contents.pop();
+        blobs.push(contents);
+    }
+
+    writer
+        .join()
+        .map_err(|_| anyhow::anyhow!("git cat-file writer panicked"))??;
+    child.wait()?;
+    Ok(blobs)
+}
+
+/// Run git in `repo_path` and return its output, failing on a non-zero exit
+fn git(repo_path: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args[0]))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
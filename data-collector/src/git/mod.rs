@@ -1,3 +1,5 @@
+pub mod graph_history;
 pub mod sparse_checkout;
 
+pub use graph_history::*;
 pub use sparse_checkout::*;
@@ -3,11 +3,13 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
-use crate::config::{CF_GRAPH_LOCAL_PATH, CF_GRAPH_REPO_URL};
-
-pub fn ensure_sparse_checkout_repo(force_reload: bool, verbose: bool) -> Result<()> {
-    let repo_path = Path::new(CF_GRAPH_LOCAL_PATH);
+use crate::config::CF_GRAPH_REPO_URL;
 
+pub fn ensure_sparse_checkout_repo(
+    repo_path: &Path,
+    force_reload: bool,
+    verbose: bool,
+) -> Result<()> {
     if force_reload && repo_path.exists() {
         println!("🗑️  Removing existing repository for fresh sparse checkout...");
         fs::remove_dir_all(repo_path).context("Failed to remove existing repository")?;
@@ -15,7 +17,7 @@ pub fn ensure_sparse_checkout_repo(force_reload: bool, verbose: bool) -> Result<
 
     if !repo_path.exists() {
         println!("📥 Creating sparse checkout of cf-graph-countyfair repository...");
-        println!("🎯 Only downloading node_attrs directory (much faster than full clone)");
+        println!("🎯 Only downloading node_attrs and pr_info (much faster than full clone)");
 
         // Create directory and initialize git
         fs::create_dir_all(repo_path).context("Failed to create repository directory")?;
@@ -75,11 +77,11 @@ pub fn ensure_sparse_checkout_repo(force_reload: bool, verbose: bool) -> Result<
 
         // Set sparse checkout patterns
         let sparse_checkout_path = repo_path.join(".git/info/sparse-checkout");
-        fs::write(&sparse_checkout_path, "node_attrs/*\n")
+        fs::write(&sparse_checkout_path, "node_attrs/*\npr_info/*\n")
             .context("Failed to write sparse-checkout file")?;
 
         if verbose {
-            println!("✅ Sparse checkout pattern set to node_attrs/*");
+            println!("✅ Sparse checkout patterns set to node_attrs/* and pr_info/*");
         }
 
         // Pull with depth=1
@@ -111,9 +113,9 @@ pub fn ensure_sparse_checkout_repo(force_reload: bool, verbose: bool) -> Result<
             }
         }
     } else {
-        // Check if existing sparse checkout is valid
-        let node_attrs_path = repo_path.join("node_attrs");
-        if node_attrs_path.exists() {
+        // Check if existing sparse checkout is valid (checkouts made before pr_info was
+        // needed are recreated once)
+        if repo_path.join("node_attrs").exists() && repo_path.join("pr_info").exists() {
             if verbose {
                 println!("📂 Using existing sparse checkout");
             }
@@ -121,16 +123,14 @@ pub fn ensure_sparse_checkout_repo(force_reload: bool, verbose: bool) -> Result<
         } else {
             println!("📂 Existing sparse checkout incomplete, recreating...");
             fs::remove_dir_all(repo_path).context("Failed to remove existing repository")?;
-            return ensure_sparse_checkout_repo(false, verbose); // Recursive call to re-create fresh
+            return ensure_sparse_checkout_repo(repo_path, false, verbose); // Recursive call to re-create fresh
         }
     }
 
     Ok(())
 }
 
-pub fn cleanup_sparse_checkout_repo(verbose: bool) -> Result<()> {
-    let repo_path = Path::new(CF_GRAPH_LOCAL_PATH);
-
+pub fn cleanup_sparse_checkout_repo(repo_path: &Path, verbose: bool) -> Result<()> {
     if repo_path.exists() {
         if verbose {
             println!("🗑️  Cleaning up sparse checkout repository...");
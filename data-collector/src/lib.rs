@@ -2,6 +2,7 @@ pub mod config;
 pub mod external;
 pub mod git;
 pub mod models;
+pub mod profiling;
 pub mod stats;
 
 pub use config::*;
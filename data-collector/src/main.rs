@@ -1,10 +1,25 @@
 use anyhow::{Context, Result};
-use clap::Parser;
-use std::fs;
+use clap::{CommandFactory, Parser};
+use std::path::Path;
+use std::process::Command;
 
-use data_collector::git::cleanup_sparse_checkout_repo;
+use data_collector::config::Paths;
+use data_collector::external::{
+    backup_stats_file, load_download_counts, write_download_counts, ObjectStorage,
+};
+use data_collector::git::{cleanup_sparse_checkout_repo, ensure_history_clone};
 use data_collector::models::*;
-use data_collector::stats::{collect_attributions, collect_stats_from_node_attrs, load_existing_stats};
+use data_collector::profiling::MemoryProfiler;
+#[cfg(feature = "parquet")]
+use data_collector::stats::write_parquet_tables;
+use data_collector::stats::{
+    attribute_one, backfill_history, backfill_last_changed, check_annotation, collect_attributions,
+    collect_stats_from_node_attrs, default_hooks, fetch_downloads, load_existing_stats_if_exists,
+    load_site_status, local_recipe_type, record_history, research_export,
+    resolve_contributor_identities, sample_dates, send_conversion_webhooks, summary_lines,
+    validate_stats, verify_conversion_attributions, write_feedstock_csv, CacheInvalidation,
+    History, NodeAttrsIgnoreList, PendingAttribution, StatsStore,
+};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -12,69 +27,364 @@ async fn main() -> Result<()> {
     dotenvy::dotenv().ok();
 
     let cli = Cli::parse();
+    let paths = cli.paths();
+    let store = &paths.store;
+    let resume = matches!(cli.command, Some(Commands::Resume));
+    // Resuming must keep the cached commit info and finished attributions
+    let invalidation = if resume {
+        CacheInvalidation::default()
+    } else {
+        cli.invalidation()
+    };
+    invalidation.clear_files(&paths)?;
+    // attribute stage: run attribution on the output of scan-graph
+    let attribute_stage = matches!(cli.command, Some(Commands::Attribute));
+    let mut profiler = MemoryProfiler::new(cli.profile_memory);
+
+    match &cli.command {
+        Some(Commands::FetchDownloads { output }) => {
+            let counts =
+                fetch_downloads(&paths, load_existing_stats_if_exists(store).as_ref()).await?;
+            write_download_counts(output, &counts)?;
+            println!("💾 Download counts saved to {}", output.display());
+            return Ok(());
+        }
+        Some(Commands::ScanGraph {
+            force_clone,
+            downloads,
+        }) => {
+            let download_counts = downloads.as_deref().map(load_download_counts).transpose()?;
+            let mut stats = collect_stats_from_node_attrs(
+                &paths,
+                download_counts,
+                cli.cf_graph_source,
+                *force_clone,
+                cli.verbose,
+                cli.allow_schema_drift,
+                cli.lint_command.as_deref(),
+                &mut profiler,
+            )
+            .await?;
+            stats.memory_profile = profiler.stages().to_vec();
+            let feedstock_states = std::mem::take(&mut stats.feedstock_states);
+            store
+                .save(&stats, &feedstock_states)
+                .with_context(|| format!("Failed to write {}", store))?;
+            record_history(&paths.history, &stats)?;
+            backup_stats(&cli, store).await;
+            cleanup_sparse_checkout_repo(&paths.cf_graph, cli.verbose)?;
+            println!(
+                "💾 Classified {} feedstocks, run `attribute` next",
+                stats.total_feedstocks
+            );
+            return Ok(());
+        }
+        Some(Commands::AttributeOne { feedstock }) => {
+            let mut stats = store
+                .load()
+                .context("Failed to load existing stats - run full analysis first")?;
+            let mut feedstock_states = std::mem::take(&mut stats.feedstock_states);
+            attribute_one(&paths, &mut feedstock_states, feedstock).await?;
+            store
+                .save(&stats, &feedstock_states)
+                .with_context(|| format!("Failed to write {}", store))?;
+            backup_stats(&cli, store).await;
+            println!("💾 Results saved to {}", store);
+            return Ok(());
+        }
+        Some(Commands::ResolveContributors { ttl_days }) => {
+            let stats = store
+                .load()
+                .context("Failed to load existing stats - run full analysis first")?;
+            resolve_contributor_identities(
+                &stats.feedstock_states,
+                &paths.identity_cache,
+                *ttl_days,
+            )
+            .await?;
+            println!("💾 Identities saved to {}", paths.identity_cache.display());
+            return Ok(());
+        }
+        Some(Commands::Crunch { site_root }) => return crunch(&paths, site_root.as_deref()),
+        Some(Commands::Export { format, output }) => {
+            let stats = store.load()?;
+            let output = output.clone().unwrap_or_else(|| format.default_output());
+            match format {
+                ExportFormat::Csv => {
+                    let file = std::fs::File::create(&output)
+                        .with_context(|| format!("Failed to create {}", output.display()))?;
+                    let rows = write_feedstock_csv(&stats, std::io::BufWriter::new(file))?;
+                    println!("📤 Exported {} feedstocks to {}", rows, output.display());
+                }
+                #[cfg(feature = "parquet")]
+                ExportFormat::Parquet => {
+                    for (file, rows) in write_parquet_tables(&stats, &output)? {
+                        println!(
+                            "📤 Exported {} rows to {}",
+                            rows,
+                            output.join(file).display()
+                        );
+                    }
+                }
+            }
+            return Ok(());
+        }
+        Some(Commands::CheckFeedstock { path, status }) => {
+            let status = match status {
+                Some(source) => match load_site_status(source).await {
+                    Ok(status) => Some(status),
+                    Err(e) => {
+                        eprintln!("⚠️  Could not read the migration status: {:#}", e);
+                        None
+                    }
+                },
+                None => None,
+            };
+            let (recipe_type, recipe_file) = local_recipe_type(path);
+            match check_annotation(&recipe_type, recipe_file, status.as_ref()) {
+                Some(annotation) => println!("{}", annotation),
+                None => println!("✅ This feedstock is on Recipe v1"),
+            }
+            return Ok(());
+        }
+        Some(Commands::Summary) => {
+            let stats = store.load()?;
+            for line in summary_lines(&stats, chrono::Utc::now()) {
+                println!("{}", line);
+            }
+            return Ok(());
+        }
+        Some(Commands::Validate) => {
+            let stats = store.load()?;
+            let problems = validate_stats(&stats);
+            if problems.is_empty() {
+                println!(
+                    "✅ {} feedstocks, no inconsistencies found",
+                    stats.feedstock_states.len()
+                );
+                return Ok(());
+            }
+            for problem in &problems {
+                println!("❌ {}", problem);
+            }
+            anyhow::bail!("Found {} inconsistencies in the stats", problems.len());
+        }
+        Some(Commands::Completions { shell }) => {
+            let mut command = Cli::command();
+            let name = command.get_name().to_string();
+            clap_complete::generate(*shell, &mut command, name, &mut std::io::stdout());
+            return Ok(());
+        }
+        Some(Commands::Man { out_dir }) => {
+            std::fs::create_dir_all(out_dir)
+                .with_context(|| format!("Failed to create {}", out_dir.display()))?;
+            clap_mangen::generate_to(Cli::command(), out_dir)
+                .with_context(|| format!("Failed to write man pages to {}", out_dir.display()))?;
+            println!("📖 Man pages written to {}", out_dir.display());
+            return Ok(());
+        }
+        Some(Commands::History {
+            command:
+                HistoryCommand::Backfill {
+                    since,
+                    until,
+                    interval_days,
+                    repo,
+                },
+        }) => {
+            ensure_history_clone(repo, cli.verbose)?;
+            let until = until.unwrap_or_else(|| chrono::Utc::now().date_naive());
+            let dates = sample_dates(*since, until, *interval_days);
+            println!("🕰️  Sampling cf-graph at {} days", dates.len());
+            let ignore_list = NodeAttrsIgnoreList::load(&paths.node_attrs_ignore)?;
+            let (points, failed) = backfill_history(repo, &dates, &ignore_list, cli.verbose)?;
+            if failed > 0 {
+                println!("⚠️  {} node_attrs versions failed to parse", failed);
+            }
 
-    // --reattribute-only mode: skip analysis/downloads, just reload and re-attribute
-    let mut stats = if cli.reattribute_only {
+            let history_path = &paths.history;
+            let mut history = History::load(history_path)?;
+            let sampled = points.len();
+            let added = points
+                .into_iter()
+                .filter(|point| history.insert_missing(point.clone()))
+                .count();
+            history
+                .save(history_path)
+                .with_context(|| format!("Failed to write {}", history_path.display()))?;
+            println!(
+                "💾 Added {} of {} sampled days to {} (days with a recorded run are kept)",
+                added,
+                sampled,
+                history_path.display()
+            );
+            return Ok(());
+        }
+        Some(Commands::ExportResearch { output, salt }) => {
+            anyhow::ensure!(
+                !salt.is_empty(),
+                "The research export salt must not be empty"
+            );
+            let stats = store.load()?;
+            let export = research_export(&stats, salt);
+            std::fs::write(output, serde_json::to_string_pretty(&export)?)
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+            println!(
+                "🔬 Anonymized {} feedstocks into {}",
+                export.feedstocks.len(),
+                output.display()
+            );
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    // resume mode: pick up an interrupted attribution run from the checkpoint
+    let mut stats = if resume {
+        println!("⏯️  Looking for an interrupted attribution run...");
+        let stats = store
+            .load()
+            .context("Failed to load checkpoint - nothing to resume")?;
+
+        let pending = PendingAttribution::from_states(&stats.feedstock_states);
+        if !pending.is_interrupted() {
+            println!("✅ No interrupted attribution run found, nothing to resume");
+            return Ok(());
+        }
+        println!(
+            "⏯️  Resuming from stage: {} ({} feedstocks with cached commit info, {} without)",
+            pending.resume_stage(),
+            pending.cached,
+            pending.uncached
+        );
+        stats
+    } else if cli.reattribute_only || attribute_stage {
+        // --reattribute-only mode: skip analysis/downloads, just reload and re-attribute
         println!("🔄 Running attribution-only mode...");
-        let path = std::env::var("CARGO_MANIFEST_DIR").context("CARGO_MANIFEST_DIR not set")?;
-        let stats_path = format!("{}/../feedstock-stats.toml", path);
-        load_existing_stats(&stats_path).context("Failed to load existing stats - run full analysis first")?
+        store
+            .load()
+            .context("Failed to load existing stats - run full analysis first")?
     } else {
         println!("🚀 Starting conda-forge feedstock analysis...");
 
         match cli.command {
             Some(Commands::Analyze { force_clone }) => {
-                collect_stats_from_node_attrs(force_clone, cli.verbose).await?
+                collect_stats_from_node_attrs(
+                    &paths,
+                    None,
+                    cli.cf_graph_source,
+                    force_clone,
+                    cli.verbose,
+                    cli.allow_schema_drift,
+                    cli.lint_command.as_deref(),
+                    &mut profiler,
+                )
+                .await?
+            }
+            Some(_) => unreachable!("other commands are handled above"),
+            None => {
+                collect_stats_from_node_attrs(
+                    &paths,
+                    None,
+                    cli.cf_graph_source,
+                    false,
+                    cli.verbose,
+                    cli.allow_schema_drift,
+                    cli.lint_command.as_deref(),
+                    &mut profiler,
+                )
+                .await?
             }
-            None => collect_stats_from_node_attrs(false, cli.verbose).await?,
         }
     };
 
+    profiler.end_stage("collect");
+
     // Collect attribution data for Recipe v1 feedstocks
     println!("\n🏆 Collecting contributor attribution...");
-    let reattribute = cli.reattribute || cli.reattribute_only;
+    let reattribute = !resume && (cli.reattribute || cli.reattribute_only);
+    if !invalidation.is_empty() {
+        let dropped = invalidation.apply(&mut stats.feedstock_states);
+        println!(
+            "🗑️  Dropped cached data of {} feedstocks (--refetch flags)",
+            dropped
+        );
+    }
 
-    // Create save function for checkpointing
-    let stats_path = {
-        let path = std::env::var("CARGO_MANIFEST_DIR").context("CARGO_MANIFEST_DIR not set")?;
-        format!("{}/../feedstock-stats.toml", path)
-    };
-    let save_checkpoint = |feedstock_states: &std::collections::BTreeMap<String, FeedstockEntry>| {
-        let checkpoint_stats = FeedstockStats {
-            total_feedstocks: stats.total_feedstocks,
-            recipe_v1_count: stats.recipe_v1_count,
-            meta_yaml_count: stats.meta_yaml_count,
-            unknown_count: stats.unknown_count,
-            last_updated: stats.last_updated.clone(),
-            feedstock_states: feedstock_states.clone(),
-            top_unconverted_by_downloads: stats.top_unconverted_by_downloads.clone(),
+    // Catch attributions that credit e.g. a rerender instead of the actual conversion
+    if cli.verify_conversions && !reattribute {
+        verify_conversion_attributions(&mut stats.feedstock_states, cli.verbose).await?;
+        profiler.end_stage("verify_conversions");
+    }
+
+    // Create save function for checkpointing, the states are saved next to the summary
+    let mut feedstock_states = std::mem::take(&mut stats.feedstock_states);
+    let save_checkpoint =
+        |feedstock_states: &std::collections::BTreeMap<String, FeedstockEntry>| {
+            store
+                .save(&stats, feedstock_states)
+                .context("Failed to write checkpoint")
         };
-        let toml_content = toml::to_string_pretty(&checkpoint_stats)
-            .context("Failed to serialize stats to TOML")?;
-        fs::write(&stats_path, toml_content).context("Failed to write checkpoint")?;
-        Ok(())
-    };
 
     let attributed = collect_attributions(
-        &mut stats.feedstock_states,
+        &paths,
+        &mut feedstock_states,
         cli.verbose,
         reattribute,
-        cli.refetch_recipe_commits,
+        cli.verify_conversions,
         save_checkpoint,
     )
     .await?;
+    stats.feedstock_states = feedstock_states;
     if attributed > 0 {
         println!("📝 Attributed {} feedstocks", attributed);
     }
+    profiler.end_stage("attribution");
 
-    // Write final stats to TOML file
-    let toml_content =
-        toml::to_string_pretty(&stats).context("Failed to serialize stats to TOML")?;
-    fs::write(&stats_path, toml_content).context("Failed to write feedstock-stats.toml")?;
+    // Replace first-run timestamps of conversions that predate the tracker
+    if cli.backfill_last_changed {
+        let backfilled = backfill_last_changed(&mut stats.feedstock_states);
+        println!("🕰️  Backfilled last_changed for {} feedstocks", backfilled);
+    }
+
+    // Announce the conversions seen since the last attributed run. The queue is saved
+    // first, so a crash while sending repeats deliveries instead of losing them.
+    if cli.webhook_urls.is_empty() {
+        stats.unannounced_conversions.clear();
+    } else if !stats.unannounced_conversions.is_empty() {
+        let feedstock_states = std::mem::take(&mut stats.feedstock_states);
+        store
+            .save(&stats, &feedstock_states)
+            .context("Failed to write checkpoint")?;
+        stats.feedstock_states = feedstock_states;
+        let deliveries = send_conversion_webhooks(
+            &cli.webhook_urls,
+            &stats.unannounced_conversions,
+            &stats.feedstock_states,
+        )
+        .await?;
+        if deliveries.delivered > 0 {
+            println!("📣 Announced {} conversions", deliveries.delivered);
+        }
+        stats.unannounced_conversions = deliveries.unannounced;
+    }
+
+    // Run experimental analyses registered as hooks
+    stats.analyses = default_hooks().run(&stats.feedstock_states);
+    profiler.end_stage("analyses");
+    stats.memory_profile = profiler.stages().to_vec();
+
+    // Write final stats
+    let feedstock_states = std::mem::take(&mut stats.feedstock_states);
+    store
+        .save(&stats, &feedstock_states)
+        .with_context(|| format!("Failed to write {}", store))?;
+    record_history(&paths.history, &stats)?;
+    backup_stats(&cli, store).await;
 
     // Clean up sparse checkout repository (only if we did full analysis)
-    if !cli.reattribute_only {
-        cleanup_sparse_checkout_repo(cli.verbose)?;
+    if !cli.reattribute_only && !resume && !attribute_stage {
+        cleanup_sparse_checkout_repo(&paths.cf_graph, cli.verbose)?;
     }
 
     println!("\n✅ Analysis complete!");
@@ -82,7 +392,68 @@ async fn main() -> Result<()> {
     println!("📝 Recipe v1 (recipe.yaml): {}", stats.recipe_v1_count);
     println!("📄 Legacy (meta.yaml): {}", stats.meta_yaml_count);
     println!("❓ Unknown/Other: {}", stats.unknown_count);
-    println!("💾 Results saved to feedstock-stats.toml");
+    println!("💾 Results saved to {}", store);
 
     Ok(())
 }
+
+/// Upload the written stats file if `--backup-to` is set. A failed upload only warns,
+/// since the local file is already saved.
+async fn backup_stats(cli: &Cli, store: &StatsStore) {
+    let Some(target) = &cli.backup_to else {
+        return;
+    };
+    let result = match ObjectStorage::from_env(cli.backup_endpoint.as_deref(), target) {
+        Ok(storage) => match store {
+            StatsStore::Sharded(_) => backup_unsharded(&storage, store).await,
+            store => backup_stats_file(&storage, store.path()).await,
+        },
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(key) => println!("☁️  Backed up the stats file as {}", key),
+        Err(e) => eprintln!("⚠️  Failed to back up the stats file: {:#}", e),
+    }
+}
+
+/// Upload sharded stats as a single TOML file, which restores as unsharded stats
+async fn backup_unsharded(storage: &ObjectStorage, store: &StatsStore) -> Result<String> {
+    let mut stats = store.load()?;
+    let feedstock_states = std::mem::take(&mut stats.feedstock_states);
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("feedstock-stats.toml");
+    StatsStore::Toml(path.clone()).save(&stats, &feedstock_states)?;
+    backup_stats_file(storage, &path).await
+}
+
+/// Run the crunch-data binary, which is built next to this one
+fn crunch(paths: &Paths, site_root: Option<&Path>) -> Result<()> {
+    let exe = std::env::current_exe()?
+        .with_file_name(format!("crunch-data{}", std::env::consts::EXE_SUFFIX));
+    anyhow::ensure!(
+        exe.exists(),
+        "{} not found, build it with `cargo build --bin crunch-data`",
+        exe.display()
+    );
+    // crunch-data reads TOML, so a SQLite store is written out to the stats file first
+    let input = match &paths.store {
+        #[cfg(feature = "sqlite")]
+        StatsStore::Sqlite(_) => {
+            let mut stats = paths.store.load()?;
+            let feedstock_states = std::mem::take(&mut stats.feedstock_states);
+            StatsStore::Toml(paths.stats_file.clone()).save(&stats, &feedstock_states)?;
+            paths.stats_file.as_path()
+        }
+        store => store.path(),
+    };
+    let mut command = Command::new(&exe);
+    command.arg("--input").arg(input);
+    if let Some(site_root) = site_root {
+        command.arg("--root").arg(site_root);
+    }
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to run {}", exe.display()))?;
+    anyhow::ensure!(status.success(), "crunch-data failed ({})", status);
+    Ok(())
+}
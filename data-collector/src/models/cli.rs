@@ -1,4 +1,13 @@
-use clap::{Parser, Subcommand};
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use crate::config::{
+    default_history, default_identity_cache, default_node_attrs_ignore, default_stats_file, Paths,
+    CF_GRAPH_HISTORY_PATH, CF_GRAPH_LOCAL_PATH, DOWNLOAD_CHECKPOINT_PATH, RECIPE_STORE_PATH,
+    VERSION_ORDER_CACHE_PATH,
+};
+use crate::stats::{CacheInvalidation, StatsStore, IDENTITY_TTL_DAYS};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -21,6 +30,166 @@ pub struct Cli {
     /// Re-fetch recipe commit info from GitHub API (clears cached commit hashes)
     #[arg(long)]
     pub refetch_recipe_commits: bool,
+
+    /// Redo new feedstock attributions, fetching their recipe maintainers again
+    #[arg(long)]
+    pub refetch_maintainers: bool,
+
+    /// Redo conversion attributions, looking up their PRs again
+    #[arg(long)]
+    pub refetch_prs: bool,
+
+    /// Drop the version order cache used to sum download counts
+    #[arg(long)]
+    pub refetch_downloads: bool,
+
+    /// Redo the commit lookup and attribution of feedstocks matching a glob, e.g. `py*`.
+    /// Can be given multiple times
+    #[arg(long, value_name = "FEEDSTOCK_GLOB")]
+    pub refetch: Vec<String>,
+
+    /// Check that attributed commits created recipe.yaml, and redo existing conversions crediting a commit that only modified it
+    #[arg(long)]
+    pub verify_conversions: bool,
+
+    /// Set `last_changed` of attributed Recipe v1 feedstocks to their conversion commit date
+    #[arg(long)]
+    pub backfill_last_changed: bool,
+
+    /// Track peak memory (RSS) of each pipeline stage and report it in feedstock-stats.toml
+    #[arg(long)]
+    pub profile_memory: bool,
+
+    /// Only warn instead of failing when cf-graph node_attrs don't have the expected fields
+    /// or too many of them fail to parse
+    #[arg(long)]
+    pub allow_schema_drift: bool,
+
+    /// Lint converted recipes with this command, e.g. `conda-smithy recipe-lint
+    /// --conda-forge`. The directory holding recipe.yaml is appended as last argument
+    #[arg(long, env = "LINT_COMMAND")]
+    pub lint_command: Option<String>,
+
+    /// feedstock-stats.toml to read and write. Defaults to the workspace root under
+    /// `cargo run`, else the working directory
+    #[arg(long, global = true, env = "STATS_FILE")]
+    pub stats_file: Option<PathBuf>,
+
+    /// Where to keep the stats: `sqlite://stats.db` for a SQLite database that is
+    /// updated in place (with the `sqlite` feature), else the `--stats-file` TOML file
+    #[arg(long, global = true, env = "STATS_STORE")]
+    pub store: Option<StatsStore>,
+
+    /// Where to check out cf-graph-countyfair's node_attrs
+    #[arg(long, global = true, env = "CF_GRAPH_PATH", default_value = CF_GRAPH_LOCAL_PATH)]
+    pub cf_graph_path: PathBuf,
+
+    /// How to fetch node_attrs and pr_info into `--cf-graph-path`
+    #[arg(
+        long,
+        global = true,
+        env = "CF_GRAPH_SOURCE",
+        value_enum,
+        default_value_t = GraphSource::Git
+    )]
+    pub cf_graph_source: GraphSource,
+
+    /// Local mirror of raw recipe files
+    #[arg(long, global = true, env = "RECIPE_STORE_PATH", default_value = RECIPE_STORE_PATH)]
+    pub recipe_store_path: PathBuf,
+
+    /// Cache of each package's newest versions, used to sum download counts
+    #[arg(
+        long,
+        global = true,
+        env = "VERSION_CACHE_PATH",
+        default_value = VERSION_ORDER_CACHE_PATH
+    )]
+    pub version_cache_path: PathBuf,
+
+    /// Progress of an interrupted download crawl, resumed by the next run
+    #[arg(
+        long,
+        global = true,
+        env = "DOWNLOAD_CHECKPOINT_PATH",
+        default_value = DOWNLOAD_CHECKPOINT_PATH
+    )]
+    pub download_checkpoint_path: PathBuf,
+
+    /// Cached GitHub identities of contributors. Defaults to contributor-identities.json
+    /// next to the stats file
+    #[arg(long, global = true, env = "IDENTITY_CACHE_PATH")]
+    pub identity_cache_path: Option<PathBuf>,
+
+    /// node_attrs entries that aren't feedstocks and are skipped without a warning.
+    /// Defaults to node-attrs-ignore.toml next to the stats file
+    #[arg(long, global = true, env = "NODE_ATTRS_IGNORE_PATH")]
+    pub node_attrs_ignore_path: Option<PathBuf>,
+
+    /// Dated snapshots of the counts, one per day, appended to on every run. Written as
+    /// JSON if the name ends in .json. Defaults to history.toml next to the stats file
+    #[arg(long, global = true, env = "HISTORY_PATH")]
+    pub history_path: Option<PathBuf>,
+
+    /// Back up the written stats file to object storage, as `BUCKET` or `BUCKET/PREFIX`.
+    /// Credentials are read from AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY
+    #[arg(long, global = true, env = "STATS_BACKUP_TARGET")]
+    pub backup_to: Option<String>,
+
+    /// S3-compatible endpoint for `--backup-to`, e.g. https://storage.googleapis.com.
+    /// Defaults to the AWS S3 endpoint of AWS_REGION
+    #[arg(long, global = true, env = "STATS_BACKUP_ENDPOINT")]
+    pub backup_endpoint: Option<String>,
+
+    /// URL to POST a JSON event to for each newly detected conversion, once it is
+    /// attributed. Can be given multiple times, or comma-separated in the variable
+    #[arg(
+        long = "webhook-url",
+        global = true,
+        env = "CONVERSION_WEBHOOK_URLS",
+        value_delimiter = ','
+    )]
+    pub webhook_urls: Vec<String>,
+}
+
+impl Cli {
+    pub fn paths(&self) -> Paths {
+        let stats_file = self.stats_file.clone().unwrap_or_else(default_stats_file);
+        Paths {
+            identity_cache: self
+                .identity_cache_path
+                .clone()
+                .unwrap_or_else(|| default_identity_cache(&stats_file)),
+            node_attrs_ignore: self
+                .node_attrs_ignore_path
+                .clone()
+                .unwrap_or_else(|| default_node_attrs_ignore(&stats_file)),
+            history: self
+                .history_path
+                .clone()
+                .unwrap_or_else(|| default_history(&stats_file)),
+            store: self
+                .store
+                .clone()
+                .unwrap_or_else(|| StatsStore::Toml(stats_file.clone())),
+            stats_file,
+            cf_graph: self.cf_graph_path.clone(),
+            recipe_store: self.recipe_store_path.clone(),
+            version_cache: self.version_cache_path.clone(),
+            download_checkpoint: self.download_checkpoint_path.clone(),
+        }
+    }
+
+    /// Cached data to drop according to the `--refetch*` flags
+    pub fn invalidation(&self) -> CacheInvalidation {
+        CacheInvalidation {
+            recipe_commits: self.refetch_recipe_commits,
+            maintainers: self.refetch_maintainers,
+            prs: self.refetch_prs,
+            downloads: self.refetch_downloads,
+            feedstocks: self.refetch.clone(),
+        }
+    }
 }
 
 #[derive(Subcommand)]
@@ -31,4 +200,151 @@ pub enum Commands {
         #[arg(long)]
         force_clone: bool,
     },
+    /// Finish an interrupted attribution run from the saved checkpoint
+    Resume,
+    /// Stage 1: fetch download counts from prefix.dev into a JSON file
+    FetchDownloads {
+        /// Where to write the download counts
+        #[arg(long, default_value = "downloads.json")]
+        output: PathBuf,
+    },
+    /// Stage 2: classify feedstocks from cf-graph and write feedstock-stats.toml,
+    /// without attribution
+    ScanGraph {
+        /// Force re-clone the repository even if it exists
+        #[arg(long)]
+        force_clone: bool,
+        /// Download counts written by `fetch-downloads`, fetched if not given
+        #[arg(long)]
+        downloads: Option<PathBuf>,
+    },
+    /// Stage 3: attribute Recipe v1 feedstocks in feedstock-stats.toml and run the analyses
+    Attribute,
+    /// Redo the attribution of one feedstock, explaining each step, and update only its
+    /// entry in feedstock-stats.toml
+    AttributeOne {
+        /// Feedstock name, with or without the `-feedstock` suffix
+        feedstock: String,
+    },
+    /// Refresh the cached GitHub names, avatars and renamed or deleted accounts of the
+    /// contributors credited in feedstock-stats.toml
+    ResolveContributors {
+        /// Fetch identities again once they are this many days old
+        #[arg(long, default_value_t = IDENTITY_TTL_DAYS)]
+        ttl_days: i64,
+    },
+    /// Stage 4: turn feedstock-stats.toml into the website data (runs crunch-data)
+    Crunch {
+        /// Write the site data under this directory instead of crunch-data's workspace,
+        /// which only exists in a checkout
+        #[arg(long, env = "SITE_ROOT")]
+        site_root: Option<PathBuf>,
+    },
+    /// Write an anonymized dataset for research, with contributors as salted hashes
+    ExportResearch {
+        /// Where to write the dataset (JSON)
+        #[arg(long, default_value = "research-export.json")]
+        output: PathBuf,
+        /// Secret salt for hashing logins; keep it to get the same ids in later exports
+        #[arg(long, env = "RESEARCH_EXPORT_SALT", hide_env_values = true)]
+        salt: String,
+    },
+    /// Flatten the feedstock states into files for analysis in other tools
+    Export {
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+        /// Where to write the export, `feedstocks.csv` or the `export` directory if not given
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// For a feedstock's own CI: print a GitHub Actions warning if the checked out
+    /// feedstock is still on meta.yaml
+    CheckFeedstock {
+        /// Root of the feedstock checkout
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+        /// The site's status.json, as a path or URL, to quote the migration's progress
+        #[arg(long, env = "RECIPE_V1_STATUS")]
+        status: Option<String>,
+    },
+    /// Print the headline numbers, top contributors and recent conversions from
+    /// feedstock-stats.toml without collecting anything
+    Summary,
+    /// Check feedstock-stats.toml for inconsistencies, e.g. counts that don't match the
+    /// feedstock states or malformed dates, and exit non-zero if there are any
+    Validate,
+    /// Print a completion script for `shell`, e.g.
+    /// `data-collector completions bash > ~/.local/share/bash-completion/completions/data-collector`
+    Completions { shell: clap_complete::Shell },
+    /// Write man pages for data-collector and each of its subcommands
+    Man {
+        /// Directory to write the `.1` pages to
+        #[arg(long, default_value = "man")]
+        out_dir: PathBuf,
+    },
+    /// Work with the dated counts in history.toml
+    History {
+        #[command(subcommand)]
+        command: HistoryCommand,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum GraphSource {
+    /// Shallow sparse checkout with git
+    Git,
+    /// Stream the repository tarball from GitHub, without needing git
+    Tarball,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// One row per feedstock, for spreadsheets
+    Csv,
+    /// A directory of feedstocks, attributions and top_unconverted tables, for DuckDB
+    /// or pandas
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl ExportFormat {
+    pub fn default_output(&self) -> PathBuf {
+        match self {
+            Self::Csv => PathBuf::from("feedstocks.csv"),
+            #[cfg(feature = "parquet")]
+            Self::Parquet => PathBuf::from("export"),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum HistoryCommand {
+    /// Reconstruct past counts from cf-graph-countyfair's git history and add them for
+    /// the days history.toml has no run for
+    Backfill {
+        /// First day to sample (YYYY-MM-DD)
+        #[arg(long, default_value = "2024-01-01")]
+        since: NaiveDate,
+        /// Last day to sample (YYYY-MM-DD), today if not given
+        #[arg(long)]
+        until: Option<NaiveDate>,
+        /// Days between samples
+        #[arg(long, default_value_t = 7)]
+        interval_days: i64,
+        /// Where to keep the blobless clone of cf-graph's history
+        #[arg(long, default_value = CF_GRAPH_HISTORY_PATH)]
+        repo: PathBuf,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        // Catches conflicting flags and names, which clap only reports at runtime
+        Cli::command().debug_assert();
+    }
 }
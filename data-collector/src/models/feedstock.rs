@@ -1,8 +1,84 @@
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 use super::RecipeType;
 
+/// Layout version of the stats file, written as `stats_format_version`. Bump it together
+/// with a new entry in `MIGRATIONS` whenever a field is renamed, moved or changes meaning.
+/// Unrelated to the `schema_version` of a recipe.
+pub const STATS_FORMAT_VERSION: u32 = 3;
+
+/// `MIGRATIONS[n]` upgrades a stats file from version `n` to `n + 1`. Files from before
+/// the version existed are version 0.
+const MIGRATIONS: &[fn(&mut toml::Table)] = &[
+    // 0 -> 1: same layout, the file only gains its version
+    |_| {},
+    // 1 -> 2: `recipe_v1_count` includes the non-standard filenames
+    |stats| {
+        let count = |stats: &toml::Table, key: &str| {
+            stats.get(key).and_then(|v| v.as_integer()).unwrap_or(0)
+        };
+        let recipe_v1 = count(stats, "recipe_v1_count") + count(stats, "non_standard_v1_count");
+        stats.insert(
+            "recipe_v1_count".to_string(),
+            toml::Value::Integer(recipe_v1),
+        );
+    },
+    // 2 -> 3: `unannounced_conversions` records the webhook URLs that accepted each
+    // conversion, none for the ones queued before
+    |stats| {
+        if let Some(toml::Value::Array(queued)) = stats.remove("unannounced_conversions") {
+            let queued = queued
+                .into_iter()
+                .filter_map(|name| Some((name.as_str()?.to_string(), toml::Value::Array(vec![]))))
+                .collect();
+            stats.insert(
+                "unannounced_conversions".to_string(),
+                toml::Value::Table(queued),
+            );
+        }
+    },
+];
+
+const _: () = assert!(MIGRATIONS.len() == STATS_FORMAT_VERSION as usize);
+
+/// Refuse stats written by a newer collector, saving them would drop the fields this one
+/// doesn't know about
+pub fn check_stats_version(version: u32) -> Result<()> {
+    anyhow::ensure!(
+        version <= STATS_FORMAT_VERSION,
+        "Stats have format version {}, but this collector only knows up to {}; update it",
+        version,
+        STATS_FORMAT_VERSION
+    );
+    Ok(())
+}
+
+/// Upgrade a parsed stats file to `STATS_FORMAT_VERSION` before it is deserialized, so
+/// renamed fields carry their data over instead of being silently dropped. Returns the
+/// version the file had.
+pub fn migrate_stats(stats: &mut toml::Table) -> Result<u32> {
+    // Written as `schema_version` before it was renamed to tell it apart from recipes'
+    let legacy = stats.remove("schema_version");
+    let version = match stats.get("stats_format_version").or(legacy.as_ref()) {
+        Some(version) => version
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .context("stats_format_version must be a non-negative integer")?,
+        None => 0,
+    };
+    check_stats_version(version)?;
+    for migrate in &MIGRATIONS[version as usize..] {
+        migrate(stats);
+    }
+    stats.insert(
+        "stats_format_version".to_string(),
+        toml::Value::Integer(STATS_FORMAT_VERSION.into()),
+    );
+    Ok(version)
+}
+
 /// Type of contribution for Recipe v1 feedstocks
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -27,18 +103,238 @@ pub struct Attribution {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FeedstockStats {
+    /// See `STATS_FORMAT_VERSION`; 0 for files from before it existed
+    #[serde(default, alias = "schema_version")]
+    pub stats_format_version: u32,
     pub total_feedstocks: u32,
     pub recipe_v1_count: u32,
     pub meta_yaml_count: u32,
     pub unknown_count: u32,
-    pub last_updated: String,
     #[serde(default)]
+    pub non_standard_v1_count: u32,
+    pub last_updated: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub feedstock_states: BTreeMap<String, FeedstockEntry>,
     #[serde(default)]
     pub top_unconverted_by_downloads: Vec<TopFeedstock>,
+    #[serde(default)]
+    pub quality_metrics: QualityMetrics,
+    #[serde(default)]
+    pub maintainer_conversion_rates: Vec<MaintainerConversionRate>,
+    /// Recipe features by how much less often feedstocks using them are converted
+    #[serde(default)]
+    pub recipe_feature_rates: Vec<RecipeFeatureRate>,
+    /// Conversion status by how many pull requests the autotick bot opened
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bot_activity_rates: Vec<BotActivityRate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<FeedstockCoverage>,
+    /// Latest CI results of converted feedstocks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_health: Option<BuildHealth>,
+    /// Open Recipe v1 migration PRs by the autotick bot, waiting for a maintainer to merge
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending_migration_prs: Vec<PendingMigrationPr>,
+    /// Lint results of converted recipes, from the last run with a lint command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipe_lint: Option<RecipeLint>,
+    /// Migration progress with feedstocks weighted by their number of dependents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency_weighted: Option<DependencyWeightedProgress>,
+    #[serde(default)]
+    pub rattler_build_adoption: RattlerBuildAdoption,
+    /// Movements of top feedstocks by downloads since the previous run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub top_feedstock_alerts: Vec<TopFeedstockAlert>,
+    /// Experimental: unconverted feedstocks most likely to convert next
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversion_forecast: Option<ConversionForecast>,
+    /// Feedstocks seen converting that the conversion webhooks haven't announced yet,
+    /// kept until attributed and accepted by every webhook URL. Each has the hashes of
+    /// the URLs that already accepted it, see `webhook_url_key`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub unannounced_conversions: BTreeMap<String, Vec<String>>,
+    /// Recipe v1 feedstocks whose recipe filename couldn't be checked yet, because
+    /// GitHub was not available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unchecked_recipe_files: Vec<String>,
+    /// Sections contributed by analysis hooks, keyed by hook name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub analyses: BTreeMap<String, toml::Value>,
+    /// Memory use per pipeline stage, only with `--profile-memory`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub memory_profile: Vec<StageMemory>,
+}
+
+/// Resident memory of the collector during one pipeline stage
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StageMemory {
+    pub stage: String,
+    /// Highest resident set size reached during the stage
+    pub peak_rss_kb: u64,
+    /// Resident set size at the end of the stage
+    pub rss_kb: u64,
+}
+
+/// What happened to a top feedstock since the previous run
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// Converted to Recipe v1, worth celebrating
+    Converted,
+    /// Went back to the unconverted list, worth investigating
+    Unconverted,
+}
+
+/// A top feedstock by downloads that changed conversion status
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TopFeedstockAlert {
+    pub name: String,
+    pub kind: AlertKind,
+    /// Position by downloads among all feedstocks (1-based)
+    pub rank: u32,
+    pub downloads: u64,
+}
+
+/// Recipe v1 feedstocks grouped by rattler-build pin and recipe schema version
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RattlerBuildAdoption {
+    /// Version constraint from `conda_build_tool_deps`, "unpinned" if none
+    pub pins: Vec<VersionCount>,
+    /// `schema_version` of the recipe, "unknown" if not available
+    pub schema_versions: Vec<VersionCount>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct VersionCount {
+    pub version: String,
+    pub feedstocks: u32,
+}
+
+/// How many of conda-forge's feedstocks the cf-graph data actually covers
 #[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeedstockCoverage {
+    /// Number of feedstock repositories in the conda-forge GitHub organization
+    pub reference_count: u32,
+    /// Fraction of those present in cf-graph (0.0 - 1.0)
+    pub coverage: f64,
+}
+
+/// CI state of the default branch of converted feedstocks
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BuildHealth {
+    /// Feedstocks with a CI result on their default branch
+    pub checked: u32,
+    pub passing: u32,
+    pub failing: u32,
+    /// CI still running or waiting to start
+    pub pending: u32,
+}
+
+/// A bot-made Recipe v1 migration PR that only needs to be merged
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PendingMigrationPr {
+    pub feedstock: String,
+    pub number: u32,
+    /// When the PR was opened (RFC 3339)
+    pub opened_at: String,
+}
+
+/// Feedstocks weighted by how many other feedstocks depend on them, so converting a
+/// widely used library counts for more than a leaf package
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct DependencyWeightedProgress {
+    /// Summed dependents of Recipe v1 feedstocks
+    pub converted: u32,
+    /// Summed dependents of all feedstocks
+    pub total: u32,
+}
+
+/// Unconverted feedstocks scored by how likely they are to convert next, and how the
+/// previous forecast turned out
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ConversionForecast {
+    /// Highest score first
+    pub likely: Vec<LikelyConversion>,
+    /// Feedstocks of the previous run's forecast that converted since
+    #[serde(default)]
+    pub converted_since: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LikelyConversion {
+    pub name: String,
+    /// Weighted sum of the signals below (0.0 - 1.0)
+    pub score: f64,
+    /// Highest share of converted feedstocks among its maintainers' other feedstocks
+    pub maintainer_share: f64,
+    /// Share of its dependency feedstocks on Recipe v1, None if its requirements are unknown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies_converted: Option<f64>,
+    /// Last push to the feedstock repository (RFC 3339), None if unknown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pushed_at: Option<String>,
+}
+
+/// How many converted recipes pass the recipe linter cleanly
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct RecipeLint {
+    /// Recipes the linter ran on
+    pub checked: u32,
+    pub passing: u32,
+    pub failing: u32,
+}
+
+/// Conversion status of feedstocks grouped by their number of maintainers
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MaintainerConversionRate {
+    /// Maintainer count bucket, e.g. "1", "2" or "5+"
+    pub maintainers: String,
+    pub feedstocks: u32,
+    pub converted: u32,
+}
+
+/// Conversion status of feedstocks grouped by how many pull requests (migrations, which
+/// rerender the feedstock) the autotick bot opened on them
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BotActivityRate {
+    /// Bot pull request count bucket, e.g. "0-9" or "100+"
+    pub bot_prs: String,
+    pub feedstocks: u32,
+    pub converted: u32,
+    /// Converted (not created) within the last `RECENT_CONVERSION_DAYS`
+    pub converted_recently: u32,
+}
+
+/// Conversion status of feedstocks with and without a recipe feature
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecipeFeatureRate {
+    /// Feature id, e.g. "selectors"
+    pub feature: String,
+    pub feedstocks: u32,
+    pub converted: u32,
+    /// Feedstocks without the feature, for comparison
+    pub other_feedstocks: u32,
+    pub other_converted: u32,
+}
+
+/// Migration quality metrics derived from the feedstock history
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct QualityMetrics {
+    /// Number of attributed conversions the metrics are based on, those on Recipe v1 and
+    /// those reverted since
+    pub conversions: u32,
+    /// Conversions that are back on meta.yaml, having gone back within 30 days
+    pub conversion_reverts_30d: u32,
+    /// Conversions whose first week has been checked for follow-up fixes
+    #[serde(default)]
+    pub follow_up_checked: u32,
+    /// Checked conversions that needed a fix to the recipe within 7 days of converting
+    #[serde(default)]
+    pub follow_up_fixes_7d: u32,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct FeedstockEntry {
     pub recipe_type: RecipeType,
     pub last_changed: String,
@@ -51,6 +347,64 @@ pub struct FeedstockEntry {
     /// Cached data from batch query (step 1-2) for resuming attribution
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recipe_commit_cache: Option<RecipeCommitCache>,
+    /// GitHub handles from the recipe's `extra.recipe-maintainers`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub maintainers: Vec<String>,
+    /// Short package description from the recipe's `about.summary`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// rattler-build version constraint pinned in conda-forge.yml, e.g. ">=0.30"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rattler_build_pin: Option<String>,
+    /// Recipe `schema_version`, if the rendered recipe reports one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<u32>,
+    /// Recipe path for `NonStandardV1` feedstocks, e.g. "recipe/recipe.yml"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_file: Option<String>,
+    /// Set when a Recipe v1 feedstock was seen going back to meta.yaml
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert: Option<RevertInfo>,
+    /// Recipe features that may make a conversion harder, e.g. "multiple-outputs"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipe_features: Vec<String>,
+    /// Latest CI state of the default branch ("SUCCESS", "FAILURE", ...), Recipe v1 only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_state: Option<String>,
+    /// Set once the week after the conversion was checked for follow-up fixes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follow_up: Option<FollowUpCheck>,
+}
+
+#[cfg(test)]
+impl FeedstockEntry {
+    /// An entry last changed on 2025-01-01 with nothing else known, for tests to fill in
+    pub(crate) fn test(recipe_type: RecipeType) -> Self {
+        Self {
+            recipe_type,
+            last_changed: "2025-01-01T00:00:00Z".to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Record of a Recipe v1 feedstock that was reverted to meta.yaml
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RevertInfo {
+    /// Date the feedstock was converted to Recipe v1 (ISO 8601)
+    pub converted_at: String,
+    /// Date the collector first saw it back on meta.yaml (ISO 8601)
+    pub reverted_at: String,
+}
+
+/// Whether a conversion needed a quick fix to the recipe afterwards
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FollowUpCheck {
+    /// Attribution date of the conversion that was checked (ISO 8601)
+    pub converted_at: String,
+    /// First non-bot PR that changed the recipe within 7 days, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_pr: Option<u32>,
 }
 
 /// Cached commit info from batch query, saved to allow resuming attribution
@@ -69,4 +423,89 @@ pub struct TopFeedstock {
     pub name: String,
     pub downloads: u64,
     pub recipe_type: RecipeType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Dependency feedstocks not converted yet; empty means ready to convert cleanly.
+    /// None if the dependencies are unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_by: Option<Vec<String>>,
+    /// Last push to the feedstock repository (RFC 3339), None if GitHub wasn't queried
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pushed_at: Option<String>,
+    /// Recipe features that may make the conversion harder, see `FeedstockEntry`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipe_features: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNVERSIONED: &str = r#"
+        total_feedstocks = 2
+        recipe_v1_count = 1
+        non_standard_v1_count = 1
+        meta_yaml_count = 0
+        unknown_count = 0
+        last_updated = "2025-01-01T00:00:00+00:00"
+        unannounced_conversions = ["numpy-feedstock"]
+
+        [feedstock_states.scipy-feedstock]
+        recipe_type = "non_standard_v1"
+        last_changed = "2025-01-01T00:00:00+00:00"
+        recipe_file = "recipe/recipe.yml"
+
+        [feedstock_states.numpy-feedstock]
+        recipe_type = "recipe_v1"
+        last_changed = "2025-01-01T00:00:00+00:00"
+
+        [feedstock_states.numpy-feedstock.attribution]
+        contribution_type = "conversion"
+        contributors = ["alice"]
+        date = "2025-01-01T00:00:00Z"
+    "#;
+
+    #[test]
+    fn test_migrate_stats() {
+        let mut table: toml::Table = toml::from_str(UNVERSIONED).unwrap();
+        assert_eq!(migrate_stats(&mut table).unwrap(), 0);
+        let stats: FeedstockStats = table.clone().try_into().unwrap();
+        assert_eq!(stats.stats_format_version, STATS_FORMAT_VERSION);
+        assert_eq!(stats.recipe_v1_count, 2);
+        let attribution = stats.feedstock_states["numpy-feedstock"]
+            .attribution
+            .as_ref()
+            .unwrap();
+        assert_eq!(attribution.contributors, ["alice"]);
+        assert_eq!(
+            stats.unannounced_conversions,
+            BTreeMap::from([("numpy-feedstock".to_string(), vec![])])
+        );
+
+        // Current files pass through unchanged
+        let migrated = table.clone();
+        assert_eq!(migrate_stats(&mut table).unwrap(), STATS_FORMAT_VERSION);
+        assert_eq!(table, migrated);
+
+        // The version's old name is renamed without migrating again
+        let mut legacy = migrated.clone();
+        let version = legacy.remove("stats_format_version").unwrap();
+        legacy.insert("schema_version".to_string(), version);
+        assert_eq!(migrate_stats(&mut legacy).unwrap(), STATS_FORMAT_VERSION);
+        assert_eq!(legacy, migrated);
+
+        table.insert(
+            "stats_format_version".to_string(),
+            toml::Value::Integer(STATS_FORMAT_VERSION as i64 + 1),
+        );
+        assert!(migrate_stats(&mut table).is_err());
+        table.insert("stats_format_version".to_string(), toml::Value::Integer(-1));
+        assert!(migrate_stats(&mut table).is_err());
+        legacy.remove("stats_format_version");
+        legacy.insert(
+            "schema_version".to_string(),
+            toml::Value::Integer(STATS_FORMAT_VERSION as i64 + 1),
+        );
+        assert!(migrate_stats(&mut legacy).is_err());
+    }
 }
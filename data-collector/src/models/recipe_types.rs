@@ -1,24 +1,61 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub enum RecipeType {
     #[serde(rename = "recipe_v1")]
     RecipeV1, // Has recipe.yaml
+    #[serde(rename = "non_standard_v1")]
+    NonStandardV1, // Recipe v1 under a near-miss filename (e.g. recipe.yml)
     #[serde(rename = "meta_yaml")]
     MetaYaml, // Has meta.yaml
     #[serde(rename = "unknown")]
+    #[default]
     Unknown, // Neither or both
 }
 
+impl RecipeType {
+    /// Whether the feedstock uses a Recipe v1 recipe, regardless of its filename
+    pub fn is_v1(&self) -> bool {
+        matches!(self, Self::RecipeV1 | Self::NonStandardV1)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct NodeAttrsJson {
     pub feedstock_name: String,
     #[serde(rename = "conda-forge.yml", default)]
     pub conda_forge_yml: Option<CondaForgeYml>,
+    /// Rendered recipe metadata, kept untyped since its shape varies between feedstocks
+    #[serde(default)]
+    pub meta_yaml: Option<serde_json::Value>,
+    /// Recipe source before rendering
+    #[serde(default)]
+    pub raw_meta_yaml: Option<String>,
+    /// Packages built by the feedstock
+    #[serde(default)]
+    pub outputs_names: Option<NodeSet>,
+}
+
+/// A feedstock's `pr_info` file in the cf-graph
+#[derive(Debug, Deserialize)]
+pub struct PrInfoJson {
+    /// Pull requests the autotick bot opened, one per migration (each also rerenders)
+    #[serde(rename = "PRed", default)]
+    pub pred: Vec<serde::de::IgnoredAny>,
+}
+
+/// A set as serialized in the cf-graph, e.g. `{"__set__": true, "elements": [...]}`
+#[derive(Debug, Deserialize)]
+pub struct NodeSet {
+    #[serde(default)]
+    pub elements: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CondaForgeYml {
     #[serde(default)]
     pub conda_build_tool: Option<String>,
+    /// Extra dependencies installed with the build tool, e.g. "rattler-build>=0.30"
+    #[serde(default)]
+    pub conda_build_tool_deps: Option<String>,
 }
@@ -0,0 +1,85 @@
+use std::fs;
+
+use crate::models::StageMemory;
+
+/// Tracks the peak resident memory of each pipeline stage. Linux only, since it
+/// reads `/proc/self/status`; elsewhere profiling is skipped with a warning.
+pub struct MemoryProfiler {
+    enabled: bool,
+    stages: Vec<StageMemory>,
+}
+
+impl MemoryProfiler {
+    /// Start tracking if `enabled`, so the first stage is measured from here
+    pub fn new(enabled: bool) -> Self {
+        let enabled = enabled && read_status().is_some();
+        if enabled {
+            reset_peak();
+        } else if cfg!(not(target_os = "linux")) {
+            println!("⚠️  Memory profiling needs /proc/self/status, skipping it");
+        }
+        Self {
+            enabled,
+            stages: Vec::new(),
+        }
+    }
+
+    /// Record the peak since the previous stage ended, then reset the peak for the next one
+    pub fn end_stage(&mut self, stage: &str) {
+        if !self.enabled {
+            return;
+        }
+        let Some(status) = read_status() else {
+            return;
+        };
+        let memory = StageMemory {
+            stage: stage.to_string(),
+            peak_rss_kb: status_kb(&status, "VmHWM").unwrap_or(0),
+            rss_kb: status_kb(&status, "VmRSS").unwrap_or(0),
+        };
+        println!(
+            "📏 {}: peak {:.1} MiB, now {:.1} MiB",
+            memory.stage,
+            memory.peak_rss_kb as f64 / 1024.0,
+            memory.rss_kb as f64 / 1024.0
+        );
+        self.stages.push(memory);
+        reset_peak();
+    }
+
+    pub fn stages(&self) -> &[StageMemory] {
+        &self.stages
+    }
+}
+
+fn read_status() -> Option<String> {
+    fs::read_to_string("/proc/self/status").ok()
+}
+
+/// Reset `VmHWM` to the current RSS. Best effort: on kernels without support the
+/// peak keeps covering the whole run so far.
+fn reset_peak() {
+    let _ = fs::write("/proc/self/clear_refs", "5");
+}
+
+/// Value of a `Key:   1234 kB` line of `/proc/self/status`
+fn status_kb(status: &str, key: &str) -> Option<u64> {
+    status.lines().find_map(|line| {
+        let value = line.strip_prefix(key)?.strip_prefix(':')?;
+        value.trim().strip_suffix("kB")?.trim().parse().ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_kb() {
+        let status = "Name:\tdata-collector\nVmHWM:\t  524288 kB\nVmRSS:\t  131072 kB\n";
+        assert_eq!(status_kb(status, "VmHWM"), Some(524288));
+        assert_eq!(status_kb(status, "VmRSS"), Some(131072));
+        assert_eq!(status_kb(status, "VmSwap"), None);
+        assert_eq!(status_kb(status, "Name"), None);
+    }
+}
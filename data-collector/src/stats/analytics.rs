@@ -1,6 +1,44 @@
-use std::collections::{BTreeMap, HashMap};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-use crate::models::{FeedstockEntry, RecipeType, TopFeedstock};
+use crate::models::{
+    AlertKind, BotActivityRate, BuildHealth, ContributionType, DependencyWeightedProgress,
+    FeedstockCoverage, FeedstockEntry, LikelyConversion, MaintainerConversionRate, QualityMetrics,
+    RattlerBuildAdoption, RecipeFeatureRate, TopFeedstock, TopFeedstockAlert, VersionCount,
+};
+
+/// A revert within this many days of the conversion counts as a broken migration
+pub const REVERT_WINDOW_DAYS: i64 = 30;
+
+/// A non-bot PR changing the recipe within this many days of the conversion counts as a
+/// follow-up fix
+pub const FOLLOW_UP_WINDOW_DAYS: i64 = 7;
+
+/// Warn when coverage drops by more than this fraction between runs
+pub const COVERAGE_DROP_WARNING: f64 = 0.01;
+
+/// Conversion status changes are reported for this many top feedstocks by downloads
+pub const TOP_FEEDSTOCK_ALERT_LIMIT: usize = 100;
+
+/// Feedstocks with at least this many maintainers share the last (open-ended) bucket
+const MAX_MAINTAINER_BUCKET: usize = 5;
+
+/// Lower bounds of the bot pull request count buckets, the last one open-ended
+const BOT_PR_BUCKETS: [u32; 5] = [0, 10, 25, 50, 100];
+
+/// Conversions within this many days count as recent in the bot activity rates
+pub const RECENT_CONVERSION_DAYS: i64 = 90;
+
+/// Weights of the conversion forecast signals, summing to 1: maintainers who converted
+/// their other feedstocks, converted dependencies and recent pushes
+const FORECAST_WEIGHTS: (f64, f64, f64) = (0.5, 0.3, 0.2);
+
+/// Feedstocks pushed to within this many days count as active in the forecast
+const FORECAST_ACTIVE_DAYS: i64 = 90;
+
+/// Recipe features found in meta.yaml syntax, which a converted recipe.yaml no longer
+/// has, so their conversion rate would only reflect the conversion itself
+const META_YAML_SYNTAX_FEATURES: [&str; 2] = ["selectors", "jinja-statements"];
 
 /// Calculates the top unconverted feedstocks based on their download counts.
 pub fn calculate_top_unconverted_feedstocks(
@@ -10,19 +48,904 @@ pub fn calculate_top_unconverted_feedstocks(
 ) -> Vec<TopFeedstock> {
     let mut unconverted_with_downloads: Vec<TopFeedstock> = feedstock_states
         .iter()
-        .filter(|(_, entry)| entry.recipe_type != RecipeType::RecipeV1)
+        .filter(|(_, entry)| !entry.recipe_type.is_v1())
         .filter_map(|(name, entry)| {
             download_counts.get(name).map(|&downloads| TopFeedstock {
                 name: name.clone(),
                 downloads,
                 recipe_type: entry.recipe_type.clone(),
+                summary: entry.summary.clone(),
+                blocked_by: None,
+                pushed_at: None,
+                recipe_features: entry.recipe_features.clone(),
             })
         })
         .collect();
 
     // Sort by downloads in descending order
-    unconverted_with_downloads.sort_by(|a, b| b.downloads.cmp(&a.downloads));
+    unconverted_with_downloads.sort_by_key(|f| std::cmp::Reverse(f.downloads));
 
     // Take top N
     unconverted_with_downloads.into_iter().take(limit).collect()
 }
+
+/// For each unconverted feedstock with known requirements, list the feedstocks of its
+/// dependencies that aren't on Recipe v1 yet. Packages no feedstock builds (e.g.
+/// compiler stubs) and the feedstock's own outputs are ignored.
+pub fn calculate_dependency_blockers(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    dependencies: &HashMap<String, Vec<String>>,
+    output_feedstocks: &HashMap<String, String>,
+) -> HashMap<String, Vec<String>> {
+    dependencies
+        .iter()
+        .filter(|(name, _)| {
+            feedstock_states
+                .get(*name)
+                .is_some_and(|entry| !entry.recipe_type.is_v1())
+        })
+        .map(|(name, packages)| {
+            let blockers: BTreeSet<&String> = packages
+                .iter()
+                .filter_map(|package| output_feedstocks.get(package))
+                .filter(|feedstock| *feedstock != name)
+                .filter(|feedstock| {
+                    feedstock_states
+                        .get(*feedstock)
+                        .is_some_and(|entry| !entry.recipe_type.is_v1())
+                })
+                .collect();
+            (name.clone(), blockers.into_iter().cloned().collect())
+        })
+        .collect()
+}
+
+/// Sum the number of dependents (other feedstocks requiring one of its outputs) over all
+/// feedstocks and over the converted ones
+pub fn calculate_dependency_weighted_progress(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    dependencies: &HashMap<String, Vec<String>>,
+    output_feedstocks: &HashMap<String, String>,
+) -> DependencyWeightedProgress {
+    let mut dependents: HashMap<&String, HashSet<&String>> = HashMap::new();
+    for (dependent, packages) in dependencies {
+        for feedstock in packages
+            .iter()
+            .filter_map(|package| output_feedstocks.get(package))
+            .filter(|feedstock| *feedstock != dependent)
+        {
+            dependents.entry(feedstock).or_default().insert(dependent);
+        }
+    }
+
+    let mut progress = DependencyWeightedProgress::default();
+    for (name, entry) in feedstock_states {
+        let weight = dependents.get(name).map_or(0, |d| d.len() as u32);
+        progress.total += weight;
+        if entry.recipe_type.is_v1() {
+            progress.converted += weight;
+        }
+    }
+    progress
+}
+
+/// Score unconverted feedstocks by signals that tend to precede a conversion, highest
+/// score first: the best share of converted feedstocks among their maintainers' other
+/// feedstocks, the share of their dependency feedstocks on Recipe v1 and a push within
+/// `FORECAST_ACTIVE_DAYS`. A simple weighted sum, so check it against the forecast's
+/// `converted_since` before trusting it.
+pub fn calculate_likely_conversions(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    dependencies: &HashMap<String, Vec<String>>,
+    output_feedstocks: &HashMap<String, String>,
+    pushed_at: &HashMap<String, String>,
+    now: DateTime<Utc>,
+) -> Vec<LikelyConversion> {
+    // Lowercased login -> (maintained, converted)
+    let mut maintainers: HashMap<String, (u32, u32)> = HashMap::new();
+    for entry in feedstock_states.values() {
+        let logins: HashSet<String> = entry.maintainers.iter().map(|m| m.to_lowercase()).collect();
+        for login in logins {
+            let counts = maintainers.entry(login).or_default();
+            counts.0 += 1;
+            if entry.recipe_type.is_v1() {
+                counts.1 += 1;
+            }
+        }
+    }
+
+    let (maintainer_weight, dependency_weight, activity_weight) = FORECAST_WEIGHTS;
+    let mut likely: Vec<LikelyConversion> = feedstock_states
+        .iter()
+        .filter(|(_, entry)| !entry.recipe_type.is_v1())
+        .map(|(name, entry)| {
+            // This feedstock is unconverted, so only their other feedstocks count
+            let maintainer_share = entry
+                .maintainers
+                .iter()
+                .filter_map(|m| maintainers.get(&m.to_lowercase()))
+                .filter(|(maintained, _)| *maintained > 1)
+                .map(|(maintained, converted)| *converted as f64 / (*maintained - 1) as f64)
+                .fold(0.0, f64::max);
+
+            let dependencies_converted = dependencies.get(name).map(|packages| {
+                let feedstocks: HashSet<&String> = packages
+                    .iter()
+                    .filter_map(|package| output_feedstocks.get(package))
+                    .filter(|feedstock| *feedstock != name)
+                    .filter(|feedstock| feedstock_states.contains_key(*feedstock))
+                    .collect();
+                let converted = feedstocks
+                    .iter()
+                    .filter(|feedstock| feedstock_states[**feedstock].recipe_type.is_v1())
+                    .count();
+                if feedstocks.is_empty() {
+                    1.0
+                } else {
+                    converted as f64 / feedstocks.len() as f64
+                }
+            });
+
+            let pushed_at = pushed_at.get(name).cloned();
+            let active = pushed_at
+                .as_deref()
+                .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+                .is_some_and(|date| {
+                    now - date.with_timezone(&Utc) <= Duration::days(FORECAST_ACTIVE_DAYS)
+                });
+
+            LikelyConversion {
+                name: name.clone(),
+                score: maintainer_weight * maintainer_share
+                    + dependency_weight * dependencies_converted.unwrap_or(0.0)
+                    + activity_weight * if active { 1.0 } else { 0.0 },
+                maintainer_share,
+                dependencies_converted,
+                pushed_at,
+            }
+        })
+        .collect();
+
+    likely.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    likely
+}
+
+/// Find top feedstocks by downloads that converted to Recipe v1 or went back to the
+/// unconverted list compared to the previous run. Feedstocks new since then are skipped.
+pub fn calculate_top_feedstock_alerts(
+    previous_states: &BTreeMap<String, FeedstockEntry>,
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    limit: usize,
+) -> Vec<TopFeedstockAlert> {
+    let mut by_downloads: Vec<(&String, &FeedstockEntry, u64)> = feedstock_states
+        .iter()
+        .filter_map(|(name, entry)| Some((name, entry, entry.downloads?)))
+        .collect();
+    by_downloads.sort_by_key(|(_, _, downloads)| std::cmp::Reverse(*downloads));
+
+    by_downloads
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .filter_map(|(i, (name, entry, downloads))| {
+            let was_v1 = previous_states.get(name)?.recipe_type.is_v1();
+            let kind = match (was_v1, entry.recipe_type.is_v1()) {
+                (false, true) => AlertKind::Converted,
+                (true, false) => AlertKind::Unconverted,
+                _ => return None,
+            };
+            Some(TopFeedstockAlert {
+                name: name.clone(),
+                kind,
+                rank: i as u32 + 1,
+                downloads,
+            })
+        })
+        .collect()
+}
+
+/// Calculates migration quality metrics from the feedstock history.
+/// Each converted feedstock counts once: as a conversion while it is on Recipe v1, and
+/// as a revert as well while it is back on meta.yaml. The revert recorded before a
+/// feedstock was converted again no longer counts.
+pub fn calculate_quality_metrics(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> QualityMetrics {
+    let converted: Vec<&FeedstockEntry> = feedstock_states
+        .values()
+        .filter(|entry| {
+            entry
+                .attribution
+                .as_ref()
+                .is_some_and(|a| a.contribution_type == ContributionType::Conversion)
+        })
+        .filter(|entry| entry.recipe_type.is_v1() || entry.revert.is_some())
+        .collect();
+    let conversions = converted.len() as u32;
+
+    let conversion_reverts_30d = converted
+        .iter()
+        .filter(|entry| !entry.recipe_type.is_v1())
+        .filter_map(|entry| entry.revert.as_ref())
+        .filter(|revert| {
+            match (
+                DateTime::parse_from_rfc3339(&revert.converted_at),
+                DateTime::parse_from_rfc3339(&revert.reverted_at),
+            ) {
+                (Ok(converted), Ok(reverted)) => {
+                    reverted - converted <= Duration::days(REVERT_WINDOW_DAYS)
+                }
+                _ => false,
+            }
+        })
+        .count() as u32;
+
+    let follow_ups: Vec<_> = converted
+        .iter()
+        .filter_map(|entry| {
+            let attribution = entry.attribution.as_ref()?;
+            entry
+                .follow_up
+                .as_ref()
+                .filter(|check| check.converted_at == attribution.date)
+        })
+        .collect();
+
+    QualityMetrics {
+        conversions,
+        conversion_reverts_30d,
+        follow_up_checked: follow_ups.len() as u32,
+        follow_up_fixes_7d: follow_ups
+            .iter()
+            .filter(|check| check.fix_pr.is_some())
+            .count() as u32,
+    }
+}
+
+/// Conversions whose follow-up window has passed but that weren't checked for a fix yet,
+/// as `(feedstock, window start, window end)` in RFC 3339
+pub fn follow_up_candidates(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    now: DateTime<Utc>,
+) -> Vec<(String, String, String)> {
+    feedstock_states
+        .iter()
+        .filter_map(|(name, entry)| {
+            let attribution = entry
+                .attribution
+                .as_ref()
+                .filter(|a| a.contribution_type == ContributionType::Conversion)?;
+            let checked = entry
+                .follow_up
+                .as_ref()
+                .is_some_and(|check| check.converted_at == attribution.date);
+            let since = DateTime::parse_from_rfc3339(&attribution.date)
+                .ok()?
+                .with_timezone(&Utc);
+            let until = since + Duration::days(FOLLOW_UP_WINDOW_DAYS);
+            (!checked && until <= now).then(|| {
+                (
+                    name.clone(),
+                    since.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    until.to_rfc3339_opts(SecondsFormat::Secs, true),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Groups feedstocks by their number of maintainers and counts how many are converted.
+/// Feedstocks without maintainer information are left out.
+pub fn calculate_maintainer_conversion_rates(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> Vec<MaintainerConversionRate> {
+    let mut buckets = vec![(0u32, 0u32); MAX_MAINTAINER_BUCKET];
+
+    for entry in feedstock_states.values() {
+        if entry.maintainers.is_empty() {
+            continue;
+        }
+        let bucket = &mut buckets[entry.maintainers.len().min(MAX_MAINTAINER_BUCKET) - 1];
+        bucket.0 += 1;
+        if entry.recipe_type.is_v1() {
+            bucket.1 += 1;
+        }
+    }
+
+    buckets
+        .into_iter()
+        .enumerate()
+        .map(|(i, (feedstocks, converted))| MaintainerConversionRate {
+            maintainers: if i + 1 == MAX_MAINTAINER_BUCKET {
+                format!("{}+", i + 1)
+            } else {
+                (i + 1).to_string()
+            },
+            feedstocks,
+            converted,
+        })
+        .collect()
+}
+
+/// Groups feedstocks by how many pull requests the autotick bot opened on them and counts
+/// how many are converted, and how many were converted recently, to see whether often
+/// rerendered feedstocks convert sooner. Feedstocks without `pr_info` are left out.
+pub fn calculate_bot_activity_rates(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    bot_prs: &HashMap<String, u32>,
+    now: DateTime<Utc>,
+) -> Vec<BotActivityRate> {
+    let mut buckets = [(0u32, 0u32, 0u32); BOT_PR_BUCKETS.len()];
+
+    for (name, entry) in feedstock_states {
+        let Some(count) = bot_prs.get(name) else {
+            continue;
+        };
+        let bucket = &mut buckets[bot_pr_bucket(*count)];
+        bucket.0 += 1;
+        if entry.recipe_type.is_v1() {
+            bucket.1 += 1;
+            let recent = entry.attribution.as_ref().is_some_and(|a| {
+                a.contribution_type == ContributionType::Conversion
+                    && DateTime::parse_from_rfc3339(&a.date).is_ok_and(|date| {
+                        now - date.with_timezone(&Utc) <= Duration::days(RECENT_CONVERSION_DAYS)
+                    })
+            });
+            if recent {
+                bucket.2 += 1;
+            }
+        }
+    }
+
+    buckets
+        .into_iter()
+        .enumerate()
+        .map(|(i, counts)| BotActivityRate {
+            bot_prs: match BOT_PR_BUCKETS.get(i + 1) {
+                Some(next) => format!("{}-{}", BOT_PR_BUCKETS[i], next - 1),
+                None => format!("{}+", BOT_PR_BUCKETS[i]),
+            },
+            feedstocks: counts.0,
+            converted: counts.1,
+            converted_recently: counts.2,
+        })
+        .collect()
+}
+
+/// Index of the `BOT_PR_BUCKETS` bucket holding `count`
+fn bot_pr_bucket(count: u32) -> usize {
+    BOT_PR_BUCKETS
+        .iter()
+        .rposition(|low| count >= *low)
+        .unwrap_or(0)
+}
+
+/// Conversion rate of feedstocks with and without each recipe feature, features most
+/// associated with staying unconverted first. Features of the meta.yaml syntax are left
+/// out, see `META_YAML_SYNTAX_FEATURES`.
+pub fn calculate_recipe_feature_rates(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> Vec<RecipeFeatureRate> {
+    let total = feedstock_states.len() as u32;
+    let total_converted = feedstock_states
+        .values()
+        .filter(|e| e.recipe_type.is_v1())
+        .count() as u32;
+
+    let mut counts: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+    for entry in feedstock_states.values() {
+        for feature in entry
+            .recipe_features
+            .iter()
+            .filter(|feature| !META_YAML_SYNTAX_FEATURES.contains(&feature.as_str()))
+        {
+            let count = counts.entry(feature).or_default();
+            count.0 += 1;
+            if entry.recipe_type.is_v1() {
+                count.1 += 1;
+            }
+        }
+    }
+
+    let rate = |converted: u32, feedstocks: u32| {
+        if feedstocks == 0 {
+            0.0
+        } else {
+            converted as f64 / feedstocks as f64
+        }
+    };
+    let mut rates: Vec<_> = counts
+        .into_iter()
+        .map(|(feature, (feedstocks, converted))| RecipeFeatureRate {
+            feature: feature.to_string(),
+            feedstocks,
+            converted,
+            other_feedstocks: total - feedstocks,
+            other_converted: total_converted - converted,
+        })
+        .collect();
+    rates.sort_by(|a, b| {
+        let gap = |r: &RecipeFeatureRate| {
+            rate(r.converted, r.feedstocks) - rate(r.other_converted, r.other_feedstocks)
+        };
+        gap(a).total_cmp(&gap(b))
+    });
+    rates
+}
+
+/// Group Recipe v1 feedstocks by their rattler-build pin and recipe schema version
+pub fn calculate_rattler_build_adoption(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> RattlerBuildAdoption {
+    let mut pins: BTreeMap<String, u32> = BTreeMap::new();
+    let mut schema_versions: BTreeMap<String, u32> = BTreeMap::new();
+
+    for entry in feedstock_states.values().filter(|e| e.recipe_type.is_v1()) {
+        let pin = entry.rattler_build_pin.as_deref().unwrap_or("unpinned");
+        *pins.entry(pin.to_string()).or_default() += 1;
+
+        let schema_version = entry
+            .schema_version
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        *schema_versions.entry(schema_version).or_default() += 1;
+    }
+
+    // Most common first, ties by version
+    let sorted = |counts: BTreeMap<String, u32>| {
+        let mut counts: Vec<_> = counts
+            .into_iter()
+            .map(|(version, feedstocks)| VersionCount {
+                version,
+                feedstocks,
+            })
+            .collect();
+        counts.sort_by_key(|c| std::cmp::Reverse(c.feedstocks));
+        counts
+    };
+
+    RattlerBuildAdoption {
+        pins: sorted(pins),
+        schema_versions: sorted(schema_versions),
+    }
+}
+
+/// Fraction of the reference feedstock count that cf-graph covers
+pub fn calculate_coverage(covered: u32, reference_count: u32) -> FeedstockCoverage {
+    let coverage = if reference_count == 0 {
+        0.0
+    } else {
+        covered as f64 / reference_count as f64
+    };
+    FeedstockCoverage {
+        reference_count,
+        coverage,
+    }
+}
+
+/// Whether coverage fell noticeably compared to the previous run
+pub fn coverage_dropped(previous: &FeedstockCoverage, current: &FeedstockCoverage) -> bool {
+    previous.coverage - current.coverage > COVERAGE_DROP_WARNING
+}
+
+/// Tally statusCheckRollup states; unknown states are not counted
+pub fn summarize_build_states<'a>(states: impl IntoIterator<Item = &'a str>) -> BuildHealth {
+    let mut health = BuildHealth::default();
+    for state in states {
+        match state {
+            "SUCCESS" => health.passing += 1,
+            "FAILURE" | "ERROR" => health.failing += 1,
+            "PENDING" | "EXPECTED" => health.pending += 1,
+            _ => continue,
+        }
+        health.checked += 1;
+    }
+    health
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Attribution, FollowUpCheck, RecipeType, RevertInfo};
+
+    fn converted_entry(revert: Option<(&str, &str)>) -> FeedstockEntry {
+        FeedstockEntry {
+            attribution: Some(Attribution {
+                contribution_type: ContributionType::Conversion,
+                contributors: vec!["someone".to_string()],
+                date: "2025-01-01T00:00:00Z".to_string(),
+                commit_sha: None,
+            }),
+            revert: revert.map(|(converted_at, reverted_at)| RevertInfo {
+                converted_at: converted_at.to_string(),
+                reverted_at: reverted_at.to_string(),
+            }),
+            ..FeedstockEntry::test(match revert {
+                Some(_) => RecipeType::MetaYaml,
+                None => RecipeType::RecipeV1,
+            })
+        }
+    }
+
+    #[test]
+    fn test_quality_metrics_counts_reverts_within_window() {
+        let mut states = BTreeMap::new();
+        states.insert("a-feedstock".to_string(), converted_entry(None));
+        states.insert(
+            "b-feedstock".to_string(),
+            converted_entry(Some(("2025-01-01T00:00:00Z", "2025-01-20T00:00:00Z"))),
+        );
+        states.insert(
+            "c-feedstock".to_string(),
+            converted_entry(Some(("2025-01-01T00:00:00Z", "2025-06-01T00:00:00Z"))),
+        );
+
+        let metrics = calculate_quality_metrics(&states);
+        assert_eq!(metrics.conversions, 3);
+        assert_eq!(metrics.conversion_reverts_30d, 1);
+
+        // Converted again after a quick revert, so it is a conversion that holds
+        let mut reconverted =
+            converted_entry(Some(("2025-01-01T00:00:00Z", "2025-01-05T00:00:00Z")));
+        reconverted.recipe_type = RecipeType::RecipeV1;
+        states.insert("d-feedstock".to_string(), reconverted);
+        // An attribution left on a meta.yaml feedstock without a recorded revert
+        let mut stale = converted_entry(None);
+        stale.recipe_type = RecipeType::MetaYaml;
+        states.insert("e-feedstock".to_string(), stale);
+
+        let metrics = calculate_quality_metrics(&states);
+        assert_eq!(metrics.conversions, 4);
+        assert_eq!(metrics.conversion_reverts_30d, 1);
+    }
+
+    #[test]
+    fn test_follow_up_candidates_and_metrics() {
+        let check = |converted_at: &str, fix_pr| FollowUpCheck {
+            converted_at: converted_at.to_string(),
+            fix_pr,
+        };
+        let mut states = BTreeMap::new();
+        states.insert("unchecked".to_string(), converted_entry(None));
+        for (name, follow_up) in [
+            ("fixed", check("2025-01-01T00:00:00Z", Some(12))),
+            ("clean", check("2025-01-01T00:00:00Z", None)),
+            // Checked for an earlier conversion, before a revert
+            ("reconverted", check("2024-06-01T00:00:00Z", Some(3))),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.follow_up = Some(follow_up);
+            states.insert(name.to_string(), entry);
+        }
+
+        let now = DateTime::parse_from_rfc3339("2025-01-08T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let candidates = follow_up_candidates(&states, now);
+        assert_eq!(
+            candidates,
+            [
+                (
+                    "reconverted".to_string(),
+                    "2025-01-01T00:00:00Z".to_string(),
+                    "2025-01-08T00:00:00Z".to_string()
+                ),
+                (
+                    "unchecked".to_string(),
+                    "2025-01-01T00:00:00Z".to_string(),
+                    "2025-01-08T00:00:00Z".to_string()
+                ),
+            ]
+        );
+        // The window hasn't passed yet
+        assert!(follow_up_candidates(&states, now - Duration::seconds(1)).is_empty());
+
+        let metrics = calculate_quality_metrics(&states);
+        assert_eq!(metrics.follow_up_checked, 2);
+        assert_eq!(metrics.follow_up_fixes_7d, 1);
+    }
+
+    #[test]
+    fn test_top_feedstock_alerts() {
+        let mut previous = BTreeMap::new();
+        let mut current = BTreeMap::new();
+        for (name, downloads, before, after) in [
+            ("converted", 500, RecipeType::MetaYaml, RecipeType::RecipeV1),
+            ("reverted", 400, RecipeType::RecipeV1, RecipeType::MetaYaml),
+            ("unchanged", 300, RecipeType::MetaYaml, RecipeType::MetaYaml),
+            ("outside", 100, RecipeType::MetaYaml, RecipeType::RecipeV1),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.recipe_type = before;
+            previous.insert(name.to_string(), entry.clone());
+            entry.recipe_type = after;
+            entry.downloads = Some(downloads);
+            current.insert(name.to_string(), entry);
+        }
+        // New feedstocks have no previous status to compare against
+        let mut new_entry = converted_entry(None);
+        new_entry.recipe_type = RecipeType::RecipeV1;
+        new_entry.downloads = Some(1000);
+        current.insert("new".to_string(), new_entry);
+
+        let alerts = calculate_top_feedstock_alerts(&previous, &current, 4);
+        let summary: Vec<_> = alerts
+            .iter()
+            .map(|a| (a.name.as_str(), a.kind.clone(), a.rank))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("converted", AlertKind::Converted, 2),
+                ("reverted", AlertKind::Unconverted, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rattler_build_adoption_counts_v1_only() {
+        let mut states = BTreeMap::new();
+        for (name, recipe_type, pin, schema_version) in [
+            ("a", RecipeType::RecipeV1, Some(">=0.30"), Some(1)),
+            ("b", RecipeType::RecipeV1, None, Some(1)),
+            ("c", RecipeType::NonStandardV1, None, None),
+            ("d", RecipeType::MetaYaml, Some(">=0.30"), None),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.recipe_type = recipe_type;
+            entry.rattler_build_pin = pin.map(String::from);
+            entry.schema_version = schema_version;
+            states.insert(name.to_string(), entry);
+        }
+
+        let adoption = calculate_rattler_build_adoption(&states);
+        let count = |version: &str, feedstocks| VersionCount {
+            version: version.to_string(),
+            feedstocks,
+        };
+        assert_eq!(
+            adoption.pins,
+            vec![count("unpinned", 2), count(">=0.30", 1)]
+        );
+        assert_eq!(
+            adoption.schema_versions,
+            vec![count("1", 2), count("unknown", 1)]
+        );
+    }
+
+    #[test]
+    fn test_coverage_drop_detection() {
+        let previous = calculate_coverage(980, 1000);
+        assert_eq!(previous.coverage, 0.98);
+        assert!(!coverage_dropped(&previous, &calculate_coverage(975, 1000)));
+        assert!(coverage_dropped(&previous, &calculate_coverage(960, 1000)));
+        assert_eq!(calculate_coverage(10, 0).coverage, 0.0);
+    }
+
+    #[test]
+    fn test_summarize_build_states() {
+        let health =
+            summarize_build_states(["SUCCESS", "SUCCESS", "FAILURE", "ERROR", "PENDING", "BOGUS"]);
+        assert_eq!(
+            health,
+            BuildHealth {
+                checked: 5,
+                passing: 2,
+                failing: 2,
+                pending: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_maintainer_conversion_rates_buckets() {
+        let mut states = BTreeMap::new();
+        for (name, maintainers, recipe_type) in [
+            ("a", 0, RecipeType::RecipeV1),
+            ("b", 1, RecipeType::MetaYaml),
+            ("c", 1, RecipeType::RecipeV1),
+            ("d", 7, RecipeType::RecipeV1),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.recipe_type = recipe_type;
+            entry.maintainers = (0..maintainers).map(|i| format!("m{}", i)).collect();
+            states.insert(name.to_string(), entry);
+        }
+
+        let rates = calculate_maintainer_conversion_rates(&states);
+        assert_eq!(rates.len(), MAX_MAINTAINER_BUCKET);
+        assert_eq!((rates[0].feedstocks, rates[0].converted), (2, 1));
+        assert_eq!(rates[4].maintainers, "5+");
+        assert_eq!((rates[4].feedstocks, rates[4].converted), (1, 1));
+    }
+
+    #[test]
+    fn test_bot_activity_rates_buckets() {
+        let now = DateTime::parse_from_rfc3339("2025-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut states = BTreeMap::new();
+        let mut bot_prs = HashMap::new();
+        for (name, count, recipe_type) in [
+            ("a", Some(3), RecipeType::MetaYaml),
+            ("b", Some(12), RecipeType::RecipeV1),
+            ("c", Some(240), RecipeType::RecipeV1),
+            ("d", None, RecipeType::RecipeV1),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.recipe_type = recipe_type;
+            if name == "c" {
+                // Converted a month ago, the others long ago
+                entry.attribution.as_mut().unwrap().date = "2025-05-01T00:00:00Z".to_string();
+            }
+            states.insert(name.to_string(), entry);
+            if let Some(count) = count {
+                bot_prs.insert(name.to_string(), count);
+            }
+        }
+
+        let rates = calculate_bot_activity_rates(&states, &bot_prs, now);
+        let rows: Vec<_> = rates
+            .iter()
+            .map(|r| {
+                (
+                    r.bot_prs.as_str(),
+                    r.feedstocks,
+                    r.converted,
+                    r.converted_recently,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("0-9", 1, 0, 0),
+                ("10-24", 1, 1, 0),
+                ("25-49", 0, 0, 0),
+                ("50-99", 0, 0, 0),
+                ("100+", 1, 1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recipe_feature_rates_hardest_first() {
+        let mut states = BTreeMap::new();
+        for (name, features, recipe_type) in [
+            ("a", vec!["multiple-outputs"], RecipeType::MetaYaml),
+            (
+                "b",
+                vec!["multiple-outputs", "noarch"],
+                RecipeType::MetaYaml,
+            ),
+            ("c", vec!["noarch", "selectors"], RecipeType::RecipeV1),
+            ("d", vec!["jinja-statements"], RecipeType::RecipeV1),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.recipe_type = recipe_type;
+            entry.recipe_features = features.into_iter().map(String::from).collect();
+            states.insert(name.to_string(), entry);
+        }
+
+        let rates = calculate_recipe_feature_rates(&states);
+        let features: Vec<_> = rates.iter().map(|r| r.feature.as_str()).collect();
+        assert_eq!(features, vec!["multiple-outputs", "noarch"]);
+        assert_eq!((rates[0].feedstocks, rates[0].converted), (2, 0));
+        assert_eq!(
+            (rates[0].other_feedstocks, rates[0].other_converted),
+            (2, 2)
+        );
+        assert_eq!((rates[1].feedstocks, rates[1].converted), (2, 1));
+    }
+
+    #[test]
+    fn test_dependency_blockers() {
+        let mut states = BTreeMap::new();
+        for (name, recipe_type) in [
+            ("app-feedstock", RecipeType::MetaYaml),
+            ("lib-feedstock", RecipeType::MetaYaml),
+            ("python-feedstock", RecipeType::RecipeV1),
+            ("done-feedstock", RecipeType::RecipeV1),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.recipe_type = recipe_type;
+            states.insert(name.to_string(), entry);
+        }
+        let output_feedstocks: HashMap<String, String> = [
+            ("app", "app-feedstock"),
+            ("libfoo", "lib-feedstock"),
+            ("libfoo-devel", "lib-feedstock"),
+            ("python", "python-feedstock"),
+        ]
+        .into_iter()
+        .map(|(package, feedstock)| (package.to_string(), feedstock.to_string()))
+        .collect();
+        let deps = |packages: &[&str]| packages.iter().map(|p| p.to_string()).collect();
+        let dependencies = HashMap::from([
+            (
+                "app-feedstock".to_string(),
+                deps(&["libfoo", "libfoo-devel", "python", "c_compiler_stub"]),
+            ),
+            ("lib-feedstock".to_string(), deps(&["python", "libfoo"])),
+            ("done-feedstock".to_string(), deps(&["libfoo"])),
+        ]);
+
+        let blockers = calculate_dependency_blockers(&states, &dependencies, &output_feedstocks);
+        assert_eq!(blockers.len(), 2);
+        assert_eq!(blockers["app-feedstock"], vec!["lib-feedstock".to_string()]);
+        assert!(blockers["lib-feedstock"].is_empty());
+
+        // lib is required by app (twice) and done, python by app and lib; lib requiring
+        // its own output doesn't count
+        let progress =
+            calculate_dependency_weighted_progress(&states, &dependencies, &output_feedstocks);
+        assert_eq!(
+            progress,
+            DependencyWeightedProgress {
+                converted: 2,
+                total: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_likely_conversions() {
+        let mut states = BTreeMap::new();
+        for (name, recipe_type, maintainers) in [
+            ("a-feedstock", RecipeType::RecipeV1, "alice"),
+            ("b-feedstock", RecipeType::RecipeV1, "Alice"),
+            ("c-feedstock", RecipeType::MetaYaml, "alice"),
+            ("d-feedstock", RecipeType::MetaYaml, "bob"),
+            ("e-feedstock", RecipeType::MetaYaml, "bob"),
+        ] {
+            let mut entry = converted_entry(None);
+            entry.recipe_type = recipe_type;
+            entry.maintainers = vec![maintainers.to_string()];
+            states.insert(name.to_string(), entry);
+        }
+        let output_feedstocks = HashMap::from([
+            ("liba".to_string(), "a-feedstock".to_string()),
+            ("libd".to_string(), "d-feedstock".to_string()),
+        ]);
+        let dependencies = HashMap::from([
+            ("c-feedstock".to_string(), vec!["liba".to_string()]),
+            ("e-feedstock".to_string(), vec!["libd".to_string()]),
+        ]);
+        let pushed_at = HashMap::from([
+            (
+                "e-feedstock".to_string(),
+                "2025-05-20T00:00:00Z".to_string(),
+            ),
+            (
+                "d-feedstock".to_string(),
+                "2024-01-01T00:00:00Z".to_string(),
+            ),
+        ]);
+        let now = DateTime::parse_from_rfc3339("2025-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let likely = calculate_likely_conversions(
+            &states,
+            &dependencies,
+            &output_feedstocks,
+            &pushed_at,
+            now,
+        );
+        let ranked: Vec<_> = likely.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(ranked, ["c-feedstock", "e-feedstock", "d-feedstock"]);
+        // alice converted both her other feedstocks, c's only dependency is converted
+        assert_eq!(likely[0].maintainer_share, 1.0);
+        assert_eq!(likely[0].dependencies_converted, Some(1.0));
+        assert!((likely[0].score - 0.8).abs() < 1e-9);
+        // e is only recently pushed to, d has no signal at all
+        assert!((likely[1].score - 0.2).abs() < 1e-9);
+        assert_eq!(likely[1].dependencies_converted, Some(0.0));
+        assert_eq!(likely[2].score, 0.0);
+        assert_eq!(likely[2].dependencies_converted, None);
+    }
+}
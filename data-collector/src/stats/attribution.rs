@@ -1,8 +1,13 @@
 use anyhow::Result;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use crate::external::{CommitAuthor, FirstRecipeCommit, GitHubClient, RecipeHistoryResult};
-use crate::models::{Attribution, ContributionType, FeedstockEntry, RecipeCommitCache, RecipeType};
+use crate::config::Paths;
+use crate::external::{
+    fetch_recipe_maintainers, CommitAuthor, FirstRecipeCommit, GitHubClient, PullRequestInfo,
+    RecipeHistoryResult, RecipeStore,
+};
+use crate::models::{Attribution, ContributionType, FeedstockEntry, RecipeCommitCache};
+use crate::stats::{is_github_login, verify_author_names, IdentityCache};
 
 /// Known bot patterns for detecting automated commits
 const BOT_PATTERNS: &[&str] = &[
@@ -34,34 +39,69 @@ pub fn is_bot_author(author: &CommitAuthor) -> bool {
     })
 }
 
+/// Attribution work left over from an interrupted run, derived from a saved checkpoint
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PendingAttribution {
+    /// Feedstocks with cached commit info (batch query done) but no attribution yet
+    pub cached: usize,
+    /// Feedstocks with neither cached commit info nor attribution
+    pub uncached: usize,
+}
+
+impl PendingAttribution {
+    pub fn from_states(feedstock_states: &BTreeMap<String, FeedstockEntry>) -> Self {
+        let mut pending = Self::default();
+        for entry in feedstock_states.values() {
+            if !entry.recipe_type.is_v1() || entry.attribution.is_some() {
+                continue;
+            }
+            if entry.recipe_commit_cache.is_some() {
+                pending.cached += 1;
+            } else {
+                pending.uncached += 1;
+            }
+        }
+        pending
+    }
+
+    /// A run was interrupted if it saved commit info without finishing attribution
+    pub fn is_interrupted(&self) -> bool {
+        self.cached > 0
+    }
+
+    /// Human readable name of the first stage that still has work to do
+    pub fn resume_stage(&self) -> &'static str {
+        if self.uncached > 0 {
+            "recipe history batch query"
+        } else {
+            "PR lookup and attribution processing"
+        }
+    }
+}
+
 /// Collect attribution data for Recipe v1 feedstocks that don't have it yet
 ///
 /// If `reattribute` is true, clears existing attributions and re-calculates all.
-/// If `refetch_recipe_commits` is true, also clears the commit cache (forces re-fetch from API).
+/// Cached commit info is reused; see `CacheInvalidation` to drop it.
+/// If `verify_commits` is true, freshly fetched commits are checked to have created the
+/// recipe file, at one REST request each (see `--verify-conversions`).
 /// The `save_fn` callback is called after the batch query to save intermediate progress.
 pub async fn collect_attributions<F>(
+    paths: &Paths,
     feedstock_states: &mut BTreeMap<String, FeedstockEntry>,
     verbose: bool,
     reattribute: bool,
-    refetch_recipe_commits: bool,
+    verify_commits: bool,
     save_fn: F,
 ) -> Result<u32>
 where
     F: Fn(&BTreeMap<String, FeedstockEntry>) -> Result<()>,
 {
-    // If refetch flag is set, clear the commit cache
-    if refetch_recipe_commits {
-        println!("🗑️  Clearing recipe commit cache (--refetch-recipe-commits flag set)");
-        for entry in feedstock_states.values_mut() {
-            entry.recipe_commit_cache = None;
-        }
-    }
-
     // If reattribute flag is set, clear all existing attributions first
     if reattribute {
         println!("🔄 Re-calculating all attributions (--reattribute flag set)");
         for entry in feedstock_states.values_mut() {
-            if entry.recipe_type == RecipeType::RecipeV1 {
+            if entry.recipe_type.is_v1() {
                 entry.attribution = None;
             }
         }
@@ -70,9 +110,7 @@ where
     // Find feedstocks that need attribution
     let needs_attribution: Vec<String> = feedstock_states
         .iter()
-        .filter(|(_, entry)| {
-            entry.recipe_type == RecipeType::RecipeV1 && entry.attribution.is_none()
-        })
+        .filter(|(_, entry)| entry.recipe_type.is_v1() && entry.attribution.is_none())
         .map(|(name, _)| name.clone())
         .collect();
 
@@ -158,32 +196,32 @@ where
         // Fetch remaining
         if !needs_fetch.is_empty() {
             let fetch_names: Vec<String> = needs_fetch.into_iter().cloned().collect();
-            let fetched = github_client
+            let mut fetched = github_client
                 .batch_query_recipe_history(&fetch_names)
                 .await?;
+            if verify_commits {
+                correct_non_creating_commits(&github_client, &mut fetched, verbose).await;
+            }
             results.extend(fetched);
         }
 
         results
     } else {
         // No cache, fetch all
-        github_client
+        let mut fetched = github_client
             .batch_query_recipe_history(&needs_attribution)
-            .await?
+            .await?;
+        if verify_commits {
+            correct_non_creating_commits(&github_client, &mut fetched, verbose).await;
+        }
+        fetched
     };
 
     // Save commit info to cache for resume capability
     for result in &batch_results {
         if let Some(commit) = &result.first_recipe_commit {
             if let Some(entry) = feedstock_states.get_mut(&result.feedstock) {
-                entry.recipe_commit_cache = Some(RecipeCommitCache {
-                    sha: commit.sha.clone(),
-                    message: commit.message.clone(),
-                    date: commit.date.clone(),
-                    author_login: commit.author.login.clone(),
-                    author_name: commit.author.name.clone(),
-                    author_email: commit.author.email.clone(),
-                });
+                entry.recipe_commit_cache = Some(commit_cache(commit));
             }
         }
     }
@@ -213,7 +251,7 @@ where
     );
 
     // Batch fetch maintainers for new feedstocks
-    let maintainers_map = if !new_feedstock_set.is_empty() {
+    let mut maintainers_map = if !new_feedstock_set.is_empty() {
         let new_feedstocks: Vec<String> = new_feedstock_set.iter().cloned().collect();
         println!("👥 Batch fetching maintainers for {} new feedstocks...", new_feedstocks.len());
         github_client
@@ -223,6 +261,26 @@ where
         std::collections::HashMap::new()
     };
 
+    // Retry the ones the batch query missed through the raw recipe mirror
+    let missing: Vec<&String> = new_feedstock_set
+        .iter()
+        .filter(|feedstock| !maintainers_map.contains_key(*feedstock))
+        .collect();
+    if !missing.is_empty() {
+        let mut store = RecipeStore::open(&paths.recipe_store)?;
+        for feedstock in missing {
+            let maintainers = fetch_recipe_maintainers(feedstock, &mut store).await?;
+            if !maintainers.is_empty() {
+                maintainers_map.insert(feedstock.clone(), maintainers);
+            }
+        }
+        store.save()?;
+        println!(
+            "👥 Recipe store: {} recipes up to date, {} downloaded",
+            store.hits, store.downloads
+        );
+    }
+
     // Batch fetch PRs for all conversions
     let pr_map = if conversion_count > 0 {
         let conversion_commits: Vec<(&str, &str)> = batch_results
@@ -259,21 +317,50 @@ where
         std::collections::HashMap::new()
     };
 
+    // Commits not linked to a GitHub account only carry the author name, which anyone can
+    // set to someone else's login, so those names are checked against GitHub first
+    let unlinked_names: Vec<String> = batch_results
+        .iter()
+        .filter(|r| !new_feedstock_set.contains(&r.feedstock))
+        .filter_map(|r| r.first_recipe_commit.as_ref())
+        .filter(|c| c.author.login.is_none() && is_github_login(&c.author.name))
+        .map(|c| c.author.name.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let verified_authors = if !unlinked_names.is_empty() {
+        println!(
+            "🪪 Verifying {} commit author names without a linked account...",
+            unlinked_names.len()
+        );
+        let accounts = github_client.batch_fetch_users(&unlinked_names).await?;
+        let identities = IdentityCache::load(&paths.identity_cache);
+        verify_author_names(&unlinked_names, &accounts, &identities)
+    } else {
+        std::collections::HashMap::new()
+    };
+
     // Process all results (now fast since everything is pre-fetched)
     println!("📝 Processing {} attributions...", batch_results.len());
     for result in batch_results {
-        let is_new_feedstock = new_feedstock_set.contains(&result.feedstock);
-        let pr_info = pr_map.get(&result.feedstock);
-        let maintainers = maintainers_map.get(&result.feedstock);
-        let bot_pr_contributor = bot_pr_contributors.get(&result.feedstock);
-
-        if let Some(attribution) =
-            process_history_result(&result, verbose, is_new_feedstock, pr_info, maintainers, bot_pr_contributor)
-        {
-            if let Some(entry) = feedstock_states.get_mut(&result.feedstock) {
-                entry.attribution = Some(attribution);
-                attributed_count += 1;
-            }
+        let Some(commit) = &result.first_recipe_commit else {
+            continue;
+        };
+        let evidence = AttributionEvidence {
+            is_new_feedstock: new_feedstock_set.contains(&result.feedstock),
+            pr: pr_map.get(&result.feedstock).cloned(),
+            maintainers: maintainers_map
+                .get(&result.feedstock)
+                .cloned()
+                .unwrap_or_default(),
+            bot_pr_contributor: bot_pr_contributors.get(&result.feedstock).cloned(),
+            author_account: verified_authors.get(&commit.author.name).cloned(),
+        };
+
+        let attribution = process_history_result(&result.feedstock, commit, &evidence, verbose);
+        if let Some(entry) = feedstock_states.get_mut(&result.feedstock) {
+            entry.attribution = Some(attribution);
+            attributed_count += 1;
         }
     }
 
@@ -282,7 +369,281 @@ where
     Ok(attributed_count)
 }
 
-/// Process a single history result and determine attribution
+/// Set `last_changed` of attributed Recipe v1 feedstocks to the attributed commit date,
+/// so feedstocks converted before the tracker existed don't show its first-run timestamp.
+/// Returns the number of entries changed.
+pub fn backfill_last_changed(feedstock_states: &mut BTreeMap<String, FeedstockEntry>) -> u32 {
+    let mut backfilled = 0u32;
+    for entry in feedstock_states.values_mut() {
+        if !entry.recipe_type.is_v1() {
+            continue;
+        }
+        let Some(attribution) = &entry.attribution else {
+            continue;
+        };
+        if !attribution.date.is_empty() && entry.last_changed != attribution.date {
+            entry.last_changed = attribution.date.clone();
+            backfilled += 1;
+        }
+    }
+    backfilled
+}
+
+/// Find the `feedstock_states` key for a feedstock given with or without the
+/// `-feedstock` suffix
+pub fn resolve_feedstock_name(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    name: &str,
+) -> Option<String> {
+    [name.to_string(), format!("{}-feedstock", name)]
+        .into_iter()
+        .find(|key| feedstock_states.contains_key(key))
+}
+
+/// Redo the attribution of a single Recipe v1 feedstock from scratch, explaining each
+/// step. Only that entry of `feedstock_states` is changed.
+pub async fn attribute_one(
+    paths: &Paths,
+    feedstock_states: &mut BTreeMap<String, FeedstockEntry>,
+    name: &str,
+) -> Result<()> {
+    let Some(name) = resolve_feedstock_name(feedstock_states, name) else {
+        anyhow::bail!("Unknown feedstock: {}", name);
+    };
+    let entry = &feedstock_states[&name];
+    anyhow::ensure!(
+        entry.recipe_type.is_v1(),
+        "{} is not a Recipe v1 feedstock ({:?})",
+        name,
+        entry.recipe_type
+    );
+
+    let previous = entry.attribution.clone();
+    let mut single = BTreeMap::from([(name.clone(), entry.clone())]);
+    let single_entry = single.get_mut(&name).expect("just inserted");
+    single_entry.attribution = None;
+    single_entry.recipe_commit_cache = None;
+
+    // Only the final result is written, so there is nothing to checkpoint
+    collect_attributions(paths, &mut single, true, false, true, |_| Ok(())).await?;
+
+    let updated = single.remove(&name).expect("still present");
+    println!("\n🔎 {}", name);
+    match &updated.recipe_commit_cache {
+        Some(commit) => println!(
+            "   First recipe.yaml commit: {} ({}) by {} <{}>: {}",
+            commit.sha,
+            commit.date,
+            commit
+                .author_login
+                .as_deref()
+                .unwrap_or(&commit.author_name),
+            commit.author_email,
+            commit.message.lines().next().unwrap_or_default()
+        ),
+        None => println!("   No recipe.yaml commit found"),
+    }
+    let describe = |attribution: Option<&Attribution>| match attribution {
+        Some(a) => format!(
+            "{:?} by {} on {}",
+            a.contribution_type,
+            a.contributors.join(", "),
+            a.date
+        ),
+        None => "none".to_string(),
+    };
+    println!("   Previous attribution: {}", describe(previous.as_ref()));
+    println!(
+        "   New attribution:      {}",
+        describe(updated.attribution.as_ref())
+    );
+
+    feedstock_states.insert(name, updated);
+    Ok(())
+}
+
+/// Cacheable form of a first recipe commit
+fn commit_cache(commit: &FirstRecipeCommit) -> RecipeCommitCache {
+    RecipeCommitCache {
+        sha: commit.sha.clone(),
+        message: commit.message.clone(),
+        date: commit.date.clone(),
+        author_login: commit.author.login.clone(),
+        author_name: commit.author.name.clone(),
+        author_email: commit.author.email.clone(),
+    }
+}
+
+/// If `commit_sha` only modified recipe.yaml (e.g. a rerender right after the actual
+/// conversion), find the commit that created the file instead.
+/// Returns None when the commit is fine or didn't touch recipe.yaml.
+async fn find_creating_commit_if_misattributed(
+    github_client: &GitHubClient,
+    feedstock: &str,
+    commit_sha: &str,
+) -> Result<Option<FirstRecipeCommit>> {
+    let Some(change) = github_client
+        .get_recipe_file_change(feedstock, commit_sha)
+        .await?
+    else {
+        return Ok(None);
+    };
+    if change.created_file() {
+        return Ok(None);
+    }
+
+    Ok(github_client
+        .find_recipe_creating_commit(feedstock, change.path)
+        .await?
+        .filter(|creating| creating.sha != commit_sha))
+}
+
+/// Replace freshly fetched first-recipe commits that did not create recipe.yaml
+async fn correct_non_creating_commits(
+    github_client: &GitHubClient,
+    results: &mut [RecipeHistoryResult],
+    verbose: bool,
+) {
+    let total = results.len();
+    let (mut corrected, mut failed) = (0u32, 0u32);
+
+    for (idx, result) in results.iter_mut().enumerate() {
+        eprint!("\r🧐 Verifying recipe commits {}/{}...", idx + 1, total);
+        let Some(commit) = &result.first_recipe_commit else {
+            continue;
+        };
+        let creating =
+            find_creating_commit_if_misattributed(github_client, &result.feedstock, &commit.sha)
+                .await;
+        let creating = match creating {
+            Ok(creating) => creating,
+            Err(e) => {
+                if verbose {
+                    eprintln!("\n  ⚠️  {}: {}", result.feedstock, e);
+                }
+                failed += 1;
+                continue;
+            }
+        };
+        if let Some(creating) = creating {
+            if verbose {
+                eprintln!(
+                    "\n  🩹 {}: {} only modified recipe.yaml, using {} instead",
+                    result.feedstock, commit.sha, creating.sha
+                );
+            }
+            result.first_recipe_commit = Some(creating);
+            corrected += 1;
+        }
+    }
+    if total > 0 {
+        eprintln!();
+    }
+
+    if corrected > 0 {
+        println!(
+            "🩹 Corrected {} recipe commits that only modified recipe.yaml",
+            corrected
+        );
+    }
+    if failed > 0 {
+        println!("⚠️  Could not verify {} recipe commits", failed);
+    }
+}
+
+/// Re-check existing conversion attributions for commits that only modified recipe.yaml
+/// (e.g. rerenders credited instead of the conversion).
+///
+/// Affected entries get their attribution cleared and the file-creating commit cached,
+/// so the next `collect_attributions` run re-attributes them.
+/// Returns the number of attributions cleared.
+pub async fn verify_conversion_attributions(
+    feedstock_states: &mut BTreeMap<String, FeedstockEntry>,
+    verbose: bool,
+) -> Result<u32> {
+    let github_client = match GitHubClient::new() {
+        Ok(client) => client,
+        Err(e) => {
+            println!("⚠️  GitHub client not available: {}", e);
+            println!("   Skipping conversion verification.");
+            return Ok(0);
+        }
+    };
+
+    let conversions: Vec<(String, String)> = feedstock_states
+        .iter()
+        .filter_map(|(name, entry)| {
+            let attribution = entry.attribution.as_ref()?;
+            if attribution.contribution_type != ContributionType::Conversion {
+                return None;
+            }
+            Some((name.clone(), attribution.commit_sha.clone()?))
+        })
+        .collect();
+
+    println!(
+        "🧐 Verifying {} conversion commits actually created recipe.yaml...",
+        conversions.len()
+    );
+
+    let (mut fixed, mut failed) = (0u32, 0u32);
+    for (idx, (feedstock, commit_sha)) in conversions.iter().enumerate() {
+        eprint!("\r   Checking {}/{}...", idx + 1, conversions.len());
+        let creating =
+            find_creating_commit_if_misattributed(&github_client, feedstock, commit_sha).await;
+        let creating = match creating {
+            Ok(Some(creating)) => creating,
+            Ok(None) => continue,
+            Err(e) => {
+                if verbose {
+                    eprintln!("\n  ⚠️  {}: {}", feedstock, e);
+                }
+                failed += 1;
+                continue;
+            }
+        };
+
+        if verbose {
+            eprintln!(
+                "\n  🩹 {}: attributed commit {} only modified recipe.yaml, created in {}",
+                feedstock, commit_sha, creating.sha
+            );
+        }
+        if let Some(entry) = feedstock_states.get_mut(feedstock) {
+            entry.attribution = None;
+            entry.recipe_commit_cache = Some(commit_cache(&creating));
+            fixed += 1;
+        }
+    }
+    eprintln!();
+
+    println!(
+        "🩹 {} conversion attributions credited a non-creating commit and will be redone",
+        fixed
+    );
+    if failed > 0 {
+        println!("⚠️  Could not verify {} conversion commits", failed);
+    }
+    Ok(fixed)
+}
+
+/// What the batch queries found out about a feedstock's first recipe.yaml commit.
+/// Attribution only looks at this, so it can be tested without GitHub.
+#[derive(Debug, Clone, Default)]
+struct AttributionEvidence {
+    /// The first recipe.yaml commit is the feedstock's initial commit
+    is_new_feedstock: bool,
+    /// PR that introduced the commit, `None` if it was pushed directly
+    pr: Option<PullRequestInfo>,
+    /// `extra.recipe-maintainers` of new feedstocks
+    maintainers: Vec<String>,
+    /// First human committer of a PR opened by a bot
+    bot_pr_contributor: Option<String>,
+    /// Verified login matching the author name of a commit without a linked account
+    author_account: Option<String>,
+}
+
+/// Determine the attribution of a feedstock from its first recipe.yaml commit
 ///
 /// New attribution rules:
 /// 1. New Feedstock: recipe.yaml exists in the very first commit of the repo
@@ -290,73 +651,71 @@ where
 /// 2. Conversion: recipe.yaml was added in a later commit
 ///    -> Look up the PR, credit the PR author (or commit author who added recipe.yaml if bot PR)
 fn process_history_result(
-    result: &RecipeHistoryResult,
+    feedstock: &str,
+    commit: &FirstRecipeCommit,
+    evidence: &AttributionEvidence,
     verbose: bool,
-    is_new_feedstock: bool,
-    pr_info: Option<&crate::external::PullRequestInfo>,
-    maintainers: Option<&Vec<String>>,
-    bot_pr_contributor: Option<&String>,
-) -> Option<Attribution> {
-    let commit = result.first_recipe_commit.as_ref()?;
-
-    if is_new_feedstock {
+) -> Attribution {
+    if evidence.is_new_feedstock {
         // New feedstock - credit the maintainers from recipe.yaml
-        let contributors = match maintainers {
-            Some(m) if !m.is_empty() => m.clone(),
-            _ => {
-                if verbose {
-                    println!(
-                        "  ⚠️  {}: No maintainers found, using 'unknown'",
-                        result.feedstock
-                    );
-                }
-                vec!["unknown".to_string()]
+        let contributors = if evidence.maintainers.is_empty() {
+            if verbose {
+                println!("  ⚠️  {}: No maintainers found, using 'unknown'", feedstock);
             }
+            vec!["unknown".to_string()]
+        } else {
+            evidence.maintainers.clone()
         };
 
         if verbose {
-            println!(
-                "  🆕 {}: New feedstock by {:?}",
-                result.feedstock, contributors
-            );
+            println!("  🆕 {}: New feedstock by {:?}", feedstock, contributors);
         }
 
-        return Some(Attribution {
+        return Attribution {
             contribution_type: ContributionType::NewFeedstock,
             contributors,
             date: commit.date.clone(),
             commit_sha: Some(commit.sha.clone()),
-        });
+        };
     }
 
     // Rule 2: This is a conversion - find who did it
-    let contributor = find_conversion_contributor(commit, verbose, pr_info, bot_pr_contributor);
+    let contributor = find_conversion_contributor(commit, evidence, verbose);
 
     if verbose {
-        println!("  🔄 {}: Conversion by {}", result.feedstock, contributor);
+        println!("  🔄 {}: Conversion by {}", feedstock, contributor);
     }
 
-    Some(Attribution {
+    Attribution {
         contribution_type: ContributionType::Conversion,
         contributors: vec![contributor],
         date: commit.date.clone(),
         commit_sha: Some(commit.sha.clone()),
-    })
+    }
 }
 
 /// Find who actually did the conversion by looking at PRs and commits
 fn find_conversion_contributor(
-    commit: &crate::external::FirstRecipeCommit,
+    commit: &FirstRecipeCommit,
+    evidence: &AttributionEvidence,
     verbose: bool,
-    pr_info: Option<&crate::external::PullRequestInfo>,
-    bot_pr_contributor: Option<&String>,
 ) -> String {
-    match pr_info {
+    // An unverified author name is never credited, it could be anyone's login
+    let commit_author = || {
+        commit
+            .author
+            .login
+            .clone()
+            .or_else(|| evidence.author_account.clone())
+            .unwrap_or_else(|| "unknown".to_string())
+    };
+
+    match &evidence.pr {
         Some(pr) => {
             // Check if PR author is a bot
             if is_bot_username(&pr.author) {
                 // Bot opened PR - use pre-fetched human contributor if available
-                if let Some(contributor) = bot_pr_contributor {
+                if let Some(contributor) = &evidence.bot_pr_contributor {
                     if verbose {
                         println!(
                             "    PR #{} opened by bot {}, human contributor: {}",
@@ -374,11 +733,7 @@ fn find_conversion_contributor(
                         pr.number, pr.author
                     );
                 }
-                commit
-                    .author
-                    .login
-                    .clone()
-                    .unwrap_or_else(|| commit.author.name.clone())
+                commit_author()
             } else {
                 // Human opened PR - credit them
                 pr.author.clone()
@@ -389,11 +744,7 @@ fn find_conversion_contributor(
             if verbose {
                 println!("    No PR found, using commit author");
             }
-            commit
-                .author
-                .login
-                .clone()
-                .unwrap_or_else(|| commit.author.name.clone())
+            commit_author()
         }
     }
 }
@@ -417,6 +768,7 @@ fn is_initial_feedstock_commit(message: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::RecipeType;
 
     #[test]
     fn test_is_bot_author_detects_bots() {
@@ -435,6 +787,209 @@ mod tests {
         assert!(is_bot_author(&bot_author2));
     }
 
+    #[test]
+    fn test_backfill_last_changed_uses_attribution_date() {
+        let mut states = BTreeMap::new();
+        for (name, recipe_type, date) in [
+            (
+                "converted",
+                RecipeType::RecipeV1,
+                Some("2023-05-01T00:00:00Z"),
+            ),
+            ("unattributed", RecipeType::RecipeV1, None),
+            (
+                "reverted",
+                RecipeType::MetaYaml,
+                Some("2023-05-01T00:00:00Z"),
+            ),
+        ] {
+            states.insert(
+                name.to_string(),
+                FeedstockEntry {
+                    attribution: date.map(|date| Attribution {
+                        contribution_type: ContributionType::Conversion,
+                        contributors: vec!["someone".to_string()],
+                        date: date.to_string(),
+                        commit_sha: None,
+                    }),
+                    ..FeedstockEntry::test(recipe_type)
+                },
+            );
+        }
+
+        assert_eq!(backfill_last_changed(&mut states), 1);
+        assert_eq!(states["converted"].last_changed, "2023-05-01T00:00:00Z");
+        assert_eq!(states["unattributed"].last_changed, "2025-01-01T00:00:00Z");
+        assert_eq!(states["reverted"].last_changed, "2025-01-01T00:00:00Z");
+        // Running again changes nothing
+        assert_eq!(backfill_last_changed(&mut states), 0);
+
+        assert_eq!(
+            resolve_feedstock_name(&states, "converted").as_deref(),
+            Some("converted")
+        );
+        assert_eq!(resolve_feedstock_name(&states, "missing"), None);
+    }
+
+    #[test]
+    fn test_pending_attribution_detects_interrupted_run() {
+        let entry = |recipe_type, cached: bool| FeedstockEntry {
+            recipe_commit_cache: cached.then(|| RecipeCommitCache {
+                sha: "abc".to_string(),
+                message: "Convert to recipe.yaml".to_string(),
+                date: "2025-01-01T00:00:00Z".to_string(),
+                author_login: None,
+                author_name: "John Doe".to_string(),
+                author_email: "john@example.com".to_string(),
+            }),
+            ..FeedstockEntry::test(recipe_type)
+        };
+
+        let mut states = BTreeMap::new();
+        states.insert("a".to_string(), entry(RecipeType::RecipeV1, true));
+        states.insert("b".to_string(), entry(RecipeType::MetaYaml, true));
+        assert_eq!(
+            PendingAttribution::from_states(&states),
+            PendingAttribution {
+                cached: 1,
+                uncached: 0
+            }
+        );
+        assert!(PendingAttribution::from_states(&states).is_interrupted());
+
+        states.insert("c".to_string(), entry(RecipeType::RecipeV1, false));
+        let pending = PendingAttribution::from_states(&states);
+        assert_eq!(pending.uncached, 1);
+        assert_eq!(pending.resume_stage(), "recipe history batch query");
+
+        states.remove("a");
+        assert!(!PendingAttribution::from_states(&states).is_interrupted());
+    }
+
+    fn commit(login: Option<&str>, message: &str) -> FirstRecipeCommit {
+        FirstRecipeCommit {
+            sha: "abc123".to_string(),
+            author: CommitAuthor {
+                login: login.map(String::from),
+                name: "Jane Doe".to_string(),
+                email: "jane@example.com".to_string(),
+            },
+            date: "2025-03-01T12:00:00Z".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    fn pr(author: &str) -> Option<PullRequestInfo> {
+        Some(PullRequestInfo {
+            number: 42,
+            author: author.to_string(),
+        })
+    }
+
+    /// Contributors credited for a conversion commit by `login` with `evidence`
+    fn credited(login: Option<&str>, evidence: AttributionEvidence) -> Vec<String> {
+        let commit = commit(login, "Convert to recipe.yaml");
+        let attribution = process_history_result("foo-feedstock", &commit, &evidence, false);
+        assert_eq!(attribution.contribution_type, ContributionType::Conversion);
+        attribution.contributors
+    }
+
+    #[test]
+    fn test_conversion_attribution() {
+        // Human opened the PR: credit them, not whoever committed
+        let human_pr = AttributionEvidence {
+            pr: pr("alice"),
+            ..Default::default()
+        };
+        assert_eq!(credited(Some("jane"), human_pr), ["alice"]);
+
+        // Bot opened the PR and a human pushed to it: credit the human
+        let bot_pr_with_human = AttributionEvidence {
+            pr: pr("regro-cf-autotick-bot"),
+            bot_pr_contributor: Some("bob".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(credited(Some("jane"), bot_pr_with_human), ["bob"]);
+
+        // Bot PR without human commits: fall back to the commit author
+        let bot_pr_alone = AttributionEvidence {
+            pr: pr("conda-forge-admin"),
+            ..Default::default()
+        };
+        assert_eq!(credited(Some("jane"), bot_pr_alone.clone()), ["jane"]);
+        // ...but not to a name of a commit without a linked account
+        assert_eq!(credited(None, bot_pr_alone), ["unknown"]);
+
+        // Direct push: credit the commit author, or the account verified for their name
+        assert_eq!(
+            credited(Some("jane"), AttributionEvidence::default()),
+            ["jane"]
+        );
+        assert_eq!(credited(None, AttributionEvidence::default()), ["unknown"]);
+        let verified_name = AttributionEvidence {
+            author_account: Some("JaneDoe".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(credited(None, verified_name), ["JaneDoe"]);
+
+        // Maintainers only matter for new feedstocks
+        let with_maintainers = AttributionEvidence {
+            maintainers: vec!["carol".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(credited(Some("jane"), with_maintainers), ["jane"]);
+    }
+
+    #[test]
+    fn test_new_feedstock_attribution() {
+        let commit = commit(Some("conda-forge-admin"), "Initial feedstock commit");
+        let evidence = AttributionEvidence {
+            is_new_feedstock: true,
+            // A PR never credits the staged-recipes submitter over the maintainers
+            pr: pr("alice"),
+            maintainers: vec!["carol".to_string(), "dave".to_string()],
+            bot_pr_contributor: None,
+            author_account: None,
+        };
+        let attribution = process_history_result("foo-feedstock", &commit, &evidence, false);
+        assert_eq!(
+            attribution.contribution_type,
+            ContributionType::NewFeedstock
+        );
+        assert_eq!(attribution.contributors, ["carol", "dave"]);
+        assert_eq!(attribution.date, "2025-03-01T12:00:00Z");
+        assert_eq!(attribution.commit_sha.as_deref(), Some("abc123"));
+
+        let without_maintainers = AttributionEvidence {
+            is_new_feedstock: true,
+            ..Default::default()
+        };
+        let attribution =
+            process_history_result("foo-feedstock", &commit, &without_maintainers, false);
+        assert_eq!(attribution.contributors, ["unknown"]);
+    }
+
+    #[test]
+    fn test_is_initial_feedstock_commit() {
+        for message in [
+            "Initial feedstock commit",
+            "initial feedstock commit",
+            "[ci skip] Initial feedstock commit",
+            "Initial commit",
+            "Initial commit of the foo feedstock\n\nFrom staged-recipes#123",
+        ] {
+            assert!(is_initial_feedstock_commit(message), "{}", message);
+        }
+        for message in [
+            "Convert to recipe.yaml",
+            "Revert initial commit",
+            "MNT: Re-rendered with conda-build 3.28",
+            "",
+        ] {
+            assert!(!is_initial_feedstock_commit(message), "{}", message);
+        }
+    }
+
     #[test]
     fn test_is_bot_author_allows_humans() {
         let human_author = CommitAuthor {
@@ -1,30 +1,69 @@
 use anyhow::Result;
 use chrono::Utc;
+use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 use walkdir::WalkDir;
 
-use crate::config::CF_GRAPH_LOCAL_PATH;
-use crate::external::fetch_download_counts;
+use crate::config::Paths;
+use crate::external::{
+    counts_with_fallback, ensure_graph_tarball, fetch_download_counts, lint_recipe, GitHubClient,
+    RecipeStore,
+};
 use crate::git::ensure_sparse_checkout_repo;
-use crate::models::{FeedstockEntry, FeedstockStats, RecipeType};
+use crate::models::{
+    migrate_stats, AlertKind, BuildHealth, ConversionForecast, FeedstockCoverage, FeedstockEntry,
+    FeedstockStats, FollowUpCheck, GraphSource, PendingMigrationPr, RecipeLint, RecipeType,
+    RevertInfo, TopFeedstock, STATS_FORMAT_VERSION,
+};
+use crate::profiling::MemoryProfiler;
 use crate::stats::{
-    calculate_top_unconverted_feedstocks, determine_recipe_type_from_node, parse_node_attrs_file,
+    calculate_bot_activity_rates, calculate_coverage, calculate_dependency_blockers,
+    calculate_dependency_weighted_progress, calculate_likely_conversions,
+    calculate_maintainer_conversion_rates, calculate_quality_metrics,
+    calculate_rattler_build_adoption, calculate_recipe_feature_rates,
+    calculate_top_feedstock_alerts, calculate_top_unconverted_feedstocks, check_schema,
+    coverage_dropped, determine_recipe_type_from_node, drift_report, extract_dependencies,
+    extract_output_names, extract_package_summary, extract_rattler_build_pin,
+    extract_recipe_features, extract_recipe_maintainers, extract_schema_version,
+    follow_up_candidates, load_feedstock_shards, node_name, parse_node_attrs_file,
+    read_bot_pr_count, sample_node_attrs, summarize_build_states, NodeAttrsIgnoreList,
+    SkippedNodeAttrs, StatsStore, FOLLOW_UP_WINDOW_DAYS, REVERT_WINDOW_DAYS,
+    TOP_FEEDSTOCK_ALERT_LIMIT,
 };
 
-pub fn load_existing_stats_if_exists() -> Option<FeedstockStats> {
-    let path = std::env::var("CARGO_MANIFEST_DIR").ok()?;
-    let stats_file = format!("{}/../feedstock-stats.toml", path);
-    load_existing_stats(&stats_file).ok()
+/// How many recipes to lint at once
+const CONCURRENT_LINTS: usize = 8;
+
+/// Best-scored feedstocks whose push activity is looked up for the conversion forecast...
+const FORECAST_CANDIDATES: usize = 100;
+/// ...and how many of them make it into the forecast
+const FORECAST_LIMIT: usize = 20;
+
+pub fn load_existing_stats_if_exists(store: &StatsStore) -> Option<FeedstockStats> {
+    store.load().ok()
 }
 
 /// Load existing stats from a specific path
 pub fn load_existing_stats(stats_path: &str) -> Result<FeedstockStats> {
     println!("🔍 Loading stats from: {}", stats_path);
     let content = fs::read_to_string(stats_path)?;
-    let stats: FeedstockStats = toml::from_str(&content)?;
+    let mut table: toml::Table = toml::from_str(&content)?;
+    if !table.contains_key("feedstock_states") {
+        let feedstock_states = load_feedstock_shards(Path::new(stats_path), &table)?;
+        table.insert("feedstock_states".to_string(), feedstock_states.into());
+    }
+    let version = migrate_stats(&mut table)?;
+    if version < STATS_FORMAT_VERSION {
+        println!(
+            "⬆️  Migrated stats from format version {} to {}",
+            version, STATS_FORMAT_VERSION
+        );
+    }
+    let stats: FeedstockStats = table.try_into()?;
     println!(
         "📂 Loaded existing stats: {} total feedstocks, {} feedstock_states entries",
         stats.total_feedstocks,
@@ -33,33 +72,126 @@ pub fn load_existing_stats(stats_path: &str) -> Result<FeedstockStats> {
     Ok(stats)
 }
 
+/// Write stats to `stats_path`, serializing `feedstock_states` one entry at a time
+/// instead of rendering the whole document in memory. The states are passed
+/// separately (`stats.feedstock_states` must be empty) so checkpoints don't need a
+/// copy of them. The file is only replaced once completely written, so an
+/// interrupted write leaves the previous stats intact.
+pub fn write_stats(
+    stats_path: &str,
+    stats: &FeedstockStats,
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> Result<()> {
+    anyhow::ensure!(
+        stats.feedstock_states.is_empty(),
+        "feedstock_states must be passed separately"
+    );
+
+    write_atomically(Path::new(stats_path), |writer| {
+        writer.write_all(toml::to_string_pretty(stats)?.as_bytes())?;
+        write_feedstock_states(writer, feedstock_states)
+    })
+}
+
+/// Write `[feedstock_states."<name>"]` sections, one entry at a time
+pub(crate) fn write_feedstock_states<'a>(
+    writer: &mut impl Write,
+    feedstock_states: impl IntoIterator<Item = (&'a String, &'a FeedstockEntry)>,
+) -> Result<()> {
+    for (name, entry) in feedstock_states {
+        let section = BTreeMap::from([("feedstock_states", BTreeMap::from([(name, entry)]))]);
+        writeln!(writer)?;
+        writer.write_all(toml::to_string_pretty(&section)?.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Write `path` with `write`, only replacing it once completely written
+pub(crate) fn write_atomically(
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<tempfile::NamedTempFile>) -> Result<()>,
+) -> Result<()> {
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let mut writer = BufWriter::new(tempfile::NamedTempFile::new_in(dir)?);
+    write(&mut writer)?;
+
+    let file = writer.into_inner().map_err(|e| e.into_error())?;
+    file.as_file().sync_all()?;
+    // Temporary files are created private, keep the permissions of the file we replace
+    if let Ok(metadata) = fs::metadata(path) {
+        file.as_file().set_permissions(metadata.permissions())?;
+    }
+    file.persist(path)?;
+    Ok(())
+}
+
+/// Fetch download counts from prefix.dev, falling back to the counts in
+/// `existing_stats` for pages that failed
+pub async fn fetch_downloads(
+    paths: &Paths,
+    existing_stats: Option<&FeedstockStats>,
+) -> Result<HashMap<String, u64>> {
+    println!("📥 Fetching download counts from prefix.dev...");
+    let previous_download_counts = existing_stats
+        .iter()
+        .flat_map(|stats| &stats.feedstock_states)
+        .filter_map(|(name, entry)| Some((name.clone(), entry.downloads?)))
+        .collect();
+    let download_counts =
+        counts_with_fallback(fetch_download_counts(paths).await, previous_download_counts)?;
+    println!("📊 Fetched {} download counts", download_counts.len());
+    Ok(download_counts)
+}
+
 /// Collect feesdstock statistics from node attributes files.
 /// Which are present in the `node_attrs` directory of the sparse checkout repository.
+/// Download counts are fetched unless passed in from the `fetch-downloads` stage.
+/// Converted recipes are linted with `lint_command` if given.
+#[allow(clippy::too_many_arguments)]
 pub async fn collect_stats_from_node_attrs(
+    paths: &Paths,
+    download_counts: Option<HashMap<String, u64>>,
+    source: GraphSource,
     force_reload: bool,
     verbose: bool,
+    allow_schema_drift: bool,
+    lint_command: Option<&str>,
+    profiler: &mut MemoryProfiler,
 ) -> Result<FeedstockStats> {
     // Load existing stats for historical comparison
-    let existing_stats = load_existing_stats_if_exists();
+    let existing_stats = load_existing_stats_if_exists(&paths.store);
 
-    // Fetch download counts
-    println!("📥 Fetching download counts from prefix.dev...");
-    let download_counts = fetch_download_counts().await?;
-    println!("📊 Fetched {} download counts", download_counts.len());
+    let download_counts = match download_counts {
+        Some(counts) => counts,
+        None => fetch_downloads(paths, existing_stats.as_ref()).await?,
+    };
+    profiler.end_stage("download_counts");
 
-    // Set up sparse checkout repository
-    ensure_sparse_checkout_repo(force_reload, verbose)?;
+    // Fetch node_attrs and pr_info
+    match source {
+        GraphSource::Git => ensure_sparse_checkout_repo(&paths.cf_graph, force_reload, verbose)?,
+        GraphSource::Tarball => {
+            ensure_graph_tarball(&paths.cf_graph, force_reload, verbose).await?
+        }
+    }
 
     println!("📂 Scanning node_attrs directory...");
-    let node_attrs_path = format!("{}/node_attrs", CF_GRAPH_LOCAL_PATH);
+    let node_attrs_path = paths.cf_graph.join("node_attrs");
+    let pr_info_path = paths.cf_graph.join("pr_info");
 
-    if !Path::new(&node_attrs_path).exists() {
+    if !node_attrs_path.exists() {
         return Err(anyhow::anyhow!(
             "node_attrs directory not found at {}",
-            node_attrs_path
+            node_attrs_path.display()
         ));
     }
 
+    let ignore_path = &paths.node_attrs_ignore;
+    let ignore_list = NodeAttrsIgnoreList::load(ignore_path)?;
+
     // Count total JSON files first for progress bar
     let json_files: Vec<_> = WalkDir::new(&node_attrs_path)
         .into_iter()
@@ -72,6 +204,32 @@ pub async fn collect_stats_from_node_attrs(
     let total_files = json_files.len();
     println!("📊 Found {} JSON files to analyze", total_files);
 
+    // Fail before classifying if upstream renamed or retyped the fields we rely on
+    let json_paths: Vec<&Path> = json_files.iter().map(|entry| entry.path()).collect();
+    let samples = sample_node_attrs(&json_paths);
+    let drift = check_schema(&samples);
+    if drift.is_empty() {
+        println!(
+            "🔎 node_attrs schema matches ({} files sampled)",
+            samples.len()
+        );
+    } else if allow_schema_drift {
+        println!("⚠️  {}", drift_report(&drift, samples.len()));
+    } else {
+        anyhow::bail!(
+            "{}\nRe-run with --allow-schema-drift to collect anyway",
+            drift_report(&drift, samples.len())
+        );
+    }
+
+    if verbose && !ignore_list.is_empty() {
+        println!(
+            "⏭️  {} node_attrs entries on the ignore list",
+            ignore_list.len()
+        );
+    }
+    let mut skipped = SkippedNodeAttrs::default();
+
     // Set up progress bar
     let pb = ProgressBar::new(total_files as u64);
     pb.set_style(
@@ -82,15 +240,51 @@ pub async fn collect_stats_from_node_attrs(
     );
 
     let mut feedstock_states = BTreeMap::new();
+    // Requirements of each feedstock and the feedstock building each package
+    let mut dependencies = HashMap::new();
+    let mut output_feedstocks = HashMap::new();
+    // Bot pull requests per feedstock, from pr_info files sharded like node_attrs
+    let mut bot_prs = HashMap::new();
     let current_time = Utc::now().to_rfc3339();
     let mut processed = 0;
 
     // Process each JSON file
     for entry in json_files {
+        let node = node_name(entry.path());
+        if ignore_list.is_ignored(&node) {
+            skipped.ignored.push(node);
+            pb.inc(1);
+            continue;
+        }
+
         match parse_node_attrs_file(entry.path()) {
             Ok(node_data) => {
                 let feedstock_name = format!("{}-feedstock", node_data.feedstock_name);
+                if let Some(pattern) = ignore_list.excluded_by(&feedstock_name) {
+                    skipped.excluded.push((feedstock_name, pattern.to_string()));
+                    pb.inc(1);
+                    continue;
+                }
                 let recipe_type = determine_recipe_type_from_node(&node_data);
+                let summary = extract_package_summary(&node_data);
+                let maintainers = extract_recipe_maintainers(&node_data);
+                let rattler_build_pin = extract_rattler_build_pin(&node_data);
+                let schema_version = extract_schema_version(&node_data);
+                let recipe_features = extract_recipe_features(&node_data);
+                for output in extract_output_names(&node_data) {
+                    output_feedstocks.insert(output, feedstock_name.clone());
+                }
+                let requirements = extract_dependencies(&node_data);
+                if !requirements.is_empty() {
+                    dependencies.insert(feedstock_name.clone(), requirements);
+                }
+                let relative = entry.path().strip_prefix(&node_attrs_path);
+                if let Some(count) = relative
+                    .ok()
+                    .and_then(|relative| read_bot_pr_count(&pr_info_path.join(relative)))
+                {
+                    bot_prs.insert(feedstock_name.clone(), count);
+                }
 
                 // Timestamp logic:
                 // 1. New feedstock -> use current timestamp
@@ -99,9 +293,7 @@ pub async fn collect_stats_from_node_attrs(
                 let last_changed = if let Some(ref existing) = existing_stats {
                     if let Some(existing_entry) = existing.feedstock_states.get(&feedstock_name) {
                         // Feedstock already exists - only update if converted to RecipeV1
-                        if existing_entry.recipe_type != RecipeType::RecipeV1
-                            && recipe_type == RecipeType::RecipeV1
-                        {
+                        if !existing_entry.recipe_type.is_v1() && recipe_type.is_v1() {
                             if verbose {
                                 println!(
                                     "🔄 CONVERTED: {} from {:?} to {:?}",
@@ -131,16 +323,43 @@ pub async fn collect_stats_from_node_attrs(
                     current_time.clone() // First run, use current timestamp
                 };
 
+                let existing_entry = existing_stats
+                    .as_ref()
+                    .and_then(|existing| existing.feedstock_states.get(&feedstock_name));
+
                 // Preserve existing attribution if present
-                let attribution = if let Some(ref existing) = existing_stats {
-                    existing
-                        .feedstock_states
-                        .get(&feedstock_name)
-                        .and_then(|e| e.attribution.clone())
-                } else {
-                    None
+                let attribution = existing_entry.and_then(|e| e.attribution.clone());
+
+                // Record feedstocks going back from Recipe v1 to meta.yaml
+                let revert = match existing_entry {
+                    Some(old) if old.recipe_type.is_v1() && !recipe_type.is_v1() => {
+                        if verbose {
+                            println!(
+                                "🔙 REVERTED: {} from {:?} to {:?}",
+                                feedstock_name, old.recipe_type, recipe_type
+                            );
+                        }
+                        Some(RevertInfo {
+                            converted_at: old
+                                .attribution
+                                .as_ref()
+                                .map(|a| a.date.clone())
+                                .unwrap_or_else(|| old.last_changed.clone()),
+                            reverted_at: current_time.clone(),
+                        })
+                    }
+                    Some(old) => old.revert.clone(),
+                    None => None,
                 };
 
+                // Keep the last known CI state until build health is checked again
+                let build_state = existing_entry
+                    .filter(|_| recipe_type.is_v1())
+                    .and_then(|e| e.build_state.clone());
+
+                // Checked once per conversion, the result doesn't change afterwards
+                let follow_up = existing_entry.and_then(|e| e.follow_up.clone());
+
                 // Look up download count for this feedstock
                 let downloads = download_counts.get(&feedstock_name).copied();
 
@@ -152,6 +371,15 @@ pub async fn collect_stats_from_node_attrs(
                         attribution,
                         downloads,
                         recipe_commit_cache: None,
+                        maintainers,
+                        summary,
+                        rattler_build_pin,
+                        schema_version,
+                        recipe_file: None,
+                        revert,
+                        recipe_features,
+                        build_state,
+                        follow_up,
                     },
                 );
                 processed += 1;
@@ -160,21 +388,42 @@ pub async fn collect_stats_from_node_attrs(
                     pb.println(format!("📊 Processed {} feedstocks...", processed));
                 }
             }
-            Err(_) => {
-                // Skip files that can't be parsed (might not be feedstock files)
-                continue;
-            }
+            Err(e) => skipped.failed.push((node, e.root_cause().to_string())),
         }
         pb.inc(1);
     }
 
     pb.finish_with_message("✅ Analysis complete!");
     println!("📈 Processed {} total feedstocks", processed);
+    for line in skipped.report(&ignore_list, verbose) {
+        println!("{}", line);
+    }
+    if skipped.is_regression(total_files) {
+        let message = format!(
+            "{} of {} node_attrs files failed to parse. Add entries that aren't feedstocks to {}",
+            skipped.failed.len(),
+            total_files,
+            ignore_path.display()
+        );
+        if !allow_schema_drift {
+            anyhow::bail!(
+                "{}, or re-run with --allow-schema-drift to collect anyway",
+                message
+            );
+        }
+        println!("⚠️  {}", message);
+    }
+    profiler.end_stage("parse_node_attrs");
 
-    // Calculate counts from the HashMap
+    // Flag Recipe v1 feedstocks whose recipe lives under a near-miss filename
+    let unchecked_recipe_files =
+        classify_non_standard_recipes(&mut feedstock_states, existing_stats.as_ref()).await;
+
+    // Calculate counts from the HashMap. Recipe v1 includes the non-standard filenames,
+    // which are counted again on their own.
     let recipe_v1_count = feedstock_states
         .values()
-        .filter(|entry| entry.recipe_type == RecipeType::RecipeV1)
+        .filter(|entry| entry.recipe_type.is_v1())
         .count() as u32;
     let meta_yaml_count = feedstock_states
         .values()
@@ -184,6 +433,10 @@ pub async fn collect_stats_from_node_attrs(
         .values()
         .filter(|entry| entry.recipe_type == RecipeType::Unknown)
         .count() as u32;
+    let non_standard_v1_count = feedstock_states
+        .values()
+        .filter(|entry| entry.recipe_type == RecipeType::NonStandardV1)
+        .count() as u32;
     let total_feedstocks = processed;
 
     println!(
@@ -192,17 +445,21 @@ pub async fn collect_stats_from_node_attrs(
     );
     println!("📄 Legacy (conda-build or other): {}", meta_yaml_count);
     println!("❓ Unknown/Other: {}", unknown_count);
+    println!(
+        "📛 Of which with a non-standard filename: {}",
+        non_standard_v1_count
+    );
 
     // Find newly converted feedstocks
     let newly_converted = if let Some(ref existing) = existing_stats {
         feedstock_states
             .iter()
             .filter(|(name, entry)| {
-                entry.recipe_type == RecipeType::RecipeV1
+                entry.recipe_type.is_v1()
                     && existing
                         .feedstock_states
                         .get(*name)
-                        .is_none_or(|old_entry| old_entry.recipe_type != RecipeType::RecipeV1)
+                        .is_none_or(|old_entry| !old_entry.recipe_type.is_v1())
             })
             .map(|(name, _)| name.clone())
             .collect::<Vec<_>>()
@@ -219,21 +476,711 @@ pub async fn collect_stats_from_node_attrs(
         }
     }
 
+    // Announced by the conversion webhooks after attribution, which may be a later run
+    let mut unannounced_conversions = existing_stats
+        .as_ref()
+        .map(|s| s.unannounced_conversions.clone())
+        .unwrap_or_default();
+    for name in newly_converted {
+        unannounced_conversions.entry(name).or_default();
+    }
+    unannounced_conversions.retain(|name, _| {
+        feedstock_states
+            .get(name)
+            .is_some_and(|entry| entry.recipe_type.is_v1())
+    });
+
     // Calculate top unconverted feedstocks by downloads
-    let top_unconverted =
+    let mut top_unconverted =
         calculate_top_unconverted_feedstocks(&feedstock_states, &download_counts, 50);
     println!(
         "🏆 Found {} top unconverted feedstocks by downloads",
         top_unconverted.len()
     );
 
+    // Flag unconverted feedstocks whose dependencies are all on Recipe v1 already
+    let blockers =
+        calculate_dependency_blockers(&feedstock_states, &dependencies, &output_feedstocks);
+    let ready_count = blockers.values().filter(|b| b.is_empty()).count();
+    println!(
+        "🧩 {} of {} unconverted feedstocks with known dependencies are ready to convert",
+        ready_count,
+        blockers.len()
+    );
+    for feedstock in &mut top_unconverted {
+        feedstock.blocked_by = blockers.get(&feedstock.name).cloned();
+    }
+
+    // How much of the ecosystem's backbone is on Recipe v1
+    let dependency_weighted = calculate_dependency_weighted_progress(
+        &feedstock_states,
+        &dependencies,
+        &output_feedstocks,
+    );
+    println!(
+        "🕸️  {:.1}% converted when weighted by dependents",
+        dependency_weighted.converted as f64 * 100.0 / dependency_weighted.total.max(1) as f64
+    );
+
+    // Tell actively maintained feedstocks apart from dormant ones
+    add_push_activity(&mut top_unconverted, existing_stats.as_ref()).await;
+
+    // Experimental: which unconverted feedstocks are likely to convert next
+    let conversion_forecast = forecast_conversions(
+        &feedstock_states,
+        &dependencies,
+        &output_feedstocks,
+        existing_stats.as_ref(),
+    )
+    .await;
+    println!(
+        "🔮 {} of the previously forecast feedstocks converted since",
+        conversion_forecast.converted_since.len()
+    );
+
+    // Flag top feedstocks that converted or went back to meta.yaml
+    let top_feedstock_alerts = existing_stats
+        .as_ref()
+        .map(|existing| {
+            calculate_top_feedstock_alerts(
+                &existing.feedstock_states,
+                &feedstock_states,
+                TOP_FEEDSTOCK_ALERT_LIMIT,
+            )
+        })
+        .unwrap_or_default();
+    for alert in &top_feedstock_alerts {
+        match alert.kind {
+            AlertKind::Converted => println!(
+                "🥳 Top-{} feedstock #{} {} converted to Recipe v1!",
+                TOP_FEEDSTOCK_ALERT_LIMIT, alert.rank, alert.name
+            ),
+            AlertKind::Unconverted => println!(
+                "🚨 Top-{} feedstock #{} {} is unconverted again, investigate!",
+                TOP_FEEDSTOCK_ALERT_LIMIT, alert.rank, alert.name
+            ),
+        }
+    }
+
+    // Look for conversions that needed a quick fix, once their window has passed
+    check_follow_up_fixes(&mut feedstock_states).await;
+
+    // Calculate migration quality metrics
+    let quality_metrics = calculate_quality_metrics(&feedstock_states);
+    println!(
+        "🔙 {} of {} conversions reverted within {} days",
+        quality_metrics.conversion_reverts_30d, quality_metrics.conversions, REVERT_WINDOW_DAYS
+    );
+    if quality_metrics.follow_up_checked > 0 {
+        println!(
+            "🩹 {} of {} checked conversions needed a follow-up fix within {} days ({:.1}%)",
+            quality_metrics.follow_up_fixes_7d,
+            quality_metrics.follow_up_checked,
+            FOLLOW_UP_WINDOW_DAYS,
+            quality_metrics.follow_up_fixes_7d as f64 * 100.0
+                / quality_metrics.follow_up_checked as f64
+        );
+    }
+
+    // Correlate maintainer counts with conversion status
+    let maintainer_conversion_rates = calculate_maintainer_conversion_rates(&feedstock_states);
+    let recipe_feature_rates = calculate_recipe_feature_rates(&feedstock_states);
+    for rate in &recipe_feature_rates {
+        println!(
+            "🧪 {}: {}/{} converted, vs {}/{} without",
+            rate.feature,
+            rate.converted,
+            rate.feedstocks,
+            rate.other_converted,
+            rate.other_feedstocks
+        );
+    }
+
+    // Whether feedstocks the bot rerenders often convert sooner
+    let bot_activity_rates = calculate_bot_activity_rates(&feedstock_states, &bot_prs, Utc::now());
+    for rate in &bot_activity_rates {
+        println!(
+            "🤖 {} bot PRs: {}/{} converted, {} recently",
+            rate.bot_prs, rate.converted, rate.feedstocks, rate.converted_recently
+        );
+    }
+
+    // Which rattler-build versions Recipe v1 feedstocks pin to
+    let rattler_build_adoption = calculate_rattler_build_adoption(&feedstock_states);
+    println!("🔧 rattler-build pins among Recipe v1 feedstocks:");
+    for pin in rattler_build_adoption.pins.iter().take(5) {
+        println!("   {}: {}", pin.version, pin.feedstocks);
+    }
+
+    // Cross-check how many of conda-forge's feedstocks cf-graph covers
+    let previous_coverage = existing_stats.as_ref().and_then(|s| s.coverage.as_ref());
+    let coverage = reconcile_feedstock_count(total_feedstocks, previous_coverage).await;
+
+    // Whether converted feedstocks still build on their default branch
+    let previous_build_health = existing_stats
+        .as_ref()
+        .and_then(|s| s.build_health.as_ref());
+    let build_health = check_build_health(&mut feedstock_states, previous_build_health).await;
+
+    // Conversions that only wait for a maintainer to merge the bot's PR
+    let previous_pending = existing_stats
+        .as_ref()
+        .map(|s| s.pending_migration_prs.as_slice())
+        .unwrap_or_default();
+    let pending_migration_prs =
+        find_pending_migration_prs(&feedstock_states, previous_pending).await;
+
+    // How many converted recipes pass the linter, when asked to run it
+    let recipe_lint = match lint_command {
+        Some(command) => {
+            lint_converted_recipes(&paths.recipe_store, &feedstock_states, command).await?
+        }
+        None => None,
+    }
+    .or_else(|| existing_stats.as_ref().and_then(|s| s.recipe_lint.clone()));
+
     Ok(FeedstockStats {
+        stats_format_version: STATS_FORMAT_VERSION,
         total_feedstocks,
         recipe_v1_count,
         meta_yaml_count,
         unknown_count,
+        non_standard_v1_count,
         last_updated: Utc::now().to_rfc3339(),
         feedstock_states,
         top_unconverted_by_downloads: top_unconverted,
+        quality_metrics,
+        maintainer_conversion_rates,
+        recipe_feature_rates,
+        bot_activity_rates,
+        coverage,
+        build_health,
+        pending_migration_prs,
+        recipe_lint,
+        dependency_weighted: Some(dependency_weighted),
+        rattler_build_adoption,
+        top_feedstock_alerts,
+        conversion_forecast: Some(conversion_forecast),
+        unannounced_conversions,
+        unchecked_recipe_files,
+        analyses: BTreeMap::new(),
+        memory_profile: Vec::new(),
     })
 }
+
+/// Reclassify Recipe v1 feedstocks that only have a near-miss recipe filename
+/// (e.g. `recipe.yml`) as `NonStandardV1`.
+/// Feedstocks that were already Recipe v1 with a standard filename keep the previous
+/// run's classification; new Recipe v1 feedstocks, the non-standard ones (to notice a
+/// rename) and those left unchecked by an earlier run are looked up on GitHub.
+/// Returns the feedstocks that couldn't be checked because GitHub was not available.
+async fn classify_non_standard_recipes(
+    feedstock_states: &mut BTreeMap<String, FeedstockEntry>,
+    existing_stats: Option<&FeedstockStats>,
+) -> Vec<String> {
+    let previously_unchecked: HashSet<&str> = existing_stats
+        .map(|existing| {
+            existing
+                .unchecked_recipe_files
+                .iter()
+                .map(String::as_str)
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut to_check = Vec::new();
+    for (name, entry) in feedstock_states.iter_mut() {
+        if !entry.recipe_type.is_v1() {
+            continue;
+        }
+        let previous = existing_stats.and_then(|existing| existing.feedstock_states.get(name));
+        match previous.map(|previous| &previous.recipe_type) {
+            Some(RecipeType::RecipeV1) if !previously_unchecked.contains(name.as_str()) => {}
+            Some(RecipeType::NonStandardV1) => {
+                // Until checked again, in case GitHub is not available
+                entry.recipe_type = RecipeType::NonStandardV1;
+                entry.recipe_file = previous.and_then(|previous| previous.recipe_file.clone());
+                to_check.push(name.clone());
+            }
+            _ => to_check.push(name.clone()),
+        }
+    }
+    if to_check.is_empty() {
+        return Vec::new();
+    }
+
+    let non_standard = match GitHubClient::new() {
+        Ok(client) => {
+            println!(
+                "🔎 Checking recipe filenames of {} new or non-standard Recipe v1 feedstocks...",
+                to_check.len()
+            );
+            match client.batch_find_non_standard_recipes(&to_check).await {
+                Ok(found) => found,
+                Err(e) => {
+                    println!("⚠️  Could not check recipe filenames: {}", e);
+                    return to_check;
+                }
+            }
+        }
+        Err(_) => {
+            println!("⚠️  GitHub client not available, keeping previous recipe filename checks");
+            return to_check;
+        }
+    };
+
+    for name in &to_check {
+        if let Some(entry) = feedstock_states.get_mut(name) {
+            match non_standard.get(name) {
+                Some(recipe_file) => {
+                    entry.recipe_type = RecipeType::NonStandardV1;
+                    entry.recipe_file = Some(recipe_file.clone());
+                }
+                None => {
+                    entry.recipe_type = RecipeType::RecipeV1;
+                    entry.recipe_file = None;
+                }
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// Look up when each top unconverted feedstock was last pushed to. Keeps the previous
+/// run's values when GitHub is not available.
+async fn add_push_activity(
+    top_unconverted: &mut [TopFeedstock],
+    existing_stats: Option<&FeedstockStats>,
+) {
+    let names: Vec<String> = top_unconverted.iter().map(|f| f.name.clone()).collect();
+    let pushed_at = match GitHubClient::new() {
+        Ok(client) => match client.batch_fetch_pushed_at(&names).await {
+            Ok(pushed_at) => pushed_at,
+            Err(e) => {
+                println!("⚠️  Could not fetch feedstock push activity: {}", e);
+                previous_pushed_at(existing_stats)
+            }
+        },
+        Err(_) => {
+            println!("⚠️  GitHub client not available, keeping previous push activity");
+            previous_pushed_at(existing_stats)
+        }
+    };
+
+    for feedstock in top_unconverted {
+        feedstock.pushed_at = pushed_at.get(&feedstock.name).cloned();
+    }
+}
+
+/// Score unconverted feedstocks with `calculate_likely_conversions`, looking up push
+/// activity for the best candidates only. Uses the previous forecast's push dates when
+/// GitHub is not available.
+async fn forecast_conversions(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    dependencies: &HashMap<String, Vec<String>>,
+    output_feedstocks: &HashMap<String, String>,
+    existing_stats: Option<&FeedstockStats>,
+) -> ConversionForecast {
+    let previous = existing_stats.and_then(|stats| stats.conversion_forecast.as_ref());
+    let candidates: Vec<String> = calculate_likely_conversions(
+        feedstock_states,
+        dependencies,
+        output_feedstocks,
+        &HashMap::new(),
+        Utc::now(),
+    )
+    .into_iter()
+    .take(FORECAST_CANDIDATES)
+    .map(|likely| likely.name)
+    .collect();
+
+    let previous_pushed_at = || -> HashMap<String, String> {
+        previous
+            .into_iter()
+            .flat_map(|forecast| &forecast.likely)
+            .filter_map(|likely| Some((likely.name.clone(), likely.pushed_at.clone()?)))
+            .collect()
+    };
+    let pushed_at = match GitHubClient::new() {
+        Ok(client) => match client.batch_fetch_pushed_at(&candidates).await {
+            Ok(pushed_at) => pushed_at,
+            Err(e) => {
+                println!("⚠️  Could not fetch push activity for the forecast: {}", e);
+                previous_pushed_at()
+            }
+        },
+        Err(_) => previous_pushed_at(),
+    };
+
+    let likely = calculate_likely_conversions(
+        feedstock_states,
+        dependencies,
+        output_feedstocks,
+        &pushed_at,
+        Utc::now(),
+    )
+    .into_iter()
+    .filter(|likely| candidates.contains(&likely.name))
+    .take(FORECAST_LIMIT)
+    .collect();
+
+    let converted_since = previous
+        .into_iter()
+        .flat_map(|forecast| &forecast.likely)
+        .filter(|likely| {
+            feedstock_states
+                .get(&likely.name)
+                .is_some_and(|entry| entry.recipe_type.is_v1())
+        })
+        .map(|likely| likely.name.clone())
+        .collect();
+
+    ConversionForecast {
+        likely,
+        converted_since,
+    }
+}
+
+fn previous_pushed_at(existing_stats: Option<&FeedstockStats>) -> HashMap<String, String> {
+    existing_stats
+        .into_iter()
+        .flat_map(|stats| &stats.top_unconverted_by_downloads)
+        .filter_map(|f| Some((f.name.clone(), f.pushed_at.clone()?)))
+        .collect()
+}
+
+/// Fetch the latest CI state of every converted feedstock's default branch; keeps the
+/// previous value when GitHub is not available.
+async fn check_build_health(
+    feedstock_states: &mut BTreeMap<String, FeedstockEntry>,
+    previous: Option<&BuildHealth>,
+) -> Option<BuildHealth> {
+    let converted: Vec<String> = feedstock_states
+        .iter()
+        .filter(|(_, entry)| entry.recipe_type.is_v1())
+        .map(|(name, _)| name.clone())
+        .collect();
+    let states = match GitHubClient::new() {
+        Ok(client) => match client.batch_fetch_build_states(&converted).await {
+            Ok(states) => states,
+            Err(e) => {
+                println!("⚠️  Could not fetch build status: {}", e);
+                return previous.cloned();
+            }
+        },
+        Err(_) => {
+            println!("⚠️  GitHub client not available, keeping previous build status");
+            return previous.cloned();
+        }
+    };
+
+    for (name, entry) in feedstock_states.iter_mut() {
+        if let Some(state) = states.get(name) {
+            entry.build_state = Some(state.clone());
+        }
+    }
+
+    let health = summarize_build_states(states.values().map(String::as_str));
+    if health.checked > 0 {
+        println!(
+            "🏗️  {} of {} Recipe v1 feedstocks build green ({:.1}%), {} failing, {} pending",
+            health.passing,
+            health.checked,
+            health.passing as f64 * 100.0 / health.checked as f64,
+            health.failing,
+            health.pending
+        );
+    }
+    Some(health)
+}
+
+/// Check conversions whose follow-up window has passed for a PR fixing the recipe; those
+/// that can't be checked are tried again on the next run.
+async fn check_follow_up_fixes(feedstock_states: &mut BTreeMap<String, FeedstockEntry>) {
+    let candidates = follow_up_candidates(feedstock_states, Utc::now());
+    if candidates.is_empty() {
+        return;
+    }
+    let conversions: Vec<(&str, &str, &str)> = candidates
+        .iter()
+        .map(|(name, since, until)| (name.as_str(), since.as_str(), until.as_str()))
+        .collect();
+    let fixes = match GitHubClient::new() {
+        Ok(client) => match client.batch_find_follow_up_fixes(&conversions).await {
+            Ok(fixes) => fixes,
+            Err(e) => {
+                println!("⚠️  Could not check for follow-up fixes: {}", e);
+                return;
+            }
+        },
+        Err(_) => {
+            println!("⚠️  GitHub client not available, skipping follow-up fix check");
+            return;
+        }
+    };
+
+    for (name, since, _) in &candidates {
+        if let (Some(fix_pr), Some(entry)) = (fixes.get(name), feedstock_states.get_mut(name)) {
+            // Keep the attribution date as is, so the check matches it exactly
+            let converted_at = entry
+                .attribution
+                .as_ref()
+                .map_or_else(|| since.clone(), |a| a.date.clone());
+            entry.follow_up = Some(FollowUpCheck {
+                converted_at,
+                fix_pr: *fix_pr,
+            });
+        }
+    }
+}
+
+/// Find the open bot migration PRs of feedstocks that aren't converted yet; keeps the
+/// previous list when GitHub is not available.
+async fn find_pending_migration_prs(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    previous: &[PendingMigrationPr],
+) -> Vec<PendingMigrationPr> {
+    let prs = match GitHubClient::new() {
+        Ok(client) => match client.search_open_migration_prs().await {
+            Ok(prs) => prs,
+            Err(e) => {
+                println!("⚠️  Could not search migration PRs: {}", e);
+                return previous.to_vec();
+            }
+        },
+        Err(_) => {
+            println!("⚠️  GitHub client not available, keeping previous migration PRs");
+            return previous.to_vec();
+        }
+    };
+
+    let mut pending: Vec<PendingMigrationPr> = prs
+        .into_iter()
+        .filter(|pr| {
+            feedstock_states
+                .get(&pr.repository)
+                .is_some_and(|entry| !entry.recipe_type.is_v1())
+        })
+        .map(|pr| PendingMigrationPr {
+            feedstock: pr.repository,
+            number: pr.number,
+            opened_at: pr.created_at,
+        })
+        .collect();
+    pending.sort_by(|a, b| a.feedstock.cmp(&b.feedstock).then(a.number.cmp(&b.number)));
+    println!(
+        "⏳ {} unconverted feedstocks have a migration PR waiting to be merged",
+        pending.len()
+    );
+    pending
+}
+
+/// Lint the recipe of every Recipe v1 feedstock with `command`, as found on its default
+/// branch. Recipes that can't be downloaded or linted are left out of the summary, and
+/// None is returned if no recipe could be linted at all.
+async fn lint_converted_recipes(
+    recipe_store: &Path,
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    command: &str,
+) -> Result<Option<RecipeLint>> {
+    // Paths to try for each feedstock, in order
+    let mut candidates: Vec<(&str, Vec<&str>)> = feedstock_states
+        .iter()
+        .filter(|(_, entry)| entry.recipe_type.is_v1())
+        .map(|(name, entry)| {
+            let paths = match entry.recipe_file.as_deref() {
+                Some(file) => vec![file],
+                None => vec!["recipe/recipe.yaml", "recipe.yaml"],
+            };
+            (name.as_str(), paths)
+        })
+        .collect();
+
+    let mut store = RecipeStore::open(recipe_store)?;
+    let mut recipes = Vec::new();
+    let (mut missing, mut fetch_errors) = (0, 0);
+    while !candidates.is_empty() {
+        let urls: Vec<String> = candidates
+            .iter()
+            .map(|(name, paths)| {
+                format!(
+                    "https://raw.githubusercontent.com/conda-forge/{}/HEAD/{}",
+                    name, paths[0]
+                )
+            })
+            .collect();
+        let results = store.fetch_many(&urls, CONCURRENT_LINTS).await;
+        let mut not_found = Vec::new();
+        for ((name, mut paths), result) in candidates.into_iter().zip(results) {
+            match result {
+                Ok(Some(recipe)) => recipes.push(recipe),
+                Ok(None) if paths.len() > 1 => {
+                    paths.remove(0);
+                    not_found.push((name, paths));
+                }
+                Ok(None) => missing += 1,
+                Err(_) => fetch_errors += 1,
+            }
+        }
+        candidates = not_found;
+    }
+    store.save()?;
+    if missing + fetch_errors > 0 {
+        println!(
+            "⚠️  Could not download {} recipes to lint ({} not found, {} failed)",
+            missing + fetch_errors,
+            missing,
+            fetch_errors
+        );
+    }
+
+    let results: Vec<Result<bool>> = futures::stream::iter(&recipes)
+        .map(|recipe| lint_recipe(command, recipe))
+        .buffer_unordered(CONCURRENT_LINTS)
+        .collect()
+        .await;
+
+    let mut lint = RecipeLint::default();
+    let mut errors = 0;
+    for result in results {
+        match result {
+            Ok(true) => lint.passing += 1,
+            Ok(false) => lint.failing += 1,
+            Err(_) => errors += 1,
+        }
+    }
+    lint.checked = lint.passing + lint.failing;
+    println!(
+        "🧹 {} of {} converted recipes pass lint, {} could not be linted",
+        lint.passing, lint.checked, errors
+    );
+    Ok((lint.checked > 0).then_some(lint))
+}
+
+/// Compare the analyzed feedstock count against the conda-forge GitHub organization.
+/// Warns when coverage dropped since the previous run; keeps the previous value when
+/// GitHub is not available.
+async fn reconcile_feedstock_count(
+    total_feedstocks: u32,
+    previous: Option<&FeedstockCoverage>,
+) -> Option<FeedstockCoverage> {
+    let reference_count = match GitHubClient::new() {
+        Ok(client) => match client.count_org_feedstocks().await {
+            Ok(count) => count,
+            Err(e) => {
+                println!("⚠️  Could not count conda-forge feedstocks: {}", e);
+                return previous.cloned();
+            }
+        },
+        Err(_) => {
+            println!("⚠️  GitHub client not available, skipping feedstock count reconciliation");
+            return previous.cloned();
+        }
+    };
+
+    let coverage = calculate_coverage(total_feedstocks, reference_count);
+    println!(
+        "🧮 cf-graph covers {} of {} conda-forge feedstocks ({:.1}%)",
+        total_feedstocks,
+        reference_count,
+        coverage.coverage * 100.0
+    );
+    if let Some(previous) = previous {
+        if coverage_dropped(previous, &coverage) {
+            println!(
+                "⚠️  Coverage dropped from {:.1}% to {:.1}%, cf-graph may be missing feedstocks",
+                previous.coverage * 100.0,
+                coverage.coverage * 100.0
+            );
+        }
+    }
+
+    Some(coverage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Attribution, ContributionType};
+
+    fn stats() -> FeedstockStats {
+        toml::from_str(
+            r#"
+            total_feedstocks = 2
+            recipe_v1_count = 1
+            meta_yaml_count = 1
+            unknown_count = 0
+            last_updated = "2025-01-01T00:00:00+00:00"
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn entry(recipe_type: RecipeType) -> FeedstockEntry {
+        FeedstockEntry {
+            attribution: Some(Attribution {
+                contribution_type: ContributionType::Conversion,
+                contributors: vec!["alice".to_string()],
+                date: "2025-01-01T00:00:00Z".to_string(),
+                commit_sha: None,
+            }),
+            downloads: Some(42),
+            maintainers: vec!["alice".to_string()],
+            ..FeedstockEntry::test(recipe_type)
+        }
+    }
+
+    #[test]
+    fn test_write_stats_round_trip() {
+        let states = BTreeMap::from([
+            ("foo-feedstock".to_string(), entry(RecipeType::RecipeV1)),
+            // Keys that need quoting in a table header
+            ("bar.baz-feedstock".to_string(), entry(RecipeType::MetaYaml)),
+        ]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("feedstock-stats.toml");
+        let path = path.to_str().unwrap();
+        write_stats(path, &stats(), &states).unwrap();
+
+        let loaded = load_existing_stats(path).unwrap();
+        assert_eq!(loaded.total_feedstocks, 2);
+        assert_eq!(loaded.feedstock_states.len(), 2);
+        let bar = &loaded.feedstock_states["bar.baz-feedstock"];
+        assert_eq!(bar.recipe_type, RecipeType::MetaYaml);
+        assert_eq!(
+            bar.attribution.as_ref().unwrap().contributors,
+            vec!["alice"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_classify_non_standard_recipes_uses_previous_run() {
+        let mut existing = stats();
+        existing.feedstock_states = BTreeMap::from([
+            ("numpy-feedstock".to_string(), entry(RecipeType::RecipeV1)),
+            ("scipy-feedstock".to_string(), entry(RecipeType::MetaYaml)),
+        ]);
+        let mut states = BTreeMap::from([
+            ("numpy-feedstock".to_string(), entry(RecipeType::RecipeV1)),
+            ("scipy-feedstock".to_string(), entry(RecipeType::MetaYaml)),
+        ]);
+
+        // Nothing new on Recipe v1, so GitHub isn't asked
+        let unchecked = classify_non_standard_recipes(&mut states, Some(&existing)).await;
+        assert!(unchecked.is_empty());
+        assert_eq!(states["numpy-feedstock"].recipe_type, RecipeType::RecipeV1);
+    }
+
+    #[test]
+    fn test_write_stats_rejects_inline_states() {
+        let mut stats = stats();
+        stats
+            .feedstock_states
+            .insert("foo-feedstock".to_string(), entry(RecipeType::RecipeV1));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("feedstock-stats.toml");
+        assert!(write_stats(path.to_str().unwrap(), &stats, &BTreeMap::new()).is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,114 @@
+//! Flat exports of `feedstock_states` for spreadsheets and other analysis tools: a single
+//! CSV file, or Parquet tables for DuckDB, pandas and the like (see `parquet_export`)
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::io::Write;
+
+use crate::models::{ContributionType, FeedstockStats, RecipeType};
+
+/// Separator between logins in the `contributors` column
+const CONTRIBUTOR_SEPARATOR: &str = ";";
+
+/// One CSV row per feedstock
+#[derive(Debug, Serialize)]
+pub struct FeedstockRow<'a> {
+    pub name: &'a str,
+    pub recipe_type: &'a RecipeType,
+    pub last_changed: &'a str,
+    pub downloads: Option<u64>,
+    /// Logins credited with the Recipe v1 recipe, `;`-separated
+    pub contributors: String,
+    pub contribution_type: Option<&'a ContributionType>,
+}
+
+/// Rows of all feedstocks, sorted by name
+pub fn feedstock_rows(stats: &FeedstockStats) -> Vec<FeedstockRow<'_>> {
+    stats
+        .feedstock_states
+        .iter()
+        .map(|(name, entry)| {
+            let attribution = entry.attribution.as_ref();
+            FeedstockRow {
+                name,
+                recipe_type: &entry.recipe_type,
+                last_changed: &entry.last_changed,
+                downloads: entry.downloads,
+                contributors: attribution
+                    .map(|a| a.contributors.join(CONTRIBUTOR_SEPARATOR))
+                    .unwrap_or_default(),
+                contribution_type: attribution.map(|a| &a.contribution_type),
+            }
+        })
+        .collect()
+}
+
+/// Write the feedstock rows as CSV with a header line, returning the number of rows
+pub fn write_feedstock_csv(stats: &FeedstockStats, writer: impl Write) -> Result<usize> {
+    let rows = feedstock_rows(stats);
+    let mut csv = csv::Writer::from_writer(writer);
+    for row in &rows {
+        csv.serialize(row)
+            .with_context(|| format!("Failed to write the row of {}", row.name))?;
+    }
+    csv.flush()?;
+    Ok(rows.len())
+}
+
+/// The name `value` serializes to, e.g. "recipe_v1" for `RecipeType::RecipeV1`
+pub fn serde_name(value: &impl Serialize) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn stats() -> FeedstockStats {
+        toml::from_str(
+            r#"
+            total_feedstocks = 2
+            recipe_v1_count = 1
+            meta_yaml_count = 1
+            unknown_count = 0
+            last_updated = "2025-06-01T00:00:00Z"
+
+            [feedstock_states.scipy-feedstock]
+            recipe_type = "meta_yaml"
+            last_changed = "2025-01-01T00:00:00Z"
+
+            [feedstock_states.numpy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-05-01T00:00:00Z"
+            downloads = 1000
+
+            [feedstock_states.numpy-feedstock.attribution]
+            contribution_type = "conversion"
+            contributors = ["alice", "bob"]
+            date = "2025-05-01T00:00:00Z"
+
+            [[top_unconverted_by_downloads]]
+            name = "scipy-feedstock"
+            downloads = 500
+            recipe_type = "meta_yaml"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_feedstock_csv() {
+        let stats = stats();
+        let mut output = Vec::new();
+        assert_eq!(write_feedstock_csv(&stats, &mut output).unwrap(), 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "name,recipe_type,last_changed,downloads,contributors,contribution_type\n\
+             numpy-feedstock,recipe_v1,2025-05-01T00:00:00Z,1000,alice;bob,conversion\n\
+             scipy-feedstock,meta_yaml,2025-01-01T00:00:00Z,,,\n"
+        );
+    }
+}
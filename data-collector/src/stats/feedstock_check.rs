@@ -0,0 +1,146 @@
+//! `check-feedstock`: run in a feedstock's own CI to nudge its maintainers towards
+//! Recipe v1 with a GitHub Actions annotation, quoting the migration's progress from
+//! the site's `status.json`.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+use crate::external::{CANONICAL_RECIPE_FILES, NON_STANDARD_RECIPE_FILES};
+use crate::models::RecipeType;
+
+/// conda-smithy looks for a conda-build recipe here
+const META_YAML_FILES: &[&str] = &["recipe/meta.yaml", "meta.yaml"];
+
+/// The parts of `status.json` (format versions 1 and 2) quoted in the annotation
+#[derive(Debug, Deserialize)]
+pub struct SiteStatus {
+    pub version: u32,
+    pub percentage: f64,
+    pub counts: SiteCounts,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SiteCounts {
+    pub total: i64,
+    pub recipe_v1: i64,
+}
+
+/// Read `status.json` from a path or an http(s) URL
+pub async fn load_site_status(source: &str) -> Result<SiteStatus> {
+    let content = if source.starts_with("http://") || source.starts_with("https://") {
+        reqwest::get(source)
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Failed to fetch {}", source))?
+            .text()
+            .await?
+    } else {
+        fs::read_to_string(source).with_context(|| format!("Failed to read {}", source))?
+    };
+    let status: SiteStatus =
+        serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", source))?;
+    anyhow::ensure!(
+        matches!(status.version, 1 | 2),
+        "Unsupported status.json version {}",
+        status.version
+    );
+    Ok(status)
+}
+
+/// Recipe type of the feedstock checked out at `feedstock_dir`, with the recipe file
+/// that decided it
+pub fn local_recipe_type(feedstock_dir: &Path) -> (RecipeType, Option<&'static str>) {
+    let find = |files: &[&'static str]| {
+        files
+            .iter()
+            .copied()
+            .find(|file| feedstock_dir.join(file).is_file())
+    };
+    if let Some(file) = find(CANONICAL_RECIPE_FILES) {
+        (RecipeType::RecipeV1, Some(file))
+    } else if let Some(file) = find(NON_STANDARD_RECIPE_FILES) {
+        (RecipeType::NonStandardV1, Some(file))
+    } else if let Some(file) = find(META_YAML_FILES) {
+        (RecipeType::MetaYaml, Some(file))
+    } else {
+        (RecipeType::Unknown, None)
+    }
+}
+
+/// The GitHub Actions workflow command to print for this feedstock, None if it is on
+/// Recipe v1 already
+pub fn check_annotation(
+    recipe_type: &RecipeType,
+    recipe_file: Option<&str>,
+    status: Option<&SiteStatus>,
+) -> Option<String> {
+    let file = recipe_file
+        .map(|file| format!("file={},", file))
+        .unwrap_or_default();
+    match recipe_type {
+        RecipeType::RecipeV1 => None,
+        RecipeType::NonStandardV1 => Some(format!(
+            "::warning {}title=Non-standard Recipe v1 filename::conda-smithy only picks up \
+             recipe.yaml; rename {} to recipe/recipe.yaml",
+            file,
+            recipe_file.unwrap_or_default()
+        )),
+        RecipeType::MetaYaml => {
+            let progress = status
+                .map(|status| {
+                    format!(
+                        " {} of {} conda-forge feedstocks ({:.1}%) have moved already.",
+                        status.counts.recipe_v1, status.counts.total, status.percentage
+                    )
+                })
+                .unwrap_or_default();
+            Some(format!(
+                "::warning {}title=Still on meta.yaml::This feedstock still uses a conda-build \
+                 meta.yaml recipe.{} Convert it to Recipe v1 (recipe.yaml) for rattler-build, \
+                 see https://rattler.build/latest/converting_from_conda_build/",
+                file, progress
+            ))
+        }
+        RecipeType::Unknown => Some(
+            "::notice title=No recipe found::Neither recipe.yaml nor meta.yaml was found"
+                .to_string(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_feedstock() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(local_recipe_type(dir.path()), (RecipeType::Unknown, None));
+
+        fs::create_dir(dir.path().join("recipe")).unwrap();
+        fs::write(dir.path().join("recipe/meta.yaml"), "").unwrap();
+        let (recipe_type, file) = local_recipe_type(dir.path());
+        assert_eq!(recipe_type, RecipeType::MetaYaml);
+
+        let status: SiteStatus = serde_json::from_str(
+            r#"{"version": 1, "last_updated": "2025-06-01T00:00:00Z", "percentage": 33.3,
+                "counts": {"total": 300, "recipe_v1": 100, "meta_yaml": 200}, "delta_7d": null}"#,
+        )
+        .unwrap();
+        let annotation = check_annotation(&recipe_type, file, Some(&status)).unwrap();
+        assert!(
+            annotation.starts_with("::warning file=recipe/meta.yaml,title=Still on meta.yaml::")
+        );
+        assert!(annotation.contains("100 of 300 conda-forge feedstocks (33.3%)"));
+
+        fs::write(dir.path().join("recipe/recipe.yaml"), "").unwrap();
+        let (recipe_type, file) = local_recipe_type(dir.path());
+        assert_eq!(
+            (&recipe_type, file),
+            (&RecipeType::RecipeV1, Some("recipe/recipe.yaml"))
+        );
+        assert_eq!(check_annotation(&recipe_type, file, Some(&status)), None);
+    }
+}
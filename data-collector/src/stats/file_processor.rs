@@ -1,8 +1,9 @@
 use anyhow::{Context, Result};
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::Path;
 
-use crate::models::{NodeAttrsJson, RecipeType};
+use crate::models::{NodeAttrsJson, PrInfoJson, RecipeType};
 
 /// Parses a JSON file containing node attributes into a `NodeAttrsJson` struct.
 pub fn parse_node_attrs_file(path: &Path) -> Result<NodeAttrsJson> {
@@ -15,6 +16,194 @@ pub fn parse_node_attrs_file(path: &Path) -> Result<NodeAttrsJson> {
     Ok(node_data)
 }
 
+/// Number of pull requests the autotick bot opened on a feedstock, from its cf-graph
+/// `pr_info` file. None if the file is missing or doesn't parse.
+pub fn read_bot_pr_count(path: &Path) -> Option<u32> {
+    let content = fs::read_to_string(path).ok()?;
+    let pr_info: PrInfoJson = serde_json::from_str(&content).ok()?;
+    Some(pr_info.pred.len() as u32)
+}
+
+/// Maximum length of a package summary kept in the stats
+const MAX_SUMMARY_LENGTH: usize = 200;
+
+/// Extracts the short package description (`about.summary`) from the node data.
+/// Whitespace is collapsed and overly long summaries are truncated.
+pub fn extract_package_summary(node_data: &NodeAttrsJson) -> Option<String> {
+    let summary = node_data
+        .meta_yaml
+        .as_ref()?
+        .get("about")?
+        .get("summary")?
+        .as_str()?;
+
+    let summary = summary.split_whitespace().collect::<Vec<_>>().join(" ");
+    if summary.is_empty() {
+        return None;
+    }
+
+    if summary.chars().count() > MAX_SUMMARY_LENGTH {
+        let truncated: String = summary.chars().take(MAX_SUMMARY_LENGTH - 1).collect();
+        Some(format!("{}…", truncated.trim_end()))
+    } else {
+        Some(summary)
+    }
+}
+
+/// Extracts the recipe maintainers (`extra.recipe-maintainers`) from the node data.
+pub fn extract_recipe_maintainers(node_data: &NodeAttrsJson) -> Vec<String> {
+    node_data
+        .meta_yaml
+        .as_ref()
+        .and_then(|meta| meta.get("extra"))
+        .and_then(|extra| extra.get("recipe-maintainers"))
+        .and_then(|maintainers| maintainers.as_array())
+        .map(|maintainers| {
+            maintainers
+                .iter()
+                .filter_map(|m| m.as_str())
+                .map(|m| m.trim().to_string())
+                .filter(|m| !m.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Extracts the names of the build, host and run requirements of the recipe and its
+/// outputs, sorted and without duplicates.
+pub fn extract_dependencies(node_data: &NodeAttrsJson) -> Vec<String> {
+    let Some(meta) = node_data.meta_yaml.as_ref() else {
+        return Vec::new();
+    };
+    let outputs = meta.get("outputs").and_then(|o| o.as_array());
+    let requirements = std::iter::once(meta)
+        .chain(outputs.into_iter().flatten())
+        .filter_map(|recipe| recipe.get("requirements"));
+
+    let mut dependencies = BTreeSet::new();
+    for requirements in requirements {
+        // Outputs may list their run requirements directly
+        let specs = match requirements.as_array() {
+            Some(specs) => vec![specs],
+            None => ["build", "host", "run"]
+                .iter()
+                .filter_map(|section| requirements.get(section)?.as_array())
+                .collect(),
+        };
+        for spec in specs.into_iter().flatten().filter_map(|s| s.as_str()) {
+            if let Some(name) = spec_name(spec) {
+                dependencies.insert(name.to_string());
+            }
+        }
+    }
+    dependencies.into_iter().collect()
+}
+
+/// Package name of a match spec, e.g. "numpy" for "numpy >=1.21" or "numpy>=1.21"
+fn spec_name(spec: &str) -> Option<&str> {
+    spec.trim()
+        .split(|c: char| c.is_whitespace() || "<>=!~".contains(c))
+        .next()
+        .filter(|name| !name.is_empty())
+}
+
+/// Extracts the names of the packages the feedstock builds
+pub fn extract_output_names(node_data: &NodeAttrsJson) -> Vec<String> {
+    node_data
+        .outputs_names
+        .as_ref()
+        .map(|outputs| outputs.elements.clone())
+        .unwrap_or_default()
+}
+
+/// Recipe features that may make a conversion harder. Selectors and Jinja statements
+/// are only visible in the raw recipe, the rest is read from the rendered one.
+pub fn extract_recipe_features(node_data: &NodeAttrsJson) -> Vec<String> {
+    let mut features = Vec::new();
+    let mut add = |feature: &str, present: bool| {
+        if present {
+            features.push(feature.to_string());
+        }
+    };
+
+    add(
+        "multiple-outputs",
+        extract_output_names(node_data).len() > 1,
+    );
+
+    let raw = node_data.raw_meta_yaml.as_deref().unwrap_or_default();
+    add(
+        "selectors",
+        raw.contains("# [")
+            || raw
+                .lines()
+                .any(|line| line.trim_start().starts_with("- if:")),
+    );
+    add("jinja-statements", raw.contains("{%"));
+
+    let meta = node_data.meta_yaml.as_ref();
+    let outputs = meta
+        .and_then(|m| m.get("outputs"))
+        .and_then(|o| o.as_array());
+    let recipes = meta.into_iter().chain(outputs.into_iter().flatten());
+    add(
+        "inline-script",
+        recipes.clone().any(|recipe| {
+            recipe.get("script").is_some()
+                || recipe.get("build").and_then(|b| b.get("script")).is_some()
+        }),
+    );
+    add(
+        "noarch",
+        recipes
+            .clone()
+            .any(|recipe| recipe.get("build").and_then(|b| b.get("noarch")).is_some()),
+    );
+    add(
+        "compiled",
+        extract_dependencies(node_data)
+            .iter()
+            .any(|name| name.ends_with("_compiler_stub")),
+    );
+
+    features
+}
+
+/// Extracts the rattler-build version constraint from `conda_build_tool_deps`
+/// (e.g. "rattler-build>=0.30" -> ">=0.30"). None if rattler-build isn't pinned.
+pub fn extract_rattler_build_pin(node_data: &NodeAttrsJson) -> Option<String> {
+    let deps = node_data
+        .conda_forge_yml
+        .as_ref()?
+        .conda_build_tool_deps
+        .as_ref()?;
+    // Specs are separated by whitespace, which may also separate a name from its version
+    let mut specs = deps.split_whitespace().peekable();
+    while let Some(spec) = specs.next() {
+        if spec_name(spec) != Some("rattler-build") {
+            continue;
+        }
+        let constraint = &spec["rattler-build".len()..];
+        if !constraint.is_empty() {
+            return Some(constraint.to_string());
+        }
+        return specs
+            .next_if(|next| next.starts_with(|c: char| c.is_ascii_digit() || "<>=!~".contains(c)))
+            .map(String::from);
+    }
+    None
+}
+
+/// Extracts the recipe `schema_version` from the rendered recipe, if present.
+pub fn extract_schema_version(node_data: &NodeAttrsJson) -> Option<u32> {
+    node_data
+        .meta_yaml
+        .as_ref()?
+        .get("schema_version")?
+        .as_u64()
+        .map(|v| v as u32)
+}
+
 /// Determines the recipe type based on the `conda_build_tool` field in the node data.
 pub fn determine_recipe_type_from_node(node_data: &NodeAttrsJson) -> RecipeType {
     // Check if conda_build_tool is set to rattler-build in conda-forge.yml
@@ -29,3 +218,79 @@ pub fn determine_recipe_type_from_node(node_data: &NodeAttrsJson) -> RecipeType
     // If no rattler-build conda_build_tool found, it's using conda-build (legacy)
     RecipeType::MetaYaml
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(json: serde_json::Value) -> NodeAttrsJson {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_extract_package_summary() {
+        let summary = |summary: serde_json::Value| {
+            extract_package_summary(&node(serde_json::json!({
+                "feedstock_name": "example",
+                "meta_yaml": {"about": {"summary": summary}}
+            })))
+        };
+        assert_eq!(
+            summary("  A fast\n\tarray   library ".into()).as_deref(),
+            Some("A fast array library")
+        );
+        assert_eq!(summary(" \n ".into()), None);
+        assert_eq!(summary(42.into()), None);
+
+        let long = summary("word ".repeat(100).into()).unwrap();
+        assert_eq!(long.chars().count(), MAX_SUMMARY_LENGTH);
+        assert!(long.ends_with("word…"));
+
+        let no_about = node(serde_json::json!({"feedstock_name": "example", "meta_yaml": {}}));
+        assert_eq!(extract_package_summary(&no_about), None);
+        let no_meta = node(serde_json::json!({"feedstock_name": "example"}));
+        assert_eq!(extract_package_summary(&no_meta), None);
+    }
+
+    #[test]
+    fn test_extract_dependencies() {
+        let node_data = node(serde_json::json!({
+            "feedstock_name": "example",
+            "meta_yaml": {
+                "requirements": {
+                    "build": ["cmake>=3.20"],
+                    "host": ["python", "numpy >=1.21", "pip"],
+                    "run": ["python", "numpy>=1.21,<3", "scipy!=1.10.0", "attrs~=23.1"]
+                },
+                "outputs": [
+                    {"requirements": ["libfoo ==1.0"]},
+                    {"requirements": {"run": ["  pandas  "]}}
+                ]
+            }
+        }));
+        assert_eq!(
+            extract_dependencies(&node_data),
+            ["attrs", "cmake", "libfoo", "numpy", "pandas", "pip", "python", "scipy"]
+        );
+        assert!(extract_dependencies(&node(serde_json::json!({"feedstock_name": "x"}))).is_empty());
+    }
+
+    #[test]
+    fn test_extract_rattler_build_pin() {
+        let pin = |deps: &str| {
+            extract_rattler_build_pin(&node(serde_json::json!({
+                "feedstock_name": "example",
+                "conda-forge.yml": {"conda_build_tool_deps": deps}
+            })))
+        };
+        assert_eq!(pin("rattler-build>=0.30").as_deref(), Some(">=0.30"));
+        assert_eq!(
+            pin("git rattler-build ==0.35.1").as_deref(),
+            Some("==0.35.1")
+        );
+        assert_eq!(pin("rattler-build 0.35 git").as_deref(), Some("0.35"));
+        assert_eq!(pin("rattler-build git"), None);
+        assert_eq!(pin("rattler-build-conda-compat>=1.2"), None);
+        assert_eq!(pin(""), None);
+    }
+}
@@ -0,0 +1,412 @@
+//! Dated snapshots of the headline counts, one per day, so migration progress can be
+//! plotted over time instead of only showing the latest counts. Days before the first
+//! recorded run can be backfilled from cf-graph's git history.
+
+use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use crate::git::{commit_at, node_attrs_blobs, read_blobs};
+use crate::models::{FeedstockStats, NodeAttrsJson, RecipeType};
+use crate::stats::{determine_recipe_type_from_node, node_name, NodeAttrsIgnoreList};
+
+/// Layout version of the history file. Version 0 files counted the non-standard recipe
+/// filenames of a run only in `non_standard_v1_count`, not in `recipe_v1_count`.
+pub const HISTORY_FORMAT_VERSION: u32 = 1;
+
+/// Counts of one run, keyed by the day it ran on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryPoint {
+    /// UTC date, `YYYY-MM-DD`
+    pub date: String,
+    pub total_feedstocks: u32,
+    /// Every Recipe v1 feedstock, including those with a non-standard recipe filename,
+    /// like `FeedstockStats::recipe_v1_count`
+    pub recipe_v1_count: u32,
+    /// The part of `recipe_v1_count` with a non-standard recipe filename. Unknown for
+    /// backfilled days, the filenames are only checked on GitHub by a run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub non_standard_v1_count: Option<u32>,
+    pub meta_yaml_count: u32,
+    pub unknown_count: u32,
+}
+
+impl HistoryPoint {
+    /// Snapshot of `stats`, dated by its `last_updated`
+    pub fn from_stats(stats: &FeedstockStats) -> Self {
+        Self {
+            date: stats.last_updated.chars().take(10).collect(),
+            total_feedstocks: stats.total_feedstocks,
+            recipe_v1_count: stats.recipe_v1_count,
+            non_standard_v1_count: Some(stats.non_standard_v1_count),
+            meta_yaml_count: stats.meta_yaml_count,
+            unknown_count: stats.unknown_count,
+        }
+    }
+}
+
+/// All snapshots, oldest first
+#[derive(Debug, Serialize, Deserialize)]
+pub struct History {
+    /// `HISTORY_FORMAT_VERSION` once loaded, 0 for files from before it existed
+    #[serde(default)]
+    pub format_version: u32,
+    #[serde(default)]
+    pub points: Vec<HistoryPoint>,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            format_version: HISTORY_FORMAT_VERSION,
+            points: Vec::new(),
+        }
+    }
+}
+
+impl History {
+    /// An empty history if the file doesn't exist. `.json` files are read as JSON,
+    /// anything else as TOML.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let history: Result<Self> = if is_json(path) {
+            serde_json::from_str(&content).map_err(anyhow::Error::from)
+        } else {
+            toml::from_str(&content).map_err(anyhow::Error::from)
+        };
+        let mut history = history.with_context(|| format!("Failed to parse {}", path.display()))?;
+        history.migrate()?;
+        Ok(history)
+    }
+
+    /// Bring points of older files to the current definitions, so recorded and
+    /// backfilled days line up
+    fn migrate(&mut self) -> Result<()> {
+        anyhow::ensure!(
+            self.format_version <= HISTORY_FORMAT_VERSION,
+            "History has format version {}, but this collector only knows up to {}; update it",
+            self.format_version,
+            HISTORY_FORMAT_VERSION
+        );
+        if self.format_version < 1 {
+            for point in &mut self.points {
+                point.recipe_v1_count += point.non_standard_v1_count.unwrap_or(0);
+            }
+        }
+        self.format_version = HISTORY_FORMAT_VERSION;
+        Ok(())
+    }
+
+    /// Add `point`, replacing a point of the same day so only a day's last run counts
+    pub fn insert(&mut self, point: HistoryPoint) {
+        match self
+            .points
+            .binary_search_by(|existing| existing.date.cmp(&point.date))
+        {
+            Ok(index) => self.points[index] = point,
+            Err(index) => self.points.insert(index, point),
+        }
+    }
+
+    /// Add `point` unless there is one for its day already, so backfilled counts never
+    /// replace those of an actual run. Returns whether it was added.
+    pub fn insert_missing(&mut self, point: HistoryPoint) -> bool {
+        match self
+            .points
+            .binary_search_by(|existing| existing.date.cmp(&point.date))
+        {
+            Ok(_) => false,
+            Err(index) => {
+                self.points.insert(index, point);
+                true
+            }
+        }
+    }
+
+    /// Write to `path`, replacing the file only once completely written
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = if is_json(path) {
+            serde_json::to_string_pretty(self)?
+        } else {
+            toml::to_string_pretty(self)?
+        };
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+        file.write_all(content.as_bytes())?;
+        file.as_file().sync_all()?;
+        // Temporary files are created private, keep the permissions of the file we replace
+        if let Ok(metadata) = fs::metadata(path) {
+            file.as_file().set_permissions(metadata.permissions())?;
+        }
+        file.persist(path)?;
+        Ok(())
+    }
+}
+
+/// Add a snapshot of `stats` to the history file at `path`
+pub fn record_history(path: &Path, stats: &FeedstockStats) -> Result<()> {
+    let mut history = History::load(path)?;
+    history.insert(HistoryPoint::from_stats(stats));
+    history
+        .save(path)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Days from `since` to `until` (inclusive), `interval_days` apart
+pub fn sample_dates(since: NaiveDate, until: NaiveDate, interval_days: i64) -> Vec<NaiveDate> {
+    let step = Duration::days(interval_days.max(1));
+    std::iter::successors(Some(since), |date| Some(*date + step))
+        .take_while(|date| *date <= until)
+        .collect()
+}
+
+/// Counts as of each of `dates`, classifying the node_attrs of the last cf-graph commit
+/// of that day with `determine_recipe_type_from_node`, like a collection run would.
+/// Recipe filenames can't be checked in the past, so non-standard ones are counted as
+/// Recipe v1 without a `non_standard_v1_count`.
+/// Days before the first commit are skipped, as are feedstocks excluded by the ignore
+/// list. Nodes that don't parse (e.g. in an older
+/// layout) are left out and counted in the second return value.
+pub fn backfill_history(
+    repo_path: &Path,
+    dates: &[NaiveDate],
+    ignore_list: &NodeAttrsIgnoreList,
+    verbose: bool,
+) -> Result<(Vec<HistoryPoint>, usize)> {
+    // Blob id -> feedstock and recipe type, None if it doesn't parse. Most nodes don't
+    // change between samples, so each blob is read and classified once.
+    let mut classified: HashMap<String, Option<(String, RecipeType)>> = HashMap::new();
+    let mut points = Vec::new();
+    let mut failed = 0;
+
+    for date in dates {
+        let Some(commit) = commit_at(repo_path, *date)? else {
+            continue;
+        };
+        let blobs: Vec<(String, String)> = node_attrs_blobs(repo_path, &commit)?
+            .into_iter()
+            .filter(|(path, _)| !ignore_list.is_ignored(&node_name(Path::new(path))))
+            .collect();
+
+        let mut new_ids: Vec<String> = blobs
+            .iter()
+            .map(|(_, id)| id)
+            .filter(|id| !classified.contains_key(*id))
+            .cloned()
+            .collect();
+        new_ids.sort();
+        new_ids.dedup();
+        for (id, contents) in new_ids.iter().zip(read_blobs(repo_path, &new_ids)?) {
+            let node = serde_json::from_slice::<NodeAttrsJson>(&contents).ok();
+            if node.is_none() {
+                failed += 1;
+            }
+            let feedstock = node.map(|node| {
+                let recipe_type = determine_recipe_type_from_node(&node);
+                (format!("{}-feedstock", node.feedstock_name), recipe_type)
+            });
+            classified.insert(id.clone(), feedstock);
+        }
+
+        // Keyed by feedstock like a run, where a later node of the same feedstock wins
+        let feedstocks: BTreeMap<&str, &RecipeType> = blobs
+            .iter()
+            .filter_map(|(_, id)| classified[id].as_ref())
+            .filter(|(name, _)| ignore_list.excluded_by(name).is_none())
+            .map(|(name, recipe_type)| (name.as_str(), recipe_type))
+            .collect();
+        let count = |wanted: RecipeType| {
+            feedstocks
+                .values()
+                .filter(|recipe_type| ***recipe_type == wanted)
+                .count() as u32
+        };
+        let point = HistoryPoint {
+            date: date.to_string(),
+            total_feedstocks: feedstocks.len() as u32,
+            recipe_v1_count: feedstocks.values().filter(|t| t.is_v1()).count() as u32,
+            non_standard_v1_count: None,
+            meta_yaml_count: count(RecipeType::MetaYaml),
+            unknown_count: count(RecipeType::Unknown),
+        };
+        if verbose {
+            println!(
+                "🕰️  {} ({}): {} of {} on Recipe v1",
+                point.date,
+                &commit[..8.min(commit.len())],
+                point.recipe_v1_count,
+                point.total_feedstocks
+            );
+        }
+        points.push(point);
+    }
+    Ok((points, failed))
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(date: &str, recipe_v1_count: u32) -> HistoryPoint {
+        HistoryPoint {
+            date: date.to_string(),
+            total_feedstocks: 100,
+            recipe_v1_count,
+            non_standard_v1_count: Some(0),
+            meta_yaml_count: 100 - recipe_v1_count,
+            unknown_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_history_dedupes_same_day() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["history.toml", "history.json"] {
+            let path = dir.path().join(file);
+            let mut history = History::load(&path).unwrap();
+            history.insert(point("2025-06-02", 20));
+            history.insert(point("2025-06-01", 10));
+            history.insert(point("2025-06-02", 25));
+            history.save(&path).unwrap();
+
+            let history = History::load(&path).unwrap();
+            assert_eq!(
+                history.points,
+                [point("2025-06-01", 10), point("2025-06-02", 25)]
+            );
+        }
+
+        let mut history = History::default();
+        history.insert(point("2025-06-02", 25));
+        assert!(!history.insert_missing(point("2025-06-02", 20)));
+        assert!(history.insert_missing(point("2025-05-26", 5)));
+        assert_eq!(
+            history.points,
+            [point("2025-05-26", 5), point("2025-06-02", 25)]
+        );
+    }
+
+    #[test]
+    fn test_history_migrates_recipe_v1_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.toml");
+        // A recorded run that counted its 2 non-standard filenames apart, and a backfilled
+        // day from the same file
+        fs::write(
+            &path,
+            r#"
+            [[points]]
+            date = "2025-06-01"
+            total_feedstocks = 100
+            recipe_v1_count = 8
+            non_standard_v1_count = 0
+            meta_yaml_count = 92
+            unknown_count = 0
+
+            [[points]]
+            date = "2025-06-08"
+            total_feedstocks = 100
+            recipe_v1_count = 10
+            non_standard_v1_count = 2
+            meta_yaml_count = 88
+            unknown_count = 0
+            "#,
+        )
+        .unwrap();
+        let history = History::load(&path).unwrap();
+        let counts: Vec<_> = history.points.iter().map(|p| p.recipe_v1_count).collect();
+        assert_eq!(counts, [8, 12]);
+
+        // Saved with the current version, so loading again doesn't count them twice
+        history.save(&path).unwrap();
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.format_version, HISTORY_FORMAT_VERSION);
+        assert_eq!(history.points[1].recipe_v1_count, 12);
+
+        fs::write(&path, "format_version = 2").unwrap();
+        assert!(History::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_backfill_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path();
+        let git = |args: &[&str], date: &str| {
+            let status = std::process::Command::new("git")
+                .current_dir(repo)
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.org"])
+                .args(args)
+                .env("GIT_AUTHOR_DATE", date)
+                .env("GIT_COMMITTER_DATE", date)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        let node = |name: &str, tool: &str| {
+            format!(
+                r#"{{"feedstock_name": "{}", "conda-forge.yml": {{"conda_build_tool": "{}"}}}}"#,
+                name, tool
+            )
+        };
+        let write = |path: &str, contents: &str| {
+            let path = repo.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+
+        git(&["init", "--quiet", "--initial-branch=main"], "");
+        write("node_attrs/n/numpy.json", &node("numpy", "conda-build"));
+        write("node_attrs/s/scipy.json", &node("scipy", "conda-build"));
+        write("node_attrs/b/broken.json", "not json");
+        git(&["add", "."], "");
+        git(&["commit", "--quiet", "-m", "a"], "2025-01-01T12:00:00Z");
+        write("node_attrs/n/numpy.json", &node("numpy", "rattler-build"));
+        git(&["commit", "--quiet", "-am", "b"], "2025-01-09T12:00:00Z");
+
+        let ignore_list: NodeAttrsIgnoreList =
+            toml::from_str("[ignore]\nbroken = \"test\"").unwrap();
+        let dates = sample_dates(
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+            7,
+        );
+        assert_eq!(dates.len(), 4);
+
+        let (points, failed) = backfill_history(repo, &dates, &ignore_list, false).unwrap();
+        assert_eq!(failed, 0);
+        assert!(points.iter().all(|p| p.non_standard_v1_count.is_none()));
+        let counts: Vec<_> = points
+            .iter()
+            .map(|p| (p.date.as_str(), p.total_feedstocks, p.recipe_v1_count))
+            .collect();
+        // Nothing committed by 2024-12-25, the conversion landed between the 8th and 15th
+        assert_eq!(
+            counts,
+            [
+                ("2025-01-01", 2, 0),
+                ("2025-01-08", 2, 0),
+                ("2025-01-15", 2, 1)
+            ]
+        );
+
+        let (_, failed) =
+            backfill_history(repo, &dates, &NodeAttrsIgnoreList::default(), false).unwrap();
+        assert_eq!(failed, 1);
+    }
+}
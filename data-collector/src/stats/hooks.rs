@@ -0,0 +1,126 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+use crate::models::FeedstockEntry;
+
+/// An extra analysis over the feedstock map, contributing one section to
+/// `[analyses.<name>]` in the output TOML. Meant for experimental metrics that
+/// shouldn't need changes to the core pipeline.
+pub trait AnalysisHook {
+    /// Section name in the output, should be unique among registered hooks
+    fn name(&self) -> &str;
+
+    fn analyze(&self, feedstock_states: &BTreeMap<String, FeedstockEntry>) -> Result<toml::Value>;
+}
+
+/// Hooks registered at startup, run in registration order
+#[derive(Default)]
+pub struct HookRegistry {
+    hooks: Vec<Box<dyn AnalysisHook>>,
+}
+
+impl HookRegistry {
+    pub fn register(&mut self, hook: impl AnalysisHook + 'static) -> &mut Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Run all hooks. A failing hook is reported and skipped so experiments
+    /// can't break a collection run.
+    pub fn run(
+        &self,
+        feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    ) -> BTreeMap<String, toml::Value> {
+        let mut sections = BTreeMap::new();
+        for hook in &self.hooks {
+            match hook.analyze(feedstock_states) {
+                Ok(section) => {
+                    sections.insert(hook.name().to_string(), section);
+                }
+                Err(e) => println!("⚠️  Analysis hook '{}' failed: {}", hook.name(), e),
+            }
+        }
+        sections
+    }
+}
+
+/// The hooks enabled by default
+pub fn default_hooks() -> HookRegistry {
+    let mut registry = HookRegistry::default();
+    registry.register(DownloadWeightedConversion);
+    registry
+}
+
+/// Share of all downloads that go to Recipe v1 feedstocks
+pub struct DownloadWeightedConversion;
+
+impl AnalysisHook for DownloadWeightedConversion {
+    fn name(&self) -> &str {
+        "download_weighted_conversion"
+    }
+
+    fn analyze(&self, feedstock_states: &BTreeMap<String, FeedstockEntry>) -> Result<toml::Value> {
+        let (mut converted, mut total) = (0u64, 0u64);
+        for entry in feedstock_states.values() {
+            let downloads = entry.downloads.unwrap_or(0);
+            total += downloads;
+            if entry.recipe_type.is_v1() {
+                converted += downloads;
+            }
+        }
+
+        let mut section = toml::Table::new();
+        section.insert("converted_downloads".to_string(), (converted as i64).into());
+        section.insert("total_downloads".to_string(), (total as i64).into());
+        let share = if total == 0 {
+            0.0
+        } else {
+            converted as f64 / total as f64
+        };
+        section.insert("share".to_string(), share.into());
+        Ok(toml::Value::Table(section))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RecipeType;
+
+    struct Failing;
+
+    impl AnalysisHook for Failing {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        fn analyze(&self, _: &BTreeMap<String, FeedstockEntry>) -> Result<toml::Value> {
+            anyhow::bail!("boom")
+        }
+    }
+
+    fn entry(recipe_type: RecipeType, downloads: u64) -> FeedstockEntry {
+        FeedstockEntry {
+            downloads: Some(downloads),
+            ..FeedstockEntry::test(recipe_type)
+        }
+    }
+
+    #[test]
+    fn test_failing_hook_is_skipped() {
+        let mut states = BTreeMap::new();
+        states.insert("a".to_string(), entry(RecipeType::RecipeV1, 300));
+        states.insert("b".to_string(), entry(RecipeType::MetaYaml, 100));
+
+        let mut registry = HookRegistry::default();
+        registry
+            .register(Failing)
+            .register(DownloadWeightedConversion);
+        let sections = registry.run(&states);
+
+        assert_eq!(sections.len(), 1);
+        let section = &sections["download_weighted_conversion"];
+        assert_eq!(section["converted_downloads"].as_integer(), Some(300));
+        assert_eq!(section["share"].as_float(), Some(0.75));
+    }
+}
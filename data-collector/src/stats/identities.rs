@@ -0,0 +1,304 @@
+//! Cached GitHub identities of credited contributors: display name, avatar and whether the
+//! account still exists under that login. The site uses them to show avatars and to avoid
+//! linking to renamed or deleted accounts.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+use crate::external::{GitHubClient, GitHubUser};
+use crate::models::FeedstockEntry;
+
+/// Identities older than this many days are fetched again
+pub const IDENTITY_TTL_DAYS: i64 = 7;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct IdentityCache {
+    /// By lowercase login, as credited in the attributions
+    pub contributors: BTreeMap<String, ContributorIdentity>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContributorIdentity {
+    /// GraphQL node id, kept to recognize the account after a rename
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// Whether the account can still be found, under its login or `renamed_to`
+    pub exists: bool,
+    /// Current login of an account renamed since it was credited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renamed_to: Option<String>,
+    /// RFC 3339
+    pub fetched_at: String,
+}
+
+impl IdentityCache {
+    /// Load the cache, starting empty if it's missing or unreadable
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Logins without an identity fetched within the last `ttl_days`
+    pub fn stale_logins(
+        &self,
+        logins: &BTreeSet<String>,
+        ttl_days: i64,
+        now: DateTime<Utc>,
+    ) -> Vec<String> {
+        logins
+            .iter()
+            .filter(|login| {
+                let fetched_at = self
+                    .contributors
+                    .get(*login)
+                    .and_then(|identity| DateTime::parse_from_rfc3339(&identity.fetched_at).ok())
+                    .map(|fetched_at| fetched_at.with_timezone(&Utc));
+                fetched_at.is_none_or(|fetched_at| now - fetched_at >= Duration::days(ttl_days))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Cached node id of `login`, if its account was found before
+    fn id(&self, login: &str) -> Option<&String> {
+        self.contributors.get(login)?.id.as_ref()
+    }
+
+    /// Record what GitHub returned for `login`: the account under that login, and the
+    /// account with its cached node id
+    fn update(
+        &mut self,
+        login: &str,
+        by_login: Option<&GitHubUser>,
+        by_id: Option<&GitHubUser>,
+        now: DateTime<Utc>,
+    ) {
+        let cached_id = self.id(login).cloned();
+        // A login that now belongs to another account means ours was renamed or deleted
+        let same_account =
+            by_login.filter(|user| cached_id.as_ref().is_none_or(|id| *id == user.id));
+        let identity = match (same_account, by_id) {
+            (Some(user), _) => identity_of(user, now),
+            (None, Some(user)) => ContributorIdentity {
+                renamed_to: (!user.login.eq_ignore_ascii_case(login)).then(|| user.login.clone()),
+                ..identity_of(user, now)
+            },
+            (None, None) => ContributorIdentity {
+                id: cached_id,
+                name: None,
+                avatar_url: None,
+                exists: false,
+                renamed_to: None,
+                fetched_at: now.to_rfc3339(),
+            },
+        };
+        self.contributors.insert(login.to_string(), identity);
+    }
+}
+
+fn identity_of(user: &GitHubUser, now: DateTime<Utc>) -> ContributorIdentity {
+    ContributorIdentity {
+        id: Some(user.id.clone()),
+        name: user.name.clone(),
+        avatar_url: Some(user.avatar_url.clone()),
+        exists: true,
+        renamed_to: None,
+        fetched_at: now.to_rfc3339(),
+    }
+}
+
+/// Lowercase logins credited in any attribution. Fallback names that can't be GitHub
+/// logins (e.g. commit author names) are left out.
+pub fn credited_logins(feedstock_states: &BTreeMap<String, FeedstockEntry>) -> BTreeSet<String> {
+    feedstock_states
+        .values()
+        .filter_map(|entry| entry.attribution.as_ref())
+        .flat_map(|attribution| &attribution.contributors)
+        .filter(|login| is_github_login(login))
+        .map(|login| login.to_lowercase())
+        .collect()
+}
+
+/// Alphanumerics and single inner hyphens, at most 39 characters
+pub fn is_github_login(login: &str) -> bool {
+    (1..=39).contains(&login.len())
+        && login.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !login.starts_with('-')
+        && !login.ends_with('-')
+        && !login.contains("--")
+}
+
+/// Logins that commit author `names` without a linked GitHub account may be credited as,
+/// by name. A name only counts if it is the login of an existing account (in `accounts`,
+/// fetched by name), and not of another account than the one cached for that login, as
+/// happens when a credited contributor renamed and someone else took the old login.
+pub fn verify_author_names(
+    names: &[String],
+    accounts: &HashMap<String, GitHubUser>,
+    identities: &IdentityCache,
+) -> HashMap<String, String> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let account = accounts.get(name)?;
+            let known_id = identities.id(&name.to_lowercase());
+            if known_id.is_some_and(|id| *id != account.id) {
+                return None;
+            }
+            Some((name.clone(), account.login.clone()))
+        })
+        .collect()
+}
+
+/// Refresh the stale identities of all credited contributors in the cache at `path`,
+/// dropping contributors who aren't credited anymore
+pub async fn resolve_contributor_identities(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    path: &Path,
+    ttl_days: i64,
+) -> Result<()> {
+    let mut cache = IdentityCache::load(path);
+    let logins = credited_logins(feedstock_states);
+    cache.contributors.retain(|login, _| logins.contains(login));
+
+    let now = Utc::now();
+    let stale = cache.stale_logins(&logins, ttl_days, now);
+    println!(
+        "🪪 Resolving {} of {} contributor identities (older than {} days)",
+        stale.len(),
+        logins.len(),
+        ttl_days
+    );
+    if !stale.is_empty() {
+        let github = GitHubClient::new()?;
+        let by_login = github.batch_fetch_users(&stale).await?;
+
+        // Look up accounts we knew that the login no longer leads to
+        let lost_ids: Vec<String> = stale
+            .iter()
+            .filter_map(|login| {
+                let id = cache.id(login)?;
+                let found = by_login.get(login).is_some_and(|user| user.id == *id);
+                (!found).then(|| id.clone())
+            })
+            .collect();
+        let by_id: HashMap<String, GitHubUser> = if lost_ids.is_empty() {
+            HashMap::new()
+        } else {
+            github.batch_fetch_users_by_id(&lost_ids).await?
+        };
+
+        for login in &stale {
+            let user_by_id = cache.id(login).and_then(|id| by_id.get(id)).cloned();
+            cache.update(login, by_login.get(login), user_by_id.as_ref(), now);
+        }
+    }
+
+    let renamed = cache
+        .contributors
+        .values()
+        .filter(|identity| identity.renamed_to.is_some())
+        .count();
+    let missing = cache
+        .contributors
+        .values()
+        .filter(|identity| !identity.exists)
+        .count();
+    println!(
+        "🪪 {} renamed and {} missing accounts among {} contributors",
+        renamed,
+        missing,
+        cache.contributors.len()
+    );
+    cache.save(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str, login: &str) -> GitHubUser {
+        GitHubUser {
+            id: id.to_string(),
+            login: login.to_string(),
+            name: Some("Alice".to_string()),
+            avatar_url: format!("https://avatars.githubusercontent.com/{}", login),
+        }
+    }
+
+    #[test]
+    fn test_identity_updates() {
+        let now = DateTime::parse_from_rfc3339("2025-06-08T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut cache = IdentityCache::default();
+
+        cache.update("alice", Some(&user("U_1", "Alice")), None, now);
+        assert!(cache.contributors["alice"].exists);
+        assert_eq!(cache.contributors["alice"].id.as_deref(), Some("U_1"));
+
+        // Renamed: the login is gone, the node id leads to the new login
+        cache.update("alice", None, Some(&user("U_1", "alice-new")), now);
+        let alice = &cache.contributors["alice"];
+        assert!(alice.exists);
+        assert_eq!(alice.renamed_to.as_deref(), Some("alice-new"));
+
+        // Someone else took the login and the original account is gone
+        cache.update("alice", Some(&user("U_2", "alice")), None, now);
+        let alice = &cache.contributors["alice"];
+        assert!(!alice.exists);
+        assert_eq!(alice.id.as_deref(), Some("U_1"));
+        assert!(alice.avatar_url.is_none());
+
+        let logins = BTreeSet::from(["alice".to_string(), "bob".to_string()]);
+        assert_eq!(cache.stale_logins(&logins, 7, now), vec!["bob"]);
+        assert_eq!(
+            cache.stale_logins(&logins, 7, now + Duration::days(7)),
+            vec!["alice", "bob"]
+        );
+    }
+
+    #[test]
+    fn test_is_github_login() {
+        assert!(is_github_login("conda-forge-admin"));
+        assert!(is_github_login("Alice42"));
+        assert!(!is_github_login("Alice Smith"));
+        assert!(!is_github_login("-alice"));
+        assert!(!is_github_login("a--b"));
+        assert!(!is_github_login("alice@example.com"));
+        assert!(!is_github_login(""));
+    }
+
+    #[test]
+    fn test_verify_author_names() {
+        let now = Utc::now();
+        let mut identities = IdentityCache::default();
+        identities.update("bob", Some(&user("U_2", "bob")), None, now);
+
+        let names = ["alice", "bob", "nobody"].map(String::from);
+        let accounts = HashMap::from([
+            ("alice".to_string(), user("U_1", "Alice")),
+            // bob's login now belongs to a different account than the credited bob
+            ("bob".to_string(), user("U_3", "bob")),
+        ]);
+        assert_eq!(
+            verify_author_names(&names, &accounts, &identities),
+            HashMap::from([("alice".to_string(), "Alice".to_string())])
+        );
+    }
+}
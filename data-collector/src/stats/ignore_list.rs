@@ -0,0 +1,265 @@
+//! Persisted list of node_attrs entries that aren't feedstocks or that are excluded from
+//! the counts, and the report of the files skipped while parsing, so parsing regressions
+//! don't go unnoticed.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::stats::matches_feedstock;
+
+/// Share of node_attrs files that may fail to parse before a run is treated as a
+/// parsing regression
+pub const MAX_FAILURE_SHARE: f64 = 0.01;
+
+/// Parse failures listed by name unless running verbose
+const REPORTED_FAILURES: usize = 10;
+
+/// node_attrs entries known not to be feedstocks, e.g. aggregate or meta nodes, and
+/// feedstocks that aren't production repos, e.g. test feedstocks or forks
+#[derive(Debug, Default, Deserialize)]
+pub struct NodeAttrsIgnoreList {
+    /// Node name (file name without `.json`) -> why it is ignored
+    #[serde(default)]
+    ignore: BTreeMap<String, String>,
+    /// Feedstock name pattern, see `matches_feedstock` -> why matching feedstocks are
+    /// left out of the counts
+    #[serde(default)]
+    exclude: BTreeMap<String, String>,
+}
+
+impl NodeAttrsIgnoreList {
+    /// An empty list if the file doesn't exist
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn is_ignored(&self, node: &str) -> bool {
+        self.ignore.contains_key(node)
+    }
+
+    /// The first exclusion pattern matching `feedstock`, if any
+    pub fn excluded_by(&self, feedstock: &str) -> Option<&str> {
+        self.exclude
+            .keys()
+            .find(|pattern| matches_feedstock(pattern, feedstock))
+            .map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ignore.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ignore.is_empty()
+    }
+}
+
+/// Name of the node a node_attrs file describes, e.g. "numpy" for `.../numpy.json`
+pub fn node_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// node_attrs files that didn't end up as feedstocks
+#[derive(Debug, Default)]
+pub struct SkippedNodeAttrs {
+    /// On the ignore list
+    pub ignored: Vec<String>,
+    /// Not on the ignore list and failed to parse: (node, error)
+    pub failed: Vec<(String, String)>,
+    /// Matched an exclusion pattern: (feedstock, pattern)
+    pub excluded: Vec<(String, String)>,
+}
+
+impl SkippedNodeAttrs {
+    /// Whether more files failed to parse than can be explained by stray entries
+    pub fn is_regression(&self, total_files: usize) -> bool {
+        self.failed.len() as f64 > total_files as f64 * MAX_FAILURE_SHARE
+    }
+
+    /// Lines describing the skipped files and ignore-list entries without a file
+    pub fn report(&self, ignore_list: &NodeAttrsIgnoreList, verbose: bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        if !self.ignored.is_empty() {
+            lines.push(format!(
+                "⏭️  Skipped {} node_attrs entries on the ignore list",
+                self.ignored.len()
+            ));
+        }
+
+        if !self.excluded.is_empty() {
+            lines.push(format!(
+                "🚫 Excluded {} feedstocks matching exclusion patterns:",
+                self.excluded.len()
+            ));
+            let mut per_pattern: BTreeMap<&str, usize> = BTreeMap::new();
+            for (_, pattern) in &self.excluded {
+                *per_pattern.entry(pattern).or_default() += 1;
+            }
+            for (pattern, count) in per_pattern {
+                lines.push(format!("   - {}: {}", pattern, count));
+            }
+            if verbose {
+                let names: Vec<&str> = self
+                    .excluded
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect();
+                lines.push(format!("   {}", names.join(", ")));
+            }
+        }
+
+        if !self.failed.is_empty() {
+            lines.push(format!(
+                "⚠️  {} node_attrs files failed to parse:",
+                self.failed.len()
+            ));
+            let shown = if verbose {
+                self.failed.len()
+            } else {
+                REPORTED_FAILURES
+            };
+            for (node, error) in self.failed.iter().take(shown) {
+                lines.push(format!("   - {}: {}", node, error));
+            }
+            if self.failed.len() > shown {
+                lines.push(format!(
+                    "   ... and {} more (run with --verbose to list all)",
+                    self.failed.len() - shown
+                ));
+            }
+        }
+
+        let seen: HashSet<&str> = self.ignored.iter().map(String::as_str).collect();
+        let stale: Vec<&str> = ignore_list
+            .ignore
+            .keys()
+            .map(String::as_str)
+            .filter(|node| !seen.contains(node))
+            .collect();
+        if !stale.is_empty() {
+            lines.push(format!(
+                "🧹 Ignore-list entries without a node_attrs file: {}",
+                stale.join(", ")
+            ));
+        }
+
+        let matched: HashSet<&str> = self.excluded.iter().map(|(_, p)| p.as_str()).collect();
+        let unused: Vec<&str> = ignore_list
+            .exclude
+            .keys()
+            .map(String::as_str)
+            .filter(|pattern| !matched.contains(pattern))
+            .collect();
+        if !unused.is_empty() {
+            lines.push(format!(
+                "🧹 Exclusion patterns matching no feedstock: {}",
+                unused.join(", ")
+            ));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skipped_node_attrs_report() {
+        let ignore_list: NodeAttrsIgnoreList = toml::from_str(
+            r#"
+            [ignore]
+            conda-forge-pinning = "global pinning, not a package"
+            removed-node = "gone upstream"
+            "#,
+        )
+        .unwrap();
+        assert!(ignore_list.is_ignored("conda-forge-pinning"));
+        assert!(!ignore_list.is_ignored("numpy"));
+
+        let skipped = SkippedNodeAttrs {
+            ignored: vec!["conda-forge-pinning".to_string()],
+            failed: (0..12)
+                .map(|i| {
+                    (
+                        format!("broken-{i}"),
+                        "missing field `feedstock_name`".to_string(),
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        };
+        assert!(skipped.is_regression(1000));
+        assert!(!skipped.is_regression(10_000));
+
+        let report = skipped.report(&ignore_list, false);
+        assert_eq!(
+            report[0],
+            "⏭️  Skipped 1 node_attrs entries on the ignore list"
+        );
+        assert_eq!(report[1], "⚠️  12 node_attrs files failed to parse:");
+        assert_eq!(report[2], "   - broken-0: missing field `feedstock_name`");
+        assert_eq!(
+            report[12],
+            "   ... and 2 more (run with --verbose to list all)"
+        );
+        assert_eq!(
+            report[13],
+            "🧹 Ignore-list entries without a node_attrs file: removed-node"
+        );
+        assert_eq!(skipped.report(&ignore_list, true).len(), 15);
+    }
+
+    #[test]
+    fn test_exclusion_patterns() {
+        let ignore_list: NodeAttrsIgnoreList = toml::from_str(
+            r#"
+            [exclude]
+            "*-split" = "test feedstocks for split recipes"
+            "staged-recipes*" = "mirrors of staged-recipes"
+            "old-fork" = "gone upstream"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            ignore_list.excluded_by("foo-split-feedstock"),
+            Some("*-split")
+        );
+        assert_eq!(
+            ignore_list.excluded_by("staged-recipes-mirror-feedstock"),
+            Some("staged-recipes*")
+        );
+        assert_eq!(ignore_list.excluded_by("numpy-feedstock"), None);
+
+        let skipped = SkippedNodeAttrs {
+            excluded: vec![
+                ("a-split-feedstock".to_string(), "*-split".to_string()),
+                ("b-split-feedstock".to_string(), "*-split".to_string()),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            skipped.report(&ignore_list, false),
+            vec![
+                "🚫 Excluded 2 feedstocks matching exclusion patterns:",
+                "   - *-split: 2",
+                "🧹 Exclusion patterns matching no feedstock: old-fork, staged-recipes*",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_node_name() {
+        assert_eq!(node_name(Path::new("node_attrs/a/b/numpy.json")), "numpy");
+    }
+}
@@ -0,0 +1,175 @@
+//! Targeted invalidation of cached data, so a correction only refetches what it needs
+//! instead of redoing every feedstock's attribution.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::config::Paths;
+use crate::models::{ContributionType, FeedstockEntry};
+
+/// Which cached data to drop before a run, see the `--refetch-*` flags
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CacheInvalidation {
+    /// First recipe.yaml commits of all feedstocks
+    pub recipe_commits: bool,
+    /// New feedstock attributions, which credit the recipe maintainers
+    pub maintainers: bool,
+    /// Conversion attributions, which credit the author of the conversion PR
+    pub prs: bool,
+    /// The version order cache used to sum download counts, and the checkpoint of an
+    /// interrupted crawl
+    pub downloads: bool,
+    /// Glob patterns of feedstocks to redo completely
+    pub feedstocks: Vec<String>,
+}
+
+impl CacheInvalidation {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Drop the per-feedstock caches. Attributions are only dropped for Recipe v1
+    /// feedstocks, the only ones attribution fills in again. Returns the number of
+    /// entries changed.
+    pub fn apply(&self, feedstock_states: &mut BTreeMap<String, FeedstockEntry>) -> usize {
+        let mut changed = 0;
+        for (name, entry) in feedstock_states.iter_mut() {
+            let matched = self
+                .feedstocks
+                .iter()
+                .any(|pattern| matches_feedstock(pattern, name));
+            let drop_commit = self.recipe_commits || matched;
+            let stale_attribution = match entry.attribution.as_ref().map(|a| &a.contribution_type) {
+                Some(ContributionType::NewFeedstock) => self.maintainers,
+                Some(ContributionType::Conversion) => self.prs,
+                None => false,
+            };
+            let drop_attribution = entry.recipe_type.is_v1() && (matched || stale_attribution);
+
+            let mut touched = false;
+            if drop_commit && entry.recipe_commit_cache.is_some() {
+                entry.recipe_commit_cache = None;
+                touched = true;
+            }
+            if drop_attribution && entry.attribution.is_some() {
+                entry.attribution = None;
+                touched = true;
+            }
+            if touched {
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Remove the caches stored in their own files
+    pub fn clear_files(&self, paths: &Paths) -> Result<()> {
+        let version_cache = &paths.version_cache;
+        if self.downloads && version_cache.exists() {
+            fs::remove_file(version_cache)
+                .with_context(|| format!("Failed to remove {}", version_cache.display()))?;
+            println!("🗑️  Removed the version order cache (--refetch-downloads)");
+        }
+        let checkpoint = &paths.download_checkpoint;
+        if self.downloads && checkpoint.exists() {
+            fs::remove_file(checkpoint)
+                .with_context(|| format!("Failed to remove {}", checkpoint.display()))?;
+            println!("🗑️  Removed the download crawl checkpoint (--refetch-downloads)");
+        }
+        Ok(())
+    }
+}
+
+/// Whether `pattern` (`*` any run of characters, `?` one character) matches the
+/// feedstock, with or without its `-feedstock` suffix
+pub fn matches_feedstock(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let short = name.strip_suffix("-feedstock").unwrap_or(name);
+    [name, short]
+        .iter()
+        .any(|candidate| glob_match(&pattern, &candidate.chars().collect::<Vec<_>>()))
+}
+
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| glob_match(rest, &text[skip..])),
+        Some((c, rest)) => text
+            .split_first()
+            .is_some_and(|(t, text)| (*c == '?' || c == t) && glob_match(rest, text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Attribution, RecipeCommitCache, RecipeType};
+
+    fn entry(contribution_type: ContributionType) -> FeedstockEntry {
+        FeedstockEntry {
+            attribution: Some(Attribution {
+                contribution_type,
+                contributors: vec!["alice".to_string()],
+                date: "2025-01-01T00:00:00Z".to_string(),
+                commit_sha: None,
+            }),
+            recipe_commit_cache: Some(RecipeCommitCache {
+                sha: "abc".to_string(),
+                message: "Convert".to_string(),
+                date: "2025-01-01T00:00:00Z".to_string(),
+                author_login: None,
+                author_name: "Alice".to_string(),
+                author_email: "alice@example.com".to_string(),
+            }),
+            ..FeedstockEntry::test(RecipeType::RecipeV1)
+        }
+    }
+
+    #[test]
+    fn test_apply_invalidation() {
+        let states = BTreeMap::from([
+            (
+                "numpy-feedstock".to_string(),
+                entry(ContributionType::Conversion),
+            ),
+            (
+                "new-feedstock".to_string(),
+                entry(ContributionType::NewFeedstock),
+            ),
+        ]);
+
+        // PRs only affect conversions, and keep the commit cache
+        let mut prs = states.clone();
+        let invalidation = CacheInvalidation {
+            prs: true,
+            ..Default::default()
+        };
+        assert_eq!(invalidation.apply(&mut prs), 1);
+        assert!(prs["numpy-feedstock"].attribution.is_none());
+        assert!(prs["numpy-feedstock"].recipe_commit_cache.is_some());
+        assert!(prs["new-feedstock"].attribution.is_some());
+
+        // A glob redoes the matching feedstocks completely
+        let mut glob = states.clone();
+        let invalidation = CacheInvalidation {
+            feedstocks: vec!["num*".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(invalidation.apply(&mut glob), 1);
+        assert!(glob["numpy-feedstock"].recipe_commit_cache.is_none());
+        assert!(glob["new-feedstock"].recipe_commit_cache.is_some());
+
+        assert!(CacheInvalidation::default().is_empty());
+    }
+
+    #[test]
+    fn test_matches_feedstock() {
+        assert!(matches_feedstock("numpy", "numpy-feedstock"));
+        assert!(matches_feedstock("py*", "python-feedstock"));
+        assert!(matches_feedstock("r-?", "r-a-feedstock"));
+        assert!(matches_feedstock("*", "anything-feedstock"));
+        assert!(!matches_feedstock("numpy", "numpy-base-feedstock"));
+        assert!(!matches_feedstock("py?", "python-feedstock"));
+    }
+}
@@ -1,9 +1,43 @@
 pub mod analytics;
 pub mod attribution;
 pub mod collector;
+pub mod export;
+pub mod feedstock_check;
 pub mod file_processor;
+pub mod history;
+pub mod hooks;
+pub mod identities;
+pub mod ignore_list;
+pub mod invalidation;
+#[cfg(feature = "parquet")]
+pub mod parquet_export;
+pub mod research;
+pub mod schema;
+pub mod shards;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_store;
+pub mod store;
+pub mod summary;
+pub mod validate;
+pub mod webhooks;
 
 pub use analytics::*;
 pub use attribution::*;
 pub use collector::*;
+pub use export::*;
+pub use feedstock_check::*;
 pub use file_processor::*;
+pub use history::*;
+pub use hooks::*;
+pub use identities::*;
+pub use ignore_list::*;
+pub use invalidation::*;
+#[cfg(feature = "parquet")]
+pub use parquet_export::*;
+pub use research::*;
+pub use schema::*;
+pub use shards::*;
+pub use store::*;
+pub use summary::*;
+pub use validate::*;
+pub use webhooks::*;
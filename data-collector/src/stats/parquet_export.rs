@@ -0,0 +1,182 @@
+//! Parquet tables of `feedstock_states` for DuckDB, pandas and the like, built with the
+//! `parquet` feature
+
+use anyhow::{Context, Result};
+use arrow_array::builder::{ListBuilder, StringBuilder};
+use arrow_array::{ArrayRef, RecordBatch, StringArray, UInt32Array, UInt64Array};
+use parquet::arrow::ArrowWriter;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::models::FeedstockStats;
+use crate::stats::serde_name;
+
+/// Write `feedstocks.parquet`, `attributions.parquet` (one row per credited contributor)
+/// and `top_unconverted.parquet` into `dir`, returning each file name and its row count
+pub fn write_parquet_tables(stats: &FeedstockStats, dir: &Path) -> Result<Vec<(String, usize)>> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let tables = [
+        ("feedstocks", feedstocks_table(stats)?),
+        ("attributions", attributions_table(stats)?),
+        ("top_unconverted", top_unconverted_table(stats)?),
+    ];
+
+    let mut written = Vec::new();
+    for (name, batch) in tables {
+        let file_name = format!("{}.parquet", name);
+        let path = dir.join(&file_name);
+        let file = fs::File::create(&path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        written.push((file_name, batch.num_rows()));
+    }
+    Ok(written)
+}
+
+fn feedstocks_table(stats: &FeedstockStats) -> Result<RecordBatch> {
+    let states = &stats.feedstock_states;
+    let mut maintainers = ListBuilder::new(StringBuilder::new());
+    let mut recipe_features = ListBuilder::new(StringBuilder::new());
+    for entry in states.values() {
+        maintainers.append_value(entry.maintainers.iter().map(Some));
+        recipe_features.append_value(entry.recipe_features.iter().map(Some));
+    }
+
+    Ok(RecordBatch::try_from_iter([
+        ("name", strings(states.keys())),
+        (
+            "recipe_type",
+            strings(states.values().map(|e| serde_name(&e.recipe_type))),
+        ),
+        (
+            "last_changed",
+            strings(states.values().map(|e| &e.last_changed)),
+        ),
+        (
+            "downloads",
+            Arc::new(UInt64Array::from_iter(states.values().map(|e| e.downloads))) as ArrayRef,
+        ),
+        ("maintainers", Arc::new(maintainers.finish()) as ArrayRef),
+        (
+            "recipe_features",
+            Arc::new(recipe_features.finish()) as ArrayRef,
+        ),
+        (
+            "reverted_at",
+            Arc::new(StringArray::from_iter(
+                states
+                    .values()
+                    .map(|e| e.revert.as_ref().map(|r| r.reverted_at.as_str())),
+            )) as ArrayRef,
+        ),
+    ])?)
+}
+
+fn attributions_table(stats: &FeedstockStats) -> Result<RecordBatch> {
+    let rows: Vec<_> = stats
+        .feedstock_states
+        .iter()
+        .filter_map(|(name, entry)| Some((name, entry.attribution.as_ref()?)))
+        .flat_map(|(name, attribution)| {
+            attribution
+                .contributors
+                .iter()
+                .map(move |contributor| (name, attribution, contributor))
+        })
+        .collect();
+
+    Ok(RecordBatch::try_from_iter([
+        ("feedstock", strings(rows.iter().map(|(name, _, _)| *name))),
+        (
+            "contribution_type",
+            strings(
+                rows.iter()
+                    .map(|(_, a, _)| serde_name(&a.contribution_type)),
+            ),
+        ),
+        (
+            "contributor",
+            strings(rows.iter().map(|(_, _, contributor)| *contributor)),
+        ),
+        ("date", strings(rows.iter().map(|(_, a, _)| &a.date))),
+        (
+            "commit_sha",
+            Arc::new(StringArray::from_iter(
+                rows.iter().map(|(_, a, _)| a.commit_sha.as_deref()),
+            )) as ArrayRef,
+        ),
+    ])?)
+}
+
+fn top_unconverted_table(stats: &FeedstockStats) -> Result<RecordBatch> {
+    let top = &stats.top_unconverted_by_downloads;
+    Ok(RecordBatch::try_from_iter([
+        (
+            "rank",
+            Arc::new(UInt32Array::from_iter_values(1..=top.len() as u32)) as ArrayRef,
+        ),
+        ("name", strings(top.iter().map(|f| &f.name))),
+        (
+            "downloads",
+            Arc::new(UInt64Array::from_iter_values(
+                top.iter().map(|f| f.downloads),
+            )) as ArrayRef,
+        ),
+        (
+            "recipe_type",
+            strings(top.iter().map(|f| serde_name(&f.recipe_type))),
+        ),
+        (
+            "pushed_at",
+            Arc::new(StringArray::from_iter(
+                top.iter().map(|f| f.pushed_at.as_deref()),
+            )) as ArrayRef,
+        ),
+    ])?)
+}
+
+fn strings<S: AsRef<str>>(values: impl IntoIterator<Item = S>) -> ArrayRef {
+    Arc::new(StringArray::from_iter_values(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::export::tests::stats;
+
+    #[test]
+    fn test_parquet_tables() {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let dir = tempfile::tempdir().unwrap();
+        let written = write_parquet_tables(&stats(), dir.path()).unwrap();
+        assert_eq!(
+            written,
+            [
+                ("feedstocks.parquet".to_string(), 2),
+                ("attributions.parquet".to_string(), 2),
+                ("top_unconverted.parquet".to_string(), 1),
+            ]
+        );
+
+        let file = fs::File::open(dir.path().join("attributions.parquet")).unwrap();
+        let batch = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let contributors = batch
+            .column_by_name("contributor")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(contributors.value(1), "bob");
+        assert_eq!(batch.column_by_name("commit_sha").unwrap().null_count(), 2);
+    }
+}
@@ -0,0 +1,161 @@
+//! Anonymized dataset for research on how the migration spreads. GitHub logins are
+//! replaced by salted hashes and commit details (SHAs, names, e-mails) are left out,
+//! so people can be followed across feedstocks but not identified.
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::models::{ContributionType, FeedstockStats, RecipeType};
+
+/// Bump when fields are removed or change meaning
+pub const RESEARCH_SCHEMA_VERSION: u32 = 1;
+
+/// Hex characters kept of each hashed login
+const PERSON_ID_LENGTH: usize = 16;
+
+#[derive(Serialize)]
+pub struct ResearchExport {
+    pub schema_version: u32,
+    /// When the underlying data was collected (RFC 3339)
+    pub collected_at: String,
+    pub feedstocks: Vec<ResearchFeedstock>,
+}
+
+#[derive(Serialize)]
+pub struct ResearchFeedstock {
+    pub feedstock: String,
+    pub recipe_type: RecipeType,
+    pub downloads: Option<u64>,
+    /// Hashed `extra.recipe-maintainers`
+    pub maintainers: Vec<String>,
+    pub recipe_features: Vec<String>,
+    pub contribution_type: Option<ContributionType>,
+    /// Hashed contributors credited with the Recipe v1 recipe
+    pub contributors: Vec<String>,
+    /// Day the recipe.yaml was added (YYYY-MM-DD)
+    pub contribution_date: Option<String>,
+    /// A maintainer converted the feedstock themselves
+    pub self_conversion: bool,
+    /// Was seen going back to meta.yaml after converting
+    pub reverted: bool,
+}
+
+/// Stable pseudonym for a GitHub login. Logins are case-insensitive, and the same salt
+/// gives the same ids across exports.
+pub fn person_id(login: &str, salt: &str) -> String {
+    let digest = Sha256::digest(format!("{}:{}", salt, login.to_lowercase()));
+    format!("{:x}", digest)[..PERSON_ID_LENGTH].to_string()
+}
+
+pub fn research_export(stats: &FeedstockStats, salt: &str) -> ResearchExport {
+    let hash_all = |logins: &[String]| -> Vec<String> {
+        logins.iter().map(|login| person_id(login, salt)).collect()
+    };
+
+    let feedstocks = stats
+        .feedstock_states
+        .iter()
+        .map(|(name, entry)| {
+            let attribution = entry.attribution.as_ref();
+            let self_conversion = attribution.is_some_and(|a| {
+                a.contribution_type == ContributionType::Conversion
+                    && a.contributors
+                        .iter()
+                        .any(|c| entry.maintainers.iter().any(|m| m.eq_ignore_ascii_case(c)))
+            });
+            ResearchFeedstock {
+                feedstock: name.clone(),
+                recipe_type: entry.recipe_type.clone(),
+                downloads: entry.downloads,
+                maintainers: hash_all(&entry.maintainers),
+                recipe_features: entry.recipe_features.clone(),
+                contribution_type: attribution.map(|a| a.contribution_type.clone()),
+                contributors: attribution
+                    .map(|a| hash_all(&a.contributors))
+                    .unwrap_or_default(),
+                contribution_date: attribution.and_then(|a| a.date.get(..10)).map(String::from),
+                self_conversion,
+                reverted: entry.revert.is_some(),
+            }
+        })
+        .collect();
+
+    ResearchExport {
+        schema_version: RESEARCH_SCHEMA_VERSION,
+        collected_at: stats.last_updated.clone(),
+        feedstocks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Attribution, FeedstockEntry, RecipeCommitCache, STATS_FORMAT_VERSION};
+
+    #[test]
+    fn test_research_export_hides_identities() {
+        let entry = FeedstockEntry {
+            last_changed: "2025-03-01T00:00:00Z".to_string(),
+            attribution: Some(Attribution {
+                contribution_type: ContributionType::Conversion,
+                contributors: vec!["Alice".to_string()],
+                date: "2025-02-10T08:30:00Z".to_string(),
+                commit_sha: Some("abc123".to_string()),
+            }),
+            downloads: Some(100),
+            recipe_commit_cache: Some(RecipeCommitCache {
+                sha: "abc123".to_string(),
+                message: "Convert to v1".to_string(),
+                date: "2025-02-10T08:30:00Z".to_string(),
+                author_login: Some("Alice".to_string()),
+                author_name: "Alice Smith".to_string(),
+                author_email: "alice@example.com".to_string(),
+            }),
+            maintainers: vec!["alice".to_string(), "bob".to_string()],
+            recipe_features: vec!["selectors".to_string()],
+            ..FeedstockEntry::test(RecipeType::RecipeV1)
+        };
+        let stats = FeedstockStats {
+            stats_format_version: STATS_FORMAT_VERSION,
+            total_feedstocks: 1,
+            recipe_v1_count: 1,
+            meta_yaml_count: 0,
+            unknown_count: 0,
+            non_standard_v1_count: 0,
+            last_updated: "2025-03-01T00:00:00Z".to_string(),
+            feedstock_states: [("foo-feedstock".to_string(), entry)].into(),
+            top_unconverted_by_downloads: Vec::new(),
+            quality_metrics: Default::default(),
+            maintainer_conversion_rates: Vec::new(),
+            recipe_feature_rates: Vec::new(),
+            bot_activity_rates: Vec::new(),
+            coverage: None,
+            build_health: None,
+            pending_migration_prs: Vec::new(),
+            recipe_lint: None,
+            dependency_weighted: None,
+            rattler_build_adoption: Default::default(),
+            top_feedstock_alerts: Vec::new(),
+            conversion_forecast: None,
+            unannounced_conversions: Default::default(),
+            unchecked_recipe_files: Vec::new(),
+            analyses: Default::default(),
+            memory_profile: Vec::new(),
+        };
+
+        let export = research_export(&stats, "salt");
+        let feedstock = &export.feedstocks[0];
+        assert_eq!(feedstock.contributors, vec![person_id("alice", "salt")]);
+        assert_eq!(feedstock.maintainers[0], feedstock.contributors[0]);
+        assert_eq!(feedstock.contribution_date.as_deref(), Some("2025-02-10"));
+        assert!(feedstock.self_conversion);
+
+        let json = serde_json::to_string(&export).unwrap();
+        for identifying in ["lice", "bob", "abc123", "example.com", "Smith"] {
+            assert!(!json.contains(identifying), "{} leaked", identifying);
+        }
+
+        // Ids only link up under the same salt
+        assert_ne!(person_id("alice", "salt"), person_id("alice", "other"));
+    }
+}
@@ -0,0 +1,232 @@
+//! Detects upstream changes to the shape of cf-graph's node_attrs files, which would
+//! otherwise silently classify every feedstock as meta.yaml.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Number of node_attrs files checked before each run
+pub const SCHEMA_SAMPLE_SIZE: usize = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FieldKind {
+    String,
+    Object,
+    /// A cf-graph set: `{"__set__": true, "elements": [...]}`
+    Set,
+}
+
+impl FieldKind {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Object => "object",
+            Self::Set => "set",
+        }
+    }
+}
+
+/// A field the collector relies on, and the share of files that must have it
+struct ExpectedField {
+    path: &'static [&'static str],
+    kind: FieldKind,
+    min_share: f64,
+}
+
+const EXPECTED_FIELDS: &[ExpectedField] = &[
+    ExpectedField {
+        path: &["feedstock_name"],
+        kind: FieldKind::String,
+        min_share: 0.95,
+    },
+    ExpectedField {
+        path: &["conda-forge.yml"],
+        kind: FieldKind::Object,
+        min_share: 0.9,
+    },
+    // Only set by a minority of feedstocks, but never by none of them
+    ExpectedField {
+        path: &["conda-forge.yml", "conda_build_tool"],
+        kind: FieldKind::String,
+        min_share: 0.01,
+    },
+    ExpectedField {
+        path: &["meta_yaml"],
+        kind: FieldKind::Object,
+        min_share: 0.9,
+    },
+    ExpectedField {
+        path: &["outputs_names"],
+        kind: FieldKind::Set,
+        min_share: 0.9,
+    },
+];
+
+/// An expected field found in too few of the sampled files
+#[derive(Debug)]
+pub struct FieldDrift {
+    /// Dotted path, e.g. `conda-forge.yml.conda_build_tool`
+    pub field: String,
+    pub expected: String,
+    pub found: String,
+}
+
+/// Read an evenly spread sample of node_attrs files. Unreadable or invalid files are
+/// kept as `null`, so a change of file format shows up as drift too.
+pub fn sample_node_attrs(paths: &[&Path]) -> Vec<Value> {
+    let step = paths.len().div_ceil(SCHEMA_SAMPLE_SIZE).max(1);
+    paths
+        .iter()
+        .step_by(step)
+        .map(|path| {
+            fs::read_to_string(path)
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok())
+                .unwrap_or(Value::Null)
+        })
+        .collect()
+}
+
+/// Compare the sampled files against the fields the collector expects
+pub fn check_schema(samples: &[Value]) -> Vec<FieldDrift> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    EXPECTED_FIELDS
+        .iter()
+        .filter_map(|expected| {
+            // What each sampled file holds at the path instead
+            let mut kinds: BTreeMap<&str, usize> = BTreeMap::new();
+            for sample in samples {
+                let value = expected
+                    .path
+                    .iter()
+                    .try_fold(sample, |value, key| value.get(key));
+                *kinds.entry(value_kind(value)).or_default() += 1;
+            }
+
+            let matching = kinds.get(expected.kind.name()).copied().unwrap_or(0);
+            let share = matching as f64 / samples.len() as f64;
+            if share >= expected.min_share {
+                return None;
+            }
+
+            let mut found: Vec<_> = kinds.into_iter().collect();
+            found.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+            let found = found
+                .iter()
+                .map(|(kind, count)| {
+                    format!(
+                        "{} in {:.0}%",
+                        kind,
+                        *count as f64 * 100.0 / samples.len() as f64
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some(FieldDrift {
+                field: expected.path.join("."),
+                expected: format!(
+                    "{} in ≥{:.0}% of files",
+                    expected.kind.name(),
+                    expected.min_share * 100.0
+                ),
+                found,
+            })
+        })
+        .collect()
+}
+
+/// Diff of expected (`-`) against found (`+`) field shapes
+pub fn drift_report(drift: &[FieldDrift], sampled: usize) -> String {
+    let mut report = format!(
+        "cf-graph node_attrs changed shape ({} files sampled):",
+        sampled
+    );
+    for field in drift {
+        report.push_str(&format!("\n- {}: {}", field.field, field.expected));
+        report.push_str(&format!("\n+ {}: {}", field.field, field.found));
+    }
+    report
+}
+
+fn value_kind(value: Option<&Value>) -> &'static str {
+    match value {
+        None | Some(Value::Null) => "missing",
+        Some(Value::String(_)) => "string",
+        Some(Value::Object(map)) if map.get("elements").is_some_and(Value::is_array) => "set",
+        Some(Value::Object(_)) => "object",
+        Some(Value::Array(_)) => "array",
+        Some(Value::Bool(_)) => "bool",
+        Some(Value::Number(_)) => "number",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node(conda_build_tool: Option<&str>) -> Value {
+        let mut node = json!({
+            "feedstock_name": "numpy",
+            "conda-forge.yml": {},
+            "meta_yaml": {"package": {"name": "numpy"}},
+            "outputs_names": {"__set__": true, "elements": ["numpy"]},
+        });
+        if let Some(tool) = conda_build_tool {
+            node["conda-forge.yml"]["conda_build_tool"] = json!(tool);
+        }
+        node
+    }
+
+    #[test]
+    fn test_expected_shape_has_no_drift() {
+        let samples: Vec<_> = (0..10)
+            .map(|i| node((i == 0).then_some("rattler-build")))
+            .collect();
+        assert!(check_schema(&samples).is_empty());
+    }
+
+    #[test]
+    fn test_renamed_field_is_reported() {
+        let samples: Vec<_> = (0..10)
+            .map(|_| {
+                let mut node = node(None);
+                let yml = node.as_object_mut().unwrap().remove("conda-forge.yml");
+                node["conda_forge_yml"] = yml.unwrap();
+                node
+            })
+            .collect();
+
+        let drift = check_schema(&samples);
+        let fields: Vec<_> = drift.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["conda-forge.yml", "conda-forge.yml.conda_build_tool"]
+        );
+        assert_eq!(drift[0].found, "missing in 100%");
+
+        let report = drift_report(&drift, samples.len());
+        assert!(report.contains("- conda-forge.yml: object in ≥90% of files"));
+        assert!(report.contains("+ conda-forge.yml: missing in 100%"));
+    }
+
+    #[test]
+    fn test_changed_type_is_reported() {
+        let samples: Vec<_> = (0..4)
+            .map(|_| {
+                let mut node = node(Some("rattler-build"));
+                node["outputs_names"] = json!(["numpy"]);
+                node
+            })
+            .collect();
+
+        let drift = check_schema(&samples);
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].field, "outputs_names");
+        assert_eq!(drift[0].found, "array in 100%");
+    }
+}
@@ -0,0 +1,190 @@
+//! Sharded stats: `feedstock_states` split over `feedstock-states/<range>.toml` next to
+//! a small summary file listing the shards, so each file parses quickly and a run's
+//! diff is spread over files of a reviewable size.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use crate::models::{FeedstockEntry, FeedstockStats};
+use crate::stats::{write_atomically, write_feedstock_states};
+
+/// Directory of the shards, next to the summary file
+pub const SHARDS_DIR: &str = "feedstock-states";
+
+/// Shards by the first letter of the feedstock name, sized to hold a similar number of
+/// feedstocks. Names starting with anything else go to `OTHER_SHARD`.
+const SHARDS: &[(&str, char, char)] = &[
+    ("a-c", 'a', 'c'),
+    ("d-f", 'd', 'f'),
+    ("g-k", 'g', 'k'),
+    ("l-o", 'l', 'o'),
+    ("p", 'p', 'p'),
+    ("q-r", 'q', 'r'),
+    ("s-z", 's', 'z'),
+];
+const OTHER_SHARD: &str = "other";
+
+/// The summary's list of shard files, relative to it
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ShardList {
+    #[serde(default)]
+    feedstock_shards: Vec<String>,
+}
+
+/// Name of the shard holding `feedstock`
+pub fn shard_name(feedstock: &str) -> &'static str {
+    let first = feedstock
+        .chars()
+        .next()
+        .map(|c| c.to_ascii_lowercase())
+        .unwrap_or_default();
+    SHARDS
+        .iter()
+        .find(|(_, from, to)| (*from..=*to).contains(&first))
+        .map_or(OTHER_SHARD, |(name, _, _)| name)
+}
+
+/// Like `write_stats`, but with the states in shards next to `stats_path`. Every file
+/// is replaced atomically, and the summary last, so it only ever lists shards that are
+/// completely written. Shards that are no longer listed are removed.
+pub fn write_sharded_stats(
+    stats_path: &str,
+    stats: &FeedstockStats,
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> Result<()> {
+    anyhow::ensure!(
+        stats.feedstock_states.is_empty(),
+        "feedstock_states must be passed separately"
+    );
+
+    let mut shards: BTreeMap<&str, Vec<(&String, &FeedstockEntry)>> = BTreeMap::new();
+    for (name, entry) in feedstock_states {
+        shards
+            .entry(shard_name(name))
+            .or_default()
+            .push((name, entry));
+    }
+
+    let path = Path::new(stats_path);
+    let dir = path.with_file_name(SHARDS_DIR);
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let mut list = ShardList::default();
+    for (shard, states) in shards {
+        let file_name = format!("{}.toml", shard);
+        write_atomically(&dir.join(&file_name), |writer| {
+            write_feedstock_states(writer, states)
+        })?;
+        list.feedstock_shards
+            .push(format!("{}/{}", SHARDS_DIR, file_name));
+    }
+
+    write_atomically(path, |writer| {
+        writer.write_all(toml::to_string(&list)?.as_bytes())?;
+        writer.write_all(toml::to_string_pretty(stats)?.as_bytes())?;
+        Ok(())
+    })?;
+
+    let listed: HashSet<_> = list
+        .feedstock_shards
+        .iter()
+        .map(|file| path.with_file_name(file))
+        .collect();
+    for file in fs::read_dir(&dir)? {
+        let file = file?.path();
+        if file.extension().is_some_and(|ext| ext == "toml") && !listed.contains(&file) {
+            fs::remove_file(&file)
+                .with_context(|| format!("Failed to remove {}", file.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// The raw states in the shards listed by the `summary` of `stats_path`, empty if it
+/// lists none. They are left unparsed so they can be migrated along with the summary.
+pub fn load_feedstock_shards(stats_path: &Path, summary: &toml::Table) -> Result<toml::Table> {
+    let list: ShardList = summary.clone().try_into()?;
+    let mut feedstock_states = toml::Table::new();
+    for file in list.feedstock_shards {
+        let path = stats_path.with_file_name(&file);
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut shard: toml::Table = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        if let Some(toml::Value::Table(states)) = shard.remove("feedstock_states") {
+            feedstock_states.extend(states);
+        }
+    }
+    Ok(feedstock_states)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RecipeType;
+    use crate::stats::{load_existing_stats, write_stats};
+
+    fn entry(recipe_type: RecipeType) -> FeedstockEntry {
+        FeedstockEntry::test(recipe_type)
+    }
+
+    #[test]
+    fn test_shard_name() {
+        assert_eq!(shard_name("numpy-feedstock"), "l-o");
+        assert_eq!(shard_name("python-feedstock"), "p");
+        assert_eq!(shard_name("Ipython-feedstock"), "g-k");
+        assert_eq!(shard_name("zstd-feedstock"), "s-z");
+        assert_eq!(shard_name("_r-mutex-feedstock"), OTHER_SHARD);
+        assert_eq!(shard_name("7zip-feedstock"), OTHER_SHARD);
+    }
+
+    #[test]
+    fn test_sharded_stats_round_trip() {
+        let stats: FeedstockStats = toml::from_str(
+            r#"
+            total_feedstocks = 3
+            recipe_v1_count = 1
+            meta_yaml_count = 2
+            unknown_count = 0
+            last_updated = "2025-01-01T00:00:00+00:00"
+            "#,
+        )
+        .unwrap();
+        let mut states = BTreeMap::from([
+            ("numpy-feedstock".to_string(), entry(RecipeType::RecipeV1)),
+            ("nump.y-feedstock".to_string(), entry(RecipeType::MetaYaml)),
+            (
+                "_r-mutex-feedstock".to_string(),
+                entry(RecipeType::MetaYaml),
+            ),
+        ]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("feedstock-stats.toml");
+        let path = path.to_str().unwrap();
+        write_sharded_stats(path, &stats, &states).unwrap();
+        let shards = dir.path().join(SHARDS_DIR);
+        assert!(shards.join("l-o.toml").is_file());
+        assert!(shards.join("other.toml").is_file());
+
+        let loaded = load_existing_stats(path).unwrap();
+        assert_eq!(loaded.feedstock_states.len(), 3);
+        assert_eq!(
+            loaded.feedstock_states["numpy-feedstock"].recipe_type,
+            RecipeType::RecipeV1
+        );
+
+        // A shard that ends up empty is removed
+        states.remove("_r-mutex-feedstock");
+        write_sharded_stats(path, &stats, &states).unwrap();
+        assert!(!shards.join("other.toml").exists());
+        assert_eq!(load_existing_stats(path).unwrap().feedstock_states.len(), 2);
+
+        // Unsharded stats have their states inline
+        write_stats(path, &stats, &states).unwrap();
+        assert_eq!(load_existing_stats(path).unwrap().feedstock_states.len(), 2);
+    }
+}
@@ -0,0 +1,385 @@
+//! The SQLite stats store, built with the `sqlite` feature, see `StatsStore::Sqlite`
+
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OptionalExtension};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::models::{migrate_stats, FeedstockEntry, FeedstockStats, STATS_FORMAT_VERSION};
+use crate::stats::serde_name;
+
+/// Tables of a SQLite store. `summary` holds everything but the feedstock states, as
+/// JSON. Deleting a feedstock deletes its attribution and download count.
+const SCHEMA: &str = "
+    PRAGMA foreign_keys = ON;
+    CREATE TABLE IF NOT EXISTS summary (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        stats TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS feedstocks (
+        name TEXT PRIMARY KEY,
+        recipe_type TEXT NOT NULL,
+        last_changed TEXT NOT NULL,
+        entry TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS attributions (
+        feedstock TEXT PRIMARY KEY REFERENCES feedstocks (name) ON DELETE CASCADE,
+        contribution_type TEXT NOT NULL,
+        contributors TEXT NOT NULL,
+        date TEXT NOT NULL,
+        commit_sha TEXT
+    );
+    CREATE TABLE IF NOT EXISTS download_counts (
+        feedstock TEXT PRIMARY KEY REFERENCES feedstocks (name) ON DELETE CASCADE,
+        downloads INTEGER NOT NULL
+    );
+";
+
+/// The upserts skip rows that didn't change, so they aren't rewritten
+const UPSERT_FEEDSTOCK: &str = "
+    INSERT INTO feedstocks (name, recipe_type, last_changed, entry) VALUES (?1, ?2, ?3, ?4)
+    ON CONFLICT (name) DO UPDATE SET
+        recipe_type = excluded.recipe_type,
+        last_changed = excluded.last_changed,
+        entry = excluded.entry
+    WHERE feedstocks.entry IS NOT excluded.entry
+";
+
+const UPSERT_ATTRIBUTION: &str = "
+    INSERT INTO attributions (feedstock, contribution_type, contributors, date, commit_sha)
+    VALUES (?1, ?2, ?3, ?4, ?5)
+    ON CONFLICT (feedstock) DO UPDATE SET
+        contribution_type = excluded.contribution_type,
+        contributors = excluded.contributors,
+        date = excluded.date,
+        commit_sha = excluded.commit_sha
+    WHERE (attributions.contribution_type, attributions.contributors, attributions.date,
+           attributions.commit_sha)
+        IS NOT (excluded.contribution_type, excluded.contributors, excluded.date,
+                excluded.commit_sha)
+";
+
+const UPSERT_DOWNLOADS: &str = "
+    INSERT INTO download_counts (feedstock, downloads) VALUES (?1, ?2)
+    ON CONFLICT (feedstock) DO UPDATE SET downloads = excluded.downloads
+    WHERE download_counts.downloads IS NOT excluded.downloads
+";
+
+fn open(path: &Path) -> Result<Connection> {
+    let conn =
+        Connection::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    conn.execute_batch(SCHEMA)?;
+    Ok(conn)
+}
+
+pub(crate) fn load_sqlite(path: &Path) -> Result<FeedstockStats> {
+    // Opening would create an empty database
+    anyhow::ensure!(path.exists(), "{} doesn't exist", path.display());
+    let conn = open(path)?;
+    let summary: String = conn
+        .query_row("SELECT stats FROM summary WHERE id = 1", [], |row| {
+            row.get(0)
+        })
+        .optional()?
+        .with_context(|| format!("No stats saved in {}", path.display()))?;
+
+    // The rows are put back together as the TOML file would have them, so databases
+    // from older collectors go through the same migrations
+    let mut stats = json_table(&summary).context("Failed to parse the saved summary")?;
+    let mut feedstock_states = toml::Table::new();
+    let mut query = conn.prepare("SELECT name, entry FROM feedstocks")?;
+    let mut rows = query.query([])?;
+    while let Some(row) = rows.next()? {
+        let (name, entry): (String, String) = (row.get(0)?, row.get(1)?);
+        let entry =
+            json_table(&entry).with_context(|| format!("Failed to parse the entry of {}", name))?;
+        feedstock_states.insert(name, entry.into());
+    }
+
+    let mut query = conn.prepare(
+        "SELECT feedstock, contribution_type, contributors, date, commit_sha FROM attributions",
+    )?;
+    let mut rows = query.query([])?;
+    while let Some(row) = rows.next()? {
+        let Some(entry) = feedstock_states
+            .get_mut(&row.get::<_, String>(0)?)
+            .and_then(|entry| entry.as_table_mut())
+        else {
+            continue;
+        };
+        let contributors: Vec<String> = serde_json::from_str(&row.get::<_, String>(2)?)?;
+        let mut attribution = toml::Table::from_iter([
+            (
+                "contribution_type".to_string(),
+                row.get::<_, String>(1)?.into(),
+            ),
+            ("contributors".to_string(), contributors.into()),
+            ("date".to_string(), row.get::<_, String>(3)?.into()),
+        ]);
+        if let Some(commit_sha) = row.get::<_, Option<String>>(4)? {
+            attribution.insert("commit_sha".to_string(), commit_sha.into());
+        }
+        entry.insert("attribution".to_string(), attribution.into());
+    }
+
+    let mut query = conn.prepare("SELECT feedstock, downloads FROM download_counts")?;
+    let mut rows = query.query([])?;
+    while let Some(row) = rows.next()? {
+        if let Some(entry) = feedstock_states
+            .get_mut(&row.get::<_, String>(0)?)
+            .and_then(|entry| entry.as_table_mut())
+        {
+            entry.insert("downloads".to_string(), row.get::<_, i64>(1)?.into());
+        }
+    }
+    stats.insert("feedstock_states".to_string(), feedstock_states.into());
+
+    let version = migrate_stats(&mut stats)?;
+    if version < STATS_FORMAT_VERSION {
+        println!(
+            "⬆️  Migrated stats from format version {} to {}",
+            version, STATS_FORMAT_VERSION
+        );
+    }
+    Ok(stats.try_into()?)
+}
+
+/// Parse a JSON object saved in the database as a TOML table
+fn json_table(json: &str) -> Result<toml::Table> {
+    match json_to_toml(serde_json::from_str(json)?) {
+        Some(toml::Value::Table(table)) => Ok(table),
+        _ => anyhow::bail!("Expected a JSON object"),
+    }
+}
+
+/// TOML has no null, fields that are null are left out, which deserializes the same
+fn json_to_toml(value: serde_json::Value) -> Option<toml::Value> {
+    Some(match value {
+        serde_json::Value::Null => return None,
+        serde_json::Value::Bool(b) => b.into(),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into(),
+            None => n.as_f64()?.into(),
+        },
+        serde_json::Value::String(s) => s.into(),
+        serde_json::Value::Array(values) => values
+            .into_iter()
+            .filter_map(json_to_toml)
+            .collect::<Vec<_>>()
+            .into(),
+        serde_json::Value::Object(fields) => fields
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, json_to_toml(value)?)))
+            .collect::<toml::Table>()
+            .into(),
+    })
+}
+
+/// Upsert everything in one transaction, so an interrupted save leaves the previous
+/// stats intact. Returns how many rows were written.
+pub(crate) fn save_sqlite(
+    path: &Path,
+    stats: &FeedstockStats,
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> Result<usize> {
+    anyhow::ensure!(
+        stats.feedstock_states.is_empty(),
+        "feedstock_states must be passed separately"
+    );
+
+    let mut conn = open(path)?;
+    let tx = conn.transaction()?;
+    let mut written = tx.execute(
+        "INSERT INTO summary (id, stats) VALUES (1, ?1)
+         ON CONFLICT (id) DO UPDATE SET stats = excluded.stats
+         WHERE summary.stats IS NOT excluded.stats",
+        [serde_json::to_string(stats)?],
+    )?;
+    {
+        let stored: Vec<String> = tx
+            .prepare("SELECT name FROM feedstocks")?
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        let mut delete = tx.prepare("DELETE FROM feedstocks WHERE name = ?1")?;
+        for name in stored
+            .iter()
+            .filter(|name| !feedstock_states.contains_key(*name))
+        {
+            written += delete.execute([name])?;
+        }
+
+        let mut upsert_feedstock = tx.prepare(UPSERT_FEEDSTOCK)?;
+        let mut upsert_attribution = tx.prepare(UPSERT_ATTRIBUTION)?;
+        let mut delete_attribution = tx.prepare("DELETE FROM attributions WHERE feedstock = ?1")?;
+        let mut upsert_downloads = tx.prepare(UPSERT_DOWNLOADS)?;
+        let mut delete_downloads =
+            tx.prepare("DELETE FROM download_counts WHERE feedstock = ?1")?;
+        for (name, entry) in feedstock_states {
+            // The attribution and download count are kept in their own tables
+            let mut row = serde_json::to_value(entry)?;
+            if let Some(fields) = row.as_object_mut() {
+                fields.remove("attribution");
+                fields.remove("downloads");
+            }
+            written += upsert_feedstock.execute((
+                name,
+                serde_name(&entry.recipe_type),
+                &entry.last_changed,
+                row.to_string(),
+            ))?;
+
+            written += match &entry.attribution {
+                Some(attribution) => upsert_attribution.execute((
+                    name,
+                    serde_name(&attribution.contribution_type),
+                    serde_json::to_string(&attribution.contributors)?,
+                    &attribution.date,
+                    &attribution.commit_sha,
+                ))?,
+                None => delete_attribution.execute([name])?,
+            };
+            written += match entry.downloads {
+                Some(downloads) => upsert_downloads.execute((name, downloads as i64))?,
+                None => delete_downloads.execute([name])?,
+            };
+        }
+    }
+    tx.commit()?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Attribution, ContributionType, RecipeType};
+    use crate::stats::StatsStore;
+
+    fn entry(recipe_type: RecipeType, downloads: Option<u64>) -> FeedstockEntry {
+        FeedstockEntry {
+            attribution: Some(Attribution {
+                contribution_type: ContributionType::Conversion,
+                contributors: vec!["alice".to_string(), "bob".to_string()],
+                date: "2025-01-01T00:00:00Z".to_string(),
+                commit_sha: Some("abc123".to_string()),
+            }),
+            downloads,
+            maintainers: vec!["alice".to_string()],
+            summary: Some("A package".to_string()),
+            recipe_features: vec!["multiple-outputs".to_string()],
+            ..FeedstockEntry::test(recipe_type)
+        }
+    }
+
+    #[test]
+    fn test_parse_sqlite_store() {
+        assert_eq!(
+            "sqlite://stats.db".parse::<StatsStore>().unwrap(),
+            StatsStore::Sqlite("stats.db".into())
+        );
+        assert_eq!(
+            StatsStore::Sqlite("stats.db".into()).to_string(),
+            "sqlite://stats.db"
+        );
+    }
+
+    #[test]
+    fn test_sqlite_store_upserts() {
+        let stats: FeedstockStats = toml::from_str(
+            r#"
+            stats_format_version = 3
+            total_feedstocks = 3
+            recipe_v1_count = 2
+            meta_yaml_count = 1
+            unknown_count = 0
+            last_updated = "2025-01-01T00:00:00+00:00"
+            "#,
+        )
+        .unwrap();
+        let mut states = BTreeMap::from([
+            (
+                "foo-feedstock".to_string(),
+                entry(RecipeType::RecipeV1, Some(42)),
+            ),
+            (
+                "bar-feedstock".to_string(),
+                entry(RecipeType::RecipeV1, None),
+            ),
+            (
+                "baz-feedstock".to_string(),
+                entry(RecipeType::MetaYaml, Some(7)),
+            ),
+        ]);
+        states.get_mut("baz-feedstock").unwrap().attribution = None;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.db");
+        let store = StatsStore::Sqlite(path.clone());
+        assert!(store.load().is_err());
+        // summary, 3 feedstocks, 2 attributions and 2 download counts
+        assert_eq!(save_sqlite(&path, &stats, &states).unwrap(), 8);
+        assert_eq!(save_sqlite(&path, &stats, &states).unwrap(), 0);
+
+        let loaded = store.load().unwrap();
+        assert_eq!(loaded.total_feedstocks, 3);
+        assert_eq!(loaded.feedstock_states.len(), 3);
+        let foo = &loaded.feedstock_states["foo-feedstock"];
+        assert_eq!(foo.downloads, Some(42));
+        assert_eq!(foo.recipe_features, ["multiple-outputs"]);
+        let attribution = foo.attribution.as_ref().unwrap();
+        assert_eq!(attribution.contributors, ["alice", "bob"]);
+        assert_eq!(attribution.commit_sha.as_deref(), Some("abc123"));
+        assert!(loaded.feedstock_states["baz-feedstock"]
+            .attribution
+            .is_none());
+        assert_eq!(loaded.feedstock_states["bar-feedstock"].downloads, None);
+
+        // Only the changed download count and the removed feedstock are written
+        states.remove("bar-feedstock");
+        states.get_mut("foo-feedstock").unwrap().downloads = Some(43);
+        assert_eq!(save_sqlite(&path, &stats, &states).unwrap(), 2);
+        let loaded = store.load().unwrap();
+        assert_eq!(loaded.feedstock_states.len(), 2);
+        assert_eq!(loaded.feedstock_states["foo-feedstock"].downloads, Some(43));
+
+        let conn = Connection::open(&path).unwrap();
+        let attributions: i64 = conn
+            .query_row("SELECT COUNT(*) FROM attributions", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(attributions, 1);
+    }
+
+    #[test]
+    fn test_sqlite_store_migrates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.db");
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(SCHEMA).unwrap();
+        // Saved by a collector from before `recipe_v1_count` included non-standard files
+        conn.execute(
+            "INSERT INTO summary (id, stats) VALUES (1, ?1)",
+            [
+                r#"{"schema_version": 1, "total_feedstocks": 2, "recipe_v1_count": 1,
+                "non_standard_v1_count": 1, "meta_yaml_count": 0, "unknown_count": 0,
+                "last_updated": "2025-01-01T00:00:00+00:00", "feedstock_states": {}}"#,
+            ],
+        )
+        .unwrap();
+        drop(conn);
+
+        let loaded = StatsStore::Sqlite(path.clone()).load().unwrap();
+        assert_eq!(loaded.stats_format_version, STATS_FORMAT_VERSION);
+        assert_eq!(loaded.recipe_v1_count, 2);
+
+        let conn = Connection::open(&path).unwrap();
+        conn.execute(
+            "UPDATE summary SET stats = ?1",
+            [format!(
+                r#"{{"stats_format_version": {}}}"#,
+                STATS_FORMAT_VERSION + 1
+            )],
+        )
+        .unwrap();
+        drop(conn);
+        assert!(StatsStore::Sqlite(path).load().is_err());
+    }
+}
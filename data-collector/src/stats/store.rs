@@ -0,0 +1,127 @@
+//! Where the stats are kept: feedstock-stats.toml, rewritten as a whole on every save,
+//! or a SQLite database with a row per feedstock, attribution and download count that
+//! is upserted in place, so a checkpoint only writes the feedstocks that changed. The
+//! SQLite store is in `sqlite_store`, built with the `sqlite` feature.
+
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::models::{FeedstockEntry, FeedstockStats};
+#[cfg(feature = "sqlite")]
+use crate::stats::sqlite_store::{load_sqlite, save_sqlite};
+use crate::stats::{load_existing_stats, write_sharded_stats, write_stats};
+
+/// Stats storage backend, see `--store`
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatsStore {
+    /// feedstock-stats.toml
+    Toml(PathBuf),
+    /// A summary TOML file with the feedstock states in shards next to it, given as
+    /// `sharded://feedstock-stats.toml`, see `write_sharded_stats`
+    Sharded(PathBuf),
+    /// SQLite database, given as `sqlite://stats.db`
+    #[cfg(feature = "sqlite")]
+    Sqlite(PathBuf),
+}
+
+impl FromStr for StatsStore {
+    type Err = anyhow::Error;
+
+    /// `sqlite://PATH`, `sharded://PATH`, `toml://PATH`, or a plain path to a TOML file
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once("://") {
+            #[cfg(feature = "sqlite")]
+            Some(("sqlite", path)) => Ok(Self::Sqlite(path.into())),
+            #[cfg(not(feature = "sqlite"))]
+            Some(("sqlite", _)) => {
+                anyhow::bail!("SQLite stores need a collector built with `--features sqlite`")
+            }
+            Some(("toml", path)) => Ok(Self::Toml(path.into())),
+            Some(("sharded", path)) => Ok(Self::Sharded(path.into())),
+            Some((scheme, _)) => anyhow::bail!(
+                "Unknown stats store {}://, expected sqlite://, sharded:// or toml://",
+                scheme
+            ),
+            None => Ok(Self::Toml(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for StatsStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Toml(path) => write!(f, "{}", path.display()),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(path) => write!(f, "sqlite://{}", path.display()),
+            Self::Sharded(path) => write!(f, "sharded://{}", path.display()),
+        }
+    }
+}
+
+impl StatsStore {
+    /// The file the stats are kept in
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Toml(path) | Self::Sharded(path) => path,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(path) => path,
+        }
+    }
+
+    pub fn load(&self) -> Result<FeedstockStats> {
+        match self {
+            // Sharded stats are recognized by their summary
+            Self::Toml(path) | Self::Sharded(path) => load_existing_stats(&path.to_string_lossy()),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(path) => {
+                println!("🔍 Loading stats from: {}", self);
+                let stats = load_sqlite(path)?;
+                println!(
+                    "📂 Loaded existing stats: {} total feedstocks, {} feedstock_states entries",
+                    stats.total_feedstocks,
+                    stats.feedstock_states.len()
+                );
+                Ok(stats)
+            }
+        }
+    }
+
+    /// Save `stats`, with `feedstock_states` passed separately like for `write_stats`
+    pub fn save(
+        &self,
+        stats: &FeedstockStats,
+        feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    ) -> Result<()> {
+        match self {
+            Self::Toml(path) => write_stats(&path.to_string_lossy(), stats, feedstock_states),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(path) => save_sqlite(path, stats, feedstock_states).map(|_| ()),
+            Self::Sharded(path) => {
+                write_sharded_stats(&path.to_string_lossy(), stats, feedstock_states)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_store() {
+        assert_eq!(
+            "feedstock-stats.toml".parse::<StatsStore>().unwrap(),
+            StatsStore::Toml("feedstock-stats.toml".into())
+        );
+        assert_eq!(
+            "sharded://feedstock-stats.toml"
+                .parse::<StatsStore>()
+                .unwrap(),
+            StatsStore::Sharded("feedstock-stats.toml".into())
+        );
+        assert!("postgres://localhost".parse::<StatsStore>().is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! `summary`: the headline numbers of the existing stats, for a quick look without
+//! running the collector.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+use crate::models::{ContributionType, FeedstockStats};
+
+/// Contributors listed in the summary
+const TOP_CONTRIBUTORS: usize = 10;
+
+/// Conversions are counted over each of these many past days
+const CONVERSION_WINDOWS_DAYS: [i64; 2] = [7, 30];
+
+/// Lines describing `stats` as of `now`
+pub fn summary_lines(stats: &FeedstockStats, now: DateTime<Utc>) -> Vec<String> {
+    let percentage = |count: u32| {
+        if stats.total_feedstocks == 0 {
+            0.0
+        } else {
+            count as f64 * 100.0 / stats.total_feedstocks as f64
+        }
+    };
+    let mut lines = vec![
+        format!("📅 Last updated: {}", stats.last_updated),
+        format!("📊 Total feedstocks: {}", stats.total_feedstocks),
+        format!(
+            "📝 Recipe v1: {} ({:.1}%)",
+            stats.recipe_v1_count,
+            percentage(stats.recipe_v1_count)
+        ),
+    ];
+    if stats.non_standard_v1_count > 0 {
+        lines.push(format!(
+            "📛 Of which with a non-standard filename: {}",
+            stats.non_standard_v1_count
+        ));
+    }
+    lines.push(format!(
+        "📄 meta.yaml: {} ({:.1}%)",
+        stats.meta_yaml_count,
+        percentage(stats.meta_yaml_count)
+    ));
+    lines.push(format!("❓ Unknown: {}", stats.unknown_count));
+
+    // Conversion dates of feedstocks still on Recipe v1
+    let conversions: Vec<DateTime<Utc>> = stats
+        .feedstock_states
+        .values()
+        .filter(|entry| entry.recipe_type.is_v1())
+        .filter_map(|entry| entry.attribution.as_ref())
+        .filter(|attribution| attribution.contribution_type == ContributionType::Conversion)
+        .filter_map(|attribution| DateTime::parse_from_rfc3339(&attribution.date).ok())
+        .map(|date| date.with_timezone(&Utc))
+        .collect();
+    for days in CONVERSION_WINDOWS_DAYS {
+        let since = now - Duration::days(days);
+        let count = conversions.iter().filter(|date| **date > since).count();
+        lines.push(format!(
+            "🔄 Conversions in the last {} days: {} ({:.1} per day)",
+            days,
+            count,
+            count as f64 / days as f64
+        ));
+    }
+
+    // Attributions of feedstocks still on Recipe v1 per contributor, matching logins
+    // case-insensitively. "unknown" is credited when nobody could be found.
+    let mut contributors: HashMap<String, (&str, u32, u32)> = HashMap::new();
+    for attribution in stats
+        .feedstock_states
+        .values()
+        .filter(|entry| entry.recipe_type.is_v1())
+        .filter_map(|entry| entry.attribution.as_ref())
+    {
+        for login in attribution.contributors.iter().filter(|c| *c != "unknown") {
+            let counts = contributors
+                .entry(login.to_lowercase())
+                .or_insert((login, 0, 0));
+            match attribution.contribution_type {
+                ContributionType::Conversion => counts.1 += 1,
+                ContributionType::NewFeedstock => counts.2 += 1,
+            }
+        }
+    }
+    let mut top: Vec<(&str, u32, u32)> = contributors.into_values().collect();
+    top.sort_by(|a, b| (b.1 + b.2).cmp(&(a.1 + a.2)).then(a.0.cmp(b.0)));
+    if !top.is_empty() {
+        lines.push("🏆 Top contributors:".to_string());
+    }
+    for (rank, (login, conversions, new_feedstocks)) in
+        top.into_iter().take(TOP_CONTRIBUTORS).enumerate()
+    {
+        lines.push(format!(
+            "   {}. {}: {} conversions, {} new feedstocks",
+            rank + 1,
+            login,
+            conversions,
+            new_feedstocks
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_lines() {
+        let stats: FeedstockStats = toml::from_str(
+            r#"
+            total_feedstocks = 4
+            recipe_v1_count = 3
+            meta_yaml_count = 1
+            unknown_count = 0
+            last_updated = "2025-06-30T00:00:00+00:00"
+
+            [feedstock_states.numpy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-06-28T00:00:00Z"
+            attribution = { contribution_type = "conversion", contributors = ["Alice"], date = "2025-06-28T00:00:00Z" }
+
+            [feedstock_states.scipy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-06-10T00:00:00Z"
+            attribution = { contribution_type = "conversion", contributors = ["alice", "bob"], date = "2025-06-10T00:00:00Z" }
+
+            [feedstock_states.new-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-06-29T00:00:00Z"
+            attribution = { contribution_type = "new_feedstock", contributors = ["carol"], date = "2025-06-29T00:00:00Z" }
+
+            [feedstock_states.reverted-feedstock]
+            recipe_type = "meta_yaml"
+            last_changed = "2025-06-29T00:00:00Z"
+            attribution = { contribution_type = "conversion", contributors = ["bob"], date = "2025-06-29T00:00:00Z" }
+
+            [feedstock_states.orphan-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-05-01T00:00:00Z"
+            attribution = { contribution_type = "conversion", contributors = ["unknown"], date = "2025-05-01T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+        let now = DateTime::parse_from_rfc3339("2025-06-30T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            summary_lines(&stats, now),
+            vec![
+                "📅 Last updated: 2025-06-30T00:00:00+00:00",
+                "📊 Total feedstocks: 4",
+                "📝 Recipe v1: 3 (75.0%)",
+                "📄 meta.yaml: 1 (25.0%)",
+                "❓ Unknown: 0",
+                "🔄 Conversions in the last 7 days: 1 (0.1 per day)",
+                "🔄 Conversions in the last 30 days: 2 (0.1 per day)",
+                "🏆 Top contributors:",
+                "   1. Alice: 2 conversions, 0 new feedstocks",
+                "   2. bob: 1 conversions, 0 new feedstocks",
+                "   3. carol: 0 conversions, 1 new feedstocks",
+            ]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! `validate`: check the invariants of feedstock-stats.toml that the site and the
+//! crunch step rely on, so a hand edit or a broken run is caught before publishing.
+
+use chrono::DateTime;
+
+use crate::models::{FeedstockStats, RecipeType};
+
+/// Everything wrong with `stats`, one line per problem; empty if it is consistent
+pub fn validate_stats(stats: &FeedstockStats) -> Vec<String> {
+    let mut problems = Vec::new();
+    let states = &stats.feedstock_states;
+
+    let count = |recipe_type: RecipeType| {
+        states
+            .values()
+            .filter(|entry| entry.recipe_type == recipe_type)
+            .count()
+    };
+    // Recipe v1 includes the non-standard filenames
+    let recipe_v1 = states
+        .values()
+        .filter(|entry| entry.recipe_type.is_v1())
+        .count();
+    for (field, stored, actual) in [
+        ("total_feedstocks", stats.total_feedstocks, states.len()),
+        ("recipe_v1_count", stats.recipe_v1_count, recipe_v1),
+        (
+            "meta_yaml_count",
+            stats.meta_yaml_count,
+            count(RecipeType::MetaYaml),
+        ),
+        (
+            "unknown_count",
+            stats.unknown_count,
+            count(RecipeType::Unknown),
+        ),
+        (
+            "non_standard_v1_count",
+            stats.non_standard_v1_count,
+            count(RecipeType::NonStandardV1),
+        ),
+    ] {
+        if stored as usize != actual {
+            problems.push(format!(
+                "{} is {} but feedstock_states has {}",
+                field, stored, actual
+            ));
+        }
+    }
+
+    let mut check_date = |location: &str, date: &str| {
+        if DateTime::parse_from_rfc3339(date).is_err() {
+            problems.push(format!("{}: {:?} is not an RFC 3339 date", location, date));
+        }
+    };
+    check_date("last_updated", &stats.last_updated);
+    for (name, entry) in states {
+        check_date(&format!("{}.last_changed", name), &entry.last_changed);
+        if let Some(attribution) = &entry.attribution {
+            check_date(&format!("{}.attribution.date", name), &attribution.date);
+        }
+        if let Some(cache) = &entry.recipe_commit_cache {
+            check_date(&format!("{}.recipe_commit_cache.date", name), &cache.date);
+        }
+        if let Some(revert) = &entry.revert {
+            check_date(
+                &format!("{}.revert.converted_at", name),
+                &revert.converted_at,
+            );
+            check_date(&format!("{}.revert.reverted_at", name), &revert.reverted_at);
+        }
+    }
+
+    for (name, entry) in states {
+        if let Some(attribution) = &entry.attribution {
+            if attribution.contributors.is_empty()
+                || attribution.contributors.iter().any(|c| c.trim().is_empty())
+            {
+                problems.push(format!("{}: attribution has an empty contributor", name));
+            }
+        }
+        if let Some(cache) = &entry.recipe_commit_cache {
+            if !is_commit_sha(&cache.sha) {
+                problems.push(format!(
+                    "{}: recipe_commit_cache.sha {:?} is not a commit SHA",
+                    name, cache.sha
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
+/// A full, lowercase hex SHA-1 as GitHub reports it
+fn is_commit_sha(sha: &str) -> bool {
+    sha.len() == 40 && sha.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_stats() {
+        let mut stats: FeedstockStats = toml::from_str(
+            r#"
+            total_feedstocks = 3
+            recipe_v1_count = 2
+            non_standard_v1_count = 1
+            meta_yaml_count = 1
+            unknown_count = 0
+            last_updated = "2025-01-01T00:00:00+00:00"
+
+            [feedstock_states.numpy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-01-01T00:00:00Z"
+
+            [feedstock_states.numpy-feedstock.attribution]
+            contribution_type = "conversion"
+            contributors = ["alice"]
+            date = "2025-01-01T00:00:00Z"
+
+            [feedstock_states.numpy-feedstock.recipe_commit_cache]
+            sha = "0123456789abcdef0123456789abcdef01234567"
+            message = "Convert to recipe v1"
+            date = "2025-01-01T00:00:00Z"
+            author_name = "Alice"
+            author_email = "alice@example.com"
+
+            [feedstock_states.scipy-feedstock]
+            recipe_type = "meta_yaml"
+            last_changed = "2025-01-01T00:00:00Z"
+
+            [feedstock_states.pandas-feedstock]
+            recipe_type = "non_standard_v1"
+            last_changed = "2025-01-01T00:00:00Z"
+            recipe_file = "recipe/recipe.yml"
+            "#,
+        )
+        .unwrap();
+        assert!(validate_stats(&stats).is_empty());
+
+        stats.recipe_v1_count = 1;
+        stats.last_updated = "yesterday".to_string();
+        let numpy = stats.feedstock_states.get_mut("numpy-feedstock").unwrap();
+        numpy.attribution.as_mut().unwrap().contributors.clear();
+        numpy.recipe_commit_cache.as_mut().unwrap().sha = "abc123".to_string();
+
+        let problems = validate_stats(&stats);
+        assert_eq!(problems.len(), 4, "{:#?}", problems);
+        assert!(problems[0].starts_with("recipe_v1_count is 1"));
+        assert!(problems[1].starts_with("last_updated"));
+        assert!(problems[2].contains("empty contributor"));
+        assert!(problems[3].contains("abc123"));
+    }
+}
@@ -0,0 +1,414 @@
+//! Outbound webhooks: POST a JSON event for each newly detected conversion to the
+//! configured URLs, for downstream automation such as thank-you bots.
+//!
+//! Each event is a JSON object with `event` ("recipe_v1_conversion"), `feedstock`,
+//! `contributors` (GitHub logins), `date` (RFC 3339) and `pull_request` /
+//! `pull_request_url` when the conversion PR is known.
+
+use anyhow::Result;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::external::GitHubClient;
+use crate::models::{ContributionType, FeedstockEntry};
+
+/// Give up on a webhook endpoint after this long
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Hex digits of a URL's hash kept in `unannounced_conversions`
+const URL_KEY_LENGTH: usize = 16;
+
+/// Payload POSTed for each conversion
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ConversionEvent {
+    pub event: &'static str,
+    pub feedstock: String,
+    pub contributors: Vec<String>,
+    /// Attribution date of the conversion
+    pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull_request: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull_request_url: Option<String>,
+}
+
+/// Result of announcing the queued conversions
+#[derive(Debug, Default, PartialEq)]
+pub struct WebhookDeliveries {
+    /// Events every URL accepted
+    pub delivered: usize,
+    /// Feedstocks to announce again on the next run, not attributed yet or not
+    /// accepted by every URL, with the keys of the URLs that accepted them
+    pub unannounced: BTreeMap<String, Vec<String>>,
+}
+
+/// Events for `feedstocks` that are still on Recipe v1, were converted rather than
+/// created with a recipe.yaml, and are attributed to someone
+pub fn conversion_events(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    feedstocks: &[String],
+) -> Vec<ConversionEvent> {
+    feedstocks
+        .iter()
+        .filter_map(|name| {
+            let entry = feedstock_states.get(name)?;
+            if !entry.recipe_type.is_v1() {
+                return None;
+            }
+            let attribution = entry.attribution.as_ref()?;
+            if attribution.contribution_type != ContributionType::Conversion
+                || attribution.contributors.is_empty()
+            {
+                return None;
+            }
+            Some(ConversionEvent {
+                event: "recipe_v1_conversion",
+                feedstock: name.clone(),
+                contributors: attribution.contributors.clone(),
+                date: attribution.date.clone(),
+                pull_request: None,
+                pull_request_url: None,
+            })
+        })
+        .collect()
+}
+
+/// Recipe v1 feedstocks among `feedstocks` that have no usable attribution yet
+fn awaiting_attribution(
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+    feedstocks: &[String],
+) -> Vec<String> {
+    feedstocks
+        .iter()
+        .filter(|name| {
+            feedstock_states.get(*name).is_some_and(|entry| {
+                entry.recipe_type.is_v1()
+                    && entry
+                        .attribution
+                        .as_ref()
+                        .is_none_or(|attribution| attribution.contributors.is_empty())
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// What a webhook URL is remembered by in `unannounced_conversions`: a short hash, as
+/// the stats are public and webhook URLs often contain tokens
+pub fn webhook_url_key(url: &str) -> String {
+    format!("{:x}", Sha256::digest(url))[..URL_KEY_LENGTH].to_string()
+}
+
+/// The host of `url`, to report failures without leaking tokens in its path or query
+fn url_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(String::from))
+        .unwrap_or_else(|| "an invalid URL".to_string())
+}
+
+/// Fill in the PR that introduced each conversion commit. Events stay without a PR
+/// when GitHub is not available.
+async fn find_pull_requests(
+    events: &mut [ConversionEvent],
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) {
+    let commits: Vec<(&str, &str)> = events
+        .iter()
+        .filter_map(|event| {
+            let sha = feedstock_states
+                .get(&event.feedstock)?
+                .attribution
+                .as_ref()?
+                .commit_sha
+                .as_deref()?;
+            Some((event.feedstock.as_str(), sha))
+        })
+        .collect();
+    if commits.is_empty() {
+        return;
+    }
+    let prs = match GitHubClient::new() {
+        Ok(client) => client.batch_query_prs_for_commits(&commits).await,
+        Err(e) => Err(e),
+    };
+    let prs = match prs {
+        Ok(prs) => prs,
+        Err(e) => {
+            println!("⚠️  Could not look up conversion PRs for webhooks: {}", e);
+            return;
+        }
+    };
+    for event in events.iter_mut() {
+        if let Some(pr) = prs.get(&event.feedstock) {
+            event.pull_request = Some(pr.number);
+            event.pull_request_url = Some(format!(
+                "https://github.com/conda-forge/{}/pull/{}",
+                event.feedstock, pr.number
+            ));
+        }
+    }
+}
+
+/// POST a conversion event for each attributed feedstock in `queue` to every URL in
+/// `urls` that hasn't accepted it yet. Failed deliveries are reported but don't fail the
+/// run; those feedstocks and the ones still waiting for attribution stay queued, so
+/// only the failed URLs get the event again.
+pub async fn send_conversion_webhooks(
+    urls: &[String],
+    queue: &BTreeMap<String, Vec<String>>,
+    feedstock_states: &BTreeMap<String, FeedstockEntry>,
+) -> Result<WebhookDeliveries> {
+    let feedstocks: Vec<String> = queue.keys().cloned().collect();
+    let mut deliveries = WebhookDeliveries {
+        delivered: 0,
+        unannounced: awaiting_attribution(feedstock_states, &feedstocks)
+            .into_iter()
+            .map(|name| {
+                let accepted = queue[&name].clone();
+                (name, accepted)
+            })
+            .collect(),
+    };
+    let mut events = conversion_events(feedstock_states, &feedstocks);
+    if urls.is_empty() || events.is_empty() {
+        return Ok(deliveries);
+    }
+    find_pull_requests(&mut events, feedstock_states).await;
+
+    let client = reqwest::Client::builder()
+        .user_agent("are-we-recipe-v1-yet/1.0")
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()?;
+    for event in &events {
+        let mut accepted = queue[&event.feedstock].clone();
+        let mut failed = false;
+        for url in urls {
+            let key = webhook_url_key(url);
+            if accepted.contains(&key) {
+                continue;
+            }
+            let result = client
+                .post(url)
+                .json(event)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            match result {
+                Ok(_) => accepted.push(key),
+                Err(e) => {
+                    let reason = match e.status() {
+                        Some(status) => status.to_string(),
+                        None if e.is_timeout() => "timed out".to_string(),
+                        None => "no response".to_string(),
+                    };
+                    println!(
+                        "⚠️  Webhook for {} to {} failed: {}",
+                        event.feedstock,
+                        url_host(url),
+                        reason
+                    );
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            deliveries
+                .unannounced
+                .insert(event.feedstock.clone(), accepted);
+        } else {
+            deliveries.delivered += 1;
+        }
+    }
+    Ok(deliveries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_conversion_events() {
+        let stats: crate::models::FeedstockStats = toml::from_str(
+            r#"
+            total_feedstocks = 4
+            recipe_v1_count = 3
+            meta_yaml_count = 1
+            unknown_count = 0
+            last_updated = "2025-06-30T00:00:00+00:00"
+
+            [feedstock_states.numpy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-06-30T00:00:00Z"
+            attribution = { contribution_type = "conversion", contributors = ["alice"], date = "2025-06-28T00:00:00Z", commit_sha = "abc" }
+
+            [feedstock_states.scipy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-06-30T00:00:00Z"
+
+            [feedstock_states.pandas-feedstock]
+            recipe_type = "non_standard_v1"
+            last_changed = "2025-06-30T00:00:00Z"
+            recipe_file = "recipe/recipe.yml"
+            attribution = { contribution_type = "conversion", contributors = [], date = "2025-06-29T00:00:00Z" }
+
+            [feedstock_states.new-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-06-30T00:00:00Z"
+            attribution = { contribution_type = "new_feedstock", contributors = ["carol"], date = "2025-06-29T00:00:00Z" }
+
+            [feedstock_states.reverted-feedstock]
+            recipe_type = "meta_yaml"
+            last_changed = "2025-06-30T00:00:00Z"
+            "#,
+        )
+        .unwrap();
+        let feedstocks: Vec<String> = [
+            "new-feedstock",
+            "numpy-feedstock",
+            "pandas-feedstock",
+            "removed-feedstock",
+            "reverted-feedstock",
+            "scipy-feedstock",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        let events = conversion_events(&stats.feedstock_states, &feedstocks);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            serde_json::to_value(&events[0]).unwrap(),
+            serde_json::json!({
+                "event": "recipe_v1_conversion",
+                "feedstock": "numpy-feedstock",
+                "contributors": ["alice"],
+                "date": "2025-06-28T00:00:00Z",
+            })
+        );
+
+        // Unattributed conversions wait for a later run
+        assert_eq!(
+            awaiting_attribution(&stats.feedstock_states, &feedstocks),
+            vec!["pandas-feedstock", "scipy-feedstock"]
+        );
+    }
+
+    /// Answer every request on a local port with `status`, returning the URL and the
+    /// number of requests received
+    async fn endpoint(status: u16) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let received = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                // Read the whole request before answering
+                let mut request = Vec::new();
+                let mut buf = [0; 4096];
+                loop {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request);
+                    let Some(end) = text.find("\r\n\r\n") else {
+                        continue;
+                    };
+                    let length = text[..end]
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())?
+                        })
+                        .unwrap_or(0);
+                    if n == 0 || request.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+                received.fetch_add(1, Ordering::SeqCst);
+                let response = format!(
+                    "HTTP/1.1 {} Status\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    status
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    #[tokio::test]
+    async fn test_send_conversion_webhooks_retries_failed_urls() {
+        let stats: crate::models::FeedstockStats = toml::from_str(
+            r#"
+            total_feedstocks = 1
+            recipe_v1_count = 1
+            meta_yaml_count = 0
+            unknown_count = 0
+            last_updated = "2025-06-30T00:00:00+00:00"
+
+            [feedstock_states.numpy-feedstock]
+            recipe_type = "recipe_v1"
+            last_changed = "2025-06-30T00:00:00Z"
+            attribution = { contribution_type = "conversion", contributors = ["alice"], date = "2025-06-28T00:00:00Z" }
+            "#,
+        )
+        .unwrap();
+        let (accepting, accepted) = endpoint(200).await;
+        let (failing, failed) = endpoint(500).await;
+        let urls = [accepting.clone(), failing.clone()];
+
+        let queue = BTreeMap::from([("numpy-feedstock".to_string(), vec![])]);
+        let deliveries = send_conversion_webhooks(&urls, &queue, &stats.feedstock_states)
+            .await
+            .unwrap();
+        assert_eq!(deliveries.delivered, 0);
+        assert_eq!(
+            deliveries.unannounced,
+            BTreeMap::from([(
+                "numpy-feedstock".to_string(),
+                vec![webhook_url_key(&accepting)]
+            )])
+        );
+
+        // The next run only sends the event to the URL that failed
+        let deliveries =
+            send_conversion_webhooks(&urls, &deliveries.unannounced, &stats.feedstock_states)
+                .await
+                .unwrap();
+        assert_eq!(deliveries.delivered, 0);
+        assert_eq!(accepted.load(Ordering::SeqCst), 1);
+        assert_eq!(failed.load(Ordering::SeqCst), 2);
+
+        // Once the failing URL is replaced, the event is announced
+        let (recovered, _) = endpoint(204).await;
+        let deliveries = send_conversion_webhooks(
+            &[accepting, recovered],
+            &deliveries.unannounced,
+            &stats.feedstock_states,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            deliveries,
+            WebhookDeliveries {
+                delivered: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(accepted.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_url_host() {
+        assert_eq!(
+            url_host("https://hooks.example.com/services/T000/B000/secret?token=x"),
+            "hooks.example.com"
+        );
+        assert_eq!(url_host("not a url"), "an invalid URL");
+    }
+}
@@ -8,7 +8,7 @@
 //! 1. New Feedstock: recipe.yaml exists in the very first commit of the repo
 //! 2. Conversion: recipe.yaml was added later, credit PR author (or human committer if bot PR)
 
-use data_collector::external::{fetch_recipe_maintainers, GitHubClient};
+use data_collector::external::{fetch_recipe_maintainers, GitHubClient, RecipeStore};
 
 /// Expected attribution for a feedstock
 struct ExpectedAttribution {
@@ -64,6 +64,9 @@ async fn test_attribution_detection() {
     let client = GitHubClient::new()
         .expect("Failed to create GitHub client - ensure gh CLI is installed or GITHUB_TOKEN is set");
 
+    let store_dir = tempfile::tempdir().unwrap();
+    let mut store = RecipeStore::open(store_dir.path()).unwrap();
+
     let mut passed = 0;
     let mut failed = 0;
 
@@ -102,7 +105,7 @@ async fn test_attribution_detection() {
             }
 
             // Fetch maintainers from recipe.yaml
-            let maintainers = fetch_recipe_maintainers(test.feedstock)
+            let maintainers = fetch_recipe_maintainers(test.feedstock, &mut store)
                 .await
                 .unwrap_or_default();
             println!("   Maintainers: {:?}", maintainers);
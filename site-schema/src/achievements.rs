@@ -0,0 +1,229 @@
+//! Achievement definitions awarded on the leaderboard - single source of truth
+
+/// What an achievement counts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    Total,
+    Conversions,
+    NewFeedstocks,
+    SelfConversion,
+}
+
+/// Achievement definition with emoji, translation id, and threshold
+#[derive(Debug, PartialEq, Eq)]
+pub struct Achievement {
+    pub emoji: &'static str,
+    /// Translated as `achievement-<id>`
+    pub id: &'static str,
+    pub category: Category,
+    pub threshold: u32,
+}
+
+impl Achievement {
+    /// Translation key of the achievement's name
+    pub fn name_key(&self) -> String {
+        format!("achievement-{}", self.id)
+    }
+
+    /// Translation key of the tooltip, taking `$name` and `$threshold`
+    pub fn tooltip_key(&self) -> &'static str {
+        match self.category {
+            Category::Total => "achievement-tooltip-total",
+            Category::Conversions => "achievement-tooltip-conversions",
+            Category::NewFeedstocks => "achievement-tooltip-new-feedstocks",
+            Category::SelfConversion => "achievement-tooltip-self-conversion",
+        }
+    }
+}
+
+// Total contribution achievements
+pub const TOTAL: &[Achievement] = &[
+    Achievement {
+        emoji: "🦄",
+        id: "conda-mythic",
+        category: Category::Total,
+        threshold: 500,
+    },
+    Achievement {
+        emoji: "👑",
+        id: "forge-legend",
+        category: Category::Total,
+        threshold: 200,
+    },
+    Achievement {
+        emoji: "🚀",
+        id: "master-smith",
+        category: Category::Total,
+        threshold: 100,
+    },
+    Achievement {
+        emoji: "💫",
+        id: "forge-smith",
+        category: Category::Total,
+        threshold: 50,
+    },
+    Achievement {
+        emoji: "🌟",
+        id: "recipe-crafter",
+        category: Category::Total,
+        threshold: 25,
+    },
+    Achievement {
+        emoji: "⭐",
+        id: "forge-apprentice",
+        category: Category::Total,
+        threshold: 10,
+    },
+];
+
+// Conversion-specific achievements
+pub const CONVERSIONS: &[Achievement] = &[
+    Achievement {
+        emoji: "💎",
+        id: "transmutation-master",
+        category: Category::Conversions,
+        threshold: 250,
+    },
+    Achievement {
+        emoji: "🔥",
+        id: "migration-furnace",
+        category: Category::Conversions,
+        threshold: 100,
+    },
+    Achievement {
+        emoji: "⚡",
+        id: "yaml-wizard",
+        category: Category::Conversions,
+        threshold: 50,
+    },
+    Achievement {
+        emoji: "🔄",
+        id: "recipe-translator",
+        category: Category::Conversions,
+        threshold: 10,
+    },
+];
+
+// New feedstock achievements
+pub const NEW_FEEDSTOCKS: &[Achievement] = &[
+    Achievement {
+        emoji: "🏞️",
+        id: "conda-terraformer",
+        category: Category::NewFeedstocks,
+        threshold: 250,
+    },
+    Achievement {
+        emoji: "🌲",
+        id: "ecosystem-grower",
+        category: Category::NewFeedstocks,
+        threshold: 100,
+    },
+    Achievement {
+        emoji: "🌳",
+        id: "package-cultivator",
+        category: Category::NewFeedstocks,
+        threshold: 50,
+    },
+    Achievement {
+        emoji: "🌱",
+        id: "feedstock-farmer",
+        category: Category::NewFeedstocks,
+        threshold: 10,
+    },
+];
+
+// Awarded for converting a feedstock you maintain yourself
+pub const SELF_CONVERSION: Achievement = Achievement {
+    emoji: "🏡",
+    id: "homegrown",
+    category: Category::SelfConversion,
+    threshold: 1,
+};
+
+/// Achievement with the given id
+pub fn find(id: &str) -> Option<&'static Achievement> {
+    TOTAL
+        .iter()
+        .chain(CONVERSIONS)
+        .chain(NEW_FEEDSTOCKS)
+        .chain([&SELF_CONVERSION])
+        .find(|achievement| achievement.id == id)
+}
+
+/// Get the highest achievement earned for a given value from a list of achievements
+pub fn get_achievement(
+    value: u32,
+    achievements: &'static [Achievement],
+) -> Option<&'static Achievement> {
+    achievements.iter().find(|a| value >= a.threshold)
+}
+
+/// Compute achievement badges for a contributor based on their stats
+pub fn compute_achievements(
+    conversions: u32,
+    new_feedstocks: u32,
+    self_conversions: u32,
+) -> Vec<&'static Achievement> {
+    let mut result = Vec::new();
+    let total = conversions + new_feedstocks;
+
+    if let Some(achievement) = get_achievement(total, TOTAL) {
+        result.push(achievement);
+    }
+    if let Some(achievement) = get_achievement(conversions, CONVERSIONS) {
+        result.push(achievement);
+    }
+    if let Some(achievement) = get_achievement(new_feedstocks, NEW_FEEDSTOCKS) {
+        result.push(achievement);
+    }
+    if self_conversions >= SELF_CONVERSION.threshold {
+        result.push(&SELF_CONVERSION);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(achievements: Vec<&Achievement>) -> Vec<&str> {
+        achievements.iter().map(|a| a.id).collect()
+    }
+
+    #[test]
+    fn test_compute_achievements_none_below_thresholds() {
+        assert!(compute_achievements(0, 0, 0).is_empty());
+        assert!(compute_achievements(5, 4, 0).is_empty());
+    }
+
+    #[test]
+    fn test_compute_achievements_picks_highest_tier() {
+        let achievements = compute_achievements(120, 0, 0);
+        assert_eq!(ids(achievements), vec!["master-smith", "migration-furnace"]);
+    }
+
+    #[test]
+    fn test_compute_achievements_combines_categories() {
+        // 6 + 6 crosses the total threshold without either category threshold
+        assert_eq!(ids(compute_achievements(6, 6, 0)), vec!["forge-apprentice"]);
+        assert_eq!(compute_achievements(10, 10, 0).len(), 3);
+    }
+
+    #[test]
+    fn test_compute_achievements_self_conversion() {
+        let achievements = compute_achievements(1, 0, 1);
+        assert_eq!(ids(achievements.clone()), vec!["homegrown"]);
+        assert_eq!(
+            achievements[0].tooltip_key(),
+            "achievement-tooltip-self-conversion"
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("homegrown"), Some(&SELF_CONVERSION));
+        assert_eq!(find("forge-smith").map(|a| a.threshold), Some(50));
+        assert_eq!(find("unknown"), None);
+    }
+}
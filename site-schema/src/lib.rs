@@ -0,0 +1,5 @@
+//! Types shared by crunch-data and the site, so the published files and the
+//! achievement thresholds can't drift apart
+
+pub mod achievements;
+pub mod schema;
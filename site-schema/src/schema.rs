@@ -0,0 +1,647 @@
+//! Shape of the crunched `stats.toml` and of the files the site only fetches when a page
+//! needs them: `insights.toml`, `data-quality.toml`, `contributions.toml` and
+//! `maintainer-coverage.toml`.
+//! crunch-data checks its output against these types too, so data the site can't read
+//! fails the crunch instead of silently dropping sections.
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Type of contribution (conversion or new feedstock)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContributionType {
+    Conversion,
+    NewFeedstock,
+}
+
+/// The crunched `stats.toml`
+#[derive(Deserialize)]
+pub struct StatsFile {
+    pub recipe_v1_count: u32,
+    pub total_feedstocks: u32,
+    #[serde(default)]
+    pub meta_yaml_count: u32,
+    #[serde(default)]
+    pub unknown_count: u32,
+    /// Recipe v1 feedstocks with a near-miss recipe filename, also counted in
+    /// `recipe_v1_count`
+    pub non_standard_v1_count: Option<u32>,
+    #[serde(default)]
+    pub last_updated: String,
+    pub activity_weeks: Option<usize>,
+    /// By feedstock name
+    #[serde(default)]
+    pub recently_updated: BTreeMap<String, RecentEntry>,
+    pub recently_updated_days: Option<u32>,
+    #[serde(default)]
+    pub top_unconverted_by_downloads: Vec<UnconvertedFeedstock>,
+    #[serde(default)]
+    pub top_contributors: Vec<ContributorStats>,
+    pub rank_change_since: Option<String>,
+    pub total_contributors: Option<u32>,
+    #[serde(default)]
+    pub self_conversions: u32,
+    #[serde(default)]
+    pub contributor_growth: Vec<GrowthPoint>,
+    #[serde(default)]
+    pub weekly_v1_growth: Vec<WeeklyGrowth>,
+    #[serde(default)]
+    pub maintainer_conversion_rates: Vec<MaintainerConversionRate>,
+    #[serde(default)]
+    pub recipe_feature_rates: Vec<RecipeFeatureRate>,
+    #[serde(default)]
+    pub non_standard_recipes: Vec<NonStandardRecipe>,
+    #[serde(default)]
+    pub pending_migrations: Vec<PendingMigration>,
+    #[serde(default)]
+    pub completionists: Vec<Completionist>,
+    #[serde(default)]
+    pub events: Vec<TimelineEvent>,
+    #[serde(default)]
+    pub achievement_feed: Vec<AchievementUnlock>,
+    pub build_health: Option<BuildHealth>,
+    pub recipe_lint: Option<RecipeLint>,
+    pub dependency_weighted: Option<DependencyWeighted>,
+    pub quality_metrics: Option<QualityMetrics>,
+    pub rattler_build_adoption: Option<RattlerBuildAdoption>,
+    pub biggest_conversion: Option<BiggestConversion>,
+    #[serde(default)]
+    pub notable_recent: Vec<NotableConversion>,
+}
+
+/// The `insights.toml` published next to the snapshots, shown on the insights page
+#[derive(Clone, Deserialize)]
+pub struct InsightsFile {
+    #[serde(default)]
+    pub contributor_retention: Vec<RetentionPoint>,
+    #[serde(default)]
+    pub contributor_mix: Vec<ContributorMix>,
+    #[serde(default)]
+    pub conversion_cohorts: Vec<ConversionCohort>,
+    #[serde(default)]
+    pub bot_activity_rates: Vec<BotActivityRate>,
+    pub conversion_forecast: Option<ConversionForecast>,
+}
+
+/// A feedstock recently converted to Recipe v1, keyed by its name
+#[derive(Deserialize)]
+pub struct RecentEntry {
+    pub date: String,
+    #[serde(default)]
+    pub contributors: Vec<String>,
+    pub summary: Option<String>,
+}
+
+/// Top package info for a contributor
+#[derive(Clone, Deserialize)]
+pub struct TopPackage {
+    pub name: String,
+    pub downloads: u64,
+    pub summary: Option<String>,
+}
+
+/// The most downloaded feedstock converted from meta.yaml so far
+#[derive(Clone, Deserialize)]
+pub struct BiggestConversion {
+    pub feedstock: String,
+    #[serde(default)]
+    pub downloads: u64,
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub contributors: Vec<String>,
+    /// When it was converted (RFC 3339)
+    pub date: Option<String>,
+}
+
+/// A recent conversion of a package above the ticker's download threshold
+#[derive(Clone, Deserialize)]
+pub struct NotableConversion {
+    pub feedstock: String,
+    pub downloads: u64,
+    /// When it was converted (RFC 3339)
+    pub date: String,
+    #[serde(default)]
+    pub contributors: Vec<String>,
+}
+
+/// An unconverted feedstock in the downloads ranking
+#[derive(Clone, Deserialize)]
+pub struct UnconvertedFeedstock {
+    pub name: String,
+    pub downloads: u64,
+    pub summary: Option<String>,
+    #[serde(flatten)]
+    pub links: Option<ConversionLinks>,
+    /// Dependency feedstocks still on meta.yaml, None if unknown
+    pub blocked_by: Option<Vec<String>>,
+    /// Pushed to recently, None if unknown
+    pub active: Option<bool>,
+    /// Recipe features that may make the conversion harder, e.g. "multiple-outputs"
+    #[serde(default)]
+    pub recipe_features: Vec<String>,
+}
+
+/// Ready-made links to start converting a feedstock
+#[derive(Clone, Deserialize)]
+pub struct ConversionLinks {
+    pub fork_url: String,
+    /// Web editor link to the feedstock's meta.yaml, which forks the feedstock and opens
+    /// a pull request when saved
+    pub edit_url: String,
+    /// Suggested title for that pull request
+    pub pr_title: String,
+}
+
+/// Cumulative number of unique contributors at the end of a month
+#[derive(Clone, Deserialize)]
+pub struct GrowthPoint {
+    /// Month as "YYYY-MM"
+    pub month: String,
+    pub contributors: u32,
+}
+
+/// Contributors active in one month, compared to the month before
+#[derive(Clone, Deserialize)]
+pub struct RetentionPoint {
+    /// Month as "YYYY-MM"
+    pub month: String,
+    pub active: u32,
+    /// Also active the month before
+    pub retained: u32,
+    /// Active the month before, but not this month
+    pub churned: u32,
+}
+
+/// Site-wide Recipe v1 contributions in one week
+#[derive(Clone, Deserialize)]
+pub struct WeeklyGrowth {
+    /// Monday of the week as "YYYY-MM-DD"
+    pub week: String,
+    pub conversions: u32,
+    pub new_feedstocks: u32,
+}
+
+/// One month's conversions by first-time and returning contributors
+#[derive(Clone, Deserialize)]
+pub struct ContributorMix {
+    /// Month as "YYYY-MM"
+    pub month: String,
+    /// Credited to someone whose first contribution landed this month
+    pub new: u32,
+    pub returning: u32,
+}
+
+/// Conversions of one month and how many of them stuck
+#[derive(Clone, Deserialize)]
+pub struct ConversionCohort {
+    /// Month as "YYYY-MM"
+    pub cohort: String,
+    pub converted: u32,
+    /// Still on Recipe v1 today
+    pub still_v1: u32,
+    /// Still on Recipe v1 today with a green default branch
+    pub passing: u32,
+    /// Not reverted within N months; absent while the cohort is younger than that
+    pub retained_1m: Option<u32>,
+    pub retained_3m: Option<u32>,
+    pub retained_6m: Option<u32>,
+    pub retained_12m: Option<u32>,
+    /// Conversions whose first week was checked for follow-up fixes
+    #[serde(default)]
+    pub follow_up_checked: u32,
+    /// Checked conversions that needed a fix to the recipe within a week
+    #[serde(default)]
+    pub follow_up_fixed: u32,
+}
+
+/// Experimental forecast of the feedstocks likely to convert next
+#[derive(Clone, Deserialize)]
+pub struct ConversionForecast {
+    /// Highest score first
+    #[serde(default)]
+    pub likely: Vec<LikelyConversion>,
+    /// Feedstocks of the previous forecast that converted since
+    #[serde(default)]
+    pub converted_since: Vec<String>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct LikelyConversion {
+    pub name: String,
+    /// 0.0 - 1.0
+    pub score: f64,
+    /// Best share of converted feedstocks among its maintainers' other feedstocks
+    pub maintainer_share: f64,
+    /// Share of its dependency feedstocks on Recipe v1, None if unknown
+    pub dependencies_converted: Option<f64>,
+    /// Last push (RFC 3339)
+    pub pushed_at: Option<String>,
+}
+
+/// A notable ecosystem event (release, CEP approval, sprint) annotated on the charts
+#[derive(Clone, Deserialize)]
+pub struct TimelineEvent {
+    /// Day as "YYYY-MM-DD"
+    pub date: String,
+    pub title: String,
+    pub url: Option<String>,
+    /// Month as "YYYY-MM", to place the event on the monthly growth chart
+    pub month: String,
+    /// Weeks before the data was crunched, matching the weekly activity buckets;
+    /// `None` for upcoming events
+    pub weeks_ago: Option<usize>,
+}
+
+/// An achievement a contributor earned, for the feed of latest unlocks
+#[derive(Clone, Deserialize)]
+pub struct AchievementUnlock {
+    pub login: String,
+    /// Achievement id, see `achievements.rs`
+    pub achievement: String,
+    /// Day as "YYYY-MM-DD"
+    pub date: String,
+}
+
+/// A Recipe v1 feedstock whose recipe uses a near-miss filename (e.g. `recipe.yml`)
+#[derive(Clone, Deserialize)]
+pub struct NonStandardRecipe {
+    pub name: String,
+    pub recipe_file: String,
+    #[serde(default)]
+    pub downloads: u64,
+}
+
+/// An unconverted feedstock with an open migration PR by the autotick bot
+#[derive(Clone, Deserialize)]
+pub struct PendingMigration {
+    pub name: String,
+    /// PR number on the feedstock
+    pub number: u32,
+    /// Day the PR was opened, as "YYYY-MM-DD"
+    pub opened: String,
+    #[serde(default)]
+    pub downloads: u64,
+}
+
+/// Conversion status of feedstocks with a given number of maintainers
+#[derive(Clone, Deserialize)]
+pub struct MaintainerConversionRate {
+    /// Maintainer count bucket, e.g. "1" or "5+"
+    pub maintainers: String,
+    pub feedstocks: u32,
+    pub converted: u32,
+}
+
+/// Conversion status of feedstocks by how many pull requests the autotick bot opened
+#[derive(Clone, Deserialize)]
+pub struct BotActivityRate {
+    /// Bot pull request count bucket, e.g. "0-9" or "100+"
+    pub bot_prs: String,
+    pub feedstocks: u32,
+    pub converted: u32,
+    /// Converted within the last 90 days
+    pub converted_recently: u32,
+}
+
+/// Conversion status of feedstocks with and without a recipe feature
+#[derive(Clone, Deserialize)]
+pub struct RecipeFeatureRate {
+    /// Feature id, e.g. "selectors"
+    pub feature: String,
+    pub feedstocks: u32,
+    pub converted: u32,
+    pub other_feedstocks: u32,
+    pub other_converted: u32,
+}
+
+/// A maintainer ranked by the share of their own feedstocks they moved to Recipe v1
+#[derive(Clone, Deserialize)]
+pub struct Completionist {
+    pub name: String,
+    /// Feedstocks listing them as a recipe maintainer
+    pub maintained: u32,
+    /// Of those, converted or created as Recipe v1 by them
+    pub converted: u32,
+}
+
+/// Gaps in the collected data, so visitors can judge how far to trust the numbers.
+/// Published as `data-quality.toml` next to the snapshots.
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DataQuality {
+    pub total_feedstocks: u32,
+    pub missing_downloads: u32,
+    pub unknown_recipe_types: u32,
+    /// Contributors that couldn't be resolved to a GitHub login
+    pub unresolved_logins: u32,
+    pub v1_feedstocks: u32,
+    pub attribution_confidence: AttributionConfidence,
+}
+
+/// Recipe v1 feedstocks per attribution confidence level
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AttributionConfidence {
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+    pub unattributed: u32,
+}
+
+/// Latest CI state on the default branch of Recipe v1 feedstocks
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BuildHealth {
+    /// Feedstocks with a CI result
+    pub checked: u32,
+    pub passing: u32,
+    pub failing: u32,
+    pub pending: u32,
+}
+
+/// How many converted recipes pass the recipe linter cleanly
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RecipeLint {
+    /// Recipes the linter ran on
+    pub checked: u32,
+    pub passing: u32,
+    pub failing: u32,
+}
+
+/// Migration progress with each feedstock weighted by its number of dependents
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DependencyWeighted {
+    pub converted: u32,
+    pub total: u32,
+}
+
+/// Conversions reverted or needing a quick fix, for the rattler-build team
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct QualityMetrics {
+    /// Conversions on Recipe v1 or reverted since
+    pub conversions: u32,
+    pub conversion_reverts_30d: u32,
+    /// Conversions whose first week has been checked for follow-up fixes
+    pub follow_up_checked: u32,
+    pub follow_up_fixes_7d: u32,
+}
+
+/// Recipe v1 feedstocks by rattler-build pin and recipe schema version
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RattlerBuildAdoption {
+    pub pins: Vec<VersionCount>,
+    pub schema_versions: Vec<VersionCount>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct VersionCount {
+    pub version: String,
+    pub feedstocks: u32,
+}
+
+/// Enriched contributor statistics
+#[derive(Clone, Deserialize)]
+pub struct ContributorStats {
+    pub name: String,
+    pub conversions: u32,
+    pub new_feedstocks: u32,
+    #[serde(default)]
+    pub total_downloads: u64,
+    /// Conversions of feedstocks this contributor maintains
+    #[serde(default)]
+    pub self_conversions: u32,
+    pub first_contribution: Option<String>,
+    pub last_contribution: Option<String>,
+    pub top_package: Option<TopPackage>,
+    /// Most downloaded package converted (not created) by this contributor
+    pub largest_conversion: Option<TopPackage>,
+    #[serde(default)]
+    pub feedstocks: Vec<FeedstockContribution>,
+    /// (conversions, new feedstocks) per week, index 0 = most recent week
+    #[serde(default)]
+    pub weekly_activity: Vec<(u32, u32)>,
+    /// Added by the contributor in `contributors.toml`
+    pub profile: Option<ContributorProfile>,
+    /// Day ("YYYY-MM-DD") each achievement was earned, by achievement id
+    #[serde(default)]
+    pub achievements_earned: HashMap<String, String>,
+    /// Places moved up (negative: down) since `rank_change_since`, None if the
+    /// contributor wasn't on that leaderboard
+    pub rank_change: Option<i64>,
+    /// Cached by the collector's `resolve-contributors`
+    pub github: Option<GitHubIdentity>,
+}
+
+/// The contributor's GitHub account as last seen by the collector
+#[derive(Clone, Deserialize)]
+pub struct GitHubIdentity {
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    /// False if the login doesn't lead to the account anymore, e.g. it was deleted
+    pub exists: bool,
+    /// Current login of a renamed account
+    pub renamed_to: Option<String>,
+}
+
+/// Opt-in details a contributor added about themselves
+#[derive(Clone, Default, Deserialize)]
+pub struct ContributorProfile {
+    pub display_name: Option<String>,
+    pub affiliation: Option<String>,
+    pub url: Option<String>,
+}
+
+/// A single feedstock contribution
+#[derive(Clone, Deserialize)]
+pub struct FeedstockContribution {
+    pub name: String,
+    pub contribution_type: ContributionType,
+    #[serde(default)]
+    pub downloads: u64,
+    #[serde(default)]
+    pub date: String,
+    pub summary: Option<String>,
+    /// Converted by one of the feedstock's own maintainers
+    #[serde(default)]
+    pub self_conversion: bool,
+}
+
+/// The `contributions.toml` published next to the snapshots
+#[derive(Deserialize)]
+pub struct ContributionsFile {
+    /// When the file was crunched (RFC 3339)
+    pub generated_at: String,
+    /// By login, oldest first
+    #[serde(default)]
+    pub contributors: HashMap<String, Vec<ContributionEntry>>,
+}
+
+#[derive(Deserialize)]
+pub struct ContributionEntry {
+    #[serde(default)]
+    pub feedstock: String,
+    /// RFC 3339
+    pub date: String,
+    pub contribution_type: ContributionType,
+}
+
+/// The `maintainer-coverage.toml` published next to the snapshots
+#[derive(Deserialize)]
+pub struct MaintainerCoverageFile {
+    /// When the file was crunched (RFC 3339)
+    pub generated_at: String,
+    /// Most maintained first
+    #[serde(default)]
+    pub maintainers: Vec<MaintainerCoverage>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct MaintainerCoverage {
+    pub login: String,
+    /// Feedstocks listing them as a recipe maintainer
+    pub maintained: u32,
+    /// Of those, on Recipe v1
+    pub converted: u32,
+    /// Names of the maintained feedstocks not on Recipe v1 yet
+    #[serde(default)]
+    pub unconverted: Vec<String>,
+}
+
+impl UnconvertedFeedstock {
+    /// All dependencies are on Recipe v1 already
+    pub fn is_ready(&self) -> bool {
+        self.blocked_by.as_ref().is_some_and(|b| b.is_empty())
+    }
+}
+
+impl RetentionPoint {
+    /// Contributors active the month before
+    pub fn previous_active(&self) -> u32 {
+        self.retained + self.churned
+    }
+
+    pub fn retention_percent(&self) -> f64 {
+        percent(self.retained, self.previous_active())
+    }
+}
+
+impl ConversionCohort {
+    /// `(months, retained)` for each horizon the cohort is old enough for
+    pub fn retention(&self) -> Vec<(u32, u32)> {
+        [
+            (1, self.retained_1m),
+            (3, self.retained_3m),
+            (6, self.retained_6m),
+            (12, self.retained_12m),
+        ]
+        .into_iter()
+        .filter_map(|(months, retained)| Some((months, retained?)))
+        .collect()
+    }
+}
+
+impl MaintainerConversionRate {
+    /// Share of feedstocks in this bucket that are converted (0.0 - 1.0)
+    pub fn rate(&self) -> f64 {
+        if self.feedstocks == 0 {
+            0.0
+        } else {
+            self.converted as f64 / self.feedstocks as f64
+        }
+    }
+}
+
+impl BotActivityRate {
+    /// Share of feedstocks in this bucket that are converted (0.0 - 1.0)
+    pub fn rate(&self) -> f64 {
+        ratio(self.converted, self.feedstocks)
+    }
+
+    /// Share of feedstocks in this bucket converted within the last 90 days (0.0 - 1.0)
+    pub fn recent_rate(&self) -> f64 {
+        ratio(self.converted_recently, self.feedstocks)
+    }
+}
+
+impl RecipeFeatureRate {
+    /// Share of feedstocks with the feature that are converted (0.0 - 1.0)
+    pub fn rate(&self) -> f64 {
+        ratio(self.converted, self.feedstocks)
+    }
+
+    /// Share of feedstocks without the feature that are converted (0.0 - 1.0)
+    pub fn other_rate(&self) -> f64 {
+        ratio(self.other_converted, self.other_feedstocks)
+    }
+}
+
+fn ratio(part: u32, whole: u32) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+impl Completionist {
+    /// Percentage of their feedstocks they converted
+    pub fn percent(&self) -> f64 {
+        percent(self.converted, self.maintained)
+    }
+}
+
+impl MaintainerCoverage {
+    /// Percentage of their feedstocks on Recipe v1
+    pub fn percent(&self) -> f64 {
+        percent(self.converted, self.maintained)
+    }
+}
+
+/// Percentage of `part` in `total`, 0 for an empty total
+pub fn percent(part: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
+
+impl ContributorStats {
+    /// Total contributions (conversions + new feedstocks)
+    pub fn total(&self) -> u32 {
+        self.conversions + self.new_feedstocks
+    }
+
+    /// Average downloads per package
+    pub fn avg_downloads(&self) -> u64 {
+        let total = self.total();
+        if total > 0 {
+            self.total_downloads / total as u64
+        } else {
+            0
+        }
+    }
+}
+
+impl BuildHealth {
+    pub fn passing_percent(&self) -> f64 {
+        percent(self.passing, self.checked)
+    }
+}
+
+impl RecipeLint {
+    pub fn passing_percent(&self) -> f64 {
+        percent(self.passing, self.checked)
+    }
+}
+
+impl DependencyWeighted {
+    pub fn percent(&self) -> f64 {
+        percent(self.converted, self.total)
+    }
+}
@@ -0,0 +1,16 @@
+#!/bin/sh
+# Trunk post-build hook: fail release builds whose gzipped WebAssembly bundle is
+# larger than WASM_BUDGET_KIB, so a heavy dependency or page doesn't slip in unnoticed
+set -eu
+
+[ "${TRUNK_PROFILE:-}" = "release" ] || exit 0
+
+budget_kib="${WASM_BUDGET_KIB:-1024}"
+for wasm in "$TRUNK_STAGING_DIR"/*.wasm; do
+    size_kib=$(( $(gzip -9 -c "$wasm" | wc -c) / 1024 ))
+    echo "📦 $(basename "$wasm"): ${size_kib} KiB gzipped, budget ${budget_kib} KiB"
+    if [ "$size_kib" -gt "$budget_kib" ]; then
+        echo "error: the WebAssembly bundle is over its size budget (WASM_BUDGET_KIB)" >&2
+        exit 1
+    fi
+done
@@ -0,0 +1,397 @@
+## Page
+
+app-title = Are we recipe v1 yet?
+app-subtitle = Tracking conda-forge's migration from meta.yaml to recipe.yaml
+app-banner-alt = rattler-build: A fast package build tool for Conda packages written in Rust
+debug-banner = Debug mode: showing edge-case test data, not the real statistics
+language-label = Language
+
+## Shared labels
+
+label-conversions = Conversions
+label-new-feedstocks = New Feedstocks
+label-feedstock-name = Feedstock Name
+label-downloads = Downloads
+label-feedstocks = Feedstocks
+label-converted = Converted
+label-learn-more = Learn more:
+
+## Learn more
+
+info-toggle = Learn more
+info-conda-forge-title = What is conda-forge?
+info-conda-forge-body = conda-forge is a community-driven collection of conda packages. It's an open-source project that provides high-quality, up-to-date conda packages for scientific computing and data science ecosystems.
+info-conda-forge-scale = With over 26,000 feedstocks, conda-forge makes it easy to install software packages using conda.
+info-github-org = GitHub organization
+info-recipe-v1-title = What is Recipe v1?
+info-recipe-v1-body = Recipe v1 is the new standardized format for conda package recipes, replacing the legacy meta.yaml format. It provides better structure, validation, and tooling support.
+info-rattler-build-title = What is rattler-build?
+info-rattler-build-body = rattler-build is a fast, modern build tool for conda packages written in Rust. It's designed to work with the new Recipe v1 format and provides significant performance improvements over conda-build.
+
+## Migration progress
+
+progress-title = Migration Progress
+progress-complete = Complete
+progress-by-feedstock = By feedstock
+progress-by-dependents = By dependents
+progress-weighted-complete = of dependencies converted
+progress-weighted-tooltip = Weighting each feedstock by how many other feedstocks depend on it shows how much of the ecosystem's backbone is on Recipe v1
+stats-title = Migration Statistics
+stats-remaining = Remaining
+stats-out-of = out of { $total } total feedstocks
+stats-builds-passing = of converted feedstocks build green on their default branch
+stats-builds-tooltip = Latest CI run: { $passing } passing, { $failing } failing, { $pending } pending
+stats-lint-passing = of converted recipes pass the recipe linter cleanly
+stats-lint-tooltip = Latest lint run: { $passing } clean, { $failing } with findings
+goals-title = Roadmap
+goal-momentum = Momentum
+goal-halfway = Halfway
+goal-home-stretch = Home stretch
+goal-done = Done
+goals-next = Next milestone: { $goal } ({ $percent }%), { $remaining } feedstocks to go.
+goals-eta = At the pace of the last { $weeks } weeks, expected around { $date }.
+goals-eta-unknown = Not enough recent progress to estimate when.
+goals-complete = Every feedstock is on Recipe v1!
+
+## Community growth
+
+growth-title = Community Growth
+growth-summary = { $total } people have converted or created Recipe v1 feedstocks so far.
+growth-point = { $month }: { $contributors } contributors
+growth-source-title = Where Recipe v1 growth comes from
+growth-source-summary = { $percent }% of Recipe v1 feedstocks were converted from meta.yaml rather than created new ({ $recent }% in the last { $weeks } weeks).
+growth-source-week = Week of { $week }: { $conversions } conversions, { $new } new feedstocks
+
+## Activity
+
+activity-week = { $weeks ->
+    [0] This week
+    [one] 1 week ago
+   *[other] { $weeks } weeks ago
+}
+activity-week-summary = { $week }: { $conversions } conv, { $new } new
+activity-week-empty = { $week }: no activity
+activity-range-weeks = { $weeks }w
+activity-range-all = all
+activity-conv = conv
+activity-new = new
+
+## Leaderboard
+
+leaderboard-title = Recipe v1 Contributors
+leaderboard-intro = A huge thank you to everyone helping migrate conda-forge to Recipe v1! Your contributions make the ecosystem better for everyone.
+leaderboard-self-conversions = 🏡 Self-conversions
+leaderboard-self-conversions-tooltip = Feedstocks converted by one of their own maintainers
+leaderboard-how-title = How do we track contributions?
+leaderboard-how-conversions = Detected when a human contributor adds a recipe.yaml to an existing feedstock. We find the first commit that introduced recipe.yaml and credit the commit author.
+leaderboard-how-new-feedstocks = Detected when the recipe.yaml was added by a bot (automated staging). In this case, we credit the recipe maintainers listed in the recipe.yaml file.
+leaderboard-legend = Achievement Legend
+leaderboard-category = Category
+leaderboard-total = Total
+leaderboard-self-conversion = Self-conversion
+leaderboard-self-conversion-hint = Convert a feedstock you maintain
+leaderboard-contributor = Contributor
+leaderboard-conv = Conv
+leaderboard-new = New
+leaderboard-footer = Showing top 50 contributors. Data refreshed daily.
+leaderboard-rank-up = ▲{ $places }
+leaderboard-rank-down = ▼{ $places }
+leaderboard-rank-new = new
+leaderboard-rank-tooltip = Change in rank since { $date }
+leaderboard-account-missing = This GitHub account no longer exists
+leaderboard-account-renamed = Now @{ $login } on GitHub
+
+## Contributor details
+
+details-total-downloads = Total Downloads*
+details-avg-downloads = Avg per Package*
+details-activity = Activity Timeline
+details-first = First: { $date }
+details-latest = Latest: { $date }
+details-top-package = Top Package
+details-package-downloads = ~{ $downloads } downloads*
+details-no-package = No package data
+details-top-feedstocks = Top Feedstocks by Downloads*
+details-largest-conversion = Largest conversion: { $name } (~{ $downloads } downloads)
+hovercard-top-packages = Top packages
+hovercard-impact = ~{ $downloads } downloads across { $feedstocks } feedstocks
+details-self-conversion = Converted by one of its own maintainers
+details-footnote = * Download counts are summed across the top 10 versions per package. Source:
+
+## Achievements
+
+achievement-conda-mythic = Conda Mythic
+achievement-forge-legend = Forge Legend
+achievement-master-smith = Master Smith
+achievement-forge-smith = Forge Smith
+achievement-recipe-crafter = Recipe Crafter
+achievement-forge-apprentice = Forge Apprentice
+achievement-transmutation-master = Transmutation Master
+achievement-migration-furnace = Migration Furnace
+achievement-yaml-wizard = YAML Wizard
+achievement-recipe-translator = Recipe Translator
+achievement-conda-terraformer = Conda Terraformer
+achievement-ecosystem-grower = Ecosystem Grower
+achievement-package-cultivator = Package Cultivator
+achievement-feedstock-farmer = Feedstock Farmer
+achievement-homegrown = Homegrown
+achievement-tooltip-total = { $name } ({ $threshold }+ v1 contributions)
+achievement-tooltip-conversions = { $name } ({ $threshold }+ v1 conversions)
+achievement-tooltip-new-feedstocks = { $name } ({ $threshold }+ new v1 feedstocks)
+achievement-tooltip-self-conversion = { $name } (converted a feedstock they maintain)
+achievement-earned = { $tooltip } · Earned: { $month }
+
+## Unconverted ranking
+
+ranking-title = Ranking: Unconverted Feedstocks by Downloads
+ranking-intro = Most downloaded feedstocks that haven't been converted to Recipe v1 yet. Migrate these to make a big impact :)
+ranking-showing = Showing top 20 feedstocks.
+ranking-downloads-note = Download counts are summed across the 10 most recent versions.
+ranking-source = Refreshed daily with data from
+ranking-fork = Fork
+ranking-edit = Edit & propose
+ranking-edit-tooltip = Edit the meta.yaml on GitHub, saving forks the feedstock and opens a pull request. Suggested title: "{ $title }"
+ranking-ready-only = Only show feedstocks ready to convert
+ranking-ready = ✅ ready
+ranking-ready-tooltip = All dependencies of this feedstock are on Recipe v1 already
+ranking-blocked = ⛓ { $count }
+ranking-blocked-tooltip = Dependencies still on meta.yaml: { $feedstocks }
+ranking-ready-none = None of the top unconverted feedstocks have all their dependencies converted yet.
+ranking-active-only = Only show actively maintained feedstocks
+ranking-active = 🛠 active
+ranking-active-tooltip = Pushed to in the last 90 days
+ranking-dormant = 💤 dormant
+ranking-dormant-tooltip = No pushes in the last 90 days
+ranking-active-none = None of the top unconverted feedstocks were pushed to in the last 90 days.
+
+## Biggest migration
+
+highlight-biggest-title = Biggest migration so far
+highlight-biggest-downloads = downloads
+highlight-biggest-by = Converted by { $contributors }
+highlight-biggest-by-on = Converted by { $contributors } on { $date }
+
+## Recently updated
+
+recent-title = Recently Updated to Recipe v1
+recent-updated = Updated { $date }
+recent-recipe-name = Recipe Name
+recent-change-detected = Change Detected
+recent-by = by
+recent-window = Updates in the last { $days } days
+recent-show-more = Show { $count } more
+recent-show-less = Show less
+
+## Non-standard recipe filenames
+
+renames-title = Almost there: Non-standard Recipe Filenames
+renames-intro = These feedstocks build with rattler-build, but their recipe isn't called recipe.yaml. Renaming the file is all it takes to count as converted.
+renames-recipe-file = Recipe File
+
+## Maintainer insight
+
+insight-title = Insight: Maintainers vs. Conversion
+insight-likelier = Feedstocks with { $most } maintainers are { $ratio }x as likely to be converted as those with { $least }.
+insight-share = { $percent }% of feedstocks with { $maintainers } maintainers are converted.
+insight-maintainers = Maintainers
+insight-footer = Based on the recipe maintainers listed in each feedstock's recipe:
+
+features-title = Insight: what makes a recipe hard to convert
+features-intro = Conversion rates of feedstocks whose recipe uses a feature, compared to those whose recipe doesn't. Features most associated with staying on meta.yaml come first.
+features-feature = Recipe feature
+features-with = With
+features-without = Without
+features-footer = Hover a cell to see the feedstock counts.
+feature-multiple-outputs = Multiple outputs
+feature-selectors = Selectors
+feature-jinja-statements = Jinja statements ({"{%"} … {"%}"})
+feature-inline-script = Inline build script
+feature-noarch = noarch
+feature-compiled = Compilers
+
+## Consent banner
+
+consent-question = May we count your visit? We only record the page path and campaign, without cookies, so we can see whether outreach leads to conversions.
+consent-deny = No thanks
+consent-accept = Count my visit
+
+## Data quality
+
+quality-link = How reliable is this data?
+quality-back = ← Back to the dashboard
+quality-title = Data Quality
+quality-intro = Every number on this site comes from automated collection, which isn't perfect. This page shows where the data has gaps, so you can judge how much to trust each figure.
+quality-loading = Loading the data quality report…
+quality-unavailable = No data quality report is available for this snapshot.
+quality-missing-downloads = Feedstocks without download counts
+quality-missing-downloads-hint = These count as zero in download rankings and totals.
+quality-unknown-recipe-types = Feedstocks with an unknown recipe type
+quality-unknown-recipe-types-hint = Neither or both recipe formats were found, so they count as unconverted.
+quality-unresolved-logins = Unresolved contributor logins
+quality-unresolved-logins-hint = Contributions that couldn't be matched to a GitHub account and are left out of the leaderboard.
+quality-confidence-title = Attribution confidence of Recipe v1 feedstocks
+quality-confidence-high = High: credited via the commit adding the recipe
+quality-confidence-medium = Medium: credited without a known commit
+quality-confidence-low = Low: contributor couldn't be resolved
+quality-confidence-unattributed = Unattributed
+
+## Ecosystem events
+
+event-marker = { $date }: { $title }
+
+## Completionists
+
+completionists-title = Completionists
+completionists-intro = Maintainers who moved the largest share of their own feedstocks to Recipe v1 themselves, no matter how many they look after.
+completionists-footer = Counts feedstocks listing them in extra.recipe-maintainers, for maintainers of at least 5 feedstocks.
+
+## Report
+
+report-link = Printable report
+report-title = Recipe v1 migration report
+report-data-as-of = Data as of { $date }
+report-print = Print / save as PDF
+report-total-contributors = Contributors
+report-blocked-by = Blocking deps
+
+## Insights
+
+insights-link = Community insights
+insights-title = Contributor retention
+insights-intro = How many contributors keep coming back. A contributor counts as active in a month when a conversion or new feedstock credited to them landed in it.
+insights-unavailable = There isn't enough history to compute retention yet.
+insights-loading = Loading insights…
+insights-load-failed = The insights couldn't be loaded.
+insights-active = Active this month
+insights-retention = Retained from last month
+insights-churned = Didn't return
+insights-chart-title = Share of last month's contributors active again
+insights-bar-tooltip = { $month }: { $retained } of { $previous } returned, { $active } active in total
+
+## Achievement feed
+
+feed-title = Latest achievements
+feed-intro = Badges recently earned by contributors crossing a new threshold.
+feed-reached = reached { $achievement }
+
+## Pending migrations
+
+pending-title = Waiting for a Merge
+pending-intro = { $count ->
+    [one] The autotick bot already opened a Recipe v1 migration PR for this feedstock. A maintainer only needs to review and merge it.
+   *[other] The autotick bot already opened Recipe v1 migration PRs for these { $count } feedstocks. A maintainer only needs to review and merge them.
+}
+pending-opened = Opened
+
+## Ticker
+
+ticker-label = Just converted
+ticker-downloads = ~{ $downloads } downloads
+
+## Palette
+
+palette-colorblind = Colorblind mode
+palette-tooltip = Tell conversions and new feedstocks apart by orange and blue with dots and stripes
+
+## Conversion cohorts
+
+cohorts-title = Do conversions stick?
+cohorts-intro = Conversions grouped by the month they landed in: the share not reverted to meta.yaml after each number of months, the share still on Recipe v1 and building today, and the share that needed a fix to the recipe within a week.
+cohorts-month = Cohort
+cohorts-after = After { $months }m
+cohorts-still-v1 = v1 today
+cohorts-passing = Green today
+cohorts-fixed = Fixed in 1w
+cohorts-footer = Build results are from the default branch's latest CI run
+
+## Contributor mix
+
+mix-title = New and returning contributors
+mix-headline = { $percent }% of the conversions of the last 12 months came from people contributing for the first time.
+mix-new = First-time contributor
+mix-returning = Returning contributor
+mix-bar-tooltip = { $month }: { $new } by first-time and { $returning } by returning contributors
+
+## Conversion tips
+
+tip-multiple-outputs = Has multiple outputs: see the conversion guide for how outputs and their build cache are written in Recipe v1
+tip-selectors = Uses selectors: `# [win]` comments become `if`/`then` blocks in Recipe v1
+tip-jinja-statements = Uses Jinja statements: `{"{%"} set {"%}"}` becomes the `context` section and other statements need rewriting
+tip-inline-script = Has an inline build script: see how build scripts are written in Recipe v1
+tip-noarch = Is noarch: check the `build.noarch` options in the recipe reference
+tip-compiled = Uses compilers: see how compilers and their requirements are declared in Recipe v1
+
+## Relative times
+
+time-just-now = just now
+time-minutes-ago = { $count ->
+    [one] a minute ago
+   *[other] { $count } minutes ago
+}
+time-hours-ago = { $count ->
+    [one] an hour ago
+   *[other] { $count } hours ago
+}
+time-days-ago = { $count ->
+    [one] yesterday
+   *[other] { $count } days ago
+}
+time-weeks-ago = { $count ->
+    [one] last week
+   *[other] { $count } weeks ago
+}
+time-months-ago = { $count ->
+    [one] last month
+   *[other] { $count } months ago
+}
+time-years-ago = { $count ->
+    [one] last year
+   *[other] { $count } years ago
+}
+
+## Watchlist
+
+watch-add = Watch: get notified when this feedstock moves to Recipe v1
+watch-remove = Stop watching
+watch-notification-title = Converted to Recipe v1
+watch-converted = { $feedstocks }, which you watch, moved to Recipe v1!
+watch-dismiss = Dismiss
+
+## Maintainer coverage
+
+maintainers-link = Maintainer coverage
+maintainers-title = Maintainer coverage
+maintainers-intro = How many of the feedstocks each recipe maintainer looks after are on Recipe v1. Search for your GitHub login to see which of your packages are left to convert.
+maintainers-search = Search by GitHub login
+maintainers-loading = Loading maintainers…
+maintainers-unavailable = The maintainer coverage couldn't be loaded.
+maintainers-no-match = No maintainer matches this login.
+maintainers-unconverted = { $count ->
+    [one] { $count } feedstock left to convert
+   *[other] { $count } feedstocks left to convert
+}
+maintainers-generated = Updated { $date }
+
+## Conversion forecast
+
+forecast-title = Likely to convert next
+forecast-experimental = Experimental
+forecast-intro = Unconverted feedstocks scored by signals that tend to come before a conversion: maintainers who converted their other feedstocks, dependencies already on Recipe v1 and recent activity in the repository.
+forecast-feedstock = Feedstock
+forecast-score = Score
+forecast-maintainers = Maintainers
+forecast-dependencies = Dependencies
+forecast-pushed = Last push
+forecast-hits = { $count ->
+    [one] From the previous forecast, { $feedstocks } converted since.
+   *[other] From the previous forecast, { $count } converted since: { $feedstocks }.
+}
+forecast-footer = A simple weighted score, not a promise. Comparing it with actual conversions shows whether outreach moves the needle.
+
+## Bot activity
+
+bot-activity-title = Bot activity vs. conversion
+bot-activity-intro = Feedstocks grouped by how many pull requests the autotick bot opened on them. Each migration PR also rerenders the feedstock, so this shows whether often rerendered feedstocks convert sooner.
+bot-activity-prs = Bot PRs
+bot-activity-recent = Last 90 days
+bot-activity-footer = Based on the PRs recorded in cf-graph's pr_info. Feedstocks with more bot PRs are usually older too.
@@ -0,0 +1,400 @@
+## Página
+
+app-title = ¿Ya estamos en recipe v1?
+app-subtitle = Seguimiento de la migración de conda-forge de meta.yaml a recipe.yaml
+app-banner-alt = rattler-build: una herramienta rápida para construir paquetes Conda, escrita en Rust
+debug-banner = Modo de depuración: se muestran datos de prueba extremos, no las estadísticas reales
+language-label = Idioma
+
+## Etiquetas compartidas
+
+label-conversions = Conversiones
+label-new-feedstocks = Feedstocks nuevos
+label-feedstock-name = Nombre del feedstock
+label-downloads = Descargas
+label-feedstocks = Feedstocks
+label-converted = Convertidos
+label-learn-more = Más información:
+
+## Más información
+
+info-toggle = Más información
+info-conda-forge-title = ¿Qué es conda-forge?
+info-conda-forge-body = conda-forge es una colección de paquetes conda mantenida por la comunidad. Es un proyecto de código abierto que ofrece paquetes conda actualizados y de alta calidad para los ecosistemas de computación científica y ciencia de datos.
+info-conda-forge-scale = Con más de 26.000 feedstocks, conda-forge facilita la instalación de software con conda.
+info-github-org = Organización en GitHub
+info-recipe-v1-title = ¿Qué es Recipe v1?
+info-recipe-v1-body = Recipe v1 es el nuevo formato estandarizado para recetas de paquetes conda, que reemplaza al antiguo formato meta.yaml. Ofrece una mejor estructura, validación y soporte de herramientas.
+info-rattler-build-title = ¿Qué es rattler-build?
+info-rattler-build-body = rattler-build es una herramienta moderna y rápida para construir paquetes conda, escrita en Rust. Está diseñada para el nuevo formato Recipe v1 y es mucho más rápida que conda-build.
+
+## Progreso de la migración
+
+progress-title = Progreso de la migración
+progress-complete = Completado
+progress-by-feedstock = Por feedstock
+progress-by-dependents = Por dependientes
+progress-weighted-complete = de las dependencias convertidas
+progress-weighted-tooltip = Ponderar cada feedstock por cuántos otros feedstocks dependen de él muestra qué parte de la columna vertebral del ecosistema usa Recipe v1
+stats-title = Estadísticas de la migración
+stats-remaining = Pendientes
+stats-out-of = de un total de { $total } feedstocks
+stats-builds-passing = de los feedstocks convertidos compilan correctamente en su rama principal
+stats-builds-tooltip = Última ejecución de CI: { $passing } correctas, { $failing } fallidas, { $pending } pendientes
+stats-lint-passing = de las recetas convertidas pasan el linter sin advertencias
+stats-lint-tooltip = Último análisis: { $passing } sin problemas, { $failing } con hallazgos
+goals-title = Hoja de ruta
+goal-momentum = Impulso
+goal-halfway = A mitad de camino
+goal-home-stretch = Recta final
+goal-done = Terminado
+goals-next = Próximo hito: { $goal } ({ $percent } %), faltan { $remaining } feedstocks.
+goals-eta = Al ritmo de las últimas { $weeks } semanas, se alcanzaría hacia { $date }.
+goals-eta-unknown = No hay suficiente progreso reciente para estimar cuándo.
+goals-complete = ¡Todos los feedstocks usan Recipe v1!
+
+## Crecimiento de la comunidad
+
+growth-title = Crecimiento de la comunidad
+growth-summary = { $total } personas han convertido o creado feedstocks con Recipe v1 hasta ahora.
+growth-point = { $month }: { $contributors } colaboradores
+growth-source-title = De dónde viene el crecimiento de Recipe v1
+growth-source-summary = El { $percent } % de los feedstocks Recipe v1 se convirtieron desde meta.yaml en lugar de crearse nuevos ({ $recent } % en las últimas { $weeks } semanas).
+growth-source-week = Semana del { $week }: { $conversions } conversiones, { $new } feedstocks nuevos
+
+## Actividad
+
+activity-week = { $weeks ->
+    [0] Esta semana
+    [one] Hace 1 semana
+   *[other] Hace { $weeks } semanas
+}
+activity-week-summary = { $week }: { $conversions } conv., { $new } nuevos
+activity-week-empty = { $week }: sin actividad
+activity-range-weeks = { $weeks } sem.
+activity-range-all = todo
+activity-conv = conv.
+activity-new = nuevos
+
+## Clasificación de colaboradores
+
+leaderboard-title = Colaboradores de Recipe v1
+leaderboard-intro = ¡Muchísimas gracias a todas las personas que ayudan a migrar conda-forge a Recipe v1! Sus contribuciones mejoran el ecosistema para todos.
+leaderboard-self-conversions = 🏡 Autoconversiones
+leaderboard-self-conversions-tooltip = Feedstocks convertidos por una de sus propias personas mantenedoras
+leaderboard-how-title = ¿Cómo contamos las contribuciones?
+leaderboard-how-conversions = Se detectan cuando una persona añade un recipe.yaml a un feedstock existente. Buscamos el primer commit que introdujo recipe.yaml y se lo atribuimos a su autor.
+leaderboard-how-new-feedstocks = Se detectan cuando un bot añadió el recipe.yaml (staging automatizado). En ese caso, se lo atribuimos a las personas mantenedoras listadas en el recipe.yaml.
+leaderboard-legend = Leyenda de logros
+leaderboard-category = Categoría
+leaderboard-total = Total
+leaderboard-self-conversion = Autoconversión
+leaderboard-self-conversion-hint = Convierte un feedstock que mantienes
+leaderboard-contributor = Colaborador
+leaderboard-conv = Conv.
+leaderboard-new = Nuevos
+leaderboard-footer = Se muestran los 50 principales colaboradores. Datos actualizados a diario.
+leaderboard-rank-up = ▲{ $places }
+leaderboard-rank-down = ▼{ $places }
+leaderboard-rank-new = nuevo
+leaderboard-rank-tooltip = Cambio de posición desde el { $date }
+leaderboard-account-missing = Esta cuenta de GitHub ya no existe
+leaderboard-account-renamed = Ahora @{ $login } en GitHub
+
+## Detalles del colaborador
+
+details-total-downloads = Descargas totales*
+details-avg-downloads = Media por paquete*
+details-activity = Actividad
+details-first = Primera: { $date }
+details-latest = Última: { $date }
+details-top-package = Paquete principal
+details-package-downloads = ~{ $downloads } descargas*
+details-no-package = Sin datos de paquetes
+details-top-feedstocks = Feedstocks principales por descargas*
+details-largest-conversion = Mayor conversión: { $name } (~{ $downloads } descargas)
+hovercard-top-packages = Paquetes principales
+hovercard-impact = ~{ $downloads } descargas en { $feedstocks } feedstocks
+details-self-conversion = Convertido por una de sus propias personas mantenedoras
+details-footnote = * Las descargas se suman sobre las 10 versiones principales de cada paquete. Fuente:
+
+## Logros
+
+achievement-conda-mythic = Mito de Conda
+achievement-forge-legend = Leyenda de la forja
+achievement-master-smith = Maestro herrero
+achievement-forge-smith = Herrero de la forja
+achievement-recipe-crafter = Artesano de recetas
+achievement-forge-apprentice = Aprendiz de la forja
+achievement-transmutation-master = Maestro de la transmutación
+achievement-migration-furnace = Horno de migración
+achievement-yaml-wizard = Mago del YAML
+achievement-recipe-translator = Traductor de recetas
+achievement-conda-terraformer = Terraformador de Conda
+achievement-ecosystem-grower = Cultivador del ecosistema
+achievement-package-cultivator = Cultivador de paquetes
+achievement-feedstock-farmer = Granjero de feedstocks
+achievement-homegrown = Cosecha propia
+achievement-tooltip-total = { $name } ({ $threshold }+ contribuciones v1)
+achievement-tooltip-conversions = { $name } ({ $threshold }+ conversiones v1)
+achievement-tooltip-new-feedstocks = { $name } ({ $threshold }+ feedstocks v1 nuevos)
+achievement-tooltip-self-conversion = { $name } (convirtió un feedstock que mantiene)
+achievement-earned = { $tooltip } · Obtenido: { $month }
+
+## Clasificación de feedstocks sin convertir
+
+ranking-title = Clasificación: feedstocks sin convertir por descargas
+ranking-intro = Los feedstocks más descargados que aún no usan Recipe v1. ¡Migrarlos tiene un gran impacto! :)
+ranking-showing = Se muestran los 20 principales feedstocks.
+ranking-downloads-note = Las descargas se suman sobre las 10 versiones más recientes.
+ranking-source = Actualizado a diario con datos de
+ranking-fork = Fork
+ranking-edit = Editar y proponer
+ranking-edit-tooltip = Editar el meta.yaml en GitHub; al guardar se hace un fork del feedstock y se abre un pull request. Título sugerido: "{ $title }"
+ranking-ready-only = Mostrar solo los feedstocks listos para convertir
+ranking-ready = ✅ listo
+ranking-ready-tooltip = Todas las dependencias de este feedstock ya usan Recipe v1
+ranking-blocked = ⛓ { $count }
+ranking-blocked-tooltip = Dependencias que aún usan meta.yaml: { $feedstocks }
+ranking-ready-none = Ninguno de los principales feedstocks sin convertir tiene todas sus dependencias convertidas todavía.
+ranking-active-only = Mostrar solo los feedstocks con mantenimiento activo
+ranking-active = 🛠 activo
+ranking-active-tooltip = Con cambios subidos en los últimos 90 días
+ranking-dormant = 💤 inactivo
+ranking-dormant-tooltip = Sin cambios subidos en los últimos 90 días
+ranking-active-none = Ninguno de los principales feedstocks sin convertir ha recibido cambios en los últimos 90 días.
+
+## Mayor migración
+
+highlight-biggest-title = La mayor migración hasta ahora
+highlight-biggest-downloads = descargas
+highlight-biggest-by = Convertido por { $contributors }
+highlight-biggest-by-on = Convertido por { $contributors } el { $date }
+
+## Actualizados recientemente
+
+recent-title = Actualizados recientemente a Recipe v1
+recent-updated = Actualizado { $date }
+recent-recipe-name = Nombre de la receta
+recent-change-detected = Cambio detectado
+recent-by = por
+recent-window = Actualizaciones de los últimos { $days } días
+recent-show-more = Mostrar { $count } más
+recent-show-less = Mostrar menos
+
+## Nombres de receta no estándar
+
+renames-title = Casi listos: nombres de receta no estándar
+renames-intro = Estos feedstocks se construyen con rattler-build, pero su receta no se llama recipe.yaml. Basta con renombrar el archivo para que cuenten como convertidos.
+renames-recipe-file = Archivo de receta
+
+## Análisis de mantenedores
+
+insight-title = Análisis: mantenedores y conversión
+insight-likelier = Los feedstocks con { $most } mantenedores tienen { $ratio } veces más probabilidades de estar convertidos que los que tienen { $least }.
+insight-share = El { $percent } % de los feedstocks con { $maintainers } mantenedores están convertidos.
+insight-maintainers = Mantenedores
+insight-footer = Basado en las personas mantenedoras listadas en la receta de cada feedstock:
+
+features-title = Análisis: qué hace difícil convertir una receta
+features-intro = Tasa de conversión de los feedstocks cuya receta usa una característica, comparada con la de los que no la usan. Primero aparecen las características más asociadas a seguir con meta.yaml.
+features-feature = Característica de la receta
+features-with = Con
+features-without = Sin
+features-footer = Pasa el cursor sobre una celda para ver el número de feedstocks.
+feature-multiple-outputs = Varias salidas
+feature-selectors = Selectores
+feature-jinja-statements = Sentencias Jinja ({"{%"} … {"%}"})
+feature-inline-script = Script de construcción en línea
+feature-noarch = noarch
+feature-compiled = Compiladores
+
+## Aviso de consentimiento
+
+consent-question = ¿Podemos contar tu visita? Solo registramos la ruta de la página y la campaña, sin cookies, para saber si la difusión se traduce en conversiones.
+consent-deny = No, gracias
+consent-accept = Contar mi visita
+
+## Calidad de los datos
+
+quality-link = ¿Qué tan fiables son estos datos?
+quality-back = ← Volver al panel
+quality-title = Calidad de los datos
+quality-intro = Todas las cifras de este sitio provienen de una recopilación automatizada, que no es perfecta. Esta página muestra dónde hay huecos en los datos, para que puedas juzgar cuánto confiar en cada cifra.
+quality-loading = Cargando el informe de calidad de datos…
+quality-unavailable = No hay un informe de calidad de datos para esta instantánea.
+quality-missing-downloads = Feedstocks sin número de descargas
+quality-missing-downloads-hint = Cuentan como cero en las clasificaciones y los totales de descargas.
+quality-unknown-recipe-types = Feedstocks con tipo de receta desconocido
+quality-unknown-recipe-types-hint = No se encontró ningún formato de receta, o se encontraron ambos, así que cuentan como sin convertir.
+quality-unresolved-logins = Usuarios de colaboradores sin resolver
+quality-unresolved-logins-hint = Contribuciones que no se pudieron asociar a una cuenta de GitHub y quedan fuera de la clasificación.
+quality-confidence-title = Confianza en la atribución de los feedstocks Recipe v1
+quality-confidence-high = Alta: atribuido mediante el commit que añadió la receta
+quality-confidence-medium = Media: atribuido sin un commit conocido
+quality-confidence-low = Baja: no se pudo resolver el colaborador
+quality-confidence-unattributed = Sin atribuir
+
+## Eventos del ecosistema
+
+event-marker = { $date }: { $title }
+
+## Completistas
+
+completionists-title = Completistas
+completionists-intro = Personas mantenedoras que migraron ellas mismas a Recipe v1 la mayor parte de sus propios feedstocks, sin importar cuántos mantengan.
+completionists-footer = Cuenta los feedstocks que las incluyen en extra.recipe-maintainers, para quienes mantienen al menos 5 feedstocks.
+
+## Informe
+
+report-link = Informe para imprimir
+report-title = Informe de la migración a Recipe v1
+report-data-as-of = Datos del { $date }
+report-print = Imprimir / guardar como PDF
+report-total-contributors = Colaboradores
+report-blocked-by = Dependencias pendientes
+
+## Perspectivas
+
+insights-link = Perspectivas de la comunidad
+insights-title = Retención de colaboradores
+insights-intro = Cuántos colaboradores vuelven a contribuir. Un colaborador cuenta como activo en un mes cuando una conversión o un feedstock nuevo atribuido a él se publicó ese mes.
+insights-unavailable = Todavía no hay suficiente historial para calcular la retención.
+insights-loading = Cargando las perspectivas…
+insights-load-failed = No se pudieron cargar las perspectivas.
+insights-active = Activos este mes
+insights-retention = Retenidos del mes anterior
+insights-churned = No volvieron
+insights-chart-title = Proporción de colaboradores del mes anterior que volvieron
+insights-bar-tooltip = { $month }: { $retained } de { $previous } volvieron, { $active } activos en total
+
+## Achievement feed
+
+feed-title = Logros recientes
+feed-intro = Insignias obtenidas recientemente por quienes superaron un nuevo umbral.
+feed-reached = alcanzó { $achievement }
+
+## Pending migrations
+
+pending-title = Esperando un merge
+pending-intro = { $count ->
+    [one] El autotick bot ya abrió un PR de migración a Recipe v1 para este feedstock. Solo falta que alguien que lo mantiene lo revise y lo fusione.
+   *[other] El autotick bot ya abrió PRs de migración a Recipe v1 para estos { $count } feedstocks. Solo falta que alguien que los mantiene los revise y los fusione.
+}
+pending-opened = Abierto
+
+## Ticker
+
+ticker-label = Recién convertidos
+ticker-downloads = ~{ $downloads } descargas
+
+## Palette
+
+palette-colorblind = Modo daltónico
+palette-tooltip = Distingue conversiones y feedstocks nuevos con naranja y azul, puntos y rayas
+
+## Conversion cohorts
+
+cohorts-title = ¿Se mantienen las conversiones?
+cohorts-intro = Conversiones agrupadas por el mes en que se publicaron: la proporción que no volvió a meta.yaml tras cada número de meses, la que sigue en Recipe v1 y compila hoy, y la que necesitó un arreglo de la receta en una semana.
+cohorts-month = Cohorte
+cohorts-after = Tras { $months }m
+cohorts-still-v1 = v1 hoy
+cohorts-passing = En verde hoy
+cohorts-fixed = Arreglo en 1 sem
+cohorts-footer = Los resultados de compilación son del último CI de la rama principal
+
+## Contributor mix
+
+mix-title = Colaboradores nuevos y recurrentes
+mix-headline = El { $percent }% de las conversiones de los últimos 12 meses vino de personas que contribuían por primera vez.
+mix-new = Colaborador nuevo
+mix-returning = Colaborador recurrente
+mix-bar-tooltip = { $month }: { $new } de colaboradores nuevos y { $returning } de recurrentes
+
+## Conversion tips
+
+tip-multiple-outputs = Tiene varias salidas: consulta la guía de conversión para ver cómo se escriben las salidas y su caché de compilación en Recipe v1
+tip-selectors = Usa selectores: los comentarios `# [win]` se convierten en bloques `if`/`then` en Recipe v1
+tip-jinja-statements = Usa sentencias Jinja: `{"{%"} set {"%}"}` pasa a la sección `context` y las demás sentencias deben reescribirse
+tip-inline-script = Tiene un script de compilación en línea: consulta cómo se escriben los scripts de compilación en Recipe v1
+tip-noarch = Es noarch: revisa las opciones de `build.noarch` en la referencia de recetas
+tip-compiled = Usa compiladores: consulta cómo se declaran los compiladores y sus requisitos en Recipe v1
+
+## Relative times
+
+time-just-now = ahora mismo
+time-minutes-ago = { $count ->
+    [one] hace un minuto
+   *[other] hace { $count } minutos
+}
+time-hours-ago = { $count ->
+    [one] hace una hora
+   *[other] hace { $count } horas
+}
+time-days-ago = { $count ->
+    [one] ayer
+   *[other] hace { $count } días
+}
+time-weeks-ago = { $count ->
+    [one] la semana pasada
+   *[other] hace { $count } semanas
+}
+time-months-ago = { $count ->
+    [one] el mes pasado
+   *[other] hace { $count } meses
+}
+time-years-ago = { $count ->
+    [one] el año pasado
+   *[other] hace { $count } años
+}
+
+## Watchlist
+
+watch-add = Seguir: recibe un aviso cuando este feedstock pase a Recipe v1
+watch-remove = Dejar de seguir
+watch-notification-title = Convertido a Recipe v1
+watch-converted = { $count ->
+    [one] ¡{ $feedstocks }, que sigues, pasó a Recipe v1!
+   *[other] ¡{ $feedstocks }, que sigues, pasaron a Recipe v1!
+}
+watch-dismiss = Cerrar
+
+## Maintainer coverage
+
+maintainers-link = Cobertura por mantenedor
+maintainers-title = Cobertura por mantenedor
+maintainers-intro = Cuántos de los feedstocks que cuida cada mantenedor de recetas están en Recipe v1. Busca tu usuario de GitHub para ver qué paquetes tuyos quedan por convertir.
+maintainers-search = Buscar por usuario de GitHub
+maintainers-loading = Cargando mantenedores…
+maintainers-unavailable = No se pudo cargar la cobertura por mantenedor.
+maintainers-no-match = Ningún mantenedor coincide con este usuario.
+maintainers-unconverted = { $count ->
+    [one] { $count } feedstock por convertir
+   *[other] { $count } feedstocks por convertir
+}
+maintainers-generated = Actualizado { $date }
+
+## Conversion forecast
+
+forecast-title = Próximos en convertirse
+forecast-experimental = Experimental
+forecast-intro = Feedstocks sin convertir puntuados según señales que suelen preceder a una conversión: mantenedores que ya convirtieron sus otros feedstocks, dependencias ya en Recipe v1 y actividad reciente en el repositorio.
+forecast-feedstock = Feedstock
+forecast-score = Puntuación
+forecast-maintainers = Mantenedores
+forecast-dependencies = Dependencias
+forecast-pushed = Último push
+forecast-hits = { $count ->
+    [one] De la previsión anterior, { $feedstocks } se convirtió desde entonces.
+   *[other] De la previsión anterior, { $count } se convirtieron desde entonces: { $feedstocks }.
+}
+forecast-footer = Una puntuación ponderada sencilla, no una promesa. Compararla con las conversiones reales muestra si la difusión tiene efecto.
+
+## Bot activity
+
+bot-activity-title = Actividad del bot y conversión
+bot-activity-intro = Feedstocks agrupados según cuántos pull requests les abrió el bot autotick. Cada PR de migración también vuelve a renderizar el feedstock, así que esto muestra si los feedstocks renderizados con frecuencia se convierten antes.
+bot-activity-prs = PRs del bot
+bot-activity-recent = Últimos 90 días
+bot-activity-footer = Basado en los PRs registrados en pr_info de cf-graph. Los feedstocks con más PRs del bot suelen ser también más antiguos.
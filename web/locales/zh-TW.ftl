@@ -0,0 +1,369 @@
+## 頁面
+
+app-title = 我們用上 recipe v1 了嗎？
+app-subtitle = 追蹤 conda-forge 從 meta.yaml 遷移到 recipe.yaml 的進度
+app-banner-alt = rattler-build：以 Rust 撰寫的快速 Conda 套件建置工具
+debug-banner = 除錯模式：顯示的是極端情況的測試資料，而非實際統計
+language-label = 語言
+
+## 共用標籤
+
+label-conversions = 轉換
+label-new-feedstocks = 新 feedstock
+label-feedstock-name = Feedstock 名稱
+label-downloads = 下載次數
+label-feedstocks = Feedstock
+label-converted = 已轉換
+label-learn-more = 了解更多：
+
+## 了解更多
+
+info-toggle = 了解更多
+info-conda-forge-title = 什麼是 conda-forge？
+info-conda-forge-body = conda-forge 是由社群推動的 conda 套件集合。這個開源專案為科學運算與資料科學生態系提供高品質、持續更新的 conda 套件。
+info-conda-forge-scale = conda-forge 擁有超過 26,000 個 feedstock，讓你輕鬆使用 conda 安裝軟體套件。
+info-github-org = GitHub 組織
+info-recipe-v1-title = 什麼是 Recipe v1？
+info-recipe-v1-body = Recipe v1 是 conda 套件配方的新標準格式，用來取代舊有的 meta.yaml 格式，提供更好的結構、驗證與工具支援。
+info-rattler-build-title = 什麼是 rattler-build？
+info-rattler-build-body = rattler-build 是以 Rust 撰寫、快速而現代的 conda 套件建置工具。它專為新的 Recipe v1 格式設計，效能遠勝 conda-build。
+
+## 遷移進度
+
+progress-title = 遷移進度
+progress-complete = 已完成
+progress-by-feedstock = 依 feedstock
+progress-by-dependents = 依相依數
+progress-weighted-complete = 的相依已轉換
+progress-weighted-tooltip = 依每個 feedstock 被多少其他 feedstock 相依來加權，可看出生態系的骨幹有多少已採用 Recipe v1
+stats-title = 遷移統計
+stats-remaining = 尚待轉換
+stats-out-of = 共 { $total } 個 feedstock
+stats-builds-passing = 的已轉換 feedstock 在預設分支上建置成功
+stats-builds-tooltip = 最近一次 CI：{ $passing } 通過、{ $failing } 失敗、{ $pending } 進行中
+stats-lint-passing = 的已轉換配方通過配方檢查
+stats-lint-tooltip = 最近一次檢查：{ $passing } 通過、{ $failing } 有問題
+goals-title = 路線圖
+goal-momentum = 起步
+goal-halfway = 過半
+goal-home-stretch = 最後衝刺
+goal-done = 完成
+goals-next = 下一個里程碑：{ $goal }（{ $percent }%），還差 { $remaining } 個 feedstock。
+goals-eta = 依最近 { $weeks } 週的速度，預計在 { $date } 左右達成。
+goals-eta-unknown = 最近的進展不足以估計達成時間。
+goals-complete = 所有 feedstock 都已使用 Recipe v1！
+
+## 社群成長
+
+growth-title = 社群成長
+growth-summary = 目前已有 { $total } 人轉換或建立了 Recipe v1 feedstock。
+growth-point = { $month }：{ $contributors } 位貢獻者
+growth-source-title = Recipe v1 的成長來源
+growth-source-summary = { $percent }% 的 Recipe v1 feedstock 是從 meta.yaml 轉換而來，而非新建立（最近 { $weeks } 週為 { $recent }%）。
+growth-source-week = { $week } 當週：轉換 { $conversions } 個，新增 { $new } 個 feedstock
+
+## 活動
+
+activity-week = { $weeks ->
+    [0] 本週
+   *[other] { $weeks } 週前
+}
+activity-week-summary = { $week }：轉換 { $conversions } 個，新增 { $new } 個
+activity-week-empty = { $week }：沒有活動
+activity-range-weeks = { $weeks } 週
+activity-range-all = 全部
+activity-conv = 轉換
+activity-new = 新增
+
+## 貢獻者排行榜
+
+leaderboard-title = Recipe v1 貢獻者
+leaderboard-intro = 衷心感謝每一位協助 conda-forge 遷移到 Recipe v1 的人！你們的貢獻讓整個生態系變得更好。
+leaderboard-self-conversions = 🏡 自行轉換
+leaderboard-self-conversions-tooltip = 由自身維護者轉換的 feedstock
+leaderboard-how-title = 我們如何計算貢獻？
+leaderboard-how-conversions = 當有人為既有的 feedstock 新增 recipe.yaml 時即計為轉換。我們會找出第一個加入 recipe.yaml 的 commit，並歸功於該 commit 的作者。
+leaderboard-how-new-feedstocks = 當 recipe.yaml 是由機器人（自動化 staging）加入時即計為新 feedstock。此時我們會歸功於 recipe.yaml 中列出的配方維護者。
+leaderboard-legend = 成就說明
+leaderboard-category = 類別
+leaderboard-total = 總計
+leaderboard-self-conversion = 自行轉換
+leaderboard-self-conversion-hint = 轉換一個你維護的 feedstock
+leaderboard-contributor = 貢獻者
+leaderboard-conv = 轉換
+leaderboard-new = 新增
+leaderboard-footer = 顯示前 50 名貢獻者，資料每日更新。
+leaderboard-rank-up = ▲{ $places }
+leaderboard-rank-down = ▼{ $places }
+leaderboard-rank-new = 新
+leaderboard-rank-tooltip = 自 { $date } 以來的名次變化
+leaderboard-account-missing = 此 GitHub 帳號已不存在
+leaderboard-account-renamed = 在 GitHub 上已改名為 @{ $login }
+
+## 貢獻者詳細資料
+
+details-total-downloads = 總下載次數*
+details-avg-downloads = 每個套件平均*
+details-activity = 活動時間軸
+details-first = 首次：{ $date }
+details-latest = 最近：{ $date }
+details-top-package = 熱門套件
+details-package-downloads = 約 { $downloads } 次下載*
+details-no-package = 沒有套件資料
+details-top-feedstocks = 下載次數最多的 feedstock*
+details-largest-conversion = 最大的轉換：{ $name }（約 { $downloads } 次下載）
+hovercard-top-packages = 熱門套件
+hovercard-impact = { $feedstocks } 個 feedstock，約 { $downloads } 次下載
+details-self-conversion = 由自身維護者轉換
+details-footnote = * 下載次數為每個套件前 10 個版本的總和。資料來源：
+
+## 成就
+
+achievement-conda-mythic = Conda 神話
+achievement-forge-legend = 鍛造傳奇
+achievement-master-smith = 鍛造大師
+achievement-forge-smith = 鍛造工匠
+achievement-recipe-crafter = 配方工匠
+achievement-forge-apprentice = 鍛造學徒
+achievement-transmutation-master = 煉成大師
+achievement-migration-furnace = 遷移熔爐
+achievement-yaml-wizard = YAML 巫師
+achievement-recipe-translator = 配方翻譯家
+achievement-conda-terraformer = Conda 地形改造者
+achievement-ecosystem-grower = 生態系栽培者
+achievement-package-cultivator = 套件培育者
+achievement-feedstock-farmer = Feedstock 農夫
+achievement-homegrown = 自家栽培
+achievement-tooltip-total = { $name }（{ $threshold }+ 個 v1 貢獻）
+achievement-tooltip-conversions = { $name }（{ $threshold }+ 個 v1 轉換）
+achievement-tooltip-new-feedstocks = { $name }（{ $threshold }+ 個新的 v1 feedstock）
+achievement-tooltip-self-conversion = { $name }（轉換了自己維護的 feedstock）
+achievement-earned = { $tooltip } · 獲得於：{ $month }
+
+## 未轉換排行
+
+ranking-title = 排行：依下載次數排列的未轉換 feedstock
+ranking-intro = 下載次數最多、但尚未轉換到 Recipe v1 的 feedstock。遷移它們影響最大 :)
+ranking-showing = 顯示前 20 個 feedstock。
+ranking-downloads-note = 下載次數為最近 10 個版本的總和。
+ranking-source = 每日更新，資料來源：
+ranking-fork = Fork
+ranking-edit = 編輯並提議
+ranking-edit-tooltip = 在 GitHub 上編輯 meta.yaml，儲存時會 fork 此 feedstock 並開啟 pull request。建議標題：「{ $title }」
+ranking-ready-only = 只顯示可以轉換的 feedstock
+ranking-ready = ✅ 可轉換
+ranking-ready-tooltip = 此 feedstock 的所有相依套件都已使用 Recipe v1
+ranking-blocked = ⛓ { $count }
+ranking-blocked-tooltip = 仍使用 meta.yaml 的相依套件：{ $feedstocks }
+ranking-ready-none = 熱門的未轉換 feedstock 中，目前還沒有相依套件全部轉換完成的。
+ranking-active-only = 只顯示仍在積極維護的 feedstock
+ranking-active = 🛠 活躍
+ranking-active-tooltip = 最近 90 天內有推送
+ranking-dormant = 💤 停滯
+ranking-dormant-tooltip = 最近 90 天內沒有推送
+ranking-active-none = 熱門的未轉換 feedstock 中，最近 90 天內都沒有推送。
+
+## 最大的遷移
+
+highlight-biggest-title = 目前最大的遷移
+highlight-biggest-downloads = 次下載
+highlight-biggest-by = 由 { $contributors } 轉換
+highlight-biggest-by-on = 由 { $contributors } 於 { $date } 轉換
+
+## 最近更新
+
+recent-title = 最近更新到 Recipe v1
+recent-updated = 更新於 { $date }
+recent-recipe-name = 配方名稱
+recent-change-detected = 偵測到變更
+recent-by = 貢獻者：
+recent-window = 最近 { $days } 天內的更新
+recent-show-more = 再顯示 { $count } 個
+recent-show-less = 顯示較少
+
+## 非標準配方檔名
+
+renames-title = 就差一步：非標準的配方檔名
+renames-intro = 這些 feedstock 已使用 rattler-build 建置，但配方檔名不是 recipe.yaml。只要重新命名檔案就能算作已轉換。
+renames-recipe-file = 配方檔案
+
+## 維護者分析
+
+insight-title = 分析：維護者人數與轉換
+insight-likelier = 有 { $most } 位維護者的 feedstock 已轉換的機率是 { $least } 位維護者的 { $ratio } 倍。
+insight-share = 有 { $maintainers } 位維護者的 feedstock 中，{ $percent }% 已轉換。
+insight-maintainers = 維護者
+insight-footer = 依據各 feedstock 配方中列出的維護者：
+
+features-title = 分析：哪些配方較難轉換
+features-intro = 使用某項配方功能的 feedstock 與未使用者的轉換率比較。最常與停留在 meta.yaml 相關的功能排在最前面。
+features-feature = 配方功能
+features-with = 使用
+features-without = 未使用
+features-footer = 將游標移到格子上可查看 feedstock 數量。
+feature-multiple-outputs = 多個輸出
+feature-selectors = 選擇器
+feature-jinja-statements = Jinja 陳述式（{"{%"} … {"%}"}）
+feature-inline-script = 內嵌建置腳本
+feature-noarch = noarch
+feature-compiled = 編譯器
+
+## 同意橫幅
+
+consent-question = 可以記錄你的這次造訪嗎？我們只記錄頁面路徑與活動名稱，不使用 cookie，以了解推廣活動是否帶來轉換。
+consent-deny = 不用了，謝謝
+consent-accept = 記錄我的造訪
+
+## 資料品質
+
+quality-link = 這些資料有多可靠？
+quality-back = ← 返回儀表板
+quality-title = 資料品質
+quality-intro = 本站所有數字都來自自動化收集，難免有疏漏。此頁面列出資料的缺口，讓你判斷每個數字的可信度。
+quality-loading = 正在載入資料品質報告…
+quality-unavailable = 此快照沒有資料品質報告。
+quality-missing-downloads = 缺少下載次數的 feedstock
+quality-missing-downloads-hint = 這些在下載排行與總計中以零計算。
+quality-unknown-recipe-types = 配方類型不明的 feedstock
+quality-unknown-recipe-types-hint = 找不到任何配方格式，或兩種格式同時存在，因此視為未轉換。
+quality-unresolved-logins = 無法辨識的貢獻者帳號
+quality-unresolved-logins-hint = 無法對應到 GitHub 帳號的貢獻，不列入排行榜。
+quality-confidence-title = Recipe v1 feedstock 的歸屬可信度
+quality-confidence-high = 高：依新增配方的 commit 歸功
+quality-confidence-medium = 中：沒有已知的 commit
+quality-confidence-low = 低：無法辨識貢獻者
+quality-confidence-unattributed = 未歸屬
+
+## 生態系事件
+
+event-marker = { $date }：{ $title }
+
+## 完成度排行
+
+completionists-title = 完成度排行
+completionists-intro = 親自將自己維護的 feedstock 遷移到 Recipe v1 比例最高的維護者，不論他們維護多少個。
+completionists-footer = 依 extra.recipe-maintainers 中列出的 feedstock 計算，僅列入維護至少 5 個 feedstock 的維護者。
+
+## 報告
+
+report-link = 可列印的報告
+report-title = Recipe v1 遷移報告
+report-data-as-of = 資料日期：{ $date }
+report-print = 列印／另存為 PDF
+report-total-contributors = 貢獻者
+report-blocked-by = 未轉換的相依套件
+
+## 社群洞察
+
+insights-link = 社群洞察
+insights-title = 貢獻者留存率
+insights-intro = 有多少貢獻者持續回來貢獻。當某個月有歸功於某位貢獻者的轉換或新 feedstock 時，該貢獻者即視為當月活躍。
+insights-unavailable = 目前的歷史資料還不足以計算留存率。
+insights-loading = 正在載入洞察…
+insights-load-failed = 無法載入洞察。
+insights-active = 本月活躍
+insights-retention = 自上月留存
+insights-churned = 未再回來
+insights-chart-title = 上月貢獻者再次活躍的比例
+insights-bar-tooltip = { $month }：{ $previous } 位中有 { $retained } 位回來，共 { $active } 位活躍
+
+## Achievement feed
+
+feed-title = 最新成就
+feed-intro = 貢獻者最近跨過新門檻所獲得的徽章。
+feed-reached = 達成 { $achievement }
+
+## Pending migrations
+
+pending-title = 等待合併
+pending-intro = autotick bot 已為這 { $count } 個 feedstock 開好 Recipe v1 遷移 PR，只需要維護者審查並合併。
+pending-opened = 開啟於
+
+## Ticker
+
+ticker-label = 最新轉換
+ticker-downloads = 約 { $downloads } 次下載
+
+## Palette
+
+palette-colorblind = 色盲友善模式
+palette-tooltip = 以橘色圓點與藍色條紋區分轉換與新 feedstock
+
+## Conversion cohorts
+
+cohorts-title = 轉換能維持下去嗎？
+cohorts-intro = 依轉換發生的月份分組：經過各個月數後未退回 meta.yaml 的比例，目前仍使用 Recipe v1 且建置成功的比例，以及一週內需要修正配方的比例。
+cohorts-month = 世代
+cohorts-after = { $months } 個月後
+cohorts-still-v1 = 目前為 v1
+cohorts-passing = 目前建置成功
+cohorts-fixed = 一週內修正
+cohorts-footer = 建置結果取自預設分支最新的 CI 執行
+
+## Contributor mix
+
+mix-title = 新進與回訪貢獻者
+mix-headline = 過去 12 個月的轉換中，有 { $percent }% 來自首次貢獻的人。
+mix-new = 首次貢獻者
+mix-returning = 回訪貢獻者
+mix-bar-tooltip = { $month }：首次貢獻者 { $new } 個、回訪貢獻者 { $returning } 個
+
+## Conversion tips
+
+tip-multiple-outputs = 有多個輸出：請參考轉換指南，了解 Recipe v1 中輸出及其建置快取的寫法
+tip-selectors = 使用選擇器：`# [win]` 註解在 Recipe v1 中改為 `if`/`then` 區塊
+tip-jinja-statements = 使用 Jinja 陳述式：`{"{%"} set {"%}"}` 改寫為 `context` 區段，其他陳述式需要重寫
+tip-inline-script = 有內嵌建置腳本：請參考 Recipe v1 中建置腳本的寫法
+tip-noarch = 為 noarch：請查看配方參考中的 `build.noarch` 選項
+tip-compiled = 使用編譯器：請參考 Recipe v1 中編譯器及其需求的宣告方式
+
+## Relative times
+
+time-just-now = 剛剛
+time-minutes-ago = { $count } 分鐘前
+time-hours-ago = { $count } 小時前
+time-days-ago = { $count } 天前
+time-weeks-ago = { $count } 週前
+time-months-ago = { $count } 個月前
+time-years-ago = { $count } 年前
+
+## Watchlist
+
+watch-add = 追蹤：此 feedstock 轉換為 Recipe v1 時通知我
+watch-remove = 停止追蹤
+watch-notification-title = 已轉換為 Recipe v1
+watch-converted = 你追蹤的 { $feedstocks } 已轉換為 Recipe v1！
+watch-dismiss = 關閉
+
+## Maintainer coverage
+
+maintainers-link = 維護者覆蓋率
+maintainers-title = 維護者覆蓋率
+maintainers-intro = 每位配方維護者所維護的 feedstock 中，有多少已使用 Recipe v1。搜尋你的 GitHub 帳號，查看你還有哪些套件尚待轉換。
+maintainers-search = 依 GitHub 帳號搜尋
+maintainers-loading = 正在載入維護者…
+maintainers-unavailable = 無法載入維護者覆蓋率。
+maintainers-no-match = 沒有符合此帳號的維護者。
+maintainers-unconverted = 尚有 { $count } 個 feedstock 待轉換
+maintainers-generated = 更新於 { $date }
+
+## Conversion forecast
+
+forecast-title = 可能即將轉換
+forecast-experimental = 實驗性
+forecast-intro = 依轉換前常見的訊號為尚未轉換的 feedstock 評分：維護者已轉換其他 feedstock、相依套件已使用 Recipe v1，以及儲存庫近期有活動。
+forecast-feedstock = Feedstock
+forecast-score = 分數
+forecast-maintainers = 維護者
+forecast-dependencies = 相依套件
+forecast-pushed = 最後推送
+forecast-hits = 上次預測中，已有 { $count } 個轉換：{ $feedstocks }。
+forecast-footer = 這只是簡單的加權分數，並非保證。與實際轉換比較，可看出推廣是否有效。
+
+## Bot activity
+
+bot-activity-title = 機器人活動與轉換
+bot-activity-intro = 依 autotick 機器人開啟的 pull request 數量將 feedstock 分組。每個遷移 PR 也會重新渲染 feedstock，因此可看出經常重新渲染的 feedstock 是否較早轉換。
+bot-activity-prs = 機器人 PR
+bot-activity-recent = 最近 90 天
+bot-activity-footer = 依據 cf-graph 的 pr_info 中記錄的 PR。機器人 PR 較多的 feedstock 通常也較舊。
@@ -0,0 +1,103 @@
+//! Optional, cookie-less page-view counting behind a consent banner
+
+use leptos::prelude::window;
+
+/// Beacon endpoint, set at build time via `ANALYTICS_ENDPOINT`.
+/// Analytics are disabled when it is unset or empty.
+pub fn endpoint() -> Option<&'static str> {
+    option_env!("ANALYTICS_ENDPOINT").filter(|endpoint| !endpoint.is_empty())
+}
+
+/// Local storage key remembering the visitor's choice
+const CONSENT_KEY: &str = "analytics-consent";
+
+/// The visitor's answer to the consent banner
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Consent {
+    Unknown,
+    Granted,
+    Denied,
+}
+
+impl Consent {
+    fn parse(s: Option<&str>) -> Self {
+        match s {
+            Some("granted") => Self::Granted,
+            Some("denied") => Self::Denied,
+            _ => Self::Unknown,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Granted => "granted",
+            Self::Denied => "denied",
+        }
+    }
+}
+
+/// Read the stored consent, `Unknown` if storage is unavailable
+pub fn load_consent() -> Consent {
+    let stored = window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(CONSENT_KEY).ok().flatten());
+    Consent::parse(stored.as_deref())
+}
+
+/// Remember the visitor's choice (best effort)
+pub fn store_consent(consent: Consent) {
+    if let Ok(Some(storage)) = window().local_storage() {
+        let _ = storage.set_item(CONSENT_KEY, consent.as_str());
+    }
+}
+
+/// Send a single page-view beacon: only the path and campaign are reported
+pub fn send_page_view(endpoint: &str) {
+    let location = window().location();
+    let path = location.pathname().unwrap_or_default();
+    let search = location.search().unwrap_or_default();
+    let _ = window()
+        .navigator()
+        .send_beacon_with_opt_str(endpoint, Some(&page_view_payload(&path, &search)));
+}
+
+/// Build the beacon body, keeping only the `utm_campaign` query parameter so
+/// campaigns can be measured without leaking anything else from the URL
+fn page_view_payload(path: &str, search: &str) -> String {
+    let campaign = search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("utm_campaign="))
+        .filter(|campaign| !campaign.is_empty());
+
+    match campaign {
+        Some(campaign) => format!("{}?utm_campaign={}", path, campaign),
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consent_roundtrip() {
+        for consent in [Consent::Unknown, Consent::Granted, Consent::Denied] {
+            assert_eq!(Consent::parse(Some(consent.as_str())), consent);
+        }
+        assert_eq!(Consent::parse(None), Consent::Unknown);
+    }
+
+    #[test]
+    fn test_page_view_payload_keeps_only_campaign() {
+        assert_eq!(page_view_payload("/site/", ""), "/site/");
+        assert_eq!(
+            page_view_payload("/site/", "?ref=x&utm_campaign=sprint&email=a@b.c"),
+            "/site/?utm_campaign=sprint"
+        );
+        assert_eq!(page_view_payload("/site/", "?utm_campaign="), "/site/");
+    }
+}
@@ -0,0 +1,229 @@
+//! Top-level application component
+
+use std::future::Future;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use super::{
+    next_milestone_share, watch_conversions, AchievementFeed, BiggestConversionCard,
+    CommunityGrowthChart, CompletionistLeaderboard, ConsentBanner, DataQualityPage,
+    GrowthSourceChart, InfoAccordion, InsightsPage, LanguagePicker, Leaderboard, MaintainerInsight,
+    MaintainersPage, MigrationChart, MigrationStats, NonStandardRecipes, NotableTicker,
+    PalettePatterns, PaletteToggle, PendingMigrations, ProgressRoadmap, RecentlyUpdated,
+    RecipeFeatureInsight, ReportPage, TopUnconvertedRanking, WatchlistAlerts, DATA_QUALITY_HASH,
+    INSIGHTS_HASH, MAINTAINERS_HASH, REPORT_HASH, ROADMAP_PACE_WEEKS,
+};
+use crate::data::{DataQuality, InsightsFile, StatsData};
+use crate::debug;
+use crate::i18n::provide_i18n;
+use crate::remote;
+use crate::theme;
+use crate::watchlist::provide_watchlist;
+
+#[component]
+pub fn App() -> impl IntoView {
+    let i18n = provide_i18n();
+    provide_watchlist();
+    let watch_converted = watch_conversions();
+    theme::apply_palette(theme::load_palette());
+
+    // Render the embedded stats right away, then swap in fresher ones if available, or
+    // the edge-case dataset in debug mode. A fetched snapshot older than the embedded
+    // one, e.g. from a stale cache or a forgotten STATS_URL, is ignored.
+    let debug = debug::is_enabled();
+    let stats = RwSignal::new(StatsData::load());
+    spawn_local(async move {
+        let url = if debug {
+            debug::DEBUG_STATS_URL
+        } else {
+            remote::stats_url()
+        };
+        if let Some(fetched) = remote::fetch_stats(url).await {
+            if debug || fetched.is_newer_than(&stats.read_untracked()) {
+                stats.set(fetched);
+            }
+        }
+    });
+
+    // Pages are picked by the URL fragment, so they work without a server-side router
+    let hash = RwSignal::new(window().location().hash().unwrap_or_default());
+    let handle = window_event_listener(leptos::ev::hashchange, move |_| {
+        hash.set(window().location().hash().unwrap_or_default());
+    });
+    on_cleanup(move || handle.remove());
+
+    // Data only shown on its own page is fetched the first time the page is opened, then
+    // kept for the rest of the visit. None while loading, Some(None) if it couldn't be.
+    let insights = RwSignal::new(None::<Option<InsightsFile>>);
+    let data_quality = RwSignal::new(None::<Option<DataQuality>>);
+    Effect::new(move |_| match hash.get().as_str() {
+        INSIGHTS_HASH => fetch_once(insights, remote::fetch_insights),
+        DATA_QUALITY_HASH => fetch_once(data_quality, remote::fetch_data_quality),
+        _ => {}
+    });
+
+    view! {
+        <div class="min-h-screen bg-gray-50">
+            {debug.then(|| view! {
+                <div class="bg-amber-100 text-amber-900 text-center text-sm py-2 print:hidden">
+                    {move || i18n.t("debug-banner")}
+                </div>
+            })}
+            // Rebuilt when the data or the language changes
+            {move || {
+                i18n.locale();
+                view! {
+                    <header class="relative text-center py-10 px-4">
+                        <div class="absolute top-4 right-4 flex gap-2 print:hidden">
+                            <PaletteToggle />
+                            <LanguagePicker />
+                        </div>
+                        <h1 class="text-5xl md:text-6xl font-bold text-gray-900 mb-4 tracking-tight">
+                            {i18n.t("app-title")}
+                        </h1>
+                        <p class="text-base text-gray-500 max-w-2xl mx-auto mb-6">
+                            {i18n.t("app-subtitle")}
+                        </p>
+                        <div class="print:hidden">
+                            <InfoAccordion />
+                        </div>
+                    </header>
+                    {match hash.get().as_str() {
+                        DATA_QUALITY_HASH => {
+                            view! { <DataQualityPage quality=data_quality.into() /> }.into_any()
+                        }
+                        REPORT_HASH => view! { <ReportPage stats=stats.get() /> }.into_any(),
+                        INSIGHTS_HASH => view! { <InsightsPage insights=insights.into() /> }.into_any(),
+                        MAINTAINERS_HASH => view! { <MaintainersPage /> }.into_any(),
+                        _ => {
+                            view! { <Dashboard stats=stats.get() watch_converted=watch_converted /> }
+                                .into_any()
+                        }
+                    }}
+                    <div class="max-w-6xl mx-auto px-4 mt-8 mb-8 print:hidden">
+                        <a href="https://rattler.build" target="_blank" class="block rounded-lg ring-0 ring-gray-900 hover:ring-2 transition-all duration-150">
+                            <img
+                                src="./banner.png"
+                                alt=i18n.t("app-banner-alt")
+                                class="w-full rounded-lg shadow-lg hover:shadow-xl transition-shadow duration-150"
+                            />
+                        </a>
+                    </div>
+                    <footer class="text-center text-sm text-gray-400 pb-8 space-x-4 print:hidden">
+                        <a href=DATA_QUALITY_HASH class="hover:text-gray-600 hover:underline">
+                            {i18n.t("quality-link")}
+                        </a>
+                        <a href=REPORT_HASH class="hover:text-gray-600 hover:underline">
+                            {i18n.t("report-link")}
+                        </a>
+                        <a href=INSIGHTS_HASH class="hover:text-gray-600 hover:underline">
+                            {i18n.t("insights-link")}
+                        </a>
+                        <a href=MAINTAINERS_HASH class="hover:text-gray-600 hover:underline">
+                            {i18n.t("maintainers-link")}
+                        </a>
+                    </footer>
+                }
+            }}
+            <ConsentBanner />
+            <PalettePatterns />
+        </div>
+    }
+}
+
+/// Fetch a page's data into `data`, unless that already happened
+fn fetch_once<T, F>(data: RwSignal<Option<Option<T>>>, fetch: impl FnOnce() -> F + 'static)
+where
+    T: Send + Sync + 'static,
+    F: Future<Output = Option<T>>,
+{
+    if data.with_untracked(Option::is_none) {
+        spawn_local(async move { data.set(Some(fetch().await)) });
+    }
+}
+
+/// All data-driven sections of the page
+#[component]
+fn Dashboard(stats: StatsData, watch_converted: RwSignal<Vec<String>>) -> impl IntoView {
+    let converted_recipes = stats.recipe_v1_count;
+    let total_recipes = stats.total_feedstocks;
+    let eta_weeks = next_milestone_share(converted_recipes, total_recipes)
+        .and_then(|share| stats.weeks_until_share(share, ROADMAP_PACE_WEEKS));
+
+    view! {
+        <div class="max-w-6xl mx-auto px-4 pb-8">
+            <WatchlistAlerts converted=watch_converted />
+            <NotableTicker conversions=stats.notable_recent.clone() />
+            <main class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+                <div class="grid md:grid-cols-2 gap-12 items-center">
+                    <MigrationChart
+                        converted=converted_recipes
+                        total=total_recipes
+                        dependency_weighted=stats.dependency_weighted.clone()
+                    />
+                    <MigrationStats
+                        converted=converted_recipes
+                        total=total_recipes
+                        build_health=stats.build_health.clone()
+                        recipe_lint=stats.recipe_lint.clone()
+                    />
+                </div>
+                <div class="mt-10">
+                    <ProgressRoadmap
+                        converted=converted_recipes
+                        total=total_recipes
+                        eta_weeks=eta_weeks
+                        last_updated=stats.last_updated.clone()
+                    />
+                </div>
+            </main>
+            <div class="mt-8">
+                <BiggestConversionCard conversion=stats.biggest_conversion />
+            </div>
+            <div class="mt-8">
+                <RecentlyUpdated
+                    feedstocks=stats.recently_updated
+                    last_updated=stats.last_updated
+                    window_days=stats.recently_updated_days
+                />
+            </div>
+            <div class="mt-8">
+                <Leaderboard
+                    contributors=stats.top_contributors
+                    activity_weeks=stats.activity_weeks
+                    self_conversions=stats.self_conversions
+                    events=stats.events.clone()
+                    rank_change_since=stats.rank_change_since
+                />
+            </div>
+            <div class="mt-8">
+                <AchievementFeed unlocks=stats.achievement_feed />
+            </div>
+            <div class="mt-8">
+                <CompletionistLeaderboard completionists=stats.completionists />
+            </div>
+            <div class="mt-8">
+                <CommunityGrowthChart
+                    growth=stats.contributor_growth
+                    total=stats.total_contributors
+                    events=stats.events
+                />
+            </div>
+            <div class="mt-8">
+                <GrowthSourceChart weeks=stats.weekly_v1_growth />
+            </div>
+            <div class="mt-8">
+                <TopUnconvertedRanking feedstocks=stats.top_unconverted />
+            </div>
+            <NonStandardRecipes feedstocks=stats.non_standard_recipes />
+            <PendingMigrations feedstocks=stats.pending_migrations />
+            <div class="mt-8">
+                <MaintainerInsight rates=stats.maintainer_conversion_rates />
+            </div>
+            <div class="mt-8">
+                <RecipeFeatureInsight rates=stats.recipe_feature_rates />
+            </div>
+        </div>
+    }
+}
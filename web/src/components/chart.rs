@@ -0,0 +1,657 @@
+//! Migration progress and activity charts
+
+use leptos::prelude::*;
+
+use crate::data::{
+    percent, BuildHealth, ContributionStyle, ContributionType, DependencyWeighted, GrowthPoint,
+    RecipeLint, TimelineEvent, WeeklyActivity, WeeklyGrowth,
+};
+use crate::i18n::{use_i18n, I18n};
+use crate::theme;
+
+/// Share of converted feedstocks. With `dependency_weighted`, a toggle switches to the
+/// share weighted by each feedstock's number of dependents.
+#[component]
+pub fn MigrationChart(
+    converted: u32,
+    total: u32,
+    dependency_weighted: Option<DependencyWeighted>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let by_feedstock = percent(converted, total);
+    let by_dependents = dependency_weighted
+        .filter(|weighted| weighted.total > 0)
+        .map(|weighted| weighted.percent());
+    let weighted = RwSignal::new(false);
+    let percentage = move || match by_dependents {
+        Some(by_dependents) if weighted.get() => by_dependents,
+        _ => by_feedstock,
+    };
+
+    // SVG circle constants
+    const CIRCLE_RADIUS: f64 = 80.0;
+    const DEGREES_PER_PERCENT: f64 = 3.6; // 360 degrees / 100 percent
+
+    // Calculate circumference: 2π * radius
+    let circumference = 2.0 * std::f64::consts::PI * CIRCLE_RADIUS;
+
+    // CSS variables for the animation
+    let style_vars = move || {
+        // Convert percentage to degrees, then to arc length
+        let converted_angle = percentage() * DEGREES_PER_PERCENT;
+        let arc_length = (converted_angle / 360.0) * circumference;
+        let remaining_length = circumference - arc_length;
+        format!(
+            "--progress-arc: {:.2}; --progress-remaining: {:.2};",
+            arc_length, remaining_length
+        )
+    };
+
+    let toggle = by_dependents.map(|_| {
+        view! {
+            <div
+                class="mt-4 flex items-center gap-1 text-xs text-gray-500 print:hidden"
+                title=i18n.t("progress-weighted-tooltip")
+            >
+                {[(false, "progress-by-feedstock"), (true, "progress-by-dependents")]
+                    .into_iter()
+                    .map(|(value, label)| {
+                        view! {
+                            <button
+                                on:click=move |_| weighted.set(value)
+                                class=move || format!(
+                                    "px-2 py-0.5 rounded transition-colors duration-150 {}",
+                                    if weighted.get() == value { "bg-gray-200 text-gray-700" } else { "hover:text-gray-600" }
+                                )
+                            >
+                                {i18n.t(label)}
+                            </button>
+                        }
+                    })
+                    .collect::<Vec<_>>()}
+            </div>
+        }
+    });
+
+    view! {
+        <div class="flex flex-col items-center">
+            <h2 class="text-2xl font-semibold text-gray-900 mb-8 tracking-tight">{i18n.t("progress-title")}</h2>
+            <div class="relative w-64 h-64">
+                <svg class="w-full h-full transform -rotate-90" viewBox="0 0 200 200">
+                    // Background circle (full circumference)
+                    <circle
+                        cx="100"
+                        cy="100"
+                        r="80"
+                        fill="none"
+                        stroke="#e5e7eb"
+                        stroke-width="20"
+                    />
+                    // Progress circle (partial circumference based on percentage)
+                    <circle
+                        cx="100"
+                        cy="100"
+                        r="80"
+                        fill="none"
+                        stroke="#F9C500"
+                        stroke-width="20"
+                        stroke-linecap="round"
+                        class="progress-circle"
+                        style=style_vars
+                    />
+                </svg>
+                <div class="absolute inset-0 flex items-center justify-center">
+                    <div class="text-center">
+                        <div class="text-3xl font-bold text-gray-900 tabular-nums">{move || format!("{:.1}%", percentage())}</div>
+                        <div class="text-sm text-gray-500">
+                            {move || i18n.t(if weighted.get() { "progress-weighted-complete" } else { "progress-complete" })}
+                        </div>
+                    </div>
+                </div>
+            </div>
+            {toggle}
+        </div>
+    }
+}
+
+#[component]
+pub fn MigrationStats(
+    converted: u32,
+    total: u32,
+    build_health: Option<BuildHealth>,
+    recipe_lint: Option<RecipeLint>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let build_health = build_health
+        .filter(|health| health.checked > 0)
+        .map(|health| {
+            let tooltip = i18n.t_with(
+                "stats-builds-tooltip",
+                &[
+                    ("passing", health.passing.into()),
+                    ("failing", health.failing.into()),
+                    ("pending", health.pending.into()),
+                ],
+            );
+            view! {
+                <div class="text-center text-sm text-gray-500" title=tooltip>
+                    <span class="font-semibold text-emerald-700 tabular-nums">
+                        {format!("{:.0}%", health.passing_percent())}
+                    </span>
+                    " "
+                    {i18n.t("stats-builds-passing")}
+                </div>
+            }
+        });
+    let recipe_lint = recipe_lint.filter(|lint| lint.checked > 0).map(|lint| {
+        let tooltip = i18n.t_with(
+            "stats-lint-tooltip",
+            &[
+                ("passing", lint.passing.into()),
+                ("failing", lint.failing.into()),
+            ],
+        );
+        view! {
+            <div class="text-center text-sm text-gray-500" title=tooltip>
+                <span class="font-semibold text-emerald-700 tabular-nums">
+                    {format!("{:.0}%", lint.passing_percent())}
+                </span>
+                " "
+                {i18n.t("stats-lint-passing")}
+            </div>
+        }
+    });
+
+    view! {
+        <div class="space-y-6">
+            <h2 class="text-2xl font-semibold text-gray-900 tracking-tight text-center">{i18n.t("stats-title")}</h2>
+
+            <div class="flex items-end justify-center gap-3">
+                <div class="text-center">
+                    <div class="text-xs font-semibold text-emerald-600 uppercase tracking-wide mb-1">{i18n.t("label-converted")}</div>
+                    <div class="text-4xl font-bold text-emerald-600 tabular-nums">{converted.to_string()}</div>
+                </div>
+                <div class="text-4xl font-light text-gray-300 pb-1">"/"</div>
+                <div class="text-center">
+                    <div class="text-xs font-semibold text-gray-500 uppercase tracking-wide mb-1">{i18n.t("stats-remaining")}</div>
+                    <div class="text-4xl font-bold text-gray-700 tabular-nums">{(total - converted).to_string()}</div>
+                </div>
+            </div>
+
+            <div class="text-center text-sm text-gray-500">
+                {i18n.t_with("stats-out-of", &[("total", total.into())])}
+            </div>
+            {build_health}
+            {recipe_lint}
+        </div>
+    }
+}
+
+/// Line chart of the cumulative number of unique contributors per month, with
+/// ecosystem events marked on it
+#[component]
+pub fn CommunityGrowthChart(
+    growth: Vec<GrowthPoint>,
+    total: u32,
+    events: Vec<TimelineEvent>,
+) -> impl IntoView {
+    if growth.len() < 2 {
+        return view! {}.into_any();
+    }
+
+    const WIDTH: f64 = 600.0;
+    const HEIGHT: f64 = 160.0;
+    const PADDING: f64 = 8.0;
+
+    let max = growth
+        .iter()
+        .map(|p| p.contributors)
+        .max()
+        .unwrap_or(1)
+        .max(1) as f64;
+    let step = (WIDTH - 2.0 * PADDING) / (growth.len() - 1) as f64;
+    let points: Vec<(f64, f64)> = growth
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let x = PADDING + i as f64 * step;
+            let y = HEIGHT - PADDING - (p.contributors as f64 / max) * (HEIGHT - 2.0 * PADDING);
+            (x, y)
+        })
+        .collect();
+
+    let line = points
+        .iter()
+        .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let (first_x, _) = points[0];
+    let (last_x, _) = points[points.len() - 1];
+    let area = format!(
+        "{:.1},{:.1} {} {:.1},{:.1}",
+        first_x,
+        HEIGHT - PADDING,
+        line,
+        last_x,
+        HEIGHT - PADDING
+    );
+
+    let i18n = use_i18n();
+    let first_month = growth[0].month.clone();
+    let last_month = growth[growth.len() - 1].month.clone();
+
+    // Events within the charted months, at the position of their month
+    let events: Vec<_> = events
+        .into_iter()
+        .filter_map(|event| {
+            let index = growth.iter().position(|p| p.month == event.month)?;
+            Some((PADDING + index as f64 * step, event))
+        })
+        .collect();
+    let markers: Vec<_> = events
+        .iter()
+        .map(|(x, event)| (*x, event_label(&i18n, event)))
+        .collect();
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">{i18n.t("growth-title")}</h2>
+                <p class="text-gray-500 leading-relaxed">
+                    {i18n.t_with("growth-summary", &[("total", total.into())])}
+                </p>
+            </div>
+            <svg class="w-full h-40" viewBox=format!("0 0 {} {}", WIDTH, HEIGHT) preserveAspectRatio="none">
+                <polygon points=area fill=theme::colors::EMERALD fill-opacity="0.1" />
+                <polyline
+                    points=line
+                    fill="none"
+                    stroke=theme::colors::EMERALD
+                    stroke-width="2"
+                    vector-effect="non-scaling-stroke"
+                />
+                {markers.into_iter().map(|(x, label)| view! {
+                    <line
+                        x1=x
+                        y1="0"
+                        x2=x
+                        y2=HEIGHT
+                        stroke=theme::colors::AMBER
+                        stroke-width="1"
+                        stroke-dasharray="4,3"
+                        vector-effect="non-scaling-stroke"
+                    >
+                        <title>{label}</title>
+                    </line>
+                }).collect::<Vec<_>>()}
+                {growth.into_iter().zip(points).map(|(point, (x, y))| {
+                    view! {
+                        <circle cx=x cy=y r="6" fill="transparent">
+                            <title>
+                                {i18n.t_with(
+                                    "growth-point",
+                                    &[("month", point.month.into()), ("contributors", point.contributors.into())],
+                                )}
+                            </title>
+                        </circle>
+                    }
+                }).collect::<Vec<_>>()}
+            </svg>
+            <div class="flex justify-between text-xs text-gray-400 mt-2 tabular-nums">
+                <span>{first_month}</span>
+                <span>{last_month}</span>
+            </div>
+            {(!events.is_empty()).then(|| view! {
+                <ul class="mt-4 flex flex-wrap gap-x-6 gap-y-1 text-xs text-gray-500">
+                    {events.into_iter().map(|(_, event)| view! {
+                        <li>
+                            <span class="text-amber-500">"◆ "</span>
+                            <span class="tabular-nums">{event.date}</span>" "
+                            {match event.url {
+                                Some(url) => view! {
+                                    <a href=url target="_blank" rel="noopener noreferrer" class="text-blue-600 hover:underline">
+                                        {event.title}
+                                    </a>
+                                }.into_any(),
+                                None => view! { <span>{event.title}</span> }.into_any(),
+                            }}
+                        </li>
+                    }).collect::<Vec<_>>()}
+                </ul>
+            })}
+        </div>
+    }.into_any()
+}
+
+/// Weeks compared against the whole history in the summary
+const RECENT_GROWTH_WEEKS: usize = 12;
+
+/// Stacked weekly bars of conversions and new Recipe v1 feedstocks, showing whether
+/// v1 grows through migrations or through new packages
+#[component]
+pub fn GrowthSourceChart(weeks: Vec<WeeklyGrowth>) -> impl IntoView {
+    if weeks.len() < 2 {
+        return view! {}.into_any();
+    }
+
+    const WIDTH: f64 = 600.0;
+    const HEIGHT: f64 = 160.0;
+
+    let i18n = use_i18n();
+    let max = weeks
+        .iter()
+        .map(|w| w.conversions + w.new_feedstocks)
+        .max()
+        .unwrap_or(1)
+        .max(1) as f64;
+    let slot = WIDTH / weeks.len() as f64;
+    let bar_width = (slot * 0.8).max(1.0);
+
+    // Share of conversions among all v1 contributions in the given weeks
+    let conversion_percent = |weeks: &[WeeklyGrowth]| {
+        let conversions: u32 = weeks.iter().map(|w| w.conversions).sum();
+        let total: u32 = weeks.iter().map(|w| w.conversions + w.new_feedstocks).sum();
+        if total == 0 {
+            0.0
+        } else {
+            conversions as f64 * 100.0 / total as f64
+        }
+    };
+    let percent = conversion_percent(&weeks);
+    let recent = conversion_percent(&weeks[weeks.len().saturating_sub(RECENT_GROWTH_WEEKS)..]);
+    let summary = i18n.t_with(
+        "growth-source-summary",
+        &[
+            ("percent", format!("{:.0}", percent).into()),
+            ("recent", format!("{:.0}", recent).into()),
+            ("weeks", RECENT_GROWTH_WEEKS.into()),
+        ],
+    );
+    let first_week = weeks[0].week.clone();
+    let last_week = weeks[weeks.len() - 1].week.clone();
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">{i18n.t("growth-source-title")}</h2>
+                <p class="text-gray-500 leading-relaxed">{summary}</p>
+            </div>
+            <svg class="w-full h-40" viewBox=format!("0 0 {} {}", WIDTH, HEIGHT) preserveAspectRatio="none">
+                {weeks.into_iter().enumerate().map(|(i, week)| {
+                    let x = i as f64 * slot;
+                    let conv_height = week.conversions as f64 / max * HEIGHT;
+                    let new_height = week.new_feedstocks as f64 / max * HEIGHT;
+                    let tooltip = i18n.t_with(
+                        "growth-source-week",
+                        &[
+                            ("week", week.week.into()),
+                            ("conversions", week.conversions.into()),
+                            ("new", week.new_feedstocks.into()),
+                        ],
+                    );
+                    // New feedstocks at the bottom, conversions stacked on top
+                    view! {
+                        <g>
+                            <title>{tooltip}</title>
+                            <rect
+                                x=x
+                                y=HEIGHT - new_height
+                                width=bar_width
+                                height=new_height
+                                fill=ContributionType::NewFeedstock.svg_color()
+                                class=ContributionType::NewFeedstock.svg_class()
+                            />
+                            <rect
+                                x=x
+                                y=HEIGHT - new_height - conv_height
+                                width=bar_width
+                                height=conv_height
+                                fill=ContributionType::Conversion.svg_color()
+                                class=ContributionType::Conversion.svg_class()
+                            />
+                        </g>
+                    }
+                }).collect::<Vec<_>>()}
+            </svg>
+            <div class="flex justify-between text-xs text-gray-400 mt-2 tabular-nums">
+                <span>{first_week}</span>
+                <span>{last_week}</span>
+            </div>
+            <div class="mt-4 flex justify-center gap-6 text-xs text-gray-500">
+                <span class="flex items-center gap-1.5">
+                    <span class=format!("w-3 h-3 rounded-sm {}", theme::classes::CONVERSION_BG)></span>
+                    {i18n.t("label-conversions")}
+                </span>
+                <span class="flex items-center gap-1.5">
+                    <span class=format!("w-3 h-3 rounded-sm {}", theme::classes::NEW_FEEDSTOCK_BG)></span>
+                    {i18n.t("label-new-feedstocks")}
+                </span>
+            </div>
+        </div>
+    }.into_any()
+}
+
+/// Tooltip text of an event marker
+fn event_label(i18n: &I18n, event: &TimelineEvent) -> String {
+    i18n.t_with(
+        "event-marker",
+        &[
+            ("date", event.date.clone().into()),
+            ("title", event.title.clone().into()),
+        ],
+    )
+}
+
+/// Weekly activity sparkline showing stacked bars, one per week, with markers
+/// for ecosystem events in the shown weeks
+#[component]
+pub fn ActivitySparkline(
+    weekly_activity: WeeklyActivity,
+    events: Vec<TimelineEvent>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    // Find max total for scaling
+    let max_total = weekly_activity
+        .iter()
+        .map(|(c, n)| c + n)
+        .max()
+        .unwrap_or(1)
+        .max(1); // Ensure at least 1 to avoid division by zero
+
+    // SVG dimensions - fixed width, bars shrink to fit longer ranges
+    const SPARKLINE_WIDTH: f64 = 220.0;
+    let height = 32;
+    let bar_count = weekly_activity.len();
+    let slot_width = SPARKLINE_WIDTH / bar_count.max(1) as f64;
+    let bar_width = (slot_width * 0.73).max(1.0);
+    let total_width = slot_width * bar_count as f64;
+
+    // Generate bars with tooltip info (reversed so most recent is on right)
+    let bars: Vec<_> = weekly_activity
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, (conv, new_fs))| {
+            let total = conv + new_fs;
+            let bar_height = if total > 0 {
+                ((total as f64 / max_total as f64) * (height as f64 - 4.0)).max(2.0)
+            } else {
+                0.0
+            };
+
+            let new_height = if total > 0 {
+                (*new_fs as f64 / total as f64) * bar_height
+            } else {
+                0.0
+            };
+            let conv_height = bar_height - new_height;
+
+            let x = i as f64 * slot_width;
+            let conv_y = height as f64 - bar_height;
+            let new_y = conv_y + conv_height;
+
+            // Weeks ago for tooltip (i is reversed, so 0 = most recent)
+            let weeks_ago = bar_count - 1 - i;
+            let week = i18n.t_with("activity-week", &[("weeks", weeks_ago.into())]);
+            let tooltip = if total > 0 {
+                i18n.t_with(
+                    "activity-week-summary",
+                    &[
+                        ("week", week.into()),
+                        ("conversions", (*conv).into()),
+                        ("new", (*new_fs).into()),
+                    ],
+                )
+            } else {
+                i18n.t_with("activity-week-empty", &[("week", week.into())])
+            };
+
+            (x, conv_y, conv_height, new_y, new_height, total, tooltip)
+        })
+        .collect();
+
+    // Event markers, centered on their week's bar
+    let markers: Vec<_> = events
+        .iter()
+        .filter_map(|event| {
+            let weeks_ago = event.weeks_ago.filter(|w| *w < bar_count)?;
+            let x = (bar_count - 1 - weeks_ago) as f64 * slot_width + bar_width / 2.0;
+            Some((x, event_label(&i18n, event)))
+        })
+        .collect();
+
+    // Calculate a nice reference line value (round to nearest 5 or 10)
+    let reference_value = if max_total >= 20 {
+        max_total.div_ceil(10) * 10 // Round up to nearest 10
+    } else if max_total >= 5 {
+        max_total.div_ceil(5) * 5 // Round up to nearest 5
+    } else {
+        max_total
+    };
+    let reference_y =
+        height as f64 - ((reference_value as f64 / max_total as f64) * (height as f64 - 4.0));
+
+    // Calculate label width based on number of digits
+    let label_width = if reference_value >= 100 {
+        25.0
+    } else if reference_value >= 10 {
+        18.0
+    } else {
+        12.0
+    };
+
+    view! {
+        <svg
+            width=total_width + label_width + 4.0
+            height=height
+            class="inline-block align-middle overflow-visible"
+            viewBox=format!("0 0 {} {}", total_width + label_width + 4.0, height)
+        >
+            // Reference line with label
+            <line
+                x1="0"
+                y1=reference_y
+                x2={total_width}
+                y2=reference_y
+                stroke=theme::colors::GRAY_MEDIUM
+                stroke-width="1"
+                stroke-dasharray="2,2"
+            />
+            <text
+                x={total_width + 3.0}
+                y={reference_y + 3.0}
+                font-size="9"
+                fill=theme::colors::GRAY_TEXT
+            >
+                {reference_value}
+            </text>
+            // Baseline
+            <line
+                x1="0"
+                y1={height - 1}
+                x2={total_width}
+                y2={height - 1}
+                stroke=theme::colors::GRAY_LIGHT
+                stroke-width="1"
+            />
+            // Event markers, behind the bars
+            {markers.into_iter().map(|(x, label)| view! {
+                <line
+                    x1=x
+                    y1="0"
+                    x2=x
+                    y2=height
+                    stroke=theme::colors::AMBER
+                    stroke-width="1"
+                    stroke-dasharray="2,2"
+                >
+                    <title>{label}</title>
+                </line>
+            }).collect::<Vec<_>>()}
+            // Bars with tooltips
+            {bars.into_iter().map(|(x, conv_y, conv_height, new_y, new_height, total, tooltip)| {
+                view! {
+                    <g>
+                        {if total == 0 {
+                            // Show thin gray placeholder for empty weeks
+                            view! {
+                                <rect
+                                    x=x
+                                    y={height - 2}
+                                    width=bar_width
+                                    height="1"
+                                    fill=theme::colors::GRAY_MEDIUM
+                                    rx="1"
+                                >
+                                    <title>{tooltip.clone()}</title>
+                                </rect>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <g>
+                                    // Conversions (emerald) - top part
+                                    {if conv_height > 0.0 {
+                                        view! {
+                                            <rect
+                                                x=x
+                                                y=conv_y
+                                                width=bar_width
+                                                height=conv_height
+                                                fill=ContributionType::Conversion.svg_color()
+                                                class=ContributionType::Conversion.svg_class()
+                                                rx="1"
+                                            >
+                                                <title>{tooltip.clone()}</title>
+                                            </rect>
+                                        }.into_any()
+                                    } else {
+                                        view! {}.into_any()
+                                    }}
+                                    // New feedstocks (blue) - bottom part
+                                    {if new_height > 0.0 {
+                                        view! {
+                                            <rect
+                                                x=x
+                                                y=new_y
+                                                width=bar_width
+                                                height=new_height
+                                                fill=ContributionType::NewFeedstock.svg_color()
+                                                class=ContributionType::NewFeedstock.svg_class()
+                                                rx="1"
+                                            >
+                                                <title>{tooltip.clone()}</title>
+                                            </rect>
+                                        }.into_any()
+                                    } else {
+                                        view! {}.into_any()
+                                    }}
+                                </g>
+                            }.into_any()
+                        }}
+                    </g>
+                }
+            }).collect::<Vec<_>>()}
+        </svg>
+    }
+}
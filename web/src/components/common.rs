@@ -0,0 +1,102 @@
+//! Small reusable UI building blocks
+
+use leptos::prelude::*;
+
+use crate::data::{ContributionStyle, ContributionType};
+use crate::format::{format_date, parse_timestamp, time_ago};
+use crate::i18n::{use_i18n, Locale};
+
+/// A small shape indicator (circle or square) for contribution type
+#[component]
+pub fn ShapeIndicator(
+    contribution_type: ContributionType,
+    #[prop(default = "w-2 h-2")] size: &'static str,
+) -> impl IntoView {
+    let class = format!(
+        "{} {} {}",
+        size,
+        contribution_type.bg_class(),
+        contribution_type.shape_class()
+    );
+    view! { <span class=class></span> }
+}
+
+/// `iso_date` in the reader's locale and time zone, e.g. "3/7/2025, 1:00:00 PM". Days
+/// without a time of day are shown as that day, without shifting time zones.
+pub fn local_date_time(iso_date: &str, locale: Locale) -> String {
+    let Some(time) = parse_timestamp(iso_date) else {
+        return format_date(iso_date);
+    };
+    let date = js_sys::Date::new(&(time.timestamp_millis() as f64).into());
+    let locale = locale.code();
+    if iso_date.contains('T') {
+        date.to_locale_string(locale, &wasm_bindgen::JsValue::UNDEFINED)
+            .into()
+    } else {
+        let options = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&options, &"timeZone".into(), &"UTC".into());
+        date.to_locale_date_string(locale, &options).into()
+    }
+}
+
+/// "3 hours ago" for an RFC 3339 timestamp or "YYYY-MM-DD" day, with the absolute time
+/// in the reader's locale as tooltip. `message` wraps it in a translation taking `$date`.
+#[component]
+pub fn RelativeTime(
+    date: String,
+    #[prop(optional)] message: Option<&'static str>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let now = chrono::DateTime::from_timestamp_millis(js_sys::Date::now() as i64);
+    let (relative, title) = match parse_timestamp(&date).zip(now) {
+        Some((then, now)) => {
+            let (key, count) = time_ago(then, now);
+            (
+                i18n.t_with(key, &[("count", count.into())]),
+                Some(local_date_time(&date, i18n.locale())),
+            )
+        }
+        None => (format_date(&date), None),
+    };
+    let text = match message {
+        Some(key) => i18n.t_with(key, &[("date", relative.into())]),
+        None => relative,
+    };
+
+    view! { <time datetime=date title=title>{text}</time> }
+}
+
+/// Floating card shown below `children` while they are hovered or focused
+#[component]
+pub fn HoverCard(card: AnyView, children: Children) -> impl IntoView {
+    view! {
+        <span class="relative inline-block group">
+            {children()}
+            <span
+                role="tooltip"
+                class="invisible opacity-0 group-hover:visible group-hover:opacity-100 group-focus-within:visible group-focus-within:opacity-100 transition-opacity duration-150 absolute left-0 top-full z-20 mt-1 w-64 rounded-lg border border-gray-200 bg-white p-3 text-sm font-normal text-gray-700 shadow-lg cursor-default"
+            >
+                {card}
+            </span>
+        </span>
+    }
+}
+
+/// A stat card with label and value
+#[component]
+pub fn StatCard(
+    label: String,
+    value: String,
+    #[prop(default = "text-gray-900")] value_class: &'static str,
+    #[prop(optional)] subtitle: Option<&'static str>,
+) -> impl IntoView {
+    view! {
+        <div class="bg-white rounded-md p-3 border border-gray-100">
+            <div class="text-xs text-gray-500 mb-1">{label}</div>
+            <div class=format!("text-xl font-bold tabular-nums {}", value_class)>{value}</div>
+            {subtitle.map(|s| view! {
+                <div class="text-xs text-gray-400 mt-1">{s}</div>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,66 @@
+//! Leaderboard normalized by workload: who converted most of their own feedstocks
+
+use leptos::prelude::*;
+
+use crate::data::Completionist;
+use crate::i18n::use_i18n;
+
+#[component]
+pub fn CompletionistLeaderboard(completionists: Vec<Completionist>) -> impl IntoView {
+    if completionists.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("completionists-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">{i18n.t("completionists-intro")}</p>
+            </div>
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="w-8"></span>
+                <span class="w-48">{i18n.t("leaderboard-contributor")}</span>
+                <span class="flex-1">{i18n.t("label-converted")}</span>
+                <span class="w-32 text-right">{i18n.t("label-feedstocks")}</span>
+            </div>
+            <ul class="space-y-0">
+                {completionists.into_iter().enumerate().map(|(index, completionist)| {
+                    let percent = completionist.percent();
+                    let github_url = format!("https://github.com/{}", completionist.name);
+                    view! {
+                        <li class="flex items-center py-2 border-b border-dashed border-gray-200">
+                            <span class="w-8 text-xs tabular-nums text-gray-400">{index + 1}</span>
+                            <a
+                                href=github_url
+                                target="_blank"
+                                rel="noopener noreferrer"
+                                class="w-48 font-medium text-blue-600 hover:underline truncate"
+                            >
+                                {completionist.name}
+                            </a>
+                            <span class="flex-1 flex items-center gap-3">
+                                <span class="flex-1 h-2 bg-gray-100 rounded-full overflow-hidden">
+                                    <span
+                                        class="block h-full bg-emerald-500 rounded-full"
+                                        style=format!("width: {:.1}%", percent)
+                                    ></span>
+                                </span>
+                                <span class="w-12 text-right text-sm text-gray-500 tabular-nums">
+                                    {format!("{:.0}%", percent)}
+                                </span>
+                            </span>
+                            <span class="w-32 text-right text-sm text-gray-500 tabular-nums">
+                                {format!("{} / {}", completionist.converted, completionist.maintained)}
+                            </span>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+            <p class="mt-4 text-center text-sm text-gray-400">{i18n.t("completionists-footer")}</p>
+        </div>
+    }.into_any()
+}
@@ -0,0 +1,52 @@
+//! Consent banner for the optional page-view counter
+
+use leptos::prelude::*;
+
+use crate::analytics::{self, Consent};
+use crate::i18n::use_i18n;
+
+/// Asks once whether a page view may be counted; renders nothing when analytics are disabled
+#[component]
+pub fn ConsentBanner() -> impl IntoView {
+    let Some(endpoint) = analytics::endpoint() else {
+        return view! {}.into_any();
+    };
+
+    let i18n = use_i18n();
+    let consent = RwSignal::new(analytics::load_consent());
+    if consent.get_untracked() == Consent::Granted {
+        analytics::send_page_view(endpoint);
+    }
+
+    let answer = move |choice: Consent| {
+        analytics::store_consent(choice);
+        consent.set(choice);
+        if choice == Consent::Granted {
+            analytics::send_page_view(endpoint);
+        }
+    };
+
+    view! {
+        <Show when=move || consent.get() == Consent::Unknown>
+            <div class="print:hidden fixed bottom-4 inset-x-4 md:left-auto md:max-w-sm bg-white rounded-lg p-4 shadow-lg border border-gray-200 text-sm text-gray-600">
+                <p class="mb-3">
+                    {move || i18n.t("consent-question")}
+                </p>
+                <div class="flex justify-end gap-2">
+                    <button
+                        class="px-3 py-1 rounded text-gray-500 hover:bg-gray-100 transition-colors duration-150"
+                        on:click=move |_| answer(Consent::Denied)
+                    >
+                        {move || i18n.t("consent-deny")}
+                    </button>
+                    <button
+                        class="px-3 py-1 rounded bg-blue-600 text-white hover:bg-blue-700 transition-colors duration-150"
+                        on:click=move |_| answer(Consent::Granted)
+                    >
+                        {move || i18n.t("consent-accept")}
+                    </button>
+                </div>
+            </div>
+        </Show>
+    }.into_any()
+}
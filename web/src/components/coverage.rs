@@ -0,0 +1,141 @@
+//! Maintainer coverage page: how many of each maintainer's feedstocks are on Recipe v1,
+//! searchable by login so maintainers can find what is left for them to convert
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use super::RelativeTime;
+use crate::data::{search_maintainers, MaintainerCoverageFile};
+use crate::i18n::use_i18n;
+use crate::remote;
+
+/// URL fragment showing the maintainer coverage page instead of the dashboard
+pub const MAINTAINERS_HASH: &str = "#maintainers";
+
+/// Maintainers shown for a search
+const SEARCH_LIMIT: usize = 25;
+
+#[component]
+pub fn MaintainersPage() -> impl IntoView {
+    let i18n = use_i18n();
+    // None while loading, Some(None) if the file couldn't be fetched
+    let coverage = RwSignal::new(None::<Option<MaintainerCoverageFile>>);
+    spawn_local(async move {
+        coverage.set(Some(remote::fetch_maintainer_coverage().await));
+    });
+    let (query, set_query) = signal(String::new());
+
+    let results = move || {
+        coverage.with(|coverage| match coverage {
+            None => view! { <p class="text-gray-500">{i18n.t("maintainers-loading")}</p> }
+                .into_any(),
+            Some(None) => {
+                view! { <p class="text-gray-500">{i18n.t("maintainers-unavailable")}</p> }
+                    .into_any()
+            }
+            Some(Some(file)) => {
+                let found: Vec<_> = query.with(|query| {
+                    search_maintainers(&file.maintainers, query, SEARCH_LIMIT)
+                        .into_iter()
+                        .cloned()
+                        .collect()
+                });
+                if found.is_empty() {
+                    return view! {
+                        <p class="text-gray-500">{i18n.t("maintainers-no-match")}</p>
+                    }
+                    .into_any();
+                }
+
+                view! {
+                    <ul>
+                        {found.into_iter().map(|maintainer| {
+                            let percent = maintainer.percent();
+                            let github_url = format!("https://github.com/{}", maintainer.login);
+                            let unconverted = maintainer.unconverted;
+                            view! {
+                                <li class="py-2 border-b border-dashed border-gray-200">
+                                    <div class="flex items-center">
+                                        <a
+                                            href=github_url
+                                            target="_blank"
+                                            rel="noopener noreferrer"
+                                            class="w-48 font-medium text-blue-600 hover:underline truncate"
+                                        >
+                                            {maintainer.login}
+                                        </a>
+                                        <span class="flex-1 flex items-center gap-3">
+                                            <span class="flex-1 h-2 bg-gray-100 rounded-full overflow-hidden">
+                                                <span
+                                                    class="block h-full bg-emerald-500 rounded-full"
+                                                    style=format!("width: {:.1}%", percent)
+                                                ></span>
+                                            </span>
+                                            <span class="w-12 text-right text-sm text-gray-500 tabular-nums">
+                                                {format!("{:.0}%", percent)}
+                                            </span>
+                                        </span>
+                                        <span class="w-32 text-right text-sm text-gray-500 tabular-nums">
+                                            {format!("{} / {}", maintainer.converted, maintainer.maintained)}
+                                        </span>
+                                    </div>
+                                    {(!unconverted.is_empty()).then(|| view! {
+                                        <details class="mt-1 text-sm text-gray-500">
+                                            <summary class="cursor-pointer hover:text-gray-700">
+                                                {i18n.t_with(
+                                                    "maintainers-unconverted",
+                                                    &[("count", unconverted.len().into())],
+                                                )}
+                                            </summary>
+                                            <div class="mt-1 flex flex-wrap gap-x-3 gap-y-1">
+                                                {unconverted.into_iter().map(|name| view! {
+                                                    <a
+                                                        href=format!("https://github.com/conda-forge/{}", name)
+                                                        target="_blank"
+                                                        rel="noopener noreferrer"
+                                                        class="text-blue-600 hover:underline"
+                                                    >
+                                                        {name.replace("-feedstock", "")}
+                                                    </a>
+                                                }).collect::<Vec<_>>()}
+                                            </div>
+                                        </details>
+                                    })}
+                                </li>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                    <p class="mt-4 text-center text-sm text-gray-400">
+                        <RelativeTime date=file.generated_at.clone() message="maintainers-generated" />
+                    </p>
+                }
+                .into_any()
+            }
+        })
+    };
+
+    view! {
+        <div class="max-w-6xl mx-auto px-4 pb-8">
+            <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200">
+                <div class="mb-6">
+                    <a href="#" class="text-sm text-blue-600 hover:underline">
+                        {i18n.t("quality-back")}
+                    </a>
+                    <h2 class="mt-2 text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                        {i18n.t("maintainers-title")}
+                    </h2>
+                    <p class="text-gray-500 leading-relaxed">{i18n.t("maintainers-intro")}</p>
+                </div>
+                <input
+                    type="search"
+                    class="w-full mb-4 rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none"
+                    placeholder=i18n.t("maintainers-search")
+                    aria-label=i18n.t("maintainers-search")
+                    prop:value=move || query.get()
+                    on:input=move |ev| set_query.set(event_target_value(&ev))
+                />
+                {results}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,248 @@
+//! Expanded contributor details panel
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use super::{ActivitySparkline, RelativeTime, ShapeIndicator, StatCard};
+use crate::data::{
+    ActivityRange, ContributionHistory, ContributionStyle, ContributionType, ContributorProfile,
+    FeedstockContribution, TimelineEvent, TopPackage, WeeklyActivity, RECENT_ACTIVITY_WEEKS,
+};
+use crate::format::format_downloads;
+use crate::i18n::{use_i18n, I18n};
+use crate::remote;
+
+/// Short label for the range toggle, e.g. "52w"
+fn range_label(i18n: I18n, range: ActivityRange) -> String {
+    match range {
+        ActivityRange::Weeks(weeks) => {
+            i18n.t_with("activity-range-weeks", &[("weeks", weeks.into())])
+        }
+        ActivityRange::All => i18n.t("activity-range-all"),
+    }
+}
+
+/// Expanded details panel for a contributor
+#[component]
+pub fn ContributorDetails(
+    name: String,
+    total_downloads: u64,
+    conversions: u32,
+    new_feedstocks: u32,
+    first_contribution: Option<String>,
+    last_contribution: Option<String>,
+    top_package: Option<TopPackage>,
+    largest_conversion: Option<TopPackage>,
+    feedstocks: Vec<FeedstockContribution>,
+    weekly_activity: WeeklyActivity,
+    activity_weeks: usize,
+    events: Vec<TimelineEvent>,
+    profile: ContributorProfile,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let total = conversions + new_feedstocks;
+    let ranges = [
+        ActivityRange::Weeks(RECENT_ACTIVITY_WEEKS),
+        ActivityRange::Weeks(activity_weeks),
+        ActivityRange::All,
+    ];
+    let (range, set_range) = signal(ActivityRange::Weeks(activity_weeks));
+    // Only the exported weeks come with the stats; longer ranges fetch the full history
+    let (history, set_history) = signal(None::<ContributionHistory>);
+    let select_range = move |r: ActivityRange| {
+        set_range.set(r);
+        if r.needs_history(activity_weeks) && history.get_untracked().is_none() {
+            let name = name.clone();
+            spawn_local(async move {
+                if let Some(fetched) = remote::fetch_contribution_history(&name).await {
+                    set_history.set(Some(fetched));
+                }
+            });
+        }
+    };
+    let avg_downloads = if total > 0 {
+        total_downloads / total as u64
+    } else {
+        0
+    };
+
+    view! {
+        <div class="stats-card bg-gray-50 rounded-lg p-4 border border-gray-200 shadow-sm">
+            // Details the contributor added in contributors.toml
+            {(profile.affiliation.is_some() || profile.url.is_some()).then(|| view! {
+                <div class="flex flex-wrap items-center gap-x-4 gap-y-1 mb-3 text-sm text-gray-600">
+                    {profile.affiliation.map(|affiliation| view! { <span>{"🏢 "}{affiliation}</span> })}
+                    {profile.url.map(|url| view! {
+                        <a href=url.clone() target="_blank" rel="noopener noreferrer me" class="text-blue-600 hover:underline">
+                            {"🔗 "}{url.trim_start_matches("https://").trim_end_matches('/').to_string()}
+                        </a>
+                    })}
+                </div>
+            })}
+            // Stats cards row
+            <div class="grid grid-cols-2 md:grid-cols-4 gap-3 mb-4">
+                <StatCard
+                    label=i18n.t("label-conversions")
+                    value=conversions.to_string()
+                    value_class=ContributionType::Conversion.text_class()
+                />
+                <StatCard
+                    label=i18n.t("label-new-feedstocks")
+                    value=new_feedstocks.to_string()
+                    value_class=ContributionType::NewFeedstock.text_class()
+                />
+                <StatCard
+                    label=i18n.t("details-total-downloads")
+                    value=format!("~{}", format_downloads(total_downloads))
+                />
+                <StatCard
+                    label=i18n.t("details-avg-downloads")
+                    value=format!("~{}", format_downloads(avg_downloads))
+                />
+            </div>
+
+            // Activity timeline and Top package row
+            <div class="grid grid-cols-1 md:grid-cols-2 gap-3 mb-4">
+                // Activity timeline card
+                <div class="bg-white rounded-md p-3 border border-gray-100">
+                    <div class="flex items-center justify-between mb-2">
+                        <div class="text-xs font-semibold text-gray-500 uppercase tracking-wide">{i18n.t("details-activity")}</div>
+                        <div class="flex items-center gap-3 text-xs text-gray-400">
+                            <span class="flex items-center gap-1">
+                                <ShapeIndicator contribution_type=ContributionType::Conversion />
+                                {i18n.t(ContributionType::Conversion.short_label_key())}
+                            </span>
+                            <span class="flex items-center gap-1">
+                                <ShapeIndicator contribution_type=ContributionType::NewFeedstock />
+                                {i18n.t(ContributionType::NewFeedstock.short_label_key())}
+                            </span>
+                            <span class="flex items-center gap-1">
+                                {ranges.into_iter().map(|r| {
+                                    let select_range = select_range.clone();
+                                    view! {
+                                        <button
+                                            on:click=move |_| select_range(r)
+                                            class=move || format!(
+                                                "px-1 rounded transition-colors duration-150 {}",
+                                                if range.get() == r { "bg-gray-200 text-gray-700" } else { "hover:text-gray-600" }
+                                            )
+                                        >
+                                            {range_label(i18n, r)}
+                                        </button>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </span>
+                        </div>
+                    </div>
+                    {move || {
+                        let weekly_activity = match history.get() {
+                            Some(history) if range.get().needs_history(activity_weeks) => {
+                                range.get().apply(&history.weekly_activity())
+                            }
+                            _ => range.get().apply(&weekly_activity),
+                        };
+                        view! { <ActivitySparkline weekly_activity=weekly_activity events=events.clone() /> }
+                    }}
+                    <div class="flex justify-between text-xs text-gray-400 mt-2">
+                        {if let Some(ref date) = first_contribution {
+                            view! { <RelativeTime date=date.clone() message="details-first" /> }.into_any()
+                        } else {
+                            view! { <span></span> }.into_any()
+                        }}
+                        {if let Some(ref date) = last_contribution {
+                            view! { <RelativeTime date=date.clone() message="details-latest" /> }.into_any()
+                        } else {
+                            view! { <span></span> }.into_any()
+                        }}
+                    </div>
+                </div>
+
+                // Top package card
+                {if let Some(ref pkg) = top_package {
+                    let package_name = pkg.name.replace("-feedstock", "");
+                    let prefix_url = format!("https://prefix.dev/channels/conda-forge/packages/{}", package_name);
+                    view! {
+                        <a
+                            href=prefix_url
+                            target="_blank"
+                            rel="noopener noreferrer"
+                            class="bg-white rounded-md p-3 border border-gray-100 flex flex-col justify-center hover:bg-gray-50 transition-colors duration-150"
+                        >
+                            <div class="text-xs text-gray-500 mb-1">{i18n.t("details-top-package")}</div>
+                            <div class="text-lg font-semibold text-emerald-600 mb-1 hover:underline">{package_name}</div>
+                            {pkg.summary.clone().map(|summary| view! {
+                                <div class="text-xs text-gray-500 mb-1 line-clamp-2">{summary}</div>
+                            })}
+                            <div class="text-sm text-gray-500 tabular-nums">
+                                {i18n.t_with("details-package-downloads", &[("downloads", format_downloads(pkg.downloads).into())])}
+                            </div>
+                        </a>
+                    }.into_any()
+                } else {
+                    view! {
+                        <div class="bg-white rounded-md p-3 border border-gray-100 flex flex-col justify-center items-center text-gray-400">
+                            <div class="text-xs">{i18n.t("details-no-package")}</div>
+                        </div>
+                    }.into_any()
+                }}
+            </div>
+
+            {largest_conversion.map(|pkg| view! {
+                <p class="text-sm text-gray-600 mb-4">
+                    {i18n.t_with(
+                        "details-largest-conversion",
+                        &[
+                            ("name", pkg.name.replace("-feedstock", "").into()),
+                            ("downloads", format_downloads(pkg.downloads).into()),
+                        ],
+                    )}
+                </p>
+            })}
+
+            // Feedstocks list (top 10 by downloads)
+            {if !feedstocks.is_empty() {
+                view! {
+                    <div class="bg-white rounded-md p-3 border border-gray-100">
+                        <h4 class="text-xs font-semibold text-gray-500 uppercase tracking-wide mb-2">{i18n.t("details-top-feedstocks")}</h4>
+                        <div class="grid grid-cols-1 md:grid-cols-2 gap-1">
+                            {feedstocks.into_iter().map(|f| {
+                                let github_url = format!("https://github.com/conda-forge/{}", f.name);
+                                let display_name = f.name.replace("-feedstock", "");
+                                let shape_class = format!(
+                                    "w-2 h-2 {} {} mr-2 flex-shrink-0",
+                                    f.contribution_type.bg_class(),
+                                    f.contribution_type.shape_class()
+                                );
+
+                                view! {
+                                    <a
+                                        href=github_url
+                                        target="_blank"
+                                        rel="noopener noreferrer"
+                                        title=f.summary
+                                        class="flex items-center text-sm py-1 group"
+                                    >
+                                        <span class=shape_class></span>
+                                        <span class="font-medium text-blue-600 truncate flex-1 group-hover:underline">{display_name}</span>
+                                        {f.self_conversion.then(|| view! {
+                                            <span class="ml-1 text-xs" title=i18n.t("details-self-conversion")>"🏡"</span>
+                                        })}
+                                        <span class="text-xs text-gray-500 ml-2 tabular-nums w-16 text-right">{"~"}{format_downloads(f.downloads)}</span>
+                                    </a>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }.into_any()
+            } else {
+                view! {}.into_any()
+            }}
+
+            // Footnote for download data source
+            <p class="text-xs text-gray-400 mt-3">
+                {i18n.t("details-footnote")}" "
+                <a href="https://prefix.dev/channels/conda-forge" target="_blank" rel="noopener noreferrer" class="text-blue-500 hover:underline">"prefix.dev"</a>
+            </p>
+        </div>
+    }
+}
@@ -0,0 +1,58 @@
+//! Feed of the latest achievements earned by anyone
+
+use leptos::prelude::*;
+
+use super::RelativeTime;
+use crate::achievements;
+use crate::data::AchievementUnlock;
+use crate::i18n::use_i18n;
+
+/// "@alice reached Forge Smith", newest first
+#[component]
+pub fn AchievementFeed(unlocks: Vec<AchievementUnlock>) -> impl IntoView {
+    if unlocks.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                {i18n.t("feed-title")}
+            </h2>
+            <p class="text-gray-500 leading-relaxed mb-6">{i18n.t("feed-intro")}</p>
+            <ul class="space-y-0">
+                {unlocks.into_iter().filter_map(|unlock| {
+                    // Ids unknown to this build come from newer stats, skip them
+                    let achievement = achievements::find(&unlock.achievement)?;
+                    let github_url = format!("https://github.com/{}", unlock.login);
+                    Some(view! {
+                        <li class="flex items-center gap-3 py-2 border-b border-dashed border-gray-200">
+                            <span class="text-xl">{achievement.emoji}</span>
+                            <span class="flex-1 text-sm text-gray-700">
+                                <a
+                                    href=github_url
+                                    target="_blank"
+                                    rel="noopener noreferrer"
+                                    class="font-medium text-blue-600 hover:underline"
+                                >
+                                    {format!("@{}", unlock.login)}
+                                </a>
+                                " "
+                                {i18n.t_with(
+                                    "feed-reached",
+                                    &[("achievement", i18n.t(&achievement.name_key()).into())],
+                                )}
+                            </span>
+                            <span class="text-xs text-gray-400 tabular-nums">
+                                <RelativeTime date=unlock.date.clone() />
+                            </span>
+                        </li>
+                    })
+                }).collect::<Vec<_>>()}
+            </ul>
+        </div>
+    }
+    .into_any()
+}
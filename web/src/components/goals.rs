@@ -0,0 +1,128 @@
+//! Roadmap-style progress bar with milestone tiers
+
+use leptos::prelude::*;
+
+use crate::format::format_month;
+use crate::i18n::use_i18n;
+
+/// Complete weeks whose average pace is used to estimate the next milestone
+pub const ROADMAP_PACE_WEEKS: usize = 12;
+
+/// Progress milestones, as percentages of all feedstocks, with their label keys
+const MILESTONES: [(u32, &str); 4] = [
+    (25, "goal-momentum"),
+    (50, "goal-halfway"),
+    (75, "goal-home-stretch"),
+    (100, "goal-done"),
+];
+
+/// The first milestone not reached yet, as a share (0.0 - 1.0)
+pub fn next_milestone_share(converted: u32, total: u32) -> Option<f64> {
+    MILESTONES
+        .iter()
+        .map(|(percent, _)| *percent as f64 / 100.0)
+        .find(|share| (converted as f64) < share * total as f64)
+}
+
+/// Progress bar with the milestones marked on it. `eta_weeks` estimates when the next
+/// milestone is reached, counted from `last_updated`.
+#[component]
+pub fn ProgressRoadmap(
+    converted: u32,
+    total: u32,
+    eta_weeks: Option<f64>,
+    last_updated: String,
+) -> impl IntoView {
+    if total == 0 {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    let percentage = converted as f64 / total as f64 * 100.0;
+    let next = MILESTONES
+        .iter()
+        .find(|(percent, _)| percentage < *percent as f64)
+        .copied();
+
+    let eta = eta_weeks.and_then(|weeks| {
+        let collected = chrono::DateTime::parse_from_rfc3339(&last_updated).ok()?;
+        let reached = collected + chrono::Duration::days((weeks * 7.0).ceil() as i64);
+        Some(format_month(&reached.format("%Y-%m-%d").to_string()))
+    });
+    let summary = match next {
+        Some((percent, key)) => {
+            let remaining = (percent as f64 / 100.0 * total as f64).ceil() as u32 - converted;
+            let next = i18n.t_with(
+                "goals-next",
+                &[
+                    ("goal", i18n.t(key).into()),
+                    ("percent", percent.into()),
+                    ("remaining", remaining.into()),
+                ],
+            );
+            let eta = match eta {
+                Some(date) => i18n.t_with(
+                    "goals-eta",
+                    &[("weeks", ROADMAP_PACE_WEEKS.into()), ("date", date.into())],
+                ),
+                None => i18n.t("goals-eta-unknown"),
+            };
+            format!("{} {}", next, eta)
+        }
+        None => i18n.t("goals-complete"),
+    };
+
+    view! {
+        <div>
+            <h3 class="text-lg font-semibold text-gray-900 mb-2 tracking-tight">{i18n.t("goals-title")}</h3>
+            <p class="text-sm text-gray-500 mb-6">{summary}</p>
+            <div class="relative h-4 rounded-full bg-gray-200">
+                <div
+                    class="absolute inset-y-0 left-0 rounded-full bg-emerald-500"
+                    style=format!("width: {:.2}%", percentage.min(100.0))
+                ></div>
+                {MILESTONES.iter().map(|(percent, _)| {
+                    let reached = percentage >= *percent as f64;
+                    let is_next = next.is_some_and(|(p, _)| p == *percent);
+                    let class = if is_next {
+                        "bg-white border-amber-400 ring-4 ring-amber-200"
+                    } else if reached {
+                        "bg-emerald-600 border-white"
+                    } else {
+                        "bg-white border-gray-300"
+                    };
+                    view! {
+                        <span
+                            class=format!("absolute top-1/2 w-4 h-4 -translate-x-1/2 -translate-y-1/2 rounded-full border-2 {}", class)
+                            style=format!("left: {}%", percent)
+                        ></span>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+            <div class="relative h-10 mt-2 text-xs">
+                {MILESTONES.iter().map(|(percent, key)| {
+                    let is_next = next.is_some_and(|(p, _)| p == *percent);
+                    let color = if percentage >= *percent as f64 {
+                        "text-emerald-700"
+                    } else if is_next {
+                        "text-amber-700 font-semibold"
+                    } else {
+                        "text-gray-400"
+                    };
+                    // Keep the 100% label inside the card
+                    let align = if *percent == 100 { "-translate-x-full text-right" } else { "-translate-x-1/2 text-center" };
+                    view! {
+                        <span
+                            class=format!("absolute whitespace-nowrap {} {}", align, color)
+                            style=format!("left: {}%", percent)
+                        >
+                            <span class="block tabular-nums">{format!("{}%", percent)}</span>
+                            <span class="block">{i18n.t(key)}</span>
+                        </span>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+        </div>
+    }
+    .into_any()
+}
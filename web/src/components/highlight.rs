@@ -0,0 +1,71 @@
+//! Highlight card for the biggest package moved to Recipe v1
+
+use leptos::prelude::*;
+
+use super::local_date_time;
+use crate::data::BiggestConversion;
+use crate::format::format_downloads;
+use crate::i18n::use_i18n;
+
+/// "Biggest migration so far": the most downloaded feedstock converted from meta.yaml
+#[component]
+pub fn BiggestConversionCard(conversion: Option<BiggestConversion>) -> impl IntoView {
+    let Some(conversion) = conversion else {
+        return view! {}.into_any();
+    };
+
+    let i18n = use_i18n();
+    let github_url = format!("https://github.com/conda-forge/{}", conversion.feedstock);
+    let display_name = conversion.feedstock.replace("-feedstock", "");
+    let contributors = conversion
+        .contributors
+        .iter()
+        .map(|c| format!("@{}", c))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let credit = match &conversion.date {
+        Some(date) => i18n.t_with(
+            "highlight-biggest-by-on",
+            &[
+                ("contributors", contributors.into()),
+                ("date", local_date_time(date, i18n.locale()).into()),
+            ],
+        ),
+        None => i18n.t_with(
+            "highlight-biggest-by",
+            &[("contributors", contributors.into())],
+        ),
+    };
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="flex items-start gap-4">
+                <span class="text-3xl">"🏔️"</span>
+                <div class="flex-1">
+                    <h2 class="text-xs font-semibold text-gray-500 uppercase tracking-wide mb-1">
+                        {i18n.t("highlight-biggest-title")}
+                    </h2>
+                    <a
+                        href=github_url
+                        target="_blank"
+                        rel="noopener noreferrer"
+                        class="text-2xl font-semibold text-emerald-600 hover:underline"
+                    >
+                        {display_name}
+                    </a>
+                    {conversion.summary.map(|summary| view! {
+                        <p class="text-sm text-gray-500 mt-1">{summary}</p>
+                    })}
+                    <p class="text-sm text-gray-600 mt-2">{credit}</p>
+                </div>
+                <div class="text-right">
+                    <div class="text-2xl font-bold text-gray-900 tabular-nums">
+                        {format!("~{}", format_downloads(conversion.downloads))}
+                    </div>
+                    <div class="text-xs text-gray-500">{i18n.t("highlight-biggest-downloads")}</div>
+                </div>
+            </div>
+        </div>
+    }
+    .into_any()
+}
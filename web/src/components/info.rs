@@ -0,0 +1,90 @@
+//! Introductory "Learn more" section
+
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+
+#[component]
+pub fn InfoAccordion() -> impl IntoView {
+    let i18n = use_i18n();
+    let (expanded, set_expanded) = signal(false);
+
+    view! {
+        <div class="max-w-6xl mx-auto">
+            <button
+                on:click=move |_| set_expanded.update(|v| *v = !*v)
+                class="inline-flex items-center gap-2 py-2 px-4 text-gray-500 hover:text-gray-700 border border-gray-300 hover:border-gray-400 rounded-full transition-all duration-150 text-sm font-medium"
+            >
+                <span>{i18n.t("info-toggle")}</span>
+                <svg
+                    class=move || format!(
+                        "w-4 h-4 transition-transform duration-200 {}",
+                        if expanded.get() { "rotate-180" } else { "" }
+                    )
+                    fill="none"
+                    stroke="currentColor"
+                    viewBox="0 0 24 24"
+                >
+                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M19 9l-7 7-7-7" />
+                </svg>
+            </button>
+            <div class=move || format!(
+                "accordion-content {}",
+                if expanded.get() { "expanded" } else { "" }
+            )>
+                <div>
+                    <div class="grid grid-cols-1 md:grid-cols-3 gap-6 pt-4 pb-2">
+                        <InfoCard
+                            title=i18n.t("info-conda-forge-title")
+                            paragraphs=vec![i18n.t("info-conda-forge-body"), i18n.t("info-conda-forge-scale")]
+                            links=vec![
+                                ("https://conda-forge.org", "conda-forge.org".to_string()),
+                                ("https://github.com/conda-forge", i18n.t("info-github-org")),
+                            ]
+                        />
+                        <InfoCard
+                            title=i18n.t("info-recipe-v1-title")
+                            paragraphs=vec![i18n.t("info-recipe-v1-body")]
+                            links=vec![
+                                ("https://github.com/conda/ceps/blob/main/cep-0013.md", "CEP-0013".to_string()),
+                                ("https://github.com/conda/ceps/blob/main/cep-0014.md", "CEP-0014".to_string()),
+                            ]
+                        />
+                        <InfoCard
+                            title=i18n.t("info-rattler-build-title")
+                            paragraphs=vec![i18n.t("info-rattler-build-body")]
+                            links=vec![("https://rattler.build", "rattler.build".to_string())]
+                        />
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// One explainer card with a title, some text and links to learn more
+#[component]
+fn InfoCard(
+    title: String,
+    paragraphs: Vec<String>,
+    links: Vec<(&'static str, String)>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let link_count = links.len();
+
+    view! {
+        <div class="bg-white rounded-lg p-6 shadow-sm border border-gray-200 hover:shadow-md hover:border-gray-300 transition-all duration-200">
+            <h3 class="text-lg font-semibold text-gray-900 mb-3 tracking-tight">{title}</h3>
+            {paragraphs.into_iter().map(|text| view! {
+                <p class="text-gray-600 mb-3 leading-relaxed text-sm">{text}</p>
+            }).collect::<Vec<_>>()}
+            <p class="text-gray-600 text-sm">
+                {i18n.t("label-learn-more")}" "
+                {links.into_iter().enumerate().map(|(i, (href, label))| view! {
+                    <a href=href class="text-blue-600 hover:text-blue-800 underline transition-colors duration-150">{label}</a>
+                    {(i + 1 < link_count).then_some(" · ")}
+                }).collect::<Vec<_>>()}
+            </p>
+        </div>
+    }
+}
@@ -0,0 +1,559 @@
+//! Small analytical insight cards
+
+use leptos::prelude::*;
+
+use super::RelativeTime;
+use crate::data::{
+    percent, BotActivityRate, ContributorMix, ConversionCohort, ConversionForecast, InsightsFile,
+    MaintainerConversionRate, RecipeFeatureRate, RetentionPoint,
+};
+use crate::i18n::use_i18n;
+
+/// URL fragment showing the community insights page instead of the dashboard
+pub const INSIGHTS_HASH: &str = "#insights";
+
+/// Months shown in the retention chart
+const RETENTION_MONTHS: usize = 12;
+
+/// Conversion cohorts shown in the survival table, newest first
+const COHORT_ROWS: usize = 12;
+
+/// Months after conversion shown as survival columns
+const COHORT_HORIZONS: [u32; 4] = [1, 3, 6, 12];
+
+/// Conversion rate per maintainer count, with a one-line headline
+#[component]
+pub fn MaintainerInsight(rates: Vec<MaintainerConversionRate>) -> impl IntoView {
+    let rates: Vec<_> = rates.into_iter().filter(|r| r.feedstocks > 0).collect();
+    if rates.len() < 2 {
+        return view! {}.into_any();
+    }
+
+    // Compare the least and most maintained buckets
+    let i18n = use_i18n();
+    let first = &rates[0];
+    let last = &rates[rates.len() - 1];
+    let headline = if first.rate() > 0.0 {
+        i18n.t_with(
+            "insight-likelier",
+            &[
+                ("most", last.maintainers.clone().into()),
+                ("ratio", format!("{:.1}", last.rate() / first.rate()).into()),
+                ("least", first.maintainers.clone().into()),
+            ],
+        )
+    } else {
+        i18n.t_with(
+            "insight-share",
+            &[
+                ("percent", format!("{:.0}", last.rate() * 100.0).into()),
+                ("maintainers", last.maintainers.clone().into()),
+            ],
+        )
+    };
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("insight-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">{headline}</p>
+            </div>
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="w-28">{i18n.t("insight-maintainers")}</span>
+                <span class="flex-1">{i18n.t("label-converted")}</span>
+                <span class="w-32 text-right">{i18n.t("label-feedstocks")}</span>
+            </div>
+            <ul class="space-y-0">
+                {rates.into_iter().map(|rate| {
+                    let percent = rate.rate() * 100.0;
+                    view! {
+                        <li class="flex items-center py-2 border-b border-dashed border-gray-200">
+                            <span class="w-28 text-sm font-medium text-gray-700 tabular-nums">
+                                {rate.maintainers.clone()}
+                            </span>
+                            <span class="flex-1 flex items-center gap-3">
+                                <span class="flex-1 h-2 bg-gray-100 rounded-full overflow-hidden">
+                                    <span
+                                        class="block h-full bg-emerald-500 rounded-full"
+                                        style=format!("width: {:.1}%", percent)
+                                    ></span>
+                                </span>
+                                <span class="w-12 text-right text-sm text-gray-500 tabular-nums">
+                                    {format!("{:.0}%", percent)}
+                                </span>
+                            </span>
+                            <span class="w-32 text-right text-sm text-gray-500 tabular-nums">
+                                {format!("{} / {}", rate.converted, rate.feedstocks)}
+                            </span>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+            <p class="mt-4 text-center text-sm text-gray-400">
+                {i18n.t("insight-footer")}" "<code>"extra.recipe-maintainers"</code>
+            </p>
+        </div>
+    }.into_any()
+}
+
+/// Heatmap of conversion rates for feedstocks with and without each recipe feature
+#[component]
+pub fn RecipeFeatureInsight(rates: Vec<RecipeFeatureRate>) -> impl IntoView {
+    let rates: Vec<_> = rates.into_iter().filter(|r| r.feedstocks > 0).collect();
+    if rates.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    // Darker green for higher conversion rates
+    let cell = |rate: f64, converted: u32, feedstocks: u32| {
+        view! {
+            <span
+                class="w-32 py-2 text-center text-sm font-medium text-gray-900 tabular-nums rounded"
+                style=format!("background-color: rgba(16, 185, 129, {:.2})", 0.1 + rate * 0.8)
+                title=format!("{} / {}", converted, feedstocks)
+            >
+                {format!("{:.0}%", rate * 100.0)}
+            </span>
+        }
+    };
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("features-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">{i18n.t("features-intro")}</p>
+            </div>
+            <div class="flex items-center gap-2 text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="flex-1">{i18n.t("features-feature")}</span>
+                <span class="w-32 text-center">{i18n.t("features-with")}</span>
+                <span class="w-32 text-center">{i18n.t("features-without")}</span>
+            </div>
+            <ul class="space-y-1">
+                {rates.into_iter().map(|rate| {
+                    view! {
+                        <li class="flex items-center gap-2">
+                            <span class="flex-1 text-sm font-medium text-gray-700">
+                                {i18n.t(&format!("feature-{}", rate.feature))}
+                            </span>
+                            {cell(rate.rate(), rate.converted, rate.feedstocks)}
+                            {cell(rate.other_rate(), rate.other_converted, rate.other_feedstocks)}
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+            <p class="mt-4 text-center text-sm text-gray-400">{i18n.t("features-footer")}</p>
+        </div>
+    }.into_any()
+}
+
+/// Stacked monthly conversions by first-time and returning contributors
+#[component]
+fn ContributorMixChart(mix: Vec<ContributorMix>) -> impl IntoView {
+    let skip = mix.len().saturating_sub(RETENTION_MONTHS);
+    let months: Vec<_> = mix.into_iter().skip(skip).collect();
+    let new: u32 = months.iter().map(|m| m.new).sum();
+    let total: u32 = months.iter().map(|m| m.new + m.returning).sum();
+    if total == 0 {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    let busiest = months
+        .iter()
+        .map(|m| m.new + m.returning)
+        .max()
+        .unwrap_or(1);
+    let headline = i18n.t_with(
+        "mix-headline",
+        &[("percent", format!("{:.0}", percent(new, total)).into())],
+    );
+    let legend = [
+        ("bg-emerald-500", "mix-new"),
+        ("bg-sky-300", "mix-returning"),
+    ];
+
+    view! {
+        <div class="mt-8 bg-white rounded-lg p-8 shadow-sm border border-gray-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("mix-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">{headline}</p>
+            </div>
+            <div class="flex items-end gap-1 h-40 border-b border-gray-200">
+                {months.iter().map(|point| {
+                    let tooltip = i18n.t_with(
+                        "mix-bar-tooltip",
+                        &[
+                            ("month", point.month.clone().into()),
+                            ("new", point.new.into()),
+                            ("returning", point.returning.into()),
+                        ],
+                    );
+                    let height = |count: u32| format!("height: {:.1}%", percent(count, busiest));
+                    view! {
+                        <div class="flex-1 h-full flex flex-col justify-end" title=tooltip>
+                            <div class="w-full bg-emerald-500 rounded-t" style=height(point.new)></div>
+                            <div class="w-full bg-sky-300" style=height(point.returning)></div>
+                        </div>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+            <div class="flex gap-1 mt-1">
+                {months.iter().map(|point| view! {
+                    <span class="flex-1 text-center text-xs text-gray-400 tabular-nums">
+                        {point.month.get(5..).unwrap_or_default().to_string()}
+                    </span>
+                }).collect::<Vec<_>>()}
+            </div>
+            <div class="flex justify-center gap-6 mt-4 text-sm text-gray-500">
+                {legend.into_iter().map(|(color, label)| view! {
+                    <span class="flex items-center gap-2">
+                        <span class=format!("w-3 h-3 rounded-sm {}", color)></span>
+                        {i18n.t(label)}
+                    </span>
+                }).collect::<Vec<_>>()}
+            </div>
+        </div>
+    }
+    .into_any()
+}
+
+/// Per monthly conversion cohort: share not reverted N months later, share still on v1
+/// and building today, and share of the checked ones that needed a quick fix
+#[component]
+fn CohortTable(cohorts: Vec<ConversionCohort>) -> impl IntoView {
+    if cohorts.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    let cell = |part: u32, total: u32| {
+        view! {
+            <span class="w-20 text-right text-sm text-gray-700 tabular-nums" title=format!("{} / {}", part, total)>
+                {format!("{:.0}%", percent(part, total))}
+            </span>
+        }
+    };
+    let rows: Vec<_> = cohorts.into_iter().rev().take(COHORT_ROWS).collect();
+
+    view! {
+        <div class="mt-8 bg-white rounded-lg p-8 shadow-sm border border-gray-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("cohorts-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">{i18n.t("cohorts-intro")}</p>
+            </div>
+            <div class="overflow-x-auto">
+                <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                    <span class="w-24">{i18n.t("cohorts-month")}</span>
+                    <span class="w-20 text-right">{i18n.t("label-converted")}</span>
+                    {COHORT_HORIZONS.into_iter().map(|months| view! {
+                        <span class="w-20 text-right">
+                            {i18n.t_with("cohorts-after", &[("months", months.into())])}
+                        </span>
+                    }).collect::<Vec<_>>()}
+                    <span class="w-20 text-right">{i18n.t("cohorts-still-v1")}</span>
+                    <span class="w-20 text-right">{i18n.t("cohorts-passing")}</span>
+                    <span class="w-20 text-right">{i18n.t("cohorts-fixed")}</span>
+                </div>
+                <ul>
+                    {rows.into_iter().map(|cohort| {
+                        let retention = cohort.retention();
+                        view! {
+                            <li class="flex items-center py-2 border-b border-dashed border-gray-200">
+                                <span class="w-24 text-sm font-medium text-gray-700 tabular-nums">
+                                    {cohort.cohort.clone()}
+                                </span>
+                                <span class="w-20 text-right text-sm text-gray-500 tabular-nums">
+                                    {cohort.converted}
+                                </span>
+                                {COHORT_HORIZONS.into_iter().map(|months| {
+                                    match retention.iter().find(|(m, _)| *m == months) {
+                                        Some(&(_, retained)) => cell(retained, cohort.converted).into_any(),
+                                        None => view! {
+                                            <span class="w-20 text-right text-sm text-gray-300">"–"</span>
+                                        }.into_any(),
+                                    }
+                                }).collect::<Vec<_>>()}
+                                {cell(cohort.still_v1, cohort.converted)}
+                                {cell(cohort.passing, cohort.converted)}
+                                {if cohort.follow_up_checked > 0 {
+                                    cell(cohort.follow_up_fixed, cohort.follow_up_checked).into_any()
+                                } else {
+                                    view! {
+                                        <span class="w-20 text-right text-sm text-gray-300">"–"</span>
+                                    }.into_any()
+                                }}
+                            </li>
+                        }
+                    }).collect::<Vec<_>>()}
+                </ul>
+            </div>
+            <p class="mt-4 text-center text-sm text-gray-400">{i18n.t("cohorts-footer")}</p>
+        </div>
+    }
+    .into_any()
+}
+
+/// Experimental: unconverted feedstocks the collector expects to convert next, with the
+/// signals behind each score and the previous forecast's hits
+#[component]
+fn ForecastTable(forecast: Option<ConversionForecast>) -> impl IntoView {
+    let Some(forecast) = forecast.filter(|f| !f.likely.is_empty()) else {
+        return view! {}.into_any();
+    };
+
+    let i18n = use_i18n();
+    let share = |value: f64| format!("{:.0}%", value * 100.0);
+    let hits = (!forecast.converted_since.is_empty()).then(|| {
+        let names: Vec<_> = forecast
+            .converted_since
+            .iter()
+            .map(|name| name.replace("-feedstock", ""))
+            .collect();
+        i18n.t_with(
+            "forecast-hits",
+            &[
+                ("count", names.len().into()),
+                ("feedstocks", names.join(", ").into()),
+            ],
+        )
+    });
+
+    view! {
+        <div class="mt-8 bg-white rounded-lg p-8 shadow-sm border border-gray-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("forecast-title")}
+                    <span class="ml-2 align-middle rounded-full bg-amber-100 px-2 py-0.5 text-xs font-medium text-amber-800">
+                        {i18n.t("forecast-experimental")}
+                    </span>
+                </h2>
+                <p class="text-gray-500 leading-relaxed">{i18n.t("forecast-intro")}</p>
+            </div>
+            <div class="overflow-x-auto">
+                <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                    <span class="flex-1">{i18n.t("forecast-feedstock")}</span>
+                    <span class="w-20 text-right">{i18n.t("forecast-score")}</span>
+                    <span class="w-28 text-right">{i18n.t("forecast-maintainers")}</span>
+                    <span class="w-28 text-right">{i18n.t("forecast-dependencies")}</span>
+                    <span class="w-32 text-right">{i18n.t("forecast-pushed")}</span>
+                </div>
+                <ul>
+                    {forecast.likely.into_iter().map(|likely| view! {
+                        <li class="flex items-center py-2 border-b border-dashed border-gray-200 text-sm">
+                            <a
+                                href=format!("https://github.com/conda-forge/{}", likely.name)
+                                target="_blank"
+                                rel="noopener noreferrer"
+                                class="flex-1 font-medium text-blue-600 hover:underline truncate"
+                            >
+                                {likely.name.replace("-feedstock", "")}
+                            </a>
+                            <span class="w-20 text-right font-semibold text-gray-900 tabular-nums">
+                                {share(likely.score)}
+                            </span>
+                            <span class="w-28 text-right text-gray-500 tabular-nums">
+                                {share(likely.maintainer_share)}
+                            </span>
+                            <span class="w-28 text-right text-gray-500 tabular-nums">
+                                {likely.dependencies_converted.map_or_else(|| "–".to_string(), share)}
+                            </span>
+                            <span class="w-32 text-right text-gray-500">
+                                {match likely.pushed_at {
+                                    Some(date) => view! { <RelativeTime date=date /> }.into_any(),
+                                    None => view! { <span class="text-gray-300">"–"</span> }.into_any(),
+                                }}
+                            </span>
+                        </li>
+                    }).collect::<Vec<_>>()}
+                </ul>
+            </div>
+            {hits.map(|hits| view! { <p class="mt-4 text-sm text-emerald-700">{hits}</p> })}
+            <p class="mt-4 text-center text-sm text-gray-400">{i18n.t("forecast-footer")}</p>
+        </div>
+    }
+    .into_any()
+}
+
+/// Conversion rates by how many pull requests the autotick bot opened on a feedstock,
+/// overall and within the last 90 days
+#[component]
+fn BotActivityTable(rates: Vec<BotActivityRate>) -> impl IntoView {
+    let rates: Vec<_> = rates.into_iter().filter(|r| r.feedstocks > 0).collect();
+    if rates.len() < 2 {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    view! {
+        <div class="mt-8 bg-white rounded-lg p-8 shadow-sm border border-gray-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("bot-activity-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">{i18n.t("bot-activity-intro")}</p>
+            </div>
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="w-28">{i18n.t("bot-activity-prs")}</span>
+                <span class="flex-1">{i18n.t("label-converted")}</span>
+                <span class="w-28 text-right">{i18n.t("bot-activity-recent")}</span>
+                <span class="w-32 text-right">{i18n.t("label-feedstocks")}</span>
+            </div>
+            <ul>
+                {rates.into_iter().map(|rate| {
+                    let converted = rate.rate() * 100.0;
+                    view! {
+                        <li class="flex items-center py-2 border-b border-dashed border-gray-200">
+                            <span class="w-28 text-sm font-medium text-gray-700 tabular-nums">
+                                {rate.bot_prs.clone()}
+                            </span>
+                            <span class="flex-1 flex items-center gap-3">
+                                <span class="flex-1 h-2 bg-gray-100 rounded-full overflow-hidden">
+                                    <span
+                                        class="block h-full bg-emerald-500 rounded-full"
+                                        style=format!("width: {:.1}%", converted)
+                                    ></span>
+                                </span>
+                                <span class="w-12 text-right text-sm text-gray-500 tabular-nums">
+                                    {format!("{:.0}%", converted)}
+                                </span>
+                            </span>
+                            <span class="w-28 text-right text-sm text-gray-500 tabular-nums">
+                                {format!("{:.1}%", rate.recent_rate() * 100.0)}
+                            </span>
+                            <span class="w-32 text-right text-sm text-gray-500 tabular-nums">
+                                {format!("{} / {}", rate.converted, rate.feedstocks)}
+                            </span>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+            <p class="mt-4 text-center text-sm text-gray-400">{i18n.t("bot-activity-footer")}</p>
+        </div>
+    }
+    .into_any()
+}
+
+/// Community insights page: month over month contributor retention and churn, how well
+/// conversions stick, how bot activity relates to converting and which feedstocks may
+/// convert next. `insights` is None while loading and Some(None) if it couldn't be
+/// fetched.
+#[component]
+pub fn InsightsPage(insights: Signal<Option<Option<InsightsFile>>>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let content = move || match insights.get() {
+        None => view! { <p class="text-gray-500">{i18n.t("insights-loading")}</p> }.into_any(),
+        Some(None) => {
+            view! { <p class="text-gray-500">{i18n.t("insights-load-failed")}</p> }.into_any()
+        }
+        Some(Some(insights)) => {
+            view! { <RetentionChart retention=insights.contributor_retention /> }.into_any()
+        }
+    };
+    let sections = move || {
+        insights.get().flatten().map(|insights| {
+            view! {
+                <ContributorMixChart mix=insights.contributor_mix />
+                <CohortTable cohorts=insights.conversion_cohorts />
+                <BotActivityTable rates=insights.bot_activity_rates />
+                <ForecastTable forecast=insights.conversion_forecast />
+            }
+        })
+    };
+
+    view! {
+        <div class="max-w-6xl mx-auto px-4 pb-8">
+            <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200">
+                <div class="mb-6">
+                    <a href="#" class="text-sm text-blue-600 hover:underline">
+                        {i18n.t("quality-back")}
+                    </a>
+                    <h2 class="mt-2 text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                        {i18n.t("insights-title")}
+                    </h2>
+                    <p class="text-gray-500 leading-relaxed">{i18n.t("insights-intro")}</p>
+                </div>
+                {content}
+            </div>
+            {sections}
+        </div>
+    }
+}
+
+/// Latest retention figures and the retention rate of the last months
+#[component]
+fn RetentionChart(retention: Vec<RetentionPoint>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    // The last months, without the first one, which has nobody to retain
+    let skip = retention.len().saturating_sub(RETENTION_MONTHS).max(1);
+    let months: Vec<_> = retention.into_iter().skip(skip).collect();
+
+    match months.last().cloned() {
+        Some(latest) => {
+            let figures = [
+                ("insights-active", latest.active.to_string()),
+                (
+                    "insights-retention",
+                    format!("{:.0}%", latest.retention_percent()),
+                ),
+                ("insights-churned", latest.churned.to_string()),
+            ];
+
+            view! {
+                <div class="grid grid-cols-3 gap-4 mb-8">
+                    {figures.into_iter().map(|(label, value)| view! {
+                        <div class="text-center">
+                            <div class="text-3xl font-bold text-gray-900 tabular-nums">{value}</div>
+                            <div class="text-xs font-semibold text-gray-500 uppercase tracking-wide mt-1">
+                                {i18n.t(label)}
+                            </div>
+                        </div>
+                    }).collect::<Vec<_>>()}
+                </div>
+                <h3 class="mb-3 text-lg font-semibold text-gray-900">
+                    {i18n.t("insights-chart-title")}
+                </h3>
+                <div class="flex items-end gap-1 h-40 border-b border-gray-200">
+                    {months.iter().map(|point| {
+                        let tooltip = i18n.t_with(
+                            "insights-bar-tooltip",
+                            &[
+                                ("month", point.month.clone().into()),
+                                ("retained", point.retained.into()),
+                                ("previous", point.previous_active().into()),
+                                ("active", point.active.into()),
+                            ],
+                        );
+                        view! {
+                            <div class="flex-1 h-full flex items-end" title=tooltip>
+                                <div
+                                    class="w-full bg-emerald-500 rounded-t"
+                                    style=format!("height: {:.1}%", point.retention_percent())
+                                ></div>
+                            </div>
+                        }
+                    }).collect::<Vec<_>>()}
+                </div>
+                <div class="flex gap-1 mt-1">
+                    {months.iter().map(|point| view! {
+                        <span class="flex-1 text-center text-xs text-gray-400 tabular-nums">
+                            {point.month.get(5..).unwrap_or_default().to_string()}
+                        </span>
+                    }).collect::<Vec<_>>()}
+                </div>
+            }
+            .into_any()
+        }
+        None => view! { <p class="text-gray-500">{i18n.t("insights-unavailable")}</p> }.into_any(),
+    }
+}
@@ -0,0 +1,29 @@
+//! Language picker
+
+use leptos::prelude::*;
+
+use crate::i18n::{use_i18n, Locale};
+
+#[component]
+pub fn LanguagePicker() -> impl IntoView {
+    let i18n = use_i18n();
+    let current = i18n.locale();
+
+    view! {
+        <select
+            aria-label=i18n.t("language-label")
+            class="text-sm text-gray-500 bg-transparent border border-gray-300 rounded-full px-3 py-1 hover:border-gray-400 transition-colors duration-150"
+            on:change=move |ev| {
+                if let Some(locale) = Locale::from_code(&event_target_value(&ev)) {
+                    i18n.set_locale(locale);
+                }
+            }
+        >
+            {Locale::ALL.into_iter().map(|locale| view! {
+                <option value=locale.code() selected=locale == current>
+                    {locale.native_name()}
+                </option>
+            }).collect::<Vec<_>>()}
+        </select>
+    }
+}
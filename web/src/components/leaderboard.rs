@@ -0,0 +1,457 @@
+//! Contributor leaderboard
+
+use leptos::prelude::*;
+
+use super::{local_date_time, ContributorDetails, HoverCard, ShapeIndicator};
+use crate::achievements::{self, compute_achievements, Achievement};
+use crate::data::{ContributionType, ContributorStats, FeedstockContribution, TimelineEvent};
+use crate::format::{format_downloads, format_month};
+use crate::i18n::{use_i18n, I18n};
+
+/// Translated tooltip such as "Master Smith (100+ v1 contributions)", followed by
+/// "· Earned: Mar 2025" if the date is known
+fn achievement_tooltip(i18n: I18n, achievement: &Achievement, earned: Option<&String>) -> String {
+    let tooltip = i18n.t_with(
+        achievement.tooltip_key(),
+        &[
+            ("name", i18n.t(&achievement.name_key()).into()),
+            ("threshold", achievement.threshold.into()),
+        ],
+    );
+    match earned {
+        Some(date) => i18n.t_with(
+            "achievement-earned",
+            &[
+                ("tooltip", tooltip.into()),
+                ("month", format_month(date).into()),
+            ],
+        ),
+        None => tooltip,
+    }
+}
+
+/// Rendered avatar size in pixels
+const AVATAR_SIZE: u32 = 20;
+
+/// GitHub avatar URL resized to `pixels`, so the leaderboard doesn't load full-size images
+fn sized_avatar(url: &str, pixels: u32) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}s={}", url, separator, pixels)
+}
+
+/// Packages shown on a contributor's hover card
+const HOVER_CARD_PACKAGES: usize = 3;
+
+/// Quick look at a contributor's biggest packages and total impact, without expanding
+/// the row
+fn contributor_card(
+    i18n: I18n,
+    total_downloads: u64,
+    total: u32,
+    feedstocks: &[FeedstockContribution],
+) -> AnyView {
+    let mut top: Vec<&FeedstockContribution> = feedstocks.iter().collect();
+    top.sort_by_key(|f| std::cmp::Reverse(f.downloads));
+    view! {
+        <span class="block text-xs text-gray-500 mb-2">
+            {i18n.t_with(
+                "hovercard-impact",
+                &[("downloads", format_downloads(total_downloads).into()), ("feedstocks", total.into())],
+            )}
+        </span>
+        <span class="block text-xs font-semibold text-gray-500 uppercase tracking-wide mb-1">
+            {i18n.t("hovercard-top-packages")}
+        </span>
+        {top.into_iter().take(HOVER_CARD_PACKAGES).map(|f| view! {
+            <span class="flex items-center py-0.5">
+                <ShapeIndicator contribution_type=f.contribution_type />
+                <span class="ml-2 flex-1 truncate">{f.name.replace("-feedstock", "")}</span>
+                <span class="ml-2 text-xs text-gray-500 tabular-nums">{"~"}{format_downloads(f.downloads)}</span>
+            </span>
+        }).collect::<Vec<_>>()}
+    }
+    .into_any()
+}
+
+/// Component for a single contributor row with expandable details
+#[component]
+pub fn ContributorRow(
+    index: usize,
+    contributor: ContributorStats,
+    activity_weeks: usize,
+    events: Vec<TimelineEvent>,
+    /// Day of the leaderboard `contributor.rank_change` compares against
+    rank_change_since: Option<String>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let (expanded, set_expanded) = signal(false);
+
+    let total = contributor.conversions + contributor.new_feedstocks;
+    // Don't link to deleted accounts, and follow renames
+    let github = contributor.github.clone();
+    let github_url = match &github {
+        Some(github) if !github.exists => None,
+        Some(github) => Some(github.renamed_to.as_deref().unwrap_or(&contributor.name)),
+        None => Some(contributor.name.as_str()),
+    }
+    .map(|login| format!("https://github.com/{}", login));
+    let account_note = github.as_ref().and_then(|github| match &github.renamed_to {
+        _ if !github.exists => Some(i18n.t("leaderboard-account-missing")),
+        Some(login) => Some(i18n.t_with(
+            "leaderboard-account-renamed",
+            &[("login", login.clone().into())],
+        )),
+        None => None,
+    });
+    let avatar_url = github
+        .as_ref()
+        .and_then(|github| github.avatar_url.as_deref())
+        .map(|url| sized_avatar(url, AVATAR_SIZE * 2));
+
+    // Medal emoji for top 3
+    let medal = match index {
+        0 => Some("🥇"),
+        1 => Some("🥈"),
+        2 => Some("🥉"),
+        _ => None,
+    };
+
+    // Compute achievements for this contributor
+    let achievements = compute_achievements(
+        contributor.conversions,
+        contributor.new_feedstocks,
+        contributor.self_conversions,
+    );
+
+    // Clone values for use in closures
+    let name = contributor.name.clone();
+    let conversions = contributor.conversions;
+    let new_feedstocks = contributor.new_feedstocks;
+    let total_downloads = contributor.total_downloads;
+    let first_contribution = contributor.first_contribution.clone();
+    let last_contribution = contributor.last_contribution.clone();
+    let top_package = contributor.top_package.clone();
+    let largest_conversion = contributor.largest_conversion.clone();
+    let feedstocks = contributor.feedstocks.clone();
+    let weekly_activity = contributor.weekly_activity.clone();
+    let profile = contributor.profile.clone().unwrap_or_default();
+    let achievements_earned = contributor.achievements_earned.clone();
+    let display_name = profile
+        .display_name
+        .clone()
+        .or_else(|| github.and_then(|github| github.name));
+    let affiliation = profile.affiliation.clone();
+    let label = display_name.clone().unwrap_or_else(|| name.clone());
+
+    view! {
+        <li class="border-b border-dashed border-gray-200">
+            <div
+                on:click=move |_| set_expanded.update(|v| *v = !*v)
+                class="flex items-center py-2 -mx-2 px-2 rounded hover:bg-gray-50 transition-colors duration-150 cursor-pointer"
+            >
+                // Expander chevron at the front
+                <span class="w-6 flex items-center justify-center mr-1">
+                    <svg
+                        class=move || format!(
+                            "w-4 h-4 text-gray-400 transition-transform duration-200 {}",
+                            if expanded.get() { "rotate-180" } else { "" }
+                        )
+                        fill="none"
+                        stroke="currentColor"
+                        viewBox="0 0 24 24"
+                    >
+                        <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M19 9l-7 7-7-7" />
+                    </svg>
+                </span>
+                {if let Some(emoji) = medal {
+                    view! {
+                        <span class="w-8 h-8 flex items-center justify-center text-xl">
+                            {emoji}
+                        </span>
+                    }.into_any()
+                } else {
+                    view! {
+                        <span class="w-8 h-8 flex items-center justify-center text-xs tabular-nums text-gray-400">
+                            {format!("{}", index + 1)}
+                        </span>
+                    }.into_any()
+                }}
+                <RankMovement rank_change=contributor.rank_change since=rank_change_since />
+                <span class="flex-1 font-medium text-blue-600">
+                    {avatar_url.map(|url| view! {
+                        <img
+                            src=url
+                            alt=""
+                            width=AVATAR_SIZE
+                            height=AVATAR_SIZE
+                            loading="lazy"
+                            class="inline-block w-5 h-5 mr-2 rounded-full align-text-bottom"
+                        />
+                    })}
+                    <HoverCard card=contributor_card(i18n, total_downloads, total, &feedstocks)>
+                        {match github_url {
+                            Some(url) => view! {
+                                <a
+                                    href=url
+                                    target="_blank"
+                                    rel="noopener noreferrer"
+                                    title=account_note.clone()
+                                    on:click=move |e| e.stop_propagation()
+                                    class="hover:underline"
+                                >
+                                    {label}
+                                </a>
+                            }.into_any(),
+                            None => view! {
+                                <span title=account_note.clone() class="text-gray-500">{label}</span>
+                            }.into_any(),
+                        }}
+                    </HoverCard>
+                    {display_name.is_some().then(|| view! {
+                        <span class="ml-1 text-sm font-normal text-gray-400">{format!("@{}", name)}</span>
+                    })}
+                    {affiliation.map(|affiliation| view! {
+                        <span class="ml-2 text-xs font-normal text-gray-500">{affiliation}</span>
+                    })}
+                    {if !achievements.is_empty() {
+                        view! {
+                            <span class="ml-2 text-base">
+                                {achievements.iter().map(|achievement| {
+                                    view! {
+                                        <span title=achievement_tooltip(i18n, achievement, achievements_earned.get(achievement.id))>
+                                            {achievement.emoji}
+                                        </span>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </span>
+                        }.into_any()
+                    } else {
+                        view! {}.into_any()
+                    }}
+                </span>
+                <span class="w-24 text-center text-sm text-emerald-600 tabular-nums">
+                    {conversions}
+                </span>
+                <span class="w-24 text-center text-sm text-blue-600 tabular-nums">
+                    {new_feedstocks}
+                </span>
+                <span class="w-16 text-right text-sm font-medium text-gray-700 tabular-nums">
+                    {total}
+                </span>
+            </div>
+
+            // Expanded details panel
+            <div class=move || format!(
+                "accordion-content {}",
+                if expanded.get() { "expanded" } else { "" }
+            )>
+                <div class="overflow-hidden">
+                    <div class="pl-14 pr-4 pb-4">
+                        <ContributorDetails
+                        name=name.clone()
+                        total_downloads=total_downloads
+                        conversions=conversions
+                        new_feedstocks=new_feedstocks
+                        first_contribution=first_contribution.clone()
+                        last_contribution=last_contribution.clone()
+                        top_package=top_package.clone()
+                        largest_conversion=largest_conversion.clone()
+                        feedstocks=feedstocks.clone()
+                        weekly_activity=weekly_activity.clone()
+                        activity_weeks=activity_weeks
+                        events=events.clone()
+                        profile=profile.clone()
+                    />
+                    </div>
+                </div>
+            </div>
+        </li>
+    }
+}
+
+/// ▲/▼ places moved since the previous week's leaderboard, or "new" for contributors
+/// who weren't on it
+#[component]
+fn RankMovement(rank_change: Option<i64>, since: Option<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let Some(since) = since else {
+        return view! {}.into_any();
+    };
+    let (label, color) = match rank_change {
+        Some(0) => return view! { <span class="w-8"></span> }.into_any(),
+        Some(up) if up > 0 => (
+            i18n.t_with("leaderboard-rank-up", &[("places", up.into())]),
+            "text-emerald-600",
+        ),
+        Some(down) => (
+            i18n.t_with("leaderboard-rank-down", &[("places", (-down).into())]),
+            "text-red-500",
+        ),
+        None => (i18n.t("leaderboard-rank-new"), "text-blue-500"),
+    };
+
+    view! {
+        <span
+            class=format!("w-8 text-xs tabular-nums {}", color)
+            title=i18n.t_with("leaderboard-rank-tooltip", &[("date", local_date_time(&since, i18n.locale()).into())])
+        >
+            {label}
+        </span>
+    }
+    .into_any()
+}
+
+#[component]
+pub fn Leaderboard(
+    contributors: Vec<ContributorStats>,
+    activity_weeks: usize,
+    self_conversions: u32,
+    events: Vec<TimelineEvent>,
+    rank_change_since: Option<String>,
+) -> impl IntoView {
+    if contributors.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+
+    // Calculate totals for summary
+    let total_conversions: u32 = contributors.iter().map(|c| c.conversions).sum();
+    let total_new_feedstocks: u32 = contributors.iter().map(|c| c.new_feedstocks).sum();
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("leaderboard-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed mb-3">
+                    {i18n.t("leaderboard-intro")}
+                </p>
+                <div class="flex gap-6 mt-4 mb-4">
+                    <div class="text-center">
+                        <div class="text-2xl font-bold text-emerald-600 tabular-nums">{total_conversions}</div>
+                        <div class="text-xs text-gray-500 uppercase tracking-wide">{i18n.t("label-conversions")}</div>
+                    </div>
+                    <div class="text-center">
+                        <div class="text-2xl font-bold text-blue-600 tabular-nums">{total_new_feedstocks}</div>
+                        <div class="text-xs text-gray-500 uppercase tracking-wide">{i18n.t("label-new-feedstocks")}</div>
+                    </div>
+                    <div class="text-center" title=i18n.t("leaderboard-self-conversions-tooltip")>
+                        <div class="text-2xl font-bold text-amber-600 tabular-nums">{self_conversions}</div>
+                        <div class="text-xs text-gray-500 uppercase tracking-wide">{i18n.t("leaderboard-self-conversions")}</div>
+                    </div>
+                </div>
+                <div class="text-xs text-gray-400 pt-3 space-y-2">
+                    <details class="cursor-pointer">
+                        <summary class="hover:text-gray-600 transition-colors">{i18n.t("leaderboard-how-title")}</summary>
+                        <div class="mt-2 space-y-1 text-gray-500">
+                            <p>
+                                <strong class="text-emerald-600">{i18n.t("label-conversions")}</strong>
+                                ": "{i18n.t("leaderboard-how-conversions")}
+                            </p>
+                            <p>
+                                <strong class="text-blue-600">{i18n.t("label-new-feedstocks")}</strong>
+                                ": "{i18n.t("leaderboard-how-new-feedstocks")}
+                            </p>
+                        </div>
+                    </details>
+                    <details class="cursor-pointer">
+                        <summary class="hover:text-gray-600 transition-colors">{i18n.t("leaderboard-legend")}</summary>
+                        <div class="mt-3 overflow-x-auto">
+                            <table class="w-full text-left text-gray-500 text-base">
+                                <thead>
+                                    <tr class="border-b border-gray-200">
+                                        <th class="pb-2 font-medium text-gray-600">{i18n.t("leaderboard-category")}</th>
+                                        <th class="pb-2 font-medium text-gray-600">"10+"</th>
+                                        <th class="pb-2 font-medium text-gray-600">"25+"</th>
+                                        <th class="pb-2 font-medium text-gray-600">"50+"</th>
+                                        <th class="pb-2 font-medium text-gray-600">"100+"</th>
+                                        <th class="pb-2 font-medium text-gray-600">"200+"</th>
+                                        <th class="pb-2 font-medium text-gray-600">"250+"</th>
+                                        <th class="pb-2 font-medium text-gray-600">"500+"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    <tr class="border-b border-gray-100">
+                                        <td class="py-2 text-gray-600">{i18n.t("leaderboard-total")}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::TOTAL[5].name_key())>{achievements::TOTAL[5].emoji}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::TOTAL[4].name_key())>{achievements::TOTAL[4].emoji}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::TOTAL[3].name_key())>{achievements::TOTAL[3].emoji}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::TOTAL[2].name_key())>{achievements::TOTAL[2].emoji}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::TOTAL[1].name_key())>{achievements::TOTAL[1].emoji}</td>
+                                        <td class="py-2"></td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::TOTAL[0].name_key())>{achievements::TOTAL[0].emoji}</td>
+                                    </tr>
+                                    <tr class="border-b border-gray-100">
+                                        <td class="py-2 text-emerald-600">{i18n.t("label-conversions")}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::CONVERSIONS[3].name_key())>{achievements::CONVERSIONS[3].emoji}</td>
+                                        <td class="py-2"></td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::CONVERSIONS[2].name_key())>{achievements::CONVERSIONS[2].emoji}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::CONVERSIONS[1].name_key())>{achievements::CONVERSIONS[1].emoji}</td>
+                                        <td class="py-2"></td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::CONVERSIONS[0].name_key())>{achievements::CONVERSIONS[0].emoji}</td>
+                                        <td class="py-2"></td>
+                                    </tr>
+                                    <tr>
+                                        <td class="py-2 text-blue-600">{i18n.t("label-new-feedstocks")}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::NEW_FEEDSTOCKS[3].name_key())>{achievements::NEW_FEEDSTOCKS[3].emoji}</td>
+                                        <td class="py-2"></td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::NEW_FEEDSTOCKS[2].name_key())>{achievements::NEW_FEEDSTOCKS[2].emoji}</td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::NEW_FEEDSTOCKS[1].name_key())>{achievements::NEW_FEEDSTOCKS[1].emoji}</td>
+                                        <td class="py-2"></td>
+                                        <td class="py-2 text-lg" title=i18n.t(&achievements::NEW_FEEDSTOCKS[0].name_key())>{achievements::NEW_FEEDSTOCKS[0].emoji}</td>
+                                        <td class="py-2"></td>
+                                    </tr>
+                                    <tr class="border-t border-gray-100">
+                                        <td class="py-2 text-amber-600">{i18n.t("leaderboard-self-conversion")}</td>
+                                        <td class="py-2 text-lg" title=achievement_tooltip(i18n, &achievements::SELF_CONVERSION, None) colspan="7">
+                                            {achievements::SELF_CONVERSION.emoji}
+                                            <span class="ml-2 text-xs text-gray-400">{i18n.t("leaderboard-self-conversion-hint")}</span>
+                                        </td>
+                                    </tr>
+                                </tbody>
+                            </table>
+                        </div>
+                    </details>
+                </div>
+            </div>
+
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="w-6 mr-1"></span>
+                <span class="w-8">"#"</span>
+                {rank_change_since.is_some().then(|| view! { <span class="w-8"></span> })}
+                <span class="flex-1">{i18n.t("leaderboard-contributor")}</span>
+                <span class="w-24 text-center flex items-center justify-center gap-1">
+                    <ShapeIndicator contribution_type=ContributionType::Conversion />
+                    {i18n.t("leaderboard-conv")}
+                </span>
+                <span class="w-24 text-center flex items-center justify-center gap-1">
+                    <ShapeIndicator contribution_type=ContributionType::NewFeedstock />
+                    {i18n.t("leaderboard-new")}
+                </span>
+                <span class="w-16 text-right">{i18n.t("leaderboard-total")}</span>
+            </div>
+
+            <ul class="space-y-0">
+                {contributors.into_iter().enumerate().map(|(index, contributor)| {
+                    view! {
+                        <ContributorRow
+                            index=index
+                            contributor=contributor
+                            activity_weeks=activity_weeks
+                            events=events.clone()
+                            rank_change_since=rank_change_since.clone()
+                        />
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+
+            <div class="mt-4 text-center">
+                <p class="text-sm text-gray-400">
+                    {i18n.t("leaderboard-footer")}
+                </p>
+            </div>
+        </div>
+    }.into_any()
+}
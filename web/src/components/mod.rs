@@ -0,0 +1,49 @@
+//! UI components of the site
+
+mod app;
+mod chart;
+mod common;
+mod completionists;
+mod consent;
+mod coverage;
+mod details;
+mod feed;
+mod goals;
+mod highlight;
+mod info;
+mod insights;
+mod language;
+mod leaderboard;
+mod palette;
+mod pending;
+mod quality;
+mod rankings;
+mod recent;
+mod renames;
+mod report;
+mod ticker;
+mod watchlist;
+
+pub use app::*;
+pub use chart::*;
+pub use common::*;
+pub use completionists::*;
+pub use consent::*;
+pub use coverage::*;
+pub use details::*;
+pub use feed::*;
+pub use goals::*;
+pub use highlight::*;
+pub use info::*;
+pub use insights::*;
+pub use language::*;
+pub use leaderboard::*;
+pub use palette::*;
+pub use pending::*;
+pub use quality::*;
+pub use rankings::*;
+pub use recent::*;
+pub use renames::*;
+pub use report::*;
+pub use ticker::*;
+pub use watchlist::*;
@@ -0,0 +1,70 @@
+//! Colorblind-friendly palette: the toggle and the SVG patterns it fills shapes with
+
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+use crate::theme::{self, Palette};
+
+/// Switch between the default and the colorblind palette, remembered across visits
+#[component]
+pub fn PaletteToggle() -> impl IntoView {
+    let i18n = use_i18n();
+    let palette = RwSignal::new(theme::load_palette());
+    let colorblind = move || palette.get() == Palette::Colorblind;
+
+    view! {
+        <button
+            type="button"
+            aria-pressed=move || colorblind().to_string()
+            title=i18n.t("palette-tooltip")
+            class=move || {
+                format!(
+                    "text-sm border rounded-full px-3 py-1 transition-colors duration-150 {}",
+                    if colorblind() {
+                        "text-gray-900 border-gray-500"
+                    } else {
+                        "text-gray-500 border-gray-300 hover:border-gray-400"
+                    },
+                )
+            }
+            on:click=move |_| {
+                let next = if colorblind() { Palette::Default } else { Palette::Colorblind };
+                theme::set_palette(next);
+                palette.set(next);
+            }
+        >
+            {i18n.t("palette-colorblind")}
+        </button>
+    }
+}
+
+/// Dotted and striped fills referenced by the stylesheet's colorblind palette, so SVG
+/// charts don't rely on color alone
+#[component]
+pub fn PalettePatterns() -> impl IntoView {
+    view! {
+        <svg width="0" height="0" class="absolute" aria-hidden="true">
+            <defs>
+                <pattern
+                    id=theme::CONVERSION_PATTERN_ID
+                    width="4"
+                    height="4"
+                    patternUnits="userSpaceOnUse"
+                >
+                    <rect width="4" height="4" fill=theme::colors::CB_ORANGE />
+                    <circle cx="2" cy="2" r="0.9" fill="white" fill-opacity="0.7" />
+                </pattern>
+                <pattern
+                    id=theme::NEW_FEEDSTOCK_PATTERN_ID
+                    width="4"
+                    height="4"
+                    patternUnits="userSpaceOnUse"
+                    patternTransform="rotate(45)"
+                >
+                    <rect width="4" height="4" fill=theme::colors::CB_BLUE />
+                    <rect width="1.5" height="4" fill="white" fill-opacity="0.6" />
+                </pattern>
+            </defs>
+        </svg>
+    }
+}
@@ -0,0 +1,68 @@
+//! Unconverted feedstocks whose migration PR by the autotick bot only needs a merge
+
+use leptos::prelude::*;
+
+use super::RelativeTime;
+use crate::data::PendingMigration;
+use crate::format::format_downloads;
+use crate::i18n::use_i18n;
+
+#[component]
+pub fn PendingMigrations(feedstocks: Vec<PendingMigration>) -> impl IntoView {
+    if feedstocks.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+
+    view! {
+        <div class="mt-8 bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("pending-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">
+                    {i18n.t_with("pending-intro", &[("count", feedstocks.len().into())])}
+                </p>
+            </div>
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="flex-1">{i18n.t("label-feedstock-name")}</span>
+                <span class="w-32">{i18n.t("pending-opened")}</span>
+                <span class="w-24 text-right">{i18n.t("label-downloads")}</span>
+            </div>
+            <ul class="space-y-0">
+                {feedstocks.into_iter().map(|feedstock| {
+                    let pr_url = format!(
+                        "https://github.com/conda-forge/{}/pull/{}",
+                        feedstock.name, feedstock.number
+                    );
+                    let display_name = feedstock.name.replace("-feedstock", "");
+
+                    view! {
+                        <li>
+                            <a
+                                href=pr_url
+                                target="_blank"
+                                rel="noopener noreferrer"
+                                class="flex items-center py-2 -mx-2 px-2 rounded border-b border-dashed border-gray-200 hover:bg-gray-50 transition-colors duration-150 cursor-pointer"
+                            >
+                                <span class="flex-1 font-medium text-blue-600">
+                                    {display_name}
+                                    <span class="ml-2 text-sm font-normal text-gray-400">
+                                        {format!("#{}", feedstock.number)}
+                                    </span>
+                                </span>
+                                <span class="w-32 text-sm text-gray-500">
+                                    <RelativeTime date=feedstock.opened.clone() />
+                                </span>
+                                <span class="w-24 text-right text-sm font-medium text-gray-700 tabular-nums">
+                                    {"~"}{format_downloads(feedstock.downloads)}
+                                </span>
+                            </a>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+        </div>
+    }.into_any()
+}
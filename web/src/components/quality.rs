@@ -0,0 +1,125 @@
+//! Data quality page, explaining how trustworthy each number on the site is
+
+use leptos::prelude::*;
+
+use crate::data::{percent, DataQuality};
+use crate::i18n::use_i18n;
+
+/// URL fragment showing the data quality page instead of the dashboard
+pub const DATA_QUALITY_HASH: &str = "#data-quality";
+
+/// `quality` is None while loading and Some(None) if it couldn't be fetched
+#[component]
+pub fn DataQualityPage(quality: Signal<Option<Option<DataQuality>>>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let content = move || match quality.get() {
+        None => view! { <p class="text-gray-500">{i18n.t("quality-loading")}</p> }.into_any(),
+        Some(Some(quality)) => {
+            let gaps = [
+                (
+                    "quality-missing-downloads",
+                    "quality-missing-downloads-hint",
+                    quality.missing_downloads,
+                    quality.total_feedstocks,
+                ),
+                (
+                    "quality-unknown-recipe-types",
+                    "quality-unknown-recipe-types-hint",
+                    quality.unknown_recipe_types,
+                    quality.total_feedstocks,
+                ),
+            ];
+            let confidence = [
+                (
+                    "quality-confidence-high",
+                    quality.attribution_confidence.high,
+                    "bg-emerald-500",
+                ),
+                (
+                    "quality-confidence-medium",
+                    quality.attribution_confidence.medium,
+                    "bg-amber-400",
+                ),
+                (
+                    "quality-confidence-low",
+                    quality.attribution_confidence.low,
+                    "bg-red-400",
+                ),
+                (
+                    "quality-confidence-unattributed",
+                    quality.attribution_confidence.unattributed,
+                    "bg-gray-300",
+                ),
+            ];
+
+            view! {
+                <ul class="space-y-4">
+                    {gaps.into_iter().map(|(label, hint, count, total)| view! {
+                        <li class="flex items-start gap-4">
+                            <span class="w-20 text-right text-2xl font-semibold text-gray-900 tabular-nums">
+                                {format!("{:.1}%", percent(count, total))}
+                            </span>
+                            <span class="flex-1">
+                                <span class="block font-medium text-gray-700">{i18n.t(label)}</span>
+                                <span class="block text-sm text-gray-500">{i18n.t(hint)}</span>
+                            </span>
+                        </li>
+                    }).collect::<Vec<_>>()}
+                    <li class="flex items-start gap-4">
+                        <span class="w-20 text-right text-2xl font-semibold text-gray-900 tabular-nums">
+                            {quality.unresolved_logins}
+                        </span>
+                        <span class="flex-1">
+                            <span class="block font-medium text-gray-700">{i18n.t("quality-unresolved-logins")}</span>
+                            <span class="block text-sm text-gray-500">{i18n.t("quality-unresolved-logins-hint")}</span>
+                        </span>
+                    </li>
+                </ul>
+                <h3 class="mt-8 mb-3 text-lg font-semibold text-gray-900">
+                    {i18n.t("quality-confidence-title")}
+                </h3>
+                <ul class="space-y-0">
+                    {confidence.into_iter().map(|(label, count, color)| {
+                        let share = percent(count, quality.v1_feedstocks);
+                        view! {
+                            <li class="flex items-center gap-3 py-2 border-b border-dashed border-gray-200">
+                                <span class="w-64 text-sm text-gray-700">{i18n.t(label)}</span>
+                                <span class="flex-1 h-2 bg-gray-100 rounded-full overflow-hidden">
+                                    <span
+                                        class=format!("block h-full rounded-full {}", color)
+                                        style=format!("width: {:.1}%", share)
+                                    ></span>
+                                </span>
+                                <span class="w-28 text-right text-sm text-gray-500 tabular-nums">
+                                    {format!("{} ({:.0}%)", count, share)}
+                                </span>
+                            </li>
+                        }
+                    }).collect::<Vec<_>>()}
+                </ul>
+            }
+            .into_any()
+        }
+        Some(None) => {
+            view! { <p class="text-gray-500">{i18n.t("quality-unavailable")}</p> }.into_any()
+        }
+    };
+
+    view! {
+        <div class="max-w-6xl mx-auto px-4 pb-8">
+            <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200">
+                <div class="mb-6">
+                    <a href="#" class="text-sm text-blue-600 hover:underline">
+                        {i18n.t("quality-back")}
+                    </a>
+                    <h2 class="mt-2 text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                        {i18n.t("quality-title")}
+                    </h2>
+                    <p class="text-gray-500 leading-relaxed">{i18n.t("quality-intro")}</p>
+                </div>
+                {content}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,250 @@
+//! Ranking of unconverted feedstocks
+
+use leptos::prelude::*;
+
+use super::WatchToggle;
+use crate::data::{ConversionLinks, UnconvertedFeedstock};
+use crate::format::format_downloads;
+use crate::i18n::use_i18n;
+
+/// Documentation covering each recipe feature reported by the collector
+const CONVERSION_TIPS: &[(&str, &str)] = &[
+    (
+        "multiple-outputs",
+        "https://rattler.build/latest/converting_from_conda_build/",
+    ),
+    ("selectors", "https://rattler.build/latest/selectors/"),
+    (
+        "jinja-statements",
+        "https://rattler.build/latest/converting_from_conda_build/",
+    ),
+    (
+        "inline-script",
+        "https://rattler.build/latest/build_script/",
+    ),
+    (
+        "noarch",
+        "https://rattler.build/latest/reference/recipe_file/",
+    ),
+    ("compiled", "https://rattler.build/latest/compilers/"),
+];
+
+#[component]
+pub fn TopUnconvertedRanking(feedstocks: Vec<UnconvertedFeedstock>) -> impl IntoView {
+    if feedstocks.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    let (ready_only, set_ready_only) = signal(false);
+    let (active_only, set_active_only) = signal(false);
+    let has_readiness = feedstocks.iter().any(|f| f.blocked_by.is_some());
+    let has_activity = feedstocks.iter().any(|f| f.active.is_some());
+
+    // Take only the top 20 for display
+    let top_feedstocks = Signal::derive(move || {
+        feedstocks
+            .iter()
+            .filter(|f| !ready_only.get() || f.is_ready())
+            .filter(|f| !active_only.get() || f.active == Some(true))
+            .take(20)
+            .cloned()
+            .enumerate()
+            .collect::<Vec<_>>()
+    });
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("ranking-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">
+                    {i18n.t("ranking-intro")}
+                </p>
+                {has_readiness.then(|| view! {
+                    <label class="mt-3 inline-flex items-center gap-2 text-sm text-gray-600 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || ready_only.get()
+                            on:change=move |ev| set_ready_only.set(event_target_checked(&ev))
+                        />
+                        {i18n.t("ranking-ready-only")}
+                    </label>
+                })}
+                {has_activity.then(|| view! {
+                    <label class="mt-3 ml-4 inline-flex items-center gap-2 text-sm text-gray-600 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || active_only.get()
+                            on:change=move |ev| set_active_only.set(event_target_checked(&ev))
+                        />
+                        {i18n.t("ranking-active-only")}
+                    </label>
+                })}
+            </div>
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="w-8">"#"</span>
+                <span class="flex-1">{i18n.t("label-feedstock-name")}</span>
+                <span class="w-24 text-right">{i18n.t("label-downloads")}</span>
+            </div>
+            <ul class="space-y-0">
+                {move || top_feedstocks.get().into_iter().map(|(index, feedstock)| {
+                    let github_url = format!("https://github.com/conda-forge/{}", feedstock.name);
+                    let display_name = feedstock.name.replace("-feedstock", "");
+                    let formatted_downloads = format_downloads(feedstock.downloads);
+
+                    view! {
+                        <li class="flex items-center py-2 -mx-2 px-2 rounded border-b border-dashed border-gray-200 hover:bg-gray-50 transition-colors duration-150">
+                            <span class="w-8 text-sm font-medium text-gray-400 tabular-nums">
+                                {format!("#{}", index + 1)}
+                            </span>
+                            <WatchToggle name=feedstock.name.clone() />
+                            <a
+                                href=github_url
+                                target="_blank"
+                                rel="noopener noreferrer"
+                                class="flex-1 min-w-0 ml-1"
+                            >
+                                <span class="block font-medium text-blue-600 hover:underline">{display_name}</span>
+                                {feedstock.summary.map(|summary| view! {
+                                    <span class="block text-xs text-gray-400 truncate">{summary}</span>
+                                })}
+                            </a>
+                            <ConversionTips features=feedstock.recipe_features />
+                            <ActivityBadge active=feedstock.active />
+                            <ReadinessBadge blocked_by=feedstock.blocked_by />
+                            {feedstock.links.map(|links| view! { <ConversionActions links=links /> })}
+                            <span class="w-24 text-right text-sm font-medium text-gray-700 tabular-nums">
+                                {"~"}{formatted_downloads}
+                            </span>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+            {move || top_feedstocks.with(Vec::is_empty).then(|| {
+                let message = if ready_only.get() { "ranking-ready-none" } else { "ranking-active-none" };
+                view! {
+                    <p class="py-4 text-center text-sm text-gray-500">{i18n.t(message)}</p>
+                }
+            })}
+            <div class="mt-4 text-center space-y-1">
+                <p class="text-sm text-gray-400">
+                    {i18n.t("ranking-showing")}
+                </p>
+                <p class="text-sm text-gray-400">
+                    {i18n.t("ranking-downloads-note")}
+                </p>
+                <p class="text-sm text-gray-400">
+                    {i18n.t("ranking-source")}" "
+                    <a href="https://prefix.dev/channels/conda-forge" target="_blank" rel="noopener noreferrer" class="text-blue-500 hover:underline">"prefix.dev"</a>
+                </p>
+            </div>
+        </div>
+    }.into_any()
+}
+
+/// Whether the feedstock's dependencies are converted, listing the ones that aren't
+#[component]
+fn ReadinessBadge(blocked_by: Option<Vec<String>>) -> impl IntoView {
+    let i18n = use_i18n();
+    let badge = "ml-2 px-1.5 py-0.5 rounded text-xs whitespace-nowrap";
+
+    match blocked_by {
+        Some(blockers) if blockers.is_empty() => view! {
+            <span class=format!("{} bg-emerald-50 text-emerald-700", badge) title=i18n.t("ranking-ready-tooltip")>
+                {i18n.t("ranking-ready")}
+            </span>
+        }
+        .into_any(),
+        Some(blockers) => {
+            let names: Vec<_> = blockers.iter().map(|b| b.replace("-feedstock", "")).collect();
+            view! {
+                <span
+                    class=format!("{} bg-gray-100 text-gray-500", badge)
+                    title=i18n.t_with("ranking-blocked-tooltip", &[("feedstocks", names.join(", ").into())])
+                >
+                    {i18n.t_with("ranking-blocked", &[("count", blockers.len().into())])}
+                </span>
+            }
+            .into_any()
+        }
+        None => view! {}.into_any(),
+    }
+}
+
+/// Links to the documentation for the recipe features that make this conversion harder
+#[component]
+fn ConversionTips(features: Vec<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let tips: Vec<_> = CONVERSION_TIPS
+        .iter()
+        .filter(|(feature, _)| features.iter().any(|f| f == feature))
+        .collect();
+    if tips.is_empty() {
+        return view! {}.into_any();
+    }
+
+    view! {
+        <span class="hidden md:flex flex-wrap gap-1 ml-2">
+            {tips.into_iter().map(|&(feature, url)| view! {
+                <a
+                    href=url
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    class="px-1.5 py-0.5 rounded bg-amber-50 text-xs text-amber-700 whitespace-nowrap hover:underline"
+                    title=i18n.t(&format!("tip-{}", feature))
+                >
+                    {i18n.t(&format!("feature-{}", feature))}
+                </a>
+            }).collect::<Vec<_>>()}
+        </span>
+    }
+    .into_any()
+}
+
+/// Whether the feedstock was pushed to recently, i.e. is still actively maintained
+#[component]
+fn ActivityBadge(active: Option<bool>) -> impl IntoView {
+    let i18n = use_i18n();
+    let badge = "ml-2 px-1.5 py-0.5 rounded text-xs whitespace-nowrap";
+
+    match active {
+        Some(true) => view! {
+            <span class=format!("{} bg-blue-50 text-blue-700", badge) title=i18n.t("ranking-active-tooltip")>
+                {i18n.t("ranking-active")}
+            </span>
+        }
+        .into_any(),
+        Some(false) => view! {
+            <span class=format!("{} bg-gray-100 text-gray-400", badge) title=i18n.t("ranking-dormant-tooltip")>
+                {i18n.t("ranking-dormant")}
+            </span>
+        }
+        .into_any(),
+        None => view! {}.into_any(),
+    }
+}
+
+/// Small buttons to fork the feedstock, or edit the meta.yaml and propose the conversion
+/// as a pull request
+#[component]
+fn ConversionActions(links: ConversionLinks) -> impl IntoView {
+    let i18n = use_i18n();
+    let button = "px-2 py-0.5 rounded border border-gray-200 text-xs text-gray-500 hover:text-blue-600 hover:border-blue-300 transition-colors duration-150";
+
+    view! {
+        <span class="hidden sm:flex gap-1 ml-2">
+            <a href=links.fork_url target="_blank" rel="noopener noreferrer" class=button>{i18n.t("ranking-fork")}</a>
+            <a
+                href=links.edit_url
+                target="_blank"
+                rel="noopener noreferrer"
+                class=button
+                title=i18n.t_with("ranking-edit-tooltip", &[("title", links.pr_title.into())])
+            >
+                {i18n.t("ranking-edit")}
+            </a>
+        </span>
+    }
+}
@@ -0,0 +1,118 @@
+//! List of feedstocks recently moved to Recipe v1
+
+use leptos::prelude::*;
+
+use super::RelativeTime;
+use crate::data::RecentFeedstock;
+use crate::i18n::use_i18n;
+
+/// Entries shown before "show more" is clicked
+const RECENT_VISIBLE: usize = 10;
+
+#[component]
+pub fn RecentlyUpdated(
+    feedstocks: Vec<RecentFeedstock>,
+    last_updated: String,
+    /// Window the list covers, None for snapshots crunched before it was configurable
+    window_days: Option<u32>,
+) -> impl IntoView {
+    if feedstocks.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    let (expanded, set_expanded) = signal(false);
+    let hidden = feedstocks.len().saturating_sub(RECENT_VISIBLE);
+    let shown = Signal::derive(move || {
+        let count = if expanded.get() {
+            feedstocks.len()
+        } else {
+            RECENT_VISIBLE
+        };
+        feedstocks.iter().take(count).cloned().collect::<Vec<_>>()
+    });
+
+    view! {
+        <div class="bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="flex items-center justify-between mb-4">
+                <div>
+                    <h2 class="text-lg font-semibold text-gray-900 tracking-tight">{i18n.t("recent-title")}</h2>
+                    {window_days.map(|days| view! {
+                        <p class="text-xs text-gray-500">{i18n.t_with("recent-window", &[("days", days.into())])}</p>
+                    })}
+                </div>
+                <span class="text-xs text-gray-400">
+                    <RelativeTime date=last_updated message="recent-updated" />
+                </span>
+            </div>
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span>{i18n.t("recent-recipe-name")}</span>
+                <span class="flex-1"></span>
+                <span>{i18n.t("recent-change-detected")}</span>
+            </div>
+            <ul class="space-y-1">
+                {move || shown.get().into_iter().map(|feedstock| {
+                    let RecentFeedstock { name, date, contributors, summary } = feedstock;
+                    let github_url = format!("https://github.com/conda-forge/{}", name);
+                    let display_name = name.replace("-feedstock", "");
+                    view! {
+                        <li class="flex items-center text-gray-700 py-2 -mx-2 px-2 rounded hover:bg-gray-50 transition-colors duration-150">
+                            <a
+                                href=github_url
+                                target="_blank"
+                                rel="noopener noreferrer"
+                                class="font-medium text-blue-600 hover:underline"
+                                title=summary
+                            >
+                                {display_name}
+                            </a>
+                            {(!contributors.is_empty()).then(|| view! {
+                                <span class="text-sm text-gray-400 ml-2">
+                                    {i18n.t("recent-by")}" "
+                                    {contributors.into_iter().enumerate().map(|(i, contributor)| view! {
+                                        {(i > 0).then_some(", ")}
+                                        <ContributorHandle login=contributor />
+                                    }).collect::<Vec<_>>()}
+                                </span>
+                            })}
+                            <span class="flex-1 border-b border-dotted border-gray-300 mx-3"></span>
+                            <span class="text-sm text-gray-500 tabular-nums"><RelativeTime date=date /></span>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+            {(hidden > 0).then(|| view! {
+                <button
+                    on:click=move |_| set_expanded.update(|v| *v = !*v)
+                    class="mt-3 w-full text-center text-sm text-blue-600 hover:underline"
+                >
+                    {move || if expanded.get() {
+                        i18n.t("recent-show-less")
+                    } else {
+                        i18n.t_with("recent-show-more", &[("count", hidden.into())])
+                    }}
+                </button>
+            })}
+        </div>
+    }.into_any()
+}
+
+/// A contributor's @handle, linking to their GitHub profile when known
+#[component]
+fn ContributorHandle(login: String) -> impl IntoView {
+    if login == "unknown" {
+        return view! { <span>{format!("@{}", login)}</span> }.into_any();
+    }
+
+    view! {
+        <a
+            href=format!("https://github.com/{}", login)
+            target="_blank"
+            rel="noopener noreferrer"
+            class="hover:text-blue-600 hover:underline"
+        >
+            {format!("@{}", login)}
+        </a>
+    }
+    .into_any()
+}
@@ -0,0 +1,57 @@
+//! Recipe v1 feedstocks that should rename their recipe to the canonical filename
+
+use leptos::prelude::*;
+
+use crate::data::NonStandardRecipe;
+use crate::format::format_downloads;
+use crate::i18n::use_i18n;
+
+#[component]
+pub fn NonStandardRecipes(feedstocks: Vec<NonStandardRecipe>) -> impl IntoView {
+    if feedstocks.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+
+    view! {
+        <div class="mt-8 bg-white rounded-lg p-8 shadow-sm border border-gray-200 hover:shadow-md transition-shadow duration-200">
+            <div class="mb-6">
+                <h2 class="text-2xl font-semibold text-gray-900 mb-2 tracking-tight">
+                    {i18n.t("renames-title")}
+                </h2>
+                <p class="text-gray-500 leading-relaxed">
+                    {i18n.t("renames-intro")}
+                </p>
+            </div>
+            <div class="flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-3">
+                <span class="flex-1">{i18n.t("label-feedstock-name")}</span>
+                <span class="w-48">{i18n.t("renames-recipe-file")}</span>
+                <span class="w-24 text-right">{i18n.t("label-downloads")}</span>
+            </div>
+            <ul class="space-y-0">
+                {feedstocks.into_iter().map(|feedstock| {
+                    let github_url = format!("https://github.com/conda-forge/{}", feedstock.name);
+                    let display_name = feedstock.name.replace("-feedstock", "");
+
+                    view! {
+                        <li>
+                            <a
+                                href=github_url
+                                target="_blank"
+                                rel="noopener noreferrer"
+                                class="flex items-center py-2 -mx-2 px-2 rounded border-b border-dashed border-gray-200 hover:bg-gray-50 transition-colors duration-150 cursor-pointer"
+                            >
+                                <span class="flex-1 font-medium text-blue-600">{display_name}</span>
+                                <code class="w-48 text-sm text-amber-600 truncate">{feedstock.recipe_file}</code>
+                                <span class="w-24 text-right text-sm font-medium text-gray-700 tabular-nums">
+                                    {"~"}{format_downloads(feedstock.downloads)}
+                                </span>
+                            </a>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+        </div>
+    }.into_any()
+}
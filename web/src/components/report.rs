@@ -0,0 +1,141 @@
+//! Report view for conda-forge core meetings: the key charts and tables laid out one
+//! section per printed page, without interactive parts, so it can be saved as a PDF
+
+use leptos::prelude::*;
+
+use super::{
+    local_date_time, CommunityGrowthChart, CompletionistLeaderboard, MigrationChart, MigrationStats,
+};
+use crate::data::StatsData;
+use crate::format::format_downloads;
+use crate::i18n::use_i18n;
+
+/// URL fragment showing the report view instead of the dashboard
+pub const REPORT_HASH: &str = "#report";
+
+/// Rows in each table of the report, to keep every section on a single page
+const REPORT_ROWS: usize = 20;
+
+#[component]
+pub fn ReportPage(stats: StatsData) -> impl IntoView {
+    let i18n = use_i18n();
+    let section = "report-section bg-white rounded-lg p-8 border border-gray-200 mt-8";
+    let header =
+        "flex items-center text-xs font-semibold text-gray-500 uppercase tracking-wide mb-2";
+    let row = "flex items-center py-1.5 border-b border-dashed border-gray-200 text-sm";
+
+    let contributors: Vec<_> = stats
+        .top_contributors
+        .into_iter()
+        .take(REPORT_ROWS)
+        .collect();
+    let unconverted: Vec<_> = stats
+        .top_unconverted
+        .into_iter()
+        .take(REPORT_ROWS)
+        .collect();
+
+    view! {
+        <div class="report max-w-4xl mx-auto px-4 pb-8">
+            <div class="flex items-center justify-between print:hidden">
+                <a href="#" class="text-sm text-blue-600 hover:underline">
+                    {i18n.t("quality-back")}
+                </a>
+                <button
+                    on:click=move |_| {
+                        let _ = window().print();
+                    }
+                    class="px-3 py-1 rounded border border-gray-300 text-sm text-gray-700 hover:bg-gray-100 transition-colors duration-150"
+                >
+                    {i18n.t("report-print")}
+                </button>
+            </div>
+
+            <section class=section>
+                <h2 class="text-2xl font-semibold text-gray-900 tracking-tight">{i18n.t("report-title")}</h2>
+                <p class="text-sm text-gray-500 mb-8">
+                    {i18n.t_with("report-data-as-of", &[("date", local_date_time(&stats.last_updated, i18n.locale()).into())])}
+                </p>
+                <div class="grid grid-cols-2 gap-12 items-center">
+                    <MigrationChart
+                        converted=stats.recipe_v1_count
+                        total=stats.total_feedstocks
+                        dependency_weighted=stats.dependency_weighted.clone()
+                    />
+                    <MigrationStats
+                        converted=stats.recipe_v1_count
+                        total=stats.total_feedstocks
+                        build_health=stats.build_health.clone()
+                        recipe_lint=stats.recipe_lint.clone()
+                    />
+                </div>
+                <dl class="mt-8 grid grid-cols-2 gap-4 text-center">
+                    <div>
+                        <dt class="text-xs font-semibold text-gray-500 uppercase tracking-wide">{i18n.t("report-total-contributors")}</dt>
+                        <dd class="text-3xl font-bold text-gray-900 tabular-nums">{stats.total_contributors}</dd>
+                    </div>
+                    <div>
+                        <dt class="text-xs font-semibold text-gray-500 uppercase tracking-wide">{i18n.t("leaderboard-self-conversions")}</dt>
+                        <dd class="text-3xl font-bold text-gray-900 tabular-nums">{stats.self_conversions}</dd>
+                    </div>
+                </dl>
+            </section>
+
+            <section class=section>
+                <CommunityGrowthChart
+                    growth=stats.contributor_growth
+                    total=stats.total_contributors
+                    events=stats.events
+                />
+            </section>
+
+            <section class=section>
+                <h2 class="text-2xl font-semibold text-gray-900 mb-4 tracking-tight">{i18n.t("leaderboard-title")}</h2>
+                <div class=header>
+                    <span class="w-8">"#"</span>
+                    <span class="flex-1">{i18n.t("leaderboard-contributor")}</span>
+                    <span class="w-20 text-right">{i18n.t("leaderboard-conv")}</span>
+                    <span class="w-20 text-right">{i18n.t("leaderboard-new")}</span>
+                    <span class="w-20 text-right">{i18n.t("leaderboard-total")}</span>
+                </div>
+                {contributors.into_iter().enumerate().map(|(index, contributor)| view! {
+                    <div class=row>
+                        <span class="w-8 text-gray-400 tabular-nums">{index + 1}</span>
+                        <span class="flex-1 font-medium text-gray-900">{contributor.name.clone()}</span>
+                        <span class="w-20 text-right tabular-nums">{contributor.conversions}</span>
+                        <span class="w-20 text-right tabular-nums">{contributor.new_feedstocks}</span>
+                        <span class="w-20 text-right font-medium tabular-nums">{contributor.total()}</span>
+                    </div>
+                }).collect::<Vec<_>>()}
+            </section>
+
+            <section class=section>
+                <h2 class="text-2xl font-semibold text-gray-900 mb-4 tracking-tight">{i18n.t("ranking-title")}</h2>
+                <div class=header>
+                    <span class="w-8">"#"</span>
+                    <span class="flex-1">{i18n.t("label-feedstock-name")}</span>
+                    <span class="w-32 text-right">{i18n.t("report-blocked-by")}</span>
+                    <span class="w-24 text-right">{i18n.t("label-downloads")}</span>
+                </div>
+                {unconverted.into_iter().enumerate().map(|(index, feedstock)| {
+                    let blocked_by = match &feedstock.blocked_by {
+                        Some(blockers) => blockers.len().to_string(),
+                        None => "–".to_string(),
+                    };
+                    view! {
+                        <div class=row>
+                            <span class="w-8 text-gray-400 tabular-nums">{index + 1}</span>
+                            <span class="flex-1 font-medium text-gray-900">{feedstock.name.replace("-feedstock", "")}</span>
+                            <span class="w-32 text-right tabular-nums">{blocked_by}</span>
+                            <span class="w-24 text-right tabular-nums">{"~"}{format_downloads(feedstock.downloads)}</span>
+                        </div>
+                    }
+                }).collect::<Vec<_>>()}
+            </section>
+
+            <section class="report-section mt-8">
+                <CompletionistLeaderboard completionists=stats.completionists />
+            </section>
+        </div>
+    }
+}
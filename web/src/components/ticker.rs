@@ -0,0 +1,69 @@
+//! Scrolling ticker of big packages recently moved to Recipe v1
+
+use leptos::prelude::*;
+
+use super::RelativeTime;
+use crate::data::NotableConversion;
+use crate::format::format_downloads;
+use crate::i18n::use_i18n;
+
+/// "numpy · ~12M downloads · Mar 03, 2025", looping above the migration chart
+#[component]
+pub fn NotableTicker(conversions: Vec<NotableConversion>) -> impl IntoView {
+    if conversions.is_empty() {
+        return view! {}.into_any();
+    }
+
+    let i18n = use_i18n();
+    let items = move |hidden: bool| {
+        conversions
+            .iter()
+            .map(|conversion| {
+                let github_url = format!("https://github.com/conda-forge/{}", conversion.feedstock);
+                let display_name = conversion.feedstock.replace("-feedstock", "");
+                let title = conversion
+                    .contributors
+                    .iter()
+                    .map(|c| format!("@{}", c))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                view! {
+                    <a
+                        href=github_url
+                        target="_blank"
+                        rel="noopener noreferrer"
+                        title=title
+                        tabindex=hidden.then_some("-1")
+                        class="flex items-center gap-2 whitespace-nowrap text-gray-600 hover:text-gray-900"
+                    >
+                        <span aria-hidden="true">"🚀"</span>
+                        <span class="font-medium text-emerald-600">{display_name}</span>
+                        <span class="text-gray-400 tabular-nums">
+                            {i18n.t_with(
+                                "ticker-downloads",
+                                &[("downloads", format_downloads(conversion.downloads).into())],
+                            )}
+                        </span>
+                        <span class="text-gray-400"><RelativeTime date=conversion.date.clone() /></span>
+                    </a>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="ticker flex items-center mb-8 bg-white rounded-lg shadow-sm border border-gray-200 text-sm overflow-hidden print:hidden">
+            <span class="shrink-0 px-4 py-2 text-xs font-semibold text-gray-500 uppercase tracking-wide border-r border-gray-200">
+                {i18n.t("ticker-label")}
+            </span>
+            <div class="overflow-hidden">
+                <div class="ticker-track flex w-max">
+                    <div class="flex gap-8 px-4 py-2">{items(false)}</div>
+                    // The copy makes the loop seamless; screen readers only get the first
+                    <div class="flex gap-8 px-4 py-2" aria-hidden="true">{items(true)}</div>
+                </div>
+            </div>
+        </div>
+    }
+    .into_any()
+}
@@ -0,0 +1,105 @@
+//! Watching unconverted feedstocks and hearing about their conversion
+
+use leptos::prelude::*;
+
+use crate::i18n::{use_i18n, I18n};
+use crate::watchlist::{notify, use_watchlist};
+
+/// Star button to watch a feedstock for its conversion
+#[component]
+pub fn WatchToggle(name: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let watchlist = use_watchlist();
+    let watched = Signal::derive({
+        let name = name.clone();
+        move || watchlist.is_watched(&name)
+    });
+    let label = move || {
+        let key = if watched.get() {
+            "watch-remove"
+        } else {
+            "watch-add"
+        };
+        i18n.t(key)
+    };
+
+    view! {
+        <button
+            type="button"
+            class=move || {
+                format!(
+                    "ml-2 text-sm transition-colors duration-150 {}",
+                    if watched.get() { "text-amber-500" } else { "text-gray-300 hover:text-amber-400" },
+                )
+            }
+            aria-pressed=move || watched.get().to_string()
+            aria-label=label
+            title=label
+            on:click=move |_| watchlist.toggle(&name)
+        >
+            {move || if watched.get() { "★" } else { "☆" }}
+        </button>
+    }
+}
+
+/// Look up the watched feedstocks once, and put those that moved to Recipe v1 in the
+/// returned signal until dismissed. They get a system notification and then stop being
+/// watched.
+pub fn watch_conversions() -> RwSignal<Vec<String>> {
+    let i18n = use_i18n();
+    let watchlist = use_watchlist();
+    let converted = RwSignal::new(Vec::new());
+    leptos::task::spawn_local(async move {
+        let found = watchlist.take_converted().await;
+        if !found.is_empty() {
+            notify(
+                &i18n.t("watch-notification-title"),
+                &converted_message(i18n, &found),
+            );
+            converted.set(found);
+        }
+    });
+    converted
+}
+
+fn converted_message(i18n: I18n, converted: &[String]) -> String {
+    let names: Vec<_> = converted
+        .iter()
+        .map(|name| name.replace("-feedstock", ""))
+        .collect();
+    i18n.t_with(
+        "watch-converted",
+        &[
+            ("count", names.len().into()),
+            ("feedstocks", names.join(", ").into()),
+        ],
+    )
+}
+
+/// Banner for the watched feedstocks that just moved to Recipe v1, see
+/// `watch_conversions`
+#[component]
+pub fn WatchlistAlerts(converted: RwSignal<Vec<String>>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <Show when=move || converted.with(|converted| !converted.is_empty())>
+            <div
+                role="status"
+                class="mb-6 flex items-center gap-3 rounded-lg border border-amber-200 bg-amber-50 px-4 py-3 text-sm text-amber-800"
+            >
+                <span aria-hidden="true">"★"</span>
+                <span class="flex-1">
+                    {move || converted.with(|converted| converted_message(i18n, converted))}
+                </span>
+                <button
+                    type="button"
+                    class="text-amber-600 hover:underline"
+                    on:click=move |_| converted.set(Vec::new())
+                >
+                    {i18n.t("watch-dismiss")}
+                </button>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,844 @@
+//! Typed loading of the crunched stats embedded in the site
+
+use crate::format::parse_timestamp;
+use crate::schema::{ContributionsFile, StatsFile};
+use crate::theme;
+
+pub use crate::schema::{
+    percent, AchievementUnlock, BiggestConversion, BotActivityRate, BuildHealth, Completionist,
+    ContributionType, ContributorMix, ContributorProfile, ContributorStats, ConversionCohort,
+    ConversionForecast, ConversionLinks, DataQuality, DependencyWeighted, FeedstockContribution,
+    GrowthPoint, InsightsFile, MaintainerConversionRate, MaintainerCoverage,
+    MaintainerCoverageFile, NonStandardRecipe, NotableConversion, PendingMigration,
+    RecipeFeatureRate, RecipeLint, RetentionPoint, TimelineEvent, TopPackage, UnconvertedFeedstock,
+    WeeklyGrowth,
+};
+
+/// How the site draws and labels each contribution type
+pub trait ContributionStyle {
+    /// Get the background color class for this type
+    fn bg_class(&self) -> &'static str;
+
+    /// Get the text color class for this type
+    fn text_class(&self) -> &'static str;
+
+    /// Get the shape class (circle for conversion, square for new)
+    fn shape_class(&self) -> &'static str;
+
+    /// Get the SVG fill color
+    fn svg_color(&self) -> &'static str;
+
+    /// Get the class of SVG shapes, for the colorblind palette's patterns
+    fn svg_class(&self) -> &'static str;
+
+    /// Translation key of the short label for display
+    fn short_label_key(&self) -> &'static str;
+}
+
+impl ContributionStyle for ContributionType {
+    fn bg_class(&self) -> &'static str {
+        match self {
+            Self::Conversion => theme::classes::CONVERSION_BG,
+            Self::NewFeedstock => theme::classes::NEW_FEEDSTOCK_BG,
+        }
+    }
+
+    fn text_class(&self) -> &'static str {
+        match self {
+            Self::Conversion => theme::classes::CONVERSION_TEXT,
+            Self::NewFeedstock => theme::classes::NEW_FEEDSTOCK_TEXT,
+        }
+    }
+
+    fn shape_class(&self) -> &'static str {
+        match self {
+            Self::Conversion => "rounded-full", // Circle
+            Self::NewFeedstock => "",           // Square (no rounding)
+        }
+    }
+
+    fn svg_color(&self) -> &'static str {
+        match self {
+            Self::Conversion => theme::colors::EMERALD,
+            Self::NewFeedstock => theme::colors::BLUE,
+        }
+    }
+
+    fn svg_class(&self) -> &'static str {
+        match self {
+            Self::Conversion => theme::classes::CONVERSION_SVG,
+            Self::NewFeedstock => theme::classes::NEW_FEEDSTOCK_SVG,
+        }
+    }
+
+    fn short_label_key(&self) -> &'static str {
+        match self {
+            Self::Conversion => "activity-conv",
+            Self::NewFeedstock => "activity-new",
+        }
+    }
+}
+
+/// A feedstock recently converted to Recipe v1
+#[derive(Clone)]
+pub struct RecentFeedstock {
+    pub name: String,
+    pub date: String,
+    pub contributors: Vec<String>,
+    pub summary: Option<String>,
+}
+
+/// Maintainers whose login contains `query` (ignoring case), an exact match first, at
+/// most `limit` of them
+pub fn search_maintainers<'a>(
+    maintainers: &'a [MaintainerCoverage],
+    query: &str,
+    limit: usize,
+) -> Vec<&'a MaintainerCoverage> {
+    let query = query.trim().trim_start_matches('@').to_lowercase();
+    let mut found: Vec<_> = maintainers
+        .iter()
+        .filter(|m| m.login.to_lowercase().contains(&query))
+        .collect();
+    // Stable, so the rest keeps the most-maintained-first order
+    found.sort_by_key(|m| m.login.to_lowercase() != query);
+    found.truncate(limit);
+    found
+}
+
+/// Weekly activity entry: (conversions, new_feedstocks)
+pub type WeeklyActivity = Vec<(u32, u32)>;
+
+/// Short activity window offered by the sparkline range toggle
+pub const RECENT_ACTIVITY_WEEKS: usize = 20;
+
+/// Fallback activity window if the stats don't specify one
+pub const DEFAULT_ACTIVITY_WEEKS: usize = 52;
+
+/// Time range selectable on the activity sparkline
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ActivityRange {
+    /// The most recent N weeks
+    Weeks(usize),
+    /// Everything since the first contribution
+    All,
+}
+
+impl ActivityRange {
+    /// Slice the weekly activity (index 0 = most recent week) to this range
+    pub fn apply(&self, weekly_activity: &[(u32, u32)]) -> WeeklyActivity {
+        match self {
+            Self::Weeks(weeks) => weekly_activity.iter().take(*weeks).copied().collect(),
+            Self::All => weekly_activity.to_vec(),
+        }
+    }
+
+    /// Whether this range reaches further back than the `exported_weeks` of activity in
+    /// the stats, so the full contribution history is needed
+    pub fn needs_history(&self, exported_weeks: usize) -> bool {
+        match self {
+            Self::Weeks(weeks) => *weeks > exported_weeks,
+            Self::All => true,
+        }
+    }
+}
+
+/// One contributor's dated contributions, from the `contributions.toml` published next to
+/// the snapshots
+#[derive(Clone)]
+pub struct ContributionHistory {
+    /// When the file was crunched, the reference for the week buckets (RFC 3339)
+    pub generated_at: String,
+    /// Contribution date (RFC 3339) and type, oldest first
+    pub contributions: Vec<(String, ContributionType)>,
+}
+
+impl ContributionHistory {
+    /// Parse the history of `login`, `None` if the file doesn't list them
+    pub fn from_toml_str(content: &str, login: &str) -> Result<Option<Self>, toml::de::Error> {
+        let mut file: ContributionsFile = toml::from_str(content)?;
+        Ok(file.contributors.remove(login).map(|entries| Self {
+            generated_at: file.generated_at,
+            contributions: entries
+                .into_iter()
+                .map(|entry| (entry.date, entry.contribution_type))
+                .collect(),
+        }))
+    }
+
+    /// Weekly activity back to the oldest contribution, index 0 = most recent week
+    pub fn weekly_activity(&self) -> WeeklyActivity {
+        let Ok(now) = chrono::DateTime::parse_from_rfc3339(&self.generated_at) else {
+            return Vec::new();
+        };
+        let weeks_ago: Vec<(usize, &ContributionType)> = self
+            .contributions
+            .iter()
+            .filter_map(|(date, contribution_type)| {
+                let days = (now - chrono::DateTime::parse_from_rfc3339(date).ok()?).num_days();
+                (days >= 0).then_some(((days / 7) as usize, contribution_type))
+            })
+            .collect();
+
+        let weeks = weeks_ago.iter().map(|(w, _)| w + 1).max().unwrap_or(0);
+        let mut weekly = vec![(0, 0); weeks];
+        for (w, contribution_type) in weeks_ago {
+            match contribution_type {
+                ContributionType::Conversion => weekly[w].0 += 1,
+                ContributionType::NewFeedstock => weekly[w].1 += 1,
+            }
+        }
+        weekly
+    }
+}
+
+/// All data shown on the site, loaded from the crunched `stats.toml`
+#[derive(Clone)]
+pub struct StatsData {
+    pub recipe_v1_count: u32,
+    pub total_feedstocks: u32,
+    pub last_updated: String,
+    pub activity_weeks: usize,
+    pub recently_updated: Vec<RecentFeedstock>,
+    /// Days covered by `recently_updated`
+    pub recently_updated_days: Option<u32>,
+    pub top_unconverted: Vec<UnconvertedFeedstock>,
+    pub top_contributors: Vec<ContributorStats>,
+    /// Day ("YYYY-MM-DD") of the leaderboard the rank changes compare against
+    pub rank_change_since: Option<String>,
+    /// All unique contributors, not just the exported top ones
+    pub total_contributors: u32,
+    /// Feedstocks converted by one of their own maintainers
+    pub self_conversions: u32,
+    pub contributor_growth: Vec<GrowthPoint>,
+    /// Oldest week first
+    pub weekly_v1_growth: Vec<WeeklyGrowth>,
+    pub maintainer_conversion_rates: Vec<MaintainerConversionRate>,
+    /// Hardest features first
+    pub recipe_feature_rates: Vec<RecipeFeatureRate>,
+    pub non_standard_recipes: Vec<NonStandardRecipe>,
+    /// Most downloaded first
+    pub pending_migrations: Vec<PendingMigration>,
+    /// Absent until the collector first reached GitHub for CI results
+    pub build_health: Option<BuildHealth>,
+    pub recipe_lint: Option<RecipeLint>,
+    pub dependency_weighted: Option<DependencyWeighted>,
+    /// Absent before the first conversion
+    pub biggest_conversion: Option<BiggestConversion>,
+    /// Big packages converted lately, newest first
+    pub notable_recent: Vec<NotableConversion>,
+    /// Sorted by date ascending
+    pub events: Vec<TimelineEvent>,
+    /// Ranked by share of their own feedstocks converted
+    pub completionists: Vec<Completionist>,
+    /// Latest achievement unlocks, newest first
+    pub achievement_feed: Vec<AchievementUnlock>,
+}
+
+impl StatsData {
+    /// Load the stats embedded at build time
+    pub fn load() -> Self {
+        Self::from_toml_str(include_str!("stats.toml"))
+            .unwrap_or_else(|e| panic!("embedded stats.toml is invalid: {}", e))
+    }
+
+    /// Parse the crunched stats from TOML content. A section that doesn't match the
+    /// schema is an error rather than being left out.
+    pub fn from_toml_str(content: &str) -> Result<Self, toml::de::Error> {
+        let file: StatsFile = toml::from_str(content)?;
+
+        let mut recently_updated: Vec<RecentFeedstock> = file
+            .recently_updated
+            .into_iter()
+            .map(|(name, entry)| RecentFeedstock {
+                name,
+                date: entry.date,
+                contributors: entry.contributors,
+                summary: entry.summary,
+            })
+            .collect();
+        recently_updated.sort_by(|a, b| b.date.cmp(&a.date)); // Sort by date descending
+
+        let total_contributors = file
+            .total_contributors
+            .unwrap_or(file.top_contributors.len() as u32);
+
+        Ok(Self {
+            recipe_v1_count: file.recipe_v1_count,
+            total_feedstocks: file.total_feedstocks,
+            last_updated: file.last_updated,
+            activity_weeks: file.activity_weeks.unwrap_or(DEFAULT_ACTIVITY_WEEKS),
+            recently_updated,
+            recently_updated_days: file.recently_updated_days,
+            top_unconverted: file.top_unconverted_by_downloads,
+            top_contributors: file.top_contributors,
+            rank_change_since: file.rank_change_since,
+            total_contributors,
+            self_conversions: file.self_conversions,
+            contributor_growth: file.contributor_growth,
+            weekly_v1_growth: file.weekly_v1_growth,
+            maintainer_conversion_rates: file.maintainer_conversion_rates,
+            recipe_feature_rates: file.recipe_feature_rates,
+            non_standard_recipes: file.non_standard_recipes,
+            pending_migrations: file.pending_migrations,
+            build_health: file.build_health,
+            recipe_lint: file.recipe_lint,
+            dependency_weighted: file.dependency_weighted,
+            biggest_conversion: file.biggest_conversion,
+            notable_recent: file.notable_recent,
+            events: file.events,
+            completionists: file.completionists,
+            achievement_feed: file.achievement_feed,
+        })
+    }
+
+    /// Whether these stats were collected after `other`. Stats without a valid
+    /// `last_updated` never are, so they can't replace dated ones.
+    pub fn is_newer_than(&self, other: &StatsData) -> bool {
+        match (
+            parse_timestamp(&self.last_updated),
+            parse_timestamp(&other.last_updated),
+        ) {
+            (Some(this), Some(other)) => this > other,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Weeks until `share` (0.0 - 1.0) of all feedstocks use Recipe v1, at the average pace
+    /// of the last `recent_weeks` complete weeks. New v1 feedstocks count towards the goal
+    /// but also grow the total. None if the pace can't get there.
+    pub fn weeks_until_share(&self, share: f64, recent_weeks: usize) -> Option<f64> {
+        let missing = share * self.total_feedstocks as f64 - self.recipe_v1_count as f64;
+        if missing <= 0.0 {
+            return Some(0.0);
+        }
+
+        // The last week is still in progress
+        let complete = &self.weekly_v1_growth[..self.weekly_v1_growth.len().saturating_sub(1)];
+        let recent = &complete[complete.len().saturating_sub(recent_weeks)..];
+        if recent.is_empty() {
+            return None;
+        }
+        let weeks = recent.len() as f64;
+        let conversions = recent.iter().map(|w| w.conversions).sum::<u32>() as f64 / weeks;
+        let new_feedstocks = recent.iter().map(|w| w.new_feedstocks).sum::<u32>() as f64 / weeks;
+
+        let progress_per_week = conversions + new_feedstocks * (1.0 - share);
+        (progress_per_week > 0.0).then(|| missing / progress_per_week)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL_STATS: &str = r#"
+recipe_v1_count = 10
+total_feedstocks = 100
+"#;
+
+    const FULL_STATS: &str = r#"
+recipe_v1_count = 10
+total_feedstocks = 100
+last_updated = "2025-03-07T12:00:00Z"
+activity_weeks = 30
+recently_updated_days = 14
+rank_change_since = "2025-02-24"
+total_contributors = 7
+
+[[contributor_growth]]
+month = "2025-01"
+contributors = 3
+
+[[weekly_v1_growth]]
+week = "2025-03-03"
+conversions = 2
+new_feedstocks = 1
+
+[recently_updated.foo-feedstock]
+date = "2025-03-01T00:00:00Z"
+contributors = ["alice"]
+summary = "The foo package"
+
+[recently_updated.bar-feedstock]
+date = "2025-03-05T00:00:00Z"
+
+[[top_unconverted_by_downloads]]
+name = "baz-feedstock"
+downloads = 1000
+recipe_type = "meta_yaml"
+fork_url = "https://github.com/conda-forge/baz-feedstock/fork"
+edit_url = "https://github.com/conda-forge/baz-feedstock/edit/HEAD/recipe/meta.yaml"
+pr_title = "Convert baz to recipe v1"
+blocked_by = ["foo-feedstock"]
+pushed_at = "2025-02-20T00:00:00Z"
+active = true
+recipe_features = ["multiple-outputs", "selectors"]
+
+[[non_standard_recipes]]
+name = "qux-feedstock"
+recipe_file = "recipe/recipe.yml"
+
+[[pending_migrations]]
+name = "baz-feedstock"
+number = 42
+opened = "2025-05-01"
+downloads = 300
+
+[biggest_conversion]
+feedstock = "numpy-feedstock"
+downloads = 5000000
+contributors = ["alice", "bob"]
+date = "2025-02-01T00:00:00Z"
+
+[[notable_recent]]
+feedstock = "numpy-feedstock"
+downloads = 5000000
+date = "2025-02-01T00:00:00Z"
+
+[build_health]
+checked = 8
+passing = 6
+failing = 1
+pending = 1
+
+[recipe_lint]
+checked = 10
+passing = 9
+failing = 1
+
+[dependency_weighted]
+converted = 30
+total = 120
+
+[[events]]
+date = "2025-02-20"
+title = "Migration sprint"
+month = "2025-02"
+weeks_ago = 2
+
+[[events]]
+date = "2025-04-01"
+title = "Upcoming release"
+month = "2025-04"
+
+[[achievement_feed]]
+login = "alice"
+achievement = "homegrown"
+date = "2025-02-01"
+
+[[completionists]]
+name = "alice"
+maintained = 8
+converted = 6
+
+[[maintainer_conversion_rates]]
+maintainers = "1"
+feedstocks = 4
+converted = 1
+
+[[maintainer_conversion_rates]]
+maintainers = "5+"
+feedstocks = 0
+converted = 0
+
+[[recipe_feature_rates]]
+feature = "selectors"
+feedstocks = 10
+converted = 1
+other_feedstocks = 5
+other_converted = 4
+
+[[top_contributors]]
+name = "alice"
+conversions = 3
+new_feedstocks = 1
+weekly_activity = [[1, 0], [0, 1]]
+profile = { display_name = "Alice Smith", url = "https://alice.dev" }
+achievements_earned = { homegrown = "2025-02-01" }
+rank_change = -2
+largest_conversion = { name = "foo-feedstock", downloads = 1200 }
+
+[[top_contributors.feedstocks]]
+name = "foo-feedstock"
+contribution_type = "conversion"
+self_conversion = true
+"#;
+
+    const INSIGHTS: &str = r#"
+[[contributor_retention]]
+month = "2025-01"
+active = 4
+retained = 0
+churned = 0
+
+[[contributor_retention]]
+month = "2025-02"
+active = 3
+retained = 1
+churned = 3
+
+[[contributor_mix]]
+month = "2025-02"
+new = 1
+returning = 3
+
+[[conversion_cohorts]]
+cohort = "2025-01"
+converted = 4
+still_v1 = 3
+passing = 2
+retained_1m = 4
+retained_3m = 3
+follow_up_checked = 4
+follow_up_fixed = 1
+
+[[bot_activity_rates]]
+bot_prs = "0-9"
+feedstocks = 8
+converted = 2
+converted_recently = 1
+
+[conversion_forecast]
+converted_since = ["scipy-feedstock"]
+
+[[conversion_forecast.likely]]
+name = "pandas-feedstock"
+score = 0.8
+maintainer_share = 1.0
+dependencies_converted = 1.0
+"#;
+
+    const DATA_QUALITY: &str = r#"
+total_feedstocks = 100
+missing_downloads = 5
+v1_feedstocks = 10
+
+[attribution_confidence]
+high = 8
+low = 2
+"#;
+
+    #[test]
+    fn test_minimal_stats_use_defaults() {
+        let stats = StatsData::from_toml_str(MINIMAL_STATS).unwrap();
+        assert_eq!(stats.recipe_v1_count, 10);
+        assert_eq!(stats.total_feedstocks, 100);
+        assert_eq!(stats.last_updated, "");
+        assert_eq!(stats.activity_weeks, DEFAULT_ACTIVITY_WEEKS);
+        assert!(stats.recently_updated.is_empty());
+        assert_eq!(stats.recently_updated_days, None);
+        assert!(stats.top_unconverted.is_empty());
+        assert_eq!(stats.rank_change_since, None);
+        assert!(stats.top_contributors.is_empty());
+        assert!(stats.maintainer_conversion_rates.is_empty());
+        assert!(stats.recipe_feature_rates.is_empty());
+        assert!(stats.non_standard_recipes.is_empty());
+        assert!(stats.pending_migrations.is_empty());
+        assert_eq!(stats.total_contributors, 0);
+        assert!(stats.contributor_growth.is_empty());
+        assert!(stats.weekly_v1_growth.is_empty());
+        assert!(stats.build_health.is_none());
+        assert!(stats.recipe_lint.is_none());
+        assert!(stats.dependency_weighted.is_none());
+        assert!(stats.biggest_conversion.is_none());
+        assert!(stats.notable_recent.is_empty());
+        assert!(stats.events.is_empty());
+
+        let insights: InsightsFile = toml::from_str("").unwrap();
+        assert!(insights.contributor_retention.is_empty());
+        assert!(insights.contributor_mix.is_empty());
+        assert!(insights.conversion_cohorts.is_empty());
+        assert!(insights.bot_activity_rates.is_empty());
+        assert!(insights.conversion_forecast.is_none());
+    }
+
+    #[test]
+    fn test_missing_required_counts() {
+        assert!(StatsData::from_toml_str("total_feedstocks = 100").is_err());
+        assert!(StatsData::from_toml_str("not toml").is_err());
+    }
+
+    #[test]
+    fn test_schema_drift_is_an_error() {
+        // A section that no longer matches is reported instead of silently dropped
+        let drifted = format!(
+            "{}\n[[contributor_growth]]\nmonth = \"2025-02\"\n",
+            MINIMAL_STATS
+        );
+        let err = StatsData::from_toml_str(&drifted).err().unwrap();
+        assert!(err.to_string().contains("contributors"));
+
+        let drifted = format!(
+            "{}\n[[top_contributors]]\nname = \"bob\"\nconversions = 1\nnew_feedstocks = 0\n\
+             [[top_contributors.feedstocks]]\nname = \"x\"\ncontribution_type = \"other\"\n",
+            MINIMAL_STATS
+        );
+        assert!(StatsData::from_toml_str(&drifted).is_err());
+    }
+
+    #[test]
+    fn test_embedded_stats_match_schema() {
+        StatsData::load();
+    }
+
+    #[test]
+    fn test_full_stats_parsing() {
+        let stats = StatsData::from_toml_str(FULL_STATS).unwrap();
+        assert_eq!(stats.activity_weeks, 30);
+        assert_eq!(stats.recently_updated_days, Some(14));
+        assert_eq!(stats.rank_change_since.as_deref(), Some("2025-02-24"));
+        assert_eq!(stats.total_contributors, 7);
+        assert_eq!(stats.contributor_growth.len(), 1);
+        assert_eq!(stats.contributor_growth[0].contributors, 3);
+        assert_eq!(stats.weekly_v1_growth.len(), 1);
+        assert_eq!(stats.weekly_v1_growth[0].week, "2025-03-03");
+        assert_eq!(stats.weekly_v1_growth[0].conversions, 2);
+
+        // Sorted by date descending, optional fields default
+        let recent: Vec<_> = stats
+            .recently_updated
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(recent, vec!["bar-feedstock", "foo-feedstock"]);
+        assert!(stats.recently_updated[0].contributors.is_empty());
+        assert_eq!(stats.recently_updated[0].summary, None);
+        assert_eq!(
+            stats.recently_updated[1].summary.as_deref(),
+            Some("The foo package")
+        );
+
+        assert_eq!(stats.top_unconverted.len(), 1);
+        assert_eq!(stats.top_unconverted[0].downloads, 1000);
+        let links = stats.top_unconverted[0].links.as_ref().unwrap();
+        assert_eq!(links.pr_title, "Convert baz to recipe v1");
+        assert_eq!(
+            stats.top_unconverted[0].blocked_by,
+            Some(vec!["foo-feedstock".to_string()])
+        );
+        assert!(!stats.top_unconverted[0].is_ready());
+        assert_eq!(stats.top_unconverted[0].active, Some(true));
+        assert_eq!(stats.top_unconverted[0].recipe_features.len(), 2);
+
+        assert_eq!(
+            stats.non_standard_recipes[0].recipe_file,
+            "recipe/recipe.yml"
+        );
+        assert_eq!(stats.non_standard_recipes[0].downloads, 0);
+        assert_eq!(stats.pending_migrations[0].number, 42);
+
+        assert_eq!(stats.events.len(), 2);
+        assert_eq!(stats.events[0].weeks_ago, Some(2));
+        assert_eq!(stats.events[1].weeks_ago, None);
+        assert!(stats.events[1].url.is_none());
+
+        let build_health = stats.build_health.as_ref().unwrap();
+        assert_eq!(build_health.passing_percent(), 75.0);
+        assert_eq!(build_health.failing, 1);
+        assert_eq!(stats.recipe_lint.as_ref().unwrap().passing_percent(), 90.0);
+        assert_eq!(stats.dependency_weighted.as_ref().unwrap().percent(), 25.0);
+
+        let biggest = stats.biggest_conversion.as_ref().unwrap();
+        assert_eq!(biggest.feedstock, "numpy-feedstock");
+        assert_eq!(biggest.contributors, vec!["alice", "bob"]);
+        assert!(biggest.summary.is_none());
+        assert_eq!(stats.notable_recent[0].feedstock, "numpy-feedstock");
+        assert!(stats.notable_recent[0].contributors.is_empty());
+
+        assert_eq!(stats.achievement_feed[0].achievement, "homegrown");
+        assert_eq!(stats.completionists.len(), 1);
+        assert_eq!(stats.completionists[0].percent(), 75.0);
+
+        let rates = &stats.maintainer_conversion_rates;
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[0].rate(), 0.25);
+        assert_eq!(rates[1].rate(), 0.0);
+
+        let features = &stats.recipe_feature_rates;
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].rate(), 0.1);
+        assert_eq!(features[0].other_rate(), 0.8);
+
+        let alice = &stats.top_contributors[0];
+        assert_eq!(alice.total(), 4);
+        assert_eq!(alice.total_downloads, 0);
+        assert!(alice.first_contribution.is_none());
+        assert!(alice.top_package.is_none());
+        assert_eq!(alice.largest_conversion.as_ref().unwrap().downloads, 1200);
+        let profile = alice.profile.as_ref().unwrap();
+        assert_eq!(profile.display_name.as_deref(), Some("Alice Smith"));
+        assert!(profile.affiliation.is_none());
+        assert_eq!(
+            alice
+                .achievements_earned
+                .get("homegrown")
+                .map(String::as_str),
+            Some("2025-02-01")
+        );
+        assert_eq!(alice.rank_change, Some(-2));
+        assert_eq!(alice.weekly_activity, vec![(1, 0), (0, 1)]);
+        assert_eq!(alice.feedstocks.len(), 1);
+        assert!(alice.feedstocks[0].self_conversion);
+        assert_eq!(alice.self_conversions, 0);
+        assert_eq!(alice.feedstocks[0].downloads, 0);
+    }
+
+    #[test]
+    fn test_insights_parsing() {
+        let insights: InsightsFile = toml::from_str(INSIGHTS).unwrap();
+        assert_eq!(insights.contributor_retention.len(), 2);
+        assert_eq!(insights.contributor_retention[1].previous_active(), 4);
+        assert_eq!(insights.contributor_retention[1].retention_percent(), 25.0);
+        assert_eq!(insights.contributor_mix[0].returning, 3);
+        assert_eq!(insights.conversion_cohorts.len(), 1);
+        assert_eq!(insights.conversion_cohorts[0].retention(), [(1, 4), (3, 3)]);
+        assert_eq!(insights.conversion_cohorts[0].follow_up_fixed, 1);
+
+        let bot_activity = &insights.bot_activity_rates;
+        assert_eq!(bot_activity[0].rate(), 0.25);
+        assert_eq!(bot_activity[0].recent_rate(), 0.125);
+
+        let forecast = insights.conversion_forecast.as_ref().unwrap();
+        assert_eq!(forecast.likely[0].name, "pandas-feedstock");
+        assert!(forecast.likely[0].pushed_at.is_none());
+        assert_eq!(forecast.converted_since, ["scipy-feedstock"]);
+    }
+
+    #[test]
+    fn test_data_quality_parsing() {
+        let quality: DataQuality = toml::from_str(DATA_QUALITY).unwrap();
+        assert_eq!(
+            percent(quality.missing_downloads, quality.total_feedstocks),
+            5.0
+        );
+        let confidence = &quality.attribution_confidence;
+        assert_eq!(confidence.high, 8);
+        assert_eq!(confidence.medium, 0);
+        assert_eq!(percent(confidence.low, quality.v1_feedstocks), 20.0);
+        assert_eq!(percent(1, 0), 0.0);
+    }
+
+    #[test]
+    fn test_is_newer_than() {
+        let stats = |last_updated: &str| {
+            let mut stats = StatsData::from_toml_str(MINIMAL_STATS).unwrap();
+            stats.last_updated = last_updated.to_string();
+            stats
+        };
+        let embedded = stats("2025-03-07T12:00:00Z");
+        assert!(stats("2025-03-08T00:00:00Z").is_newer_than(&embedded));
+        assert!(!stats("2025-03-07T12:00:00Z").is_newer_than(&embedded));
+        assert!(!stats("2025-03-01T00:00:00Z").is_newer_than(&embedded));
+        assert!(!stats("").is_newer_than(&embedded));
+        assert!(stats("2025-03-01T00:00:00Z").is_newer_than(&stats("")));
+    }
+
+    #[test]
+    fn test_weeks_until_share() {
+        let mut stats = StatsData::from_toml_str(MINIMAL_STATS).unwrap();
+        stats.recipe_v1_count = 40;
+        stats.total_feedstocks = 100;
+        let week = |conversions, new_feedstocks| WeeklyGrowth {
+            week: String::new(),
+            conversions,
+            new_feedstocks,
+        };
+        // The current week (last) is ignored
+        stats.weekly_v1_growth = vec![week(100, 0), week(4, 0), week(2, 2), week(50, 50)];
+
+        assert_eq!(stats.weeks_until_share(0.25, 2), Some(0.0));
+        // 10 missing for 50%, at 3 conversions and 1 new feedstock (half of it) a week
+        assert_eq!(stats.weeks_until_share(0.5, 2), Some(10.0 / 3.5));
+        assert_eq!(stats.weeks_until_share(0.5, 0), None);
+
+        stats.weekly_v1_growth = vec![week(0, 0), week(0, 0)];
+        assert_eq!(stats.weeks_until_share(0.5, 12), None);
+    }
+
+    #[test]
+    fn test_empty_weekly_activity() {
+        let stats: ContributorStats =
+            toml::from_str("name = \"bob\"\nconversions = 0\nnew_feedstocks = 0").unwrap();
+        assert!(stats.weekly_activity.is_empty());
+        assert_eq!(stats.avg_downloads(), 0);
+        assert!(ActivityRange::Weeks(20)
+            .apply(&stats.weekly_activity)
+            .is_empty());
+        assert!(ActivityRange::All.apply(&stats.weekly_activity).is_empty());
+    }
+
+    #[test]
+    fn test_activity_range_apply() {
+        let weekly = vec![(1, 0), (2, 0), (3, 0)];
+        assert_eq!(ActivityRange::Weeks(2).apply(&weekly), vec![(1, 0), (2, 0)]);
+        assert_eq!(ActivityRange::Weeks(10).apply(&weekly), weekly);
+        assert_eq!(ActivityRange::All.apply(&weekly), weekly);
+
+        assert!(!ActivityRange::Weeks(52).needs_history(52));
+        assert!(ActivityRange::Weeks(104).needs_history(52));
+        assert!(ActivityRange::All.needs_history(52));
+    }
+
+    #[test]
+    fn test_contribution_history() {
+        let content = r#"
+generated_at = "2025-03-15T00:00:00+00:00"
+
+[[contributors.alice]]
+feedstock = "old-feedstock"
+date = "2023-03-15T00:00:00Z"
+contribution_type = "conversion"
+
+[[contributors.alice]]
+feedstock = "new-feedstock"
+date = "2025-03-10T00:00:00Z"
+contribution_type = "new_feedstock"
+"#;
+        let history = ContributionHistory::from_toml_str(content, "alice")
+            .unwrap()
+            .unwrap();
+        let weekly = history.weekly_activity();
+        // Two years back, beyond the exported 52 weeks
+        assert_eq!(weekly.len(), 105);
+        assert_eq!(weekly[0], (0, 1));
+        assert_eq!(weekly[104], (1, 0));
+
+        assert!(ContributionHistory::from_toml_str(content, "bob")
+            .unwrap()
+            .is_none());
+        assert!(ContributionHistory::from_toml_str("not toml", "alice").is_err());
+    }
+
+    #[test]
+    fn test_search_maintainers() {
+        let file: MaintainerCoverageFile = toml::from_str(
+            r#"
+            generated_at = "2025-06-01T00:00:00Z"
+
+            [[maintainers]]
+            login = "alice-bot"
+            maintained = 10
+            converted = 5
+            unconverted = ["scipy-feedstock"]
+
+            [[maintainers]]
+            login = "Alice"
+            maintained = 4
+            converted = 1
+            "#,
+        )
+        .unwrap();
+        let logins = |query| {
+            search_maintainers(&file.maintainers, query, 10)
+                .iter()
+                .map(|m| m.login.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(logins("@alice"), ["Alice", "alice-bot"]);
+        assert_eq!(logins("bot"), ["alice-bot"]);
+        assert!(logins("carol").is_empty());
+        assert_eq!(file.maintainers[0].percent(), 50.0);
+    }
+}
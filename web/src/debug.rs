@@ -0,0 +1,57 @@
+//! Hidden `?debug=1` mode for visual QA: the site shows the edge-case dataset in
+//! `debug-stats.toml` (very long names, zero downloads, 1000 weeks of activity, unicode
+//! handles) instead of the real stats, with `debug-insights.toml` and
+//! `debug-data-quality.toml` for the pages that fetch their own data. The dataset is
+//! fetched like the real stats, so it doesn't add to the bundle.
+
+use leptos::prelude::window;
+
+/// Copied next to the site by Trunk, see `index.html`
+pub const DEBUG_STATS_URL: &str = "debug-stats.toml";
+pub const DEBUG_INSIGHTS_URL: &str = "debug-insights.toml";
+pub const DEBUG_DATA_QUALITY_URL: &str = "debug-data-quality.toml";
+
+/// Whether the page was opened with `?debug=1`
+pub fn is_enabled() -> bool {
+    requested(&window().location().search().unwrap_or_default())
+}
+
+fn requested(search: &str) -> bool {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .any(|pair| pair == "debug=1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{DataQuality, InsightsFile, StatsData};
+
+    #[test]
+    fn test_requested() {
+        assert!(requested("?debug=1"));
+        assert!(requested("?utm_campaign=x&debug=1"));
+        assert!(!requested(""));
+        assert!(!requested("?debug=0"));
+        assert!(!requested("?nodebug=1"));
+    }
+
+    #[test]
+    fn test_debug_stats_match_schema() {
+        let stats = StatsData::from_toml_str(include_str!("../debug-stats.toml")).unwrap();
+        assert_eq!(stats.activity_weeks, 1000);
+        assert!(stats
+            .top_contributors
+            .iter()
+            .any(|c| c.weekly_activity.len() == 1000));
+        assert!(stats.top_unconverted.iter().any(|f| f.downloads == 0));
+
+        let insights: InsightsFile =
+            toml::from_str(include_str!("../debug-insights.toml")).unwrap();
+        assert!(insights.conversion_forecast.is_some());
+        let quality: DataQuality =
+            toml::from_str(include_str!("../debug-data-quality.toml")).unwrap();
+        assert_eq!(quality.v1_feedstocks, 0);
+    }
+}
@@ -0,0 +1,111 @@
+//! Formatting helpers for numbers and dates
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// Helper function to format download counts
+pub fn format_downloads(count: u64) -> String {
+    if count >= 1_000_000 {
+        format!("{:.1}M", count as f64 / 1_000_000.0)
+    } else if count >= 1_000 {
+        format!("{:.1}K", count as f64 / 1_000.0)
+    } else {
+        count.to_string()
+    }
+}
+
+/// Helper function to format ISO date to human readable
+pub fn format_date(iso_date: &str) -> String {
+    if let Some(date_part) = iso_date.split('T').next() {
+        if let Ok(date) = NaiveDate::parse_from_str(date_part, "%Y-%m-%d") {
+            return date.format("%b %d, %Y").to_string();
+        }
+    }
+    iso_date.to_string()
+}
+
+/// Parse an RFC 3339 timestamp, or a "YYYY-MM-DD" day as its start in UTC
+pub fn parse_timestamp(iso_date: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(iso_date) {
+        return Some(time.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(iso_date, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+/// Translation key and count for the time between `then` and `now`, e.g.
+/// `("time-hours-ago", 3)`. Times in the future count as just now.
+pub fn time_ago(then: DateTime<Utc>, now: DateTime<Utc>) -> (&'static str, i64) {
+    let elapsed = now - then;
+    let days = elapsed.num_days();
+    if elapsed.num_minutes() < 1 {
+        ("time-just-now", 0)
+    } else if elapsed.num_hours() < 1 {
+        ("time-minutes-ago", elapsed.num_minutes())
+    } else if days < 1 {
+        ("time-hours-ago", elapsed.num_hours())
+    } else if days < 7 {
+        ("time-days-ago", days)
+    } else if days < 30 {
+        ("time-weeks-ago", days / 7)
+    } else if days < 365 {
+        ("time-months-ago", days / 30)
+    } else {
+        ("time-years-ago", days / 365)
+    }
+}
+
+/// Month of an ISO date, e.g. "Mar 2025"
+pub fn format_month(iso_date: &str) -> String {
+    if let Some(date_part) = iso_date.split('T').next() {
+        if let Ok(date) = NaiveDate::parse_from_str(date_part, "%Y-%m-%d") {
+            return date.format("%b %Y").to_string();
+        }
+    }
+    iso_date.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_downloads() {
+        assert_eq!(format_downloads(0), "0");
+        assert_eq!(format_downloads(999), "999");
+        assert_eq!(format_downloads(1_000), "1.0K");
+        assert_eq!(format_downloads(12_345), "12.3K");
+        assert_eq!(format_downloads(1_000_000), "1.0M");
+        assert_eq!(format_downloads(2_550_000), "2.5M");
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date("2025-03-07T12:34:56Z"), "Mar 07, 2025");
+        assert_eq!(format_date("2025-03-07"), "Mar 07, 2025");
+        // Unparseable input is returned unchanged
+        assert_eq!(format_date("not a date"), "not a date");
+        assert_eq!(format_date(""), "");
+    }
+
+    #[test]
+    fn test_time_ago() {
+        let now = parse_timestamp("2025-03-07T12:00:00Z").unwrap();
+        let ago = |iso: &str| time_ago(parse_timestamp(iso).unwrap(), now);
+        assert_eq!(ago("2025-03-07T11:59:30Z"), ("time-just-now", 0));
+        assert_eq!(ago("2025-03-07T13:00:00Z"), ("time-just-now", 0));
+        assert_eq!(ago("2025-03-07T11:15:00+00:00"), ("time-minutes-ago", 45));
+        assert_eq!(ago("2025-03-07T09:00:00Z"), ("time-hours-ago", 3));
+        assert_eq!(ago("2025-03-05"), ("time-days-ago", 2));
+        assert_eq!(ago("2025-02-20"), ("time-weeks-ago", 2));
+        assert_eq!(ago("2024-12-01"), ("time-months-ago", 3));
+        assert_eq!(ago("2023-01-01"), ("time-years-ago", 2));
+        assert!(parse_timestamp("soon").is_none());
+    }
+
+    #[test]
+    fn test_format_month() {
+        assert_eq!(format_month("2025-03-07T12:34:56Z"), "Mar 2025");
+        assert_eq!(format_month("2025-03-07"), "Mar 2025");
+        assert_eq!(format_month("soon"), "soon");
+    }
+}
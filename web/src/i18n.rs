@@ -0,0 +1,233 @@
+//! Translations of the site's static text, using Fluent resources in `web/locales`
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use leptos::prelude::*;
+use unic_langid::LanguageIdentifier;
+
+/// Local storage key remembering the chosen language
+const LOCALE_KEY: &str = "locale";
+
+/// Languages the site is translated to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    Es,
+    ZhTw,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::Es, Locale::ZhTw];
+
+    /// BCP 47 language tag
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Es => "es",
+            Self::ZhTw => "zh-TW",
+        }
+    }
+
+    /// Name of the language in itself, for the language picker
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Self::En => "English",
+            Self::Es => "Español",
+            Self::ZhTw => "繁體中文",
+        }
+    }
+
+    /// Match a language tag such as `es-AR`, by its primary language
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        match primary.as_str() {
+            "en" => Some(Self::En),
+            "es" => Some(Self::Es),
+            "zh" => Some(Self::ZhTw),
+            _ => None,
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Self::En => include_str!("../locales/en.ftl"),
+            Self::Es => include_str!("../locales/es.ftl"),
+            Self::ZhTw => include_str!("../locales/zh-TW.ftl"),
+        }
+    }
+
+    fn bundle(&self) -> FluentBundle<FluentResource> {
+        let langid: LanguageIdentifier = self.code().parse().expect("invalid language tag");
+        let resource = FluentResource::try_new(self.source().to_string())
+            .unwrap_or_else(|_| panic!("invalid {}.ftl", self.code()));
+        let mut bundle = FluentBundle::new(vec![langid]);
+        // Isolation marks would show up in `title` attributes
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(resource)
+            .unwrap_or_else(|_| panic!("duplicate message in {}.ftl", self.code()));
+        bundle
+    }
+}
+
+thread_local! {
+    static BUNDLES: Vec<FluentBundle<FluentResource>> =
+        Locale::ALL.iter().map(Locale::bundle).collect();
+}
+
+/// Translate `key`, falling back to English and finally to the key itself
+pub fn translate(locale: Locale, key: &str, args: Option<&FluentArgs>) -> String {
+    BUNDLES.with(|bundles| {
+        [locale, Locale::En]
+            .iter()
+            .find_map(|locale| {
+                let bundle = &bundles[*locale as usize];
+                let pattern = bundle.get_message(key)?.value()?;
+                let mut errors = Vec::new();
+                Some(
+                    bundle
+                        .format_pattern(pattern, args, &mut errors)
+                        .into_owned(),
+                )
+            })
+            .unwrap_or_else(|| key.to_string())
+    })
+}
+
+/// The visitor's language, shared through context
+#[derive(Clone, Copy)]
+pub struct I18n {
+    locale: RwSignal<Locale>,
+}
+
+impl I18n {
+    pub fn locale(&self) -> Locale {
+        self.locale.get()
+    }
+
+    /// Switch language and remember the choice (best effort)
+    pub fn set_locale(&self, locale: Locale) {
+        if let Ok(Some(storage)) = window().local_storage() {
+            let _ = storage.set_item(LOCALE_KEY, locale.code());
+        }
+        set_document_lang(locale);
+        self.locale.set(locale);
+    }
+
+    pub fn t(&self, key: &str) -> String {
+        translate(self.locale.get(), key, None)
+    }
+
+    /// Translate a message with variables, e.g. `t_with("recent-updated", &[("date", date.into())])`
+    pub fn t_with(&self, key: &str, args: &[(&str, FluentValue)]) -> String {
+        let args: FluentArgs = args.iter().cloned().collect();
+        translate(self.locale.get(), key, Some(&args))
+    }
+}
+
+/// Pick the stored language, then the browser's, then English, and share it with all components
+pub fn provide_i18n() -> I18n {
+    let stored = window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(LOCALE_KEY).ok().flatten());
+    let locale = stored
+        .or_else(|| window().navigator().language())
+        .and_then(|code| Locale::from_code(&code))
+        .unwrap_or(Locale::En);
+
+    set_document_lang(locale);
+    let i18n = I18n {
+        locale: RwSignal::new(locale),
+    };
+    provide_context(i18n);
+    i18n
+}
+
+pub fn use_i18n() -> I18n {
+    expect_context::<I18n>()
+}
+
+fn set_document_lang(locale: Locale) {
+    if let Some(root) = document().document_element() {
+        let _ = root.set_attribute("lang", locale.code());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Message ids defined in a Fluent source, ignoring attributes and comments
+    fn message_ids(source: &str) -> Vec<&str> {
+        source
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once(" =").map(|(id, _)| id))
+            .collect()
+    }
+
+    #[test]
+    fn test_all_locales_translate_every_message() {
+        let english = message_ids(Locale::En.source());
+        assert!(!english.is_empty());
+        for locale in Locale::ALL {
+            let ids = message_ids(locale.source());
+            let missing: Vec<_> = english.iter().filter(|id| !ids.contains(id)).collect();
+            assert!(
+                missing.is_empty(),
+                "{} is missing {:?}",
+                locale.code(),
+                missing
+            );
+            // Parses without errors
+            locale.bundle();
+        }
+    }
+
+    #[test]
+    fn test_every_achievement_has_a_name() {
+        use crate::achievements::{CONVERSIONS, NEW_FEEDSTOCKS, SELF_CONVERSION, TOTAL};
+
+        let all = TOTAL.iter().chain(CONVERSIONS).chain(NEW_FEEDSTOCKS);
+        for achievement in all.chain([&SELF_CONVERSION]) {
+            let key = achievement.name_key();
+            assert_ne!(translate(Locale::En, &key, None), key);
+        }
+    }
+
+    #[test]
+    fn test_translate_with_args_and_fallback() {
+        let mut args = FluentArgs::new();
+        args.set("date", "Mar 07, 2025");
+        assert_eq!(
+            translate(Locale::En, "recent-updated", Some(&args)),
+            "Updated Mar 07, 2025"
+        );
+        assert_eq!(
+            translate(Locale::Es, "no-such-message", None),
+            "no-such-message"
+        );
+    }
+
+    #[test]
+    fn test_plural_week_labels() {
+        let week = |locale, weeks: u32| {
+            let mut args = FluentArgs::new();
+            args.set("weeks", weeks);
+            translate(locale, "activity-week", Some(&args))
+        };
+        assert_eq!(week(Locale::En, 0), "This week");
+        assert_eq!(week(Locale::En, 1), "1 week ago");
+        assert_eq!(week(Locale::En, 3), "3 weeks ago");
+        assert_eq!(week(Locale::Es, 3), "Hace 3 semanas");
+    }
+
+    #[test]
+    fn test_locale_from_code() {
+        assert_eq!(Locale::from_code("es-AR"), Some(Locale::Es));
+        assert_eq!(Locale::from_code("zh_CN"), Some(Locale::ZhTw));
+        assert_eq!(Locale::from_code("EN"), Some(Locale::En));
+        assert_eq!(Locale::from_code("fr"), None);
+    }
+}
@@ -0,0 +1,159 @@
+//! Fetch the latest crunched stats at runtime, so data refreshes don't need a new bundle
+
+use leptos::logging::warn;
+use leptos::prelude::window;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Response;
+
+use crate::data::{
+    ContributionHistory, DataQuality, InsightsFile, MaintainerCoverageFile, StatsData,
+};
+use crate::debug;
+
+/// Snapshot manifest shipped next to the site by `crunch-data`
+const DEFAULT_STATS_URL: &str = "data/latest.toml";
+
+/// Sections of the insights page published by `crunch-data`, next to the stats manifest
+const INSIGHTS_FILE: &str = "insights.toml";
+
+/// Data quality report published by `crunch-data`, next to the stats manifest
+const DATA_QUALITY_FILE: &str = "data-quality.toml";
+
+/// Full contribution history published by `crunch-data`, next to the stats manifest
+const CONTRIBUTIONS_FILE: &str = "contributions.toml";
+
+/// Per-maintainer coverage published by `crunch-data`, next to the stats manifest
+const MAINTAINER_COVERAGE_FILE: &str = "maintainer-coverage.toml";
+
+/// One JSON record per feedstock published by `crunch-data`, next to the stats manifest
+const FEEDSTOCK_API_DIR: &str = "api/feedstock";
+
+/// Where to fetch stats from, overridable at build time via `STATS_URL`.
+/// Either a `latest.toml` manifest or a crunched `stats.toml` itself.
+pub fn stats_url() -> &'static str {
+    option_env!("STATS_URL")
+        .filter(|url| !url.is_empty())
+        .unwrap_or(DEFAULT_STATS_URL)
+}
+
+/// Fetch and parse the stats, `None` on any network or parse failure
+pub async fn fetch_stats(url: &str) -> Option<StatsData> {
+    let content = fetch_text(url).await?;
+
+    // A manifest points at the newest snapshot, relative to itself
+    let manifest: toml::Table = toml::from_str(&content).ok()?;
+    if let Some(path) = manifest.get("path").and_then(|v| v.as_str()) {
+        let content = fetch_text(&resolve_relative(url, path)).await?;
+        return parse_stats(&content);
+    }
+
+    parse_stats(&content)
+}
+
+/// Fetch one contributor's full contribution history, `None` on any failure
+pub async fn fetch_contribution_history(login: &str) -> Option<ContributionHistory> {
+    let content = fetch_text(&resolve_relative(stats_url(), CONTRIBUTIONS_FILE)).await?;
+    ContributionHistory::from_toml_str(&content, login)
+        .map_err(|e| warn!("{} doesn't match the schema: {}", CONTRIBUTIONS_FILE, e))
+        .ok()
+        .flatten()
+}
+
+/// Fetch the coverage of every maintainer, `None` on any failure
+pub async fn fetch_maintainer_coverage() -> Option<MaintainerCoverageFile> {
+    fetch_toml(&resolve_relative(stats_url(), MAINTAINER_COVERAGE_FILE)).await
+}
+
+/// Fetch the sections of the insights page, `None` on any failure
+pub async fn fetch_insights() -> Option<InsightsFile> {
+    let url = page_data_url(INSIGHTS_FILE, debug::DEBUG_INSIGHTS_URL);
+    fetch_toml(&url).await
+}
+
+/// Fetch the data quality report, `None` on any failure
+pub async fn fetch_data_quality() -> Option<DataQuality> {
+    let url = page_data_url(DATA_QUALITY_FILE, debug::DEBUG_DATA_QUALITY_URL);
+    fetch_toml(&url).await
+}
+
+/// Fetch the current recipe type of one feedstock, e.g. "recipe_v1", `None` on any
+/// failure
+pub async fn fetch_recipe_type(feedstock: &str) -> Option<String> {
+    let path = format!("{}/{}.json", FEEDSTOCK_API_DIR, feedstock);
+    let content = fetch_text(&resolve_relative(stats_url(), &path)).await?;
+    let record = js_sys::JSON::parse(&content).ok()?;
+    js_sys::Reflect::get(&record, &"recipe_type".into())
+        .ok()?
+        .as_string()
+}
+
+/// Parse fetched stats, logging why stats that don't match the schema were rejected
+fn parse_stats(content: &str) -> Option<StatsData> {
+    StatsData::from_toml_str(content)
+        .map_err(|e| warn!("Fetched stats don't match the schema: {}", e))
+        .ok()
+}
+
+/// Where a page's data is fetched from: next to the stats, or the edge-case dataset in
+/// debug mode
+fn page_data_url(file: &str, debug_url: &str) -> String {
+    if debug::is_enabled() {
+        debug_url.to_string()
+    } else {
+        resolve_relative(stats_url(), file)
+    }
+}
+
+/// Fetch and parse a TOML file, logging why one that doesn't match the schema was
+/// rejected
+async fn fetch_toml<T: serde::de::DeserializeOwned>(url: &str) -> Option<T> {
+    let content = fetch_text(url).await?;
+    toml::from_str(&content)
+        .map_err(|e| warn!("{} doesn't match the schema: {}", url, e))
+        .ok()
+}
+
+async fn fetch_text(url: &str) -> Option<String> {
+    let response = JsFuture::from(window().fetch_with_str(url)).await.ok()?;
+    let response: Response = response.dyn_into().ok()?;
+    if !response.ok() {
+        return None;
+    }
+    JsFuture::from(response.text().ok()?)
+        .await
+        .ok()?
+        .as_string()
+}
+
+/// Resolve `path` against the directory containing `base`
+fn resolve_relative(base: &str, path: &str) -> String {
+    match base.rfind('/') {
+        Some(idx) => format!("{}/{}", &base[..idx], path),
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_relative() {
+        assert_eq!(
+            resolve_relative("data/latest.toml", "2025-01-01/stats.toml"),
+            "data/2025-01-01/stats.toml"
+        );
+        assert_eq!(
+            resolve_relative(
+                "https://example.org/data/latest.toml",
+                "2025-01-01/stats.toml"
+            ),
+            "https://example.org/data/2025-01-01/stats.toml"
+        );
+        assert_eq!(
+            resolve_relative("latest.toml", "x/stats.toml"),
+            "x/stats.toml"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Theme & style constants
+
+use leptos::prelude::{document, window};
+
+/// Colors used throughout the application
+pub mod colors {
+    pub const EMERALD: &str = "#10b981";
+    pub const BLUE: &str = "#3b82f6";
+    pub const GRAY_LIGHT: &str = "#e5e7eb";
+    pub const GRAY_MEDIUM: &str = "#d1d5db";
+    pub const GRAY_TEXT: &str = "#9ca3af";
+    /// Ecosystem event markers
+    pub const AMBER: &str = "#f59e0b";
+    /// Conversions in the colorblind palette (Okabe-Ito orange), dotted
+    pub const CB_ORANGE: &str = "#e69f00";
+    /// New feedstocks in the colorblind palette (Okabe-Ito blue), striped
+    pub const CB_BLUE: &str = "#0072b2";
+}
+
+/// CSS classes for contribution types
+/// The `conversion-*` and `new-feedstock-*` marker classes switch to the colorblind
+/// palette and its patterns in `input.css`
+pub mod classes {
+    pub const CONVERSION_BG: &str = "bg-emerald-500 conversion-fill";
+    pub const CONVERSION_TEXT: &str = "text-emerald-600 conversion-text";
+    /// SVG shapes, colored by their `fill` attribute
+    pub const CONVERSION_SVG: &str = "conversion-fill";
+    pub const NEW_FEEDSTOCK_BG: &str = "bg-blue-500 new-feedstock-fill";
+    pub const NEW_FEEDSTOCK_TEXT: &str = "text-blue-600 new-feedstock-text";
+    pub const NEW_FEEDSTOCK_SVG: &str = "new-feedstock-fill";
+}
+
+/// Ids of the SVG patterns filling conversions and new feedstocks in the colorblind palette
+pub const CONVERSION_PATTERN_ID: &str = "pattern-conversion";
+pub const NEW_FEEDSTOCK_PATTERN_ID: &str = "pattern-new-feedstock";
+
+/// Local storage key remembering the chosen palette
+const PALETTE_KEY: &str = "palette";
+
+/// How conversions and new feedstocks are told apart
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Palette {
+    /// Emerald and blue
+    Default,
+    /// Orange and blue, with dots and stripes
+    Colorblind,
+}
+
+impl Palette {
+    fn parse(s: Option<&str>) -> Self {
+        match s {
+            Some("colorblind") => Self::Colorblind,
+            _ => Self::Default,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Colorblind => "colorblind",
+        }
+    }
+}
+
+/// Read the stored palette, `Default` if storage is unavailable
+pub fn load_palette() -> Palette {
+    let stored = window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(PALETTE_KEY).ok().flatten());
+    Palette::parse(stored.as_deref())
+}
+
+/// Switch the page to `palette` and remember the choice (best effort)
+pub fn set_palette(palette: Palette) {
+    if let Ok(Some(storage)) = window().local_storage() {
+        let _ = storage.set_item(PALETTE_KEY, palette.as_str());
+    }
+    apply_palette(palette);
+}
+
+/// Mark the document root, which the stylesheet keys the colorblind palette on
+pub fn apply_palette(palette: Palette) {
+    if let Some(root) = document().document_element() {
+        let _ = root.set_attribute("data-palette", palette.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_round_trip() {
+        for palette in [Palette::Default, Palette::Colorblind] {
+            assert_eq!(Palette::parse(Some(palette.as_str())), palette);
+        }
+        assert_eq!(Palette::parse(None), Palette::Default);
+        assert_eq!(Palette::parse(Some("bogus")), Palette::Default);
+    }
+}
@@ -0,0 +1,149 @@
+//! Feedstocks the visitor watches, and the browser notification sent when one converts
+
+use leptos::prelude::*;
+use web_sys::{Notification, NotificationOptions, NotificationPermission};
+
+use crate::remote;
+
+/// Local storage key holding the watched feedstock names, one per line
+const WATCHLIST_KEY: &str = "watchlist";
+
+/// Watched feedstock names, shared through context and kept in local storage
+#[derive(Clone, Copy)]
+pub struct Watchlist {
+    feedstocks: RwSignal<Vec<String>>,
+}
+
+impl Watchlist {
+    pub fn is_watched(&self, name: &str) -> bool {
+        self.feedstocks
+            .with(|watched| watched.iter().any(|w| w == name))
+    }
+
+    /// Start or stop watching `name`. Watching asks for notification permission, which
+    /// browsers only allow in response to a click.
+    pub fn toggle(&self, name: &str) {
+        if self.is_watched(name) {
+            self.feedstocks
+                .update(|watched| watched.retain(|w| w != name));
+        } else {
+            request_permission();
+            self.feedstocks
+                .update(|watched| watched.push(name.to_string()));
+        }
+        self.feedstocks.with_untracked(|watched| store(watched));
+    }
+
+    /// Watched feedstocks that are on Recipe v1 according to their published records,
+    /// which stop being watched. Feedstocks whose record can't be fetched stay watched.
+    pub async fn take_converted(&self) -> Vec<String> {
+        let watched = self.feedstocks.get_untracked();
+        let mut converted = Vec::new();
+        for name in watched {
+            if let Some(recipe_type) = remote::fetch_recipe_type(&name).await {
+                if matches!(recipe_type.as_str(), "recipe_v1" | "non_standard_v1") {
+                    converted.push(name);
+                }
+            }
+        }
+        self.take_watched(converted.iter().map(String::as_str))
+    }
+
+    /// Watched feedstocks among `converted`, which stop being watched
+    fn take_watched<'a>(&self, converted: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+        let found = self
+            .feedstocks
+            .with_untracked(|watched| watched_among(watched, converted));
+        if !found.is_empty() {
+            self.feedstocks
+                .update(|watched| watched.retain(|w| !found.contains(w)));
+            self.feedstocks.with_untracked(|watched| store(watched));
+        }
+        found
+    }
+}
+
+/// Read the stored watchlist and share it with all components
+pub fn provide_watchlist() -> Watchlist {
+    let stored = window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(WATCHLIST_KEY).ok().flatten());
+    let watchlist = Watchlist {
+        feedstocks: RwSignal::new(parse(stored.as_deref())),
+    };
+    provide_context(watchlist);
+    watchlist
+}
+
+pub fn use_watchlist() -> Watchlist {
+    expect_context::<Watchlist>()
+}
+
+fn parse(stored: Option<&str>) -> Vec<String> {
+    stored
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn store(watched: &[String]) {
+    if let Ok(Some(storage)) = window().local_storage() {
+        let _ = storage.set_item(WATCHLIST_KEY, &watched.join("\n"));
+    }
+}
+
+/// `watched` names that are in `converted`, in watchlist order
+fn watched_among<'a>(
+    watched: &[String],
+    converted: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let converted: Vec<&str> = converted.into_iter().collect();
+    watched
+        .iter()
+        .filter(|name| converted.contains(&name.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Whether the browser has the Notification API at all (e.g. not iOS Safari outside an
+/// installed web app)
+fn notifications_supported() -> bool {
+    js_sys::Reflect::has(&window(), &"Notification".into()).unwrap_or(false)
+}
+
+/// Ask for permission to show notifications unless the visitor already decided
+fn request_permission() {
+    if notifications_supported() && Notification::permission() == NotificationPermission::Default {
+        let _ = Notification::request_permission();
+    }
+}
+
+/// Show a system notification if the visitor allowed them (best effort)
+pub fn notify(title: &str, body: &str) {
+    if !notifications_supported() || Notification::permission() != NotificationPermission::Granted {
+        return;
+    }
+    let options = NotificationOptions::new();
+    options.set_body(body);
+    let _ = Notification::new_with_options(title, &options);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_match_watchlist() {
+        let watched = parse(Some("numpy-feedstock\n\n  scipy-feedstock \n"));
+        assert_eq!(watched, ["numpy-feedstock", "scipy-feedstock"]);
+        assert!(parse(None).is_empty());
+
+        let converted = ["pandas-feedstock", "scipy-feedstock"];
+        assert_eq!(watched_among(&watched, converted), ["scipy-feedstock"]);
+    }
+}